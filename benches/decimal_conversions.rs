@@ -0,0 +1,34 @@
+use core::convert::TryFrom;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use embedded_time::{duration::*, fixed_point::FixedPoint, fraction::Fraction};
+
+fn decimal_conversions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Decimal Conversions");
+
+    group.bench_function("Microseconds<u32> -> Milliseconds<u32>", |b| {
+        b.iter(|| Milliseconds::<u32>::from(black_box(Microseconds(123_456_u32))))
+    });
+
+    group.bench_function("Milliseconds<u32> -> Microseconds<u32>", |b| {
+        b.iter(|| Microseconds::<u32>::try_from(black_box(Milliseconds(123_u32))))
+    });
+
+    group.bench_function("Nanoseconds<u64> -> Milliseconds<u64>", |b| {
+        b.iter(|| Milliseconds::<u64>::from(black_box(Nanoseconds(123_456_789_012_u64))))
+    });
+
+    group.bench_function("Microseconds<u32> -> 1 MHz ticks", |b| {
+        b.iter(|| {
+            black_box(Microseconds(123_456_u32)).into_ticks::<u32>(Fraction::new(1, 1_000_000))
+        })
+    });
+
+    group.bench_function("32.768 kHz ticks -> Milliseconds<u32>", |b| {
+        b.iter(|| Milliseconds::<u32>::from_ticks(black_box(123_456_u32), Fraction::new(1, 32_768)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decimal_conversions);
+criterion_main!(benches);
@@ -1,5 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use embedded_time::{duration::*, rate::*};
+use embedded_time::{
+    duration::*,
+    rate::{Extensions as _, Rate as _},
+};
 use std::convert::TryFrom;
 use std::mem::size_of;
 
@@ -2,8 +2,6 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use embedded_time::{duration::*, rate::*};
 
 fn rem_duration_rate(c: &mut Criterion) {
-    use core::time::Duration;
-
     let mut group = c.benchmark_group("Rem Duration vs Rate");
 
     group.bench_function("duration", |b| {
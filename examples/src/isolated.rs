@@ -9,6 +9,7 @@ use panic_halt as _;
 pub struct SysClock;
 impl time::Clock for SysClock {
     type T = u32;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: time::fraction::Fraction = <time::fraction::Fraction>::new(1, 1_000_000);
 
     fn try_now(&self) -> Result<time::Instant<Self>, time::clock::Error> {
@@ -48,6 +49,7 @@ mod instant {
 
         impl time::Clock for Clock {
             type T = u32;
+            type Error = time::clock::Error;
             const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
 
             fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
@@ -188,10 +190,13 @@ mod duration {
 
         assert_eq!(
             Milliseconds(2_001_u32).checked_mul(&2),
-            Some(Milliseconds(4_002_u32))
+            Ok(Milliseconds(4_002_u32))
         );
 
-        assert_eq!(Milliseconds(u32::MAX).checked_mul(&2), None);
+        assert_eq!(
+            Milliseconds(u32::MAX).checked_mul(&2),
+            Err(ConversionError::Overflow)
+        );
     }
 
     fn div() {
@@ -199,10 +204,13 @@ mod duration {
 
         assert_eq!(
             Milliseconds(2_002_u32).checked_div(&2),
-            Some(Milliseconds(1_001_u32))
+            Ok(Milliseconds(1_001_u32))
         );
 
-        assert_eq!(Milliseconds(u32::MAX).checked_div(&0), None);
+        assert_eq!(
+            Milliseconds(u32::MAX).checked_div(&0),
+            Err(ConversionError::DivByZero)
+        );
     }
 
     fn remainder() {
@@ -319,10 +327,13 @@ mod rate {
 
         assert_eq!(
             Kilohertz(2_001_u32).checked_mul(&2),
-            Some(Kilohertz(4_002_u32))
+            Ok(Kilohertz(4_002_u32))
         );
 
-        assert_eq!(Kilohertz(u32::MAX).checked_mul(&2), None);
+        assert_eq!(
+            Kilohertz(u32::MAX).checked_mul(&2),
+            Err(ConversionError::Overflow)
+        );
     }
 
     fn div() {
@@ -330,10 +341,13 @@ mod rate {
 
         assert_eq!(
             Kilohertz(2_002_u32).checked_div(&2),
-            Some(Kilohertz(1_001_u32))
+            Ok(Kilohertz(1_001_u32))
         );
 
-        assert_eq!(Kilohertz(u32::MAX).checked_div(&0), None);
+        assert_eq!(
+            Kilohertz(u32::MAX).checked_div(&0),
+            Err(ConversionError::DivByZero)
+        );
     }
 
     fn remainder() {
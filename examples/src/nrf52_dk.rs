@@ -36,6 +36,7 @@ impl SysClock {
 
 impl time::Clock for SysClock {
     type T = u64;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: time::fraction::Fraction = <time::fraction::Fraction>::new(1, 16_000_000);
 
     fn try_now(&self) -> Result<time::Instant<Self>, time::clock::Error> {
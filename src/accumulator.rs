@@ -0,0 +1,117 @@
+//! Duration-weighted accumulation of readings
+//!
+//! Integrating a current over time gives a charge (eg. mA × h = mAh), integrating a power gives an
+//! energy (eg. mW × s = mJ). Summing `reading × elapsed` naively overflows or loses precision to
+//! early unit conversions. The [`Accumulator`] keeps the exact sum in units of the sampling
+//! duration and only converts when the total is read.
+
+use crate::{duration::Duration, fixed_point::FixedPoint, ConversionError};
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+
+/// The exact sum of readings weighted by the durations they were held for
+///
+/// Readings are signed (eg. a battery current, positive while discharging and negative while
+/// charging) and in any unit, the total is in the same unit multiplied by any duration unit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{accumulator::Accumulator, duration::*};
+/// let mut charge = Accumulator::<Milliseconds<u32>>::new();
+///
+/// // 150 mA for 90 minutes, sampled every 100 ms
+/// for _ in 0..54_000 {
+///     charge.add(150, Milliseconds(100)).unwrap();
+/// }
+/// // then charging at 600 mA for a minute
+/// charge.add(-600, Milliseconds(60_000)).unwrap();
+///
+/// assert_eq!(charge.total::<Hours<u32>>(), Ok(215)); // mAh
+/// assert_eq!(charge.total::<Seconds<u32>>(), Ok(774_000)); // mC
+/// ```
+pub struct Accumulator<Dur> {
+    total: i128,
+    _duration: PhantomData<Dur>,
+}
+
+impl<Dur> Accumulator<Dur>
+where
+    Dur: Duration + FixedPoint,
+    i128: TryFrom<Dur::T>,
+{
+    /// Construct an empty accumulator
+    pub fn new() -> Self {
+        Self {
+            total: 0,
+            _duration: PhantomData,
+        }
+    }
+
+    /// Add a reading held for the `elapsed` duration
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The total overflowed, it is left unchanged
+    pub fn add(&mut self, reading: i32, elapsed: Dur) -> Result<(), ConversionError> {
+        self.total = i128::try_from(elapsed.integer())
+            .ok()
+            .and_then(|elapsed| elapsed.checked_mul(i128::from(reading)))
+            .and_then(|weighted| self.total.checked_add(weighted))
+            .ok_or(ConversionError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Returns the total in units of the readings × `Unit` (truncated toward zero)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The total doesn't fit in an [`i64`]
+    pub fn total<Unit>(&self) -> Result<i64, ConversionError>
+    where
+        Unit: Duration + FixedPoint,
+    {
+        // total × duration scaling factor / unit scaling factor
+        let numerator = i128::from(*Dur::SCALING_FACTOR.numerator())
+            * i128::from(*Unit::SCALING_FACTOR.denominator());
+        let denominator = i128::from(*Dur::SCALING_FACTOR.denominator())
+            * i128::from(*Unit::SCALING_FACTOR.numerator());
+
+        self.total
+            .checked_mul(numerator)
+            .and_then(|total| total.checked_div(denominator))
+            .and_then(|total| i64::try_from(total).ok())
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Clear the total
+    pub fn reset(&mut self) {
+        self.total = 0;
+    }
+}
+
+impl<Dur> Default for Accumulator<Dur>
+where
+    Dur: Duration + FixedPoint,
+    i128: TryFrom<Dur::T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Dur> Copy for Accumulator<Dur> {}
+
+impl<Dur> Clone for Accumulator<Dur> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Dur> fmt::Debug for Accumulator<Dur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Accumulator")
+            .field("total", &self.total)
+            .finish()
+    }
+}
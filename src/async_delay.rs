@@ -0,0 +1,251 @@
+//! Asynchronous delays backed by a [`Clock`](crate::Clock)
+
+use crate::{
+    clock,
+    duration::{Duration, Microseconds, Milliseconds, Nanoseconds},
+    fixed_point::{FixedPoint, RoundingMode},
+    long_delay, Instant, TimeError,
+};
+use core::{
+    convert::TryFrom,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use num::{traits::WrappingAdd, Bounded};
+
+/// Arranges for a pending delay to be woken
+///
+/// A [`DelayFuture`] that hasn't expired calls [`WakeHook::register()`] each time it is polled.
+/// An implementation would typically store the waker and program a compare/alarm interrupt for the
+/// expiration. The interrupt handler then wakes the stored waker.
+pub trait WakeHook<Clock: crate::Clock> {
+    /// Register `waker` to be woken at (or after) the given `expiration`
+    fn register(&self, expiration: &Instant<Clock>, waker: &Waker);
+}
+
+/// A [`WakeHook`] that wakes the task immediately so that the executor polls the delay again
+///
+/// This effectively busy-waits, but needs no interrupt support.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct WakeImmediately;
+
+impl<Clock: crate::Clock> WakeHook<Clock> for WakeImmediately {
+    fn register(&self, _expiration: &Instant<Clock>, waker: &Waker) {
+        waker.wake_by_ref();
+    }
+}
+
+/// A [`Future`] that completes once a duration has elapsed on a [`Clock`](crate::Clock)
+///
+/// The delay starts when the future is constructed. The duration is rounded up to whole ticks of
+/// the clock and the future completes once _more_ than that many ticks have elapsed, so the delay
+/// is never shorter than requested. It resolves to an error if the clock can't be read or the
+/// duration isn't less than half the range of the clock.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, Clock as _, Instant};
+/// # use core::{cell::Cell, future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let clock = Clock(Cell::new(0));
+/// let mut delay = pin!(clock.delay_async(Milliseconds(5_u32)));
+/// let mut cx = Context::from_waker(Waker::noop());
+///
+/// assert_eq!(delay.as_mut().poll(&mut cx), Poll::Pending);
+///
+/// // more than 5 ticks
+/// clock.0.set(5);
+/// assert_eq!(delay.as_mut().poll(&mut cx), Poll::Pending);
+/// clock.0.set(6);
+/// assert_eq!(delay.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+/// ```
+#[derive(Debug)]
+pub struct DelayFuture<'a, Clock: crate::Clock, Hook> {
+    clock: &'a Clock,
+    hook: Hook,
+    expiration: Result<Instant<Clock>, TimeError>,
+}
+
+impl<'a, Clock: crate::Clock, Hook: WakeHook<Clock>> DelayFuture<'a, Clock, Hook> {
+    /// Start a delay of the given duration, woken by the given [`WakeHook`]
+    pub fn new<Dur>(clock: &'a Clock, duration: Dur, hook: Hook) -> Self
+    where
+        Dur: Duration + FixedPoint,
+        u128: From<Dur::T>,
+        Clock::T: TryFrom<u128>,
+    {
+        let ticks = duration
+            .to_ticks_with(Clock::SCALING_FACTOR.into(), RoundingMode::Ceil)
+            .map_err(TimeError::from)
+            .and_then(|ticks: u128| {
+                // the expiration (one tick later) must be within half the range of the clock
+                Clock::T::try_from(ticks)
+                    .ok()
+                    .filter(|ticks| *ticks < Clock::T::max_value() / Clock::T::from(2))
+                    .ok_or(TimeError::Overflow)
+            });
+
+        match ticks {
+            Ok(ticks) => Self::after_ticks(clock, ticks, hook),
+            Err(error) => Self {
+                clock,
+                hook,
+                expiration: Err(error),
+            },
+        }
+    }
+
+    /// Start a delay that completes once _more_ than `ticks` ticks have elapsed
+    ///
+    /// Waiting for _more_ than `ticks` ensures the delay is not shortened by reading the start
+    /// instant late in a tick. `ticks` must be less than half the range of the clock's integer
+    /// type.
+    fn after_ticks(clock: &'a Clock, ticks: Clock::T, hook: Hook) -> Self {
+        let expiration = clock::try_now(clock).map_err(TimeError::from).map(|now| {
+            Instant::new(
+                now.duration_since_epoch()
+                    .integer()
+                    .wrapping_add(&ticks)
+                    .wrapping_add(&Clock::T::from(1)),
+            )
+        });
+
+        Self {
+            clock,
+            hook,
+            expiration,
+        }
+    }
+}
+
+// The future is never structurally pinned
+impl<Clock: crate::Clock, Hook> Unpin for DelayFuture<'_, Clock, Hook> {}
+
+impl<Clock: crate::Clock, Hook: WakeHook<Clock>> Future for DelayFuture<'_, Clock, Hook> {
+    type Output = Result<(), TimeError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let expiration = match &mut this.expiration {
+            Ok(expiration) => *expiration,
+            Err(error) => return Poll::Ready(Err(mem::take(error))),
+        };
+
+        match clock::try_now(this.clock) {
+            Ok(now) if now >= expiration => Poll::Ready(Ok(())),
+            Ok(_) => {
+                this.hook.register(&expiration, cx.waker());
+                Poll::Pending
+            }
+            Err(error) => Poll::Ready(Err(error.into())),
+        }
+    }
+}
+
+/// Implements the `embedded-hal-async` [`DelayNs`](embedded_hal_async::delay::DelayNs) trait
+/// using any [`Clock`](crate::Clock)
+///
+/// Delays are waited on with [`DelayFuture`]s woken by the given [`WakeHook`]. Their durations are
+/// rounded up to whole ticks of the clock and are never shorter than requested. Delays longer than
+/// half the range of the clock are made of several waits.
+#[derive(Debug)]
+pub struct AsyncDelay<Clock: crate::Clock, Hook> {
+    clock: Clock,
+    hook: Hook,
+}
+
+impl<Clock: crate::Clock, Hook: WakeHook<Clock>> AsyncDelay<Clock, Hook> {
+    /// Wrap a clock
+    pub fn new(clock: Clock, hook: Hook) -> Self {
+        Self { clock, hook }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the underlying clock and wake hook
+    pub fn into_inner(self) -> (Clock, Hook) {
+        (self.clock, self.hook)
+    }
+
+    async fn delay<Dur>(&self, duration: Dur)
+    where
+        Dur: Duration + FixedPoint<T = u32>,
+        u64: TryFrom<Clock::T>,
+        Clock::T: TryFrom<u64>,
+    {
+        // the longest wait (of more than `max_ticks`) that an `Instant` can span
+        let max_ticks = Clock::T::max_value() / Clock::T::from(2) - Clock::T::from(1);
+        let chunks = match long_delay::chunks::<Clock, Dur>(duration, max_ticks) {
+            Ok(chunks) => chunks,
+            Err(_) => panic!("Invalid clock scaling factor"),
+        };
+
+        for chunk in chunks {
+            if DelayFuture::after_ticks(&self.clock, chunk.integer(), &self.hook)
+                .await
+                .is_err()
+            {
+                panic!("Clock read failed")
+            }
+        }
+    }
+}
+
+impl<Clock: crate::Clock, Hook: WakeHook<Clock>> WakeHook<Clock> for &Hook {
+    fn register(&self, expiration: &Instant<Clock>, waker: &Waker) {
+        (*self).register(expiration, waker)
+    }
+}
+
+impl<Clock, Hook> embedded_hal_async::delay::DelayNs for AsyncDelay<Clock, Hook>
+where
+    Clock: crate::Clock,
+    Hook: WakeHook<Clock>,
+    u64: TryFrom<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    /// Delays for (at least) the given number of nanoseconds
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    async fn delay_ns(&mut self, ns: u32) {
+        self.delay(Nanoseconds(ns)).await
+    }
+
+    /// Delays for (at least) the given number of microseconds
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    async fn delay_us(&mut self, us: u32) {
+        self.delay(Microseconds(us)).await
+    }
+
+    /// Delays for (at least) the given number of milliseconds
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    async fn delay_ms(&mut self, ms: u32) {
+        self.delay(Milliseconds(ms)).await
+    }
+}
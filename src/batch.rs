@@ -0,0 +1,163 @@
+//! Batch conversion of raw tick buffers
+//!
+//! Timestamps captured by DMA (eg. from a timer input-capture channel) arrive as buffers of raw
+//! ticks. Converting each one individually would recompute the conversion factor for every
+//! element. The functions in this module compute the conversion factor once and apply it to every
+//! element using a widened (`u64`) multiply and divide.
+//!
+//! Results are truncated (rounded toward `0`) as with all other conversions in this crate.
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, fraction::Fraction, ConversionError, Instant,
+};
+use core::convert::TryFrom;
+
+/// Pre-computed conversion from one _scaling factor_ to another
+#[derive(Copy, Clone, Debug)]
+struct Conversion {
+    factor: Fraction,
+}
+
+impl Conversion {
+    fn new(from: Fraction, to: Fraction) -> Result<Self, ConversionError> {
+        if *to.numerator() == 0 || *from.denominator() == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+
+        Ok(Self {
+            factor: from.checked_div(&to).ok_or(ConversionError::Unspecified)?,
+        })
+    }
+
+    fn convert<T: TryFrom<u64>>(&self, ticks: u32) -> Result<T, ConversionError> {
+        // a u32 × u32 product always fits in a u64
+        let ticks = u64::from(ticks) * u64::from(*self.factor.numerator())
+            / u64::from(*self.factor.denominator());
+
+        T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)
+    }
+}
+
+/// Convert a slice of raw ticks with the given _scaling factor_ into durations
+///
+/// Elements are converted until either `ticks` or `output` is exhausted. Returns the number of
+/// elements converted.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{batch, duration::*};
+///
+/// // ticks of a 32.768 kHz timer
+/// let ticks = [0_u32, 32_768, 49_152];
+/// let mut durations = [Milliseconds(0_u32); 3];
+///
+/// assert_eq!(batch::convert_slice(&ticks, Fraction::new(1, 32_768), &mut durations), Ok(3));
+/// assert_eq!(durations, [Milliseconds(0_u32), Milliseconds(1_000), Milliseconds(1_500)]);
+/// ```
+///
+/// # Errors
+///
+/// - [`ConversionError::Unspecified`] : The conversion factor doesn't fit in a [`Fraction`]
+/// - [`ConversionError::DivByZero`] : A _scaling factor_ is invalid
+/// - [`ConversionError::ConversionFailure`] : A converted value doesn't fit in the _integer_ type
+///   of the duration (the preceding elements have been converted)
+pub fn convert_slice<Dur>(
+    ticks: &[u32],
+    scaling_factor: Fraction,
+    output: &mut [Dur],
+) -> Result<usize, ConversionError>
+where
+    Dur: Duration + FixedPoint,
+    Dur::T: TryFrom<u64>,
+{
+    let conversion = Conversion::new(scaling_factor, Dur::SCALING_FACTOR)?;
+
+    for (ticks, output) in ticks.iter().zip(output.iter_mut()) {
+        *output = Dur::new(conversion.convert(*ticks)?);
+    }
+
+    Ok(ticks.len().min(output.len()))
+}
+
+/// Lazily convert raw ticks with the given _scaling factor_ into durations
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{batch, duration::*};
+///
+/// let ticks = [1_000_u32, 2_500];
+/// let mut micros = batch::durations::<Microseconds<u32>, _>(ticks.iter().copied(), Fraction::new(1, 1_000)).unwrap();
+///
+/// assert_eq!(micros.next(), Some(Ok(Microseconds(1_000_000_u32))));
+/// assert_eq!(micros.next(), Some(Ok(Microseconds(2_500_000_u32))));
+/// assert_eq!(micros.next(), None);
+/// ```
+///
+/// # Errors
+///
+/// - [`ConversionError::Unspecified`] : The conversion factor doesn't fit in a [`Fraction`]
+/// - [`ConversionError::DivByZero`] : A _scaling factor_ is invalid
+///
+/// Each item is a [`ConversionError::ConversionFailure`] if the converted value doesn't fit in the
+/// _integer_ type of the duration.
+pub fn durations<Dur, Ticks>(
+    ticks: Ticks,
+    scaling_factor: Fraction,
+) -> Result<impl Iterator<Item = Result<Dur, ConversionError>>, ConversionError>
+where
+    Dur: Duration + FixedPoint,
+    Dur::T: TryFrom<u64>,
+    Ticks: IntoIterator<Item = u32>,
+{
+    let conversion = Conversion::new(scaling_factor, Dur::SCALING_FACTOR)?;
+
+    Ok(ticks
+        .into_iter()
+        .map(move |ticks| Ok(Dur::new(conversion.convert(ticks)?))))
+}
+
+/// Lazily convert raw ticks with the given _scaling factor_ into [`Instant`]s of a
+/// [`Clock`](crate::Clock)
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{batch, fraction::Fraction, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // captured by a 1 MHz timer
+/// let ticks = [3_000_u32, 5_000];
+/// let mut instants = batch::instants::<Clock, _>(ticks.iter().copied(), Fraction::new(1, 1_000_000)).unwrap();
+///
+/// assert_eq!(instants.next(), Some(Ok(Instant::new(3))));
+/// assert_eq!(instants.next(), Some(Ok(Instant::new(5))));
+/// ```
+///
+/// # Errors
+///
+/// See [`durations()`]
+pub fn instants<Clock, Ticks>(
+    ticks: Ticks,
+    scaling_factor: Fraction,
+) -> Result<impl Iterator<Item = Result<Instant<Clock>, ConversionError>>, ConversionError>
+where
+    Clock: crate::Clock,
+    Clock::T: TryFrom<u64>,
+    Ticks: IntoIterator<Item = u32>,
+{
+    let conversion = Conversion::new(scaling_factor, Clock::SCALING_FACTOR)?;
+
+    Ok(ticks
+        .into_iter()
+        .map(move |ticks| Ok(Instant::new(conversion.convert(ticks)?))))
+}
@@ -0,0 +1,153 @@
+//! Civil (calendar) date and time conversions
+//!
+//! Converts between seconds since the Unix epoch (eg. [`UnixTime::secs()`]) and a (proleptic
+//! Gregorian, UTC) year, month, day, hour, minute and second, so drivers can present
+//! human-readable time (or program an RTC's calendar registers) without allocating, using floating
+//! point or depending on a date-time crate. Leap seconds aren't represented, as with Unix time.
+//!
+//! The day conversions are Howard Hinnant's [`days_from_civil`/`civil_from_days`
+//! algorithms](https://howardhinnant.github.io/date_algorithms.html), which are exact for every date
+//! representable here.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use embedded_time::calendar::DateTime;
+//! let date_time = DateTime::from_unix_secs(1_600_000_000).unwrap();
+//! assert_eq!(
+//!     date_time,
+//!     DateTime { year: 2020, month: 9, day: 13, hour: 12, minute: 26, second: 40 }
+//! );
+//! assert_eq!(date_time.to_unix_secs(), Some(1_600_000_000));
+//!
+//! // 30 February
+//! assert_eq!(DateTime { month: 2, day: 30, ..date_time }.to_unix_secs(), None);
+//! ```
+//!
+//! [`UnixTime::secs()`]: crate::epoch::UnixTime::secs()
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// A (proleptic Gregorian, UTC) date and time of day
+///
+/// Values are ordered chronologically. A `DateTime` constructed from its fields isn't checked until
+/// it is converted with [`to_unix_secs()`](DateTime::to_unix_secs()).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DateTime {
+    /// The year (eg. `2024`)
+    pub year: u32,
+    /// The month, `1` (January) to `12` (December)
+    pub month: u8,
+    /// The day of the month, from `1`
+    pub day: u8,
+    /// The hour, `0` to `23`
+    pub hour: u8,
+    /// The minute, `0` to `59`
+    pub minute: u8,
+    /// The second, `0` to `59`
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Returns the date and time of the given seconds since the Unix epoch
+    ///
+    /// Returns [`None`] if the year doesn't fit a [`u32`].
+    pub const fn from_unix_secs(secs: u64) -> Option<Self> {
+        let (year, month, day) = civil_from_days((secs / SECS_PER_DAY) as i64);
+        if year > u32::MAX as i64 {
+            return None;
+        }
+
+        let secs_of_day = secs % SECS_PER_DAY;
+        Some(Self {
+            year: year as u32,
+            month,
+            day,
+            hour: (secs_of_day / 3_600) as u8,
+            minute: (secs_of_day / 60 % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+        })
+    }
+
+    /// Returns the seconds since the Unix epoch
+    ///
+    /// Returns [`None`] if any field is out of range (including a day beyond the end of the month)
+    /// or the date is before the Unix epoch (1970-01-01).
+    pub const fn to_unix_secs(&self) -> Option<u64> {
+        if self.year < 1970
+            || self.month < 1
+            || self.month > 12
+            || self.day < 1
+            || self.day > days_in_month(self.year, self.month)
+            || self.hour > 23
+            || self.minute > 59
+            || self.second > 59
+        {
+            return None;
+        }
+
+        let days = days_from_civil(self.year as i64, self.month, self.day) as u64;
+        Some(
+            days * SECS_PER_DAY
+                + self.hour as u64 * 3_600
+                + self.minute as u64 * 60
+                + self.second as u64,
+        )
+    }
+}
+
+/// Returns whether `year` is a leap year
+pub const fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Returns the number of days in `month` (`1` to `12`) of `year`, or `0` for an invalid month
+pub const fn days_in_month(year: u32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Returns the number of days from the Unix epoch (1970-01-01) to the given date (negative before
+/// it)
+///
+/// The `month` (`1` to `12`) and `day` must be valid, they aren't checked.
+const fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the `(year, month, day)` of the given number of days from the Unix epoch (1970-01-01)
+const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400;
+
+    (
+        if month <= 2 { year + 1 } else { year },
+        month as u8,
+        day as u8,
+    )
+}
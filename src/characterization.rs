@@ -0,0 +1,210 @@
+//! Characterization of a [`Clock`](crate::Clock) against a reference clock
+
+use crate::{
+    clock,
+    duration::{Duration, Nanoseconds},
+    fixed_point::FixedPoint,
+    Instant, TimeError,
+};
+use core::convert::TryFrom;
+
+/// The number of consecutive reads used to measure the read latency
+const LATENCY_READS: u32 = 256;
+
+/// The number of tick transitions used to measure the resolution
+const RESOLUTION_SAMPLES: u32 = 8;
+
+/// The results of [`characterize()`]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Report {
+    /// The smallest observed step between two successive, differing readings of the clock
+    pub resolution: Nanoseconds<u64>,
+    /// The drift of the clock relative to the reference clock in parts per million
+    ///
+    /// A positive value indicates the clock runs fast.
+    pub drift_ppm: i64,
+    /// The average time taken to read the clock, as measured by the reference clock
+    pub read_latency: Nanoseconds<u64>,
+}
+
+/// Compare a clock to a reference clock
+///
+/// The function blocks for at least the `sample_window` (as measured by the reference clock) in
+/// order to measure the drift. A longer window gives a more accurate drift measurement. The window
+/// also bounds the waits on each clock: a clock that doesn't tick within it (or, for the reference
+/// clock, within twice the window measured by the other clock) is reported as not running.
+///
+/// This is intended for bring-up and production test firmware, not for use at run time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, duration::*, characterization::characterize, Instant};
+/// # use core::cell::Cell;
+/// # // the true time in nanoseconds, advanced by each clock read
+/// # #[derive(Debug)]
+/// # struct Time(Cell<u64>);
+/// # impl Time {
+/// #     fn read(&self) -> u64 {
+/// #         self.0.set(self.0.get() + 100);
+/// #         self.0.get()
+/// #     }
+/// # }
+/// # #[derive(Debug)]
+/// # struct Clock<'a>(&'a Time);
+/// # impl embedded_time::Clock for Clock<'_> {
+/// #     type T = u64;
+/// #     type Error = embedded_time::clock::Error;
+/// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+/// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #         Ok(Instant::new(self.0.read() * 1_000_050 / 1_000_000_000))
+/// #     }
+/// # }
+/// # #[derive(Debug)]
+/// # struct ReferenceClock<'a>(&'a Time);
+/// # impl embedded_time::Clock for ReferenceClock<'_> {
+/// #     type T = u64;
+/// #     type Error = embedded_time::clock::Error;
+/// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000_000);
+/// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #         Ok(Instant::new(self.0.read()))
+/// #     }
+/// # }
+/// # let time = Time(Cell::new(0));
+/// # let clock = Clock(&time);
+/// # let reference_clock = ReferenceClock(&time);
+/// // (`clock` is a 1 MHz clock running 50 ppm fast, each clock read takes 100 ns)
+/// let report = characterize(&clock, &reference_clock, Seconds(1_u32)).unwrap();
+///
+/// assert_eq!(report.resolution, Nanoseconds(1_000_u64));
+/// assert_eq!(report.read_latency, Nanoseconds(100_u64));
+/// assert!((report.drift_ppm - 50).abs() <= 1);
+/// ```
+///
+/// # Errors
+///
+/// - [`TimeError::Clock`] : Either clock could not be read or
+///   ([`clock::Error::NotRunning`]) is stuck
+/// - [`TimeError::NegDuration`] : Either clock went backwards
+/// - [`TimeError::Overflow`], [`TimeError::ConversionFailure`], [`TimeError::Unspecified`] : A
+///   measurement doesn't fit in [`Nanoseconds<u64>`]
+/// - [`TimeError::DivByZero`] : The sample window is zero
+pub fn characterize<Clock, Reference, Dur>(
+    clock: &Clock,
+    reference_clock: &Reference,
+    sample_window: Dur,
+) -> Result<Report, TimeError>
+where
+    Clock: crate::Clock,
+    Reference: crate::Clock,
+    Dur: Duration + FixedPoint,
+    u64: TryFrom<Clock::T> + TryFrom<Reference::T> + TryFrom<Dur::T>,
+{
+    let sample_window: u64 = sample_window.into_ticks(Nanoseconds::<u64>::SCALING_FACTOR)?;
+    if sample_window == 0 {
+        return Err(TimeError::DivByZero);
+    }
+
+    Ok(Report {
+        resolution: resolution(clock, reference_clock, sample_window)?,
+        read_latency: read_latency(clock, reference_clock)?,
+        drift_ppm: drift_ppm(clock, reference_clock, sample_window)?,
+    })
+}
+
+/// Returns the time elapsed from `earlier` to `later` in nanoseconds
+fn nanos_between<Clock>(earlier: &Instant<Clock>, later: &Instant<Clock>) -> Result<u64, TimeError>
+where
+    Clock: crate::Clock,
+    u64: TryFrom<Clock::T>,
+{
+    let elapsed = later
+        .checked_duration_since(earlier)
+        .ok_or(TimeError::NegDuration)?;
+
+    Ok(Nanoseconds::<u64>::try_from(elapsed)?.integer())
+}
+
+/// Fails with [`clock::Error::NotRunning`] if the clock doesn't tick within `timeout`
+/// nanoseconds of the reference clock
+fn resolution<Clock, Reference>(
+    clock: &Clock,
+    reference_clock: &Reference,
+    timeout: u64,
+) -> Result<Nanoseconds<u64>, TimeError>
+where
+    Clock: crate::Clock,
+    Reference: crate::Clock,
+    u64: TryFrom<Clock::T> + TryFrom<Reference::T>,
+{
+    let mut resolution = u64::MAX;
+    let mut previous = clock::try_now(clock)?;
+
+    for _ in 0..RESOLUTION_SAMPLES {
+        let reference_start = clock::try_now(reference_clock)?;
+        let mut now = clock::try_now(clock)?;
+        while now == previous {
+            if nanos_between(&reference_start, &clock::try_now(reference_clock)?)? > timeout {
+                return Err(clock::Error::NotRunning.into());
+            }
+            now = clock::try_now(clock)?;
+        }
+
+        resolution = resolution.min(nanos_between(&previous, &now)?);
+        previous = now;
+    }
+
+    Ok(Nanoseconds(resolution))
+}
+
+fn read_latency<Clock, Reference>(
+    clock: &Clock,
+    reference_clock: &Reference,
+) -> Result<Nanoseconds<u64>, TimeError>
+where
+    Clock: crate::Clock,
+    Reference: crate::Clock,
+    u64: TryFrom<Reference::T>,
+{
+    let start = clock::try_now(reference_clock)?;
+    for _ in 0..LATENCY_READS {
+        clock::try_now(clock)?;
+    }
+    let end = clock::try_now(reference_clock)?;
+
+    Ok(Nanoseconds(
+        nanos_between(&start, &end)? / u64::from(LATENCY_READS),
+    ))
+}
+
+/// Fails with [`clock::Error::NotRunning`] if the reference clock doesn't reach the
+/// `sample_window` within twice the window of the clock
+fn drift_ppm<Clock, Reference>(
+    clock: &Clock,
+    reference_clock: &Reference,
+    sample_window: u64,
+) -> Result<i64, TimeError>
+where
+    Clock: crate::Clock,
+    Reference: crate::Clock,
+    u64: TryFrom<Clock::T> + TryFrom<Reference::T>,
+{
+    let reference_start = clock::try_now(reference_clock)?;
+    let start = clock::try_now(clock)?;
+
+    let mut reference_elapsed = 0;
+    while reference_elapsed < sample_window {
+        if nanos_between(&start, &clock::try_now(clock)?)? > sample_window.saturating_mul(2) {
+            return Err(clock::Error::NotRunning.into());
+        }
+        reference_elapsed = nanos_between(&reference_start, &clock::try_now(reference_clock)?)?;
+    }
+
+    let elapsed = nanos_between(&start, &clock::try_now(clock)?)?;
+    let reference_elapsed = nanos_between(&reference_start, &clock::try_now(reference_clock)?)?;
+
+    let drift = (i128::from(elapsed) - i128::from(reference_elapsed)) * 1_000_000
+        / i128::from(reference_elapsed);
+
+    i64::try_from(drift).map_err(|_| TimeError::Overflow)
+}
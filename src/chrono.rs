@@ -0,0 +1,176 @@
+//! Conversions to and from [`chrono`] durations and date-times
+//!
+//! Durations convert to and from [`TimeDelta`] (`chrono::Duration`). Instants of a [`UnixClock`]
+//! (a clock whose `0` is the Unix epoch) convert to and from [`DateTime<Utc>`]. This lets host-side
+//! tooling and tests that already use `chrono` work with values logged from the target.
+//!
+//! Conversions to `chrono` are truncated to whole nanoseconds, conversions from `chrono` are
+//! truncated to whole ticks.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use embedded_time::{chrono::UnixClock, duration::*, Instant};
+//! # use core::convert::TryFrom;
+//! use chrono::{DateTime, TimeDelta, Utc};
+//!
+//! #[derive(Debug)]
+//! struct SysClock;
+//! impl embedded_time::Clock for SysClock {
+//!     type T = u64;
+//! # type Error = embedded_time::clock::Error;
+//!     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+//!     // ...
+//! # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+//! }
+//! impl UnixClock for SysClock {}
+//!
+//! assert_eq!(TimeDelta::try_from(Milliseconds(2_500_u32)), Ok(TimeDelta::milliseconds(2_500)));
+//! assert_eq!(Seconds::<u32>::try_from(TimeDelta::milliseconds(2_500)), Ok(Seconds(2_u32)));
+//!
+//! let logged = Instant::<SysClock>::new(1_600_000_000_250);
+//! let date_time = DateTime::<Utc>::try_from(logged).unwrap();
+//! assert_eq!(date_time, DateTime::from_timestamp(1_600_000_000, 250_000_000).unwrap());
+//! assert_eq!(Instant::<SysClock>::try_from(date_time), Ok(logged));
+//! ```
+
+use crate::{
+    duration,
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::Fraction,
+    ConversionError, Instant, TimeInt,
+};
+use ::chrono::{DateTime, TimeDelta, Utc};
+use core::convert::TryFrom;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+const NANOSECOND: Fraction = Fraction::new(1, 1_000_000_000);
+
+/// A [`Clock`](crate::Clock) whose epoch (tick `0`) is the Unix epoch (1970-01-01 00:00:00 UTC)
+///
+/// Implementing this allows the clock's [`Instant`]s to be converted to and from
+/// [`DateTime<Utc>`].
+pub trait UnixClock: crate::Clock {}
+
+/// Converts `ticks` of the `scaling_factor` to a [`TimeDelta`] (truncated to nanoseconds)
+fn to_time_delta(ticks: u128, scaling_factor: Fraction) -> Result<TimeDelta, ConversionError> {
+    let nanos = fixed_point::rescale(ticks, scaling_factor, NANOSECOND, RoundingMode::Floor)?;
+    let secs = i64::try_from(nanos / NANOS_PER_SEC).map_err(|_| ConversionError::Overflow)?;
+
+    TimeDelta::new(secs, (nanos % NANOS_PER_SEC) as u32).ok_or(ConversionError::Overflow)
+}
+
+/// Converts a [`TimeDelta`] to ticks of the `scaling_factor` (truncated)
+fn from_time_delta(delta: TimeDelta, scaling_factor: Fraction) -> Result<u128, ConversionError> {
+    if delta < TimeDelta::zero() {
+        return Err(ConversionError::NegDuration);
+    }
+
+    let nanos = u128::from(delta.num_seconds() as u64) * NANOS_PER_SEC
+        + u128::from(delta.subsec_nanos() as u32);
+
+    fixed_point::rescale(nanos, NANOSECOND, scaling_factor, RoundingMode::Floor)
+}
+
+macro_rules! impl_chrono_duration {
+    ( $($name:ident),* ) => {$(
+        /// Truncated to nanoseconds
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The duration doesn't fit a [`TimeDelta`]
+        impl<T: TimeInt> TryFrom<duration::$name<T>> for TimeDelta {
+            type Error = ConversionError;
+
+            fn try_from(duration: duration::$name<T>) -> Result<Self, Self::Error> {
+                let ticks = duration.integer().to_u128().ok_or(ConversionError::Overflow)?;
+                to_time_delta(ticks, duration::$name::<T>::SCALING_FACTOR)
+            }
+        }
+
+        /// Truncated to whole units
+        ///
+        /// # Errors
+        ///
+        /// - [`ConversionError::NegDuration`] : The [`TimeDelta`] is negative
+        /// - [`ConversionError::ConversionFailure`] : The duration doesn't fit the integer type
+        impl<T: TimeInt + TryFrom<u128>> TryFrom<TimeDelta> for duration::$name<T> {
+            type Error = ConversionError;
+
+            fn try_from(delta: TimeDelta) -> Result<Self, Self::Error> {
+                let ticks = from_time_delta(delta, Self::SCALING_FACTOR)?;
+                T::try_from(ticks)
+                    .map(Self::new)
+                    .map_err(|_| ConversionError::ConversionFailure)
+            }
+        }
+    )*};
+}
+
+impl_chrono_duration![
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds
+];
+
+/// Truncated to nanoseconds
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The duration doesn't fit a [`TimeDelta`]
+/// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+impl<T: TimeInt> TryFrom<duration::Generic<T>> for TimeDelta {
+    type Error = ConversionError;
+
+    fn try_from(duration: duration::Generic<T>) -> Result<Self, Self::Error> {
+        let ticks = duration
+            .integer()
+            .to_u128()
+            .ok_or(ConversionError::Overflow)?;
+        to_time_delta(ticks, *duration.scaling_factor())
+    }
+}
+
+/// The time since the Unix epoch (truncated to nanoseconds)
+///
+/// # Errors
+///
+/// [`ConversionError::Overflow`] : The instant is beyond the range of [`DateTime`]
+impl<Clock: UnixClock> TryFrom<Instant<Clock>> for DateTime<Utc> {
+    type Error = ConversionError;
+
+    fn try_from(instant: Instant<Clock>) -> Result<Self, Self::Error> {
+        let since_epoch = TimeDelta::try_from(instant.duration_since_epoch())?;
+
+        DateTime::from_timestamp(since_epoch.num_seconds(), since_epoch.subsec_nanos() as u32)
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+/// Truncated to whole ticks
+///
+/// # Errors
+///
+/// - [`ConversionError::NegDuration`] : The date-time is before the Unix epoch
+/// - [`ConversionError::ConversionFailure`] : The instant doesn't fit the clock's integer type
+impl<Clock: UnixClock> TryFrom<DateTime<Utc>> for Instant<Clock>
+where
+    Clock::T: TryFrom<u128>,
+{
+    type Error = ConversionError;
+
+    fn try_from(date_time: DateTime<Utc>) -> Result<Self, Self::Error> {
+        let since_epoch = TimeDelta::new(date_time.timestamp(), date_time.timestamp_subsec_nanos())
+            .ok_or(ConversionError::Overflow)?;
+        let ticks = from_time_delta(since_epoch, Clock::SCALING_FACTOR)?;
+
+        Clock::T::try_from(ticks)
+            .map(Self::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+}
@@ -4,31 +4,31 @@ use crate::{
     duration::Duration, fixed_point::FixedPoint, fraction::Fraction, instant::Instant,
     time_int::TimeInt, timer::param, timer::Timer,
 };
-use core::hash::Hash;
+use core::{fmt, hash::Hash};
 
 /// Potential `Clock` errors
 #[non_exhaustive]
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     /// Exact cause of failure is unknown
+    #[default]
     Unspecified,
     /// The clock has either stopped or never started
     NotRunning,
-}
-
-impl Default for Error {
-    fn default() -> Self {
-        Self::Unspecified
-    }
+    /// The clock jumped backwards
+    NonMonotonic,
 }
 
 /// The `Clock` trait provides an abstraction for hardware-specific timer peripherals, external
 /// timer devices, RTCs, etc.
 ///
-/// The `Clock` is characterized by an inner unsigned integer storage type (either [`u32`] or
-/// [`u64`]), a [`u32`]/[`u32`] [`Fraction`] defining the duration (in seconds) of one
-/// count of the `Clock`, and a custom error type representing errors that may be generated by the
-/// implementation.
+/// The `Clock` is characterized by an inner unsigned integer storage type ([`u8`], [`u16`],
+/// [`u32`], [`u64`] or [`u128`]) and a [`u32`]/[`u32`] [`Fraction`] defining the duration (in
+/// seconds) of one count of the `Clock`. Reading the clock is fallible, implementations report
+/// hardware failures (peripheral not started, bus error, stopped counter) with their
+/// [`Clock::Error`] rather than returning a bogus [`Instant`]. Implementations without an error
+/// type of their own use [`Error`].
 ///
 /// In addition to the [`Clock::try_now()`] method which returns an [`Instant`],
 /// software [`Timer`]s can be spawned from a `Clock` object.
@@ -36,6 +36,11 @@ pub trait Clock: Sized {
     /// The type to hold the tick count
     type T: TimeInt + Hash;
 
+    /// The error reading the clock
+    ///
+    /// It converts into an [`Error`], so that the generic parts of the crate can report it.
+    type Error: Into<Error> + fmt::Debug;
+
     /// The duration of one clock tick in seconds, AKA the clock precision.
     const SCALING_FACTOR: Fraction;
 
@@ -43,18 +48,42 @@ pub trait Clock: Sized {
     ///
     /// # Errors
     ///
-    /// - [`Error::NotRunning`]
-    /// - [`Error::Unspecified`]
-    fn try_now(&self) -> Result<Instant<Self>, Error>;
+    /// The implementation's [`Clock::Error`] (eg. [`Error::NotRunning`])
+    fn try_now(&self) -> Result<Instant<Self>, Self::Error>;
 
-    /// Spawn a new, `OneShot` [`Timer`] from this clock
-    fn new_timer<Dur: Duration>(
+    /// Returns a [`Future`](core::future::Future) that completes once (at least) the given
+    /// duration has elapsed
+    ///
+    /// The duration is rounded up to whole ticks of the clock. The returned [`DelayFuture`] asks
+    /// the executor to poll it again immediately while pending. Use [`DelayFuture::new()`] with a
+    /// custom [`WakeHook`] to be woken by an interrupt instead.
+    ///
+    /// [`DelayFuture`]: crate::async_delay::DelayFuture
+    /// [`DelayFuture::new()`]: crate::async_delay::DelayFuture::new()
+    /// [`WakeHook`]: crate::async_delay::WakeHook
+    #[cfg(feature = "async")]
+    fn delay_async<Dur>(
         &self,
         duration: Dur,
-    ) -> Timer<param::OneShot, param::Armed, Self, Dur>
+    ) -> crate::async_delay::DelayFuture<'_, Self, crate::async_delay::WakeImmediately>
     where
-        Dur: FixedPoint,
+        Dur: Duration + FixedPoint,
+        u128: From<Dur::T>,
+        Self::T: core::convert::TryFrom<u128>,
     {
-        Timer::<param::None, param::None, Self, Dur>::new(&self, duration)
+        crate::async_delay::DelayFuture::new(self, duration, crate::async_delay::WakeImmediately)
     }
+
+    /// Spawn a new, `OneShot` [`Timer`] from this clock
+    fn new_timer<Dur>(&self, duration: Dur) -> Timer<'_, param::OneShot, param::Armed, Self, Dur>
+    where
+        Dur: Duration + FixedPoint,
+    {
+        Timer::<param::None, param::None, Self, Dur>::new(self, duration)
+    }
+}
+
+/// Reads the clock, converting its error into an [`Error`]
+pub(crate) fn try_now<Clock: self::Clock>(clock: &Clock) -> Result<Instant<Clock>, Error> {
+    clock.try_now().map_err(Into::into)
 }
@@ -0,0 +1,80 @@
+//! Shared handles to a single [`Clock`](crate::Clock)
+
+use crate::{fraction::Fraction, Instant};
+
+/// A cheap, copyable handle to a shared [`Clock`](crate::Clock)
+///
+/// A `ClockHandle` is itself a [`Clock`](crate::Clock) with the same integer type and _scaling
+/// factor_, so the same hardware clock can be handed to several drivers, timers and profilers that
+/// each take a clock by value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{clock_handle::ClockHandle, fraction::Fraction, Clock as _, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(23))}
+/// }
+///
+/// let clock = Clock;
+/// let handle = ClockHandle::new(&clock);
+/// let another_handle = handle;
+///
+/// assert_eq!(handle.try_now(), Ok(Instant::new(23)));
+/// assert_eq!(another_handle.try_now(), Ok(Instant::new(23)));
+/// ```
+#[derive(Debug)]
+pub struct ClockHandle<'a, Clock: crate::Clock> {
+    clock: &'a Clock,
+}
+
+impl<'a, Clock: crate::Clock> ClockHandle<'a, Clock> {
+    /// Construct a handle to the given clock
+    pub fn new(clock: &'a Clock) -> Self {
+        Self { clock }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &'a Clock {
+        self.clock
+    }
+
+    /// Convert an [`Instant`] of the underlying clock into one of this handle
+    pub fn from_clock_instant(instant: Instant<Clock>) -> Instant<Self> {
+        Instant::new(instant.duration_since_epoch().integer())
+    }
+
+    /// Convert an [`Instant`] of this handle into one of the underlying clock
+    pub fn into_clock_instant(instant: Instant<Self>) -> Instant<Clock> {
+        Instant::new(instant.duration_since_epoch().integer())
+    }
+}
+
+impl<Clock: crate::Clock> Copy for ClockHandle<'_, Clock> {}
+
+impl<Clock: crate::Clock> Clone for ClockHandle<'_, Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for ClockHandle<'_, Clock> {
+    type T = Clock::T;
+    type Error = Clock::Error;
+    const SCALING_FACTOR: Fraction = Clock::SCALING_FACTOR;
+
+    /// Get the current Instant of the underlying clock
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the underlying clock
+    fn try_now(&self) -> Result<Instant<Self>, Clock::Error> {
+        self.clock.try_now().map(Self::from_clock_instant)
+    }
+}
@@ -0,0 +1,33 @@
+//! Ready-made [`Clock`](crate::Clock) implementations
+//!
+//! Clocks depending on particular hardware or on other crates are gated behind a Cargo feature.
+
+#[cfg(feature = "cortex-m-dwt")]
+mod dwt;
+#[cfg(target_has_atomic = "64")]
+mod injected;
+#[cfg(feature = "mock")]
+mod mock;
+#[cfg(feature = "riscv")]
+mod mtime;
+#[cfg(feature = "std")]
+mod std_clock;
+#[cfg(feature = "cortex-m")]
+mod systick;
+mod tick;
+
+#[cfg(feature = "cortex-m-dwt")]
+pub use dwt::DwtClock;
+#[cfg(target_has_atomic = "64")]
+pub use injected::InjectedClock;
+#[cfg(feature = "mock")]
+pub use mock::MockClock;
+#[cfg(feature = "riscv")]
+pub use mtime::MtimeClock;
+#[cfg(feature = "std")]
+pub use std_clock::StdClock;
+#[cfg(feature = "cortex-m")]
+pub use systick::SysTickClock;
+pub use tick::TickClock;
+#[cfg(target_has_atomic = "64")]
+pub use tick::TickClock64;
@@ -0,0 +1,68 @@
+use crate::{clock, fraction::Fraction, Instant};
+use core::fmt;
+use cortex_m::peripheral::{DCB, DWT};
+
+/// A [`Clock`](crate::Clock) backed by the Cortex-M DWT cycle counter (`CYCCNT`)
+///
+/// The counter increments once per CPU cycle, `CPU_HZ` is the CPU frequency (in Hz). It needs no
+/// interrupts and wraps over the full 32-bit range, making it suitable for profiling and short
+/// timeouts (the usable range is half the counter period, eg. ~22 s at 48 MHz).
+///
+/// The cycle counter is not available on ARMv6-M (Cortex-M0/M0+) devices, which is why the clock
+/// has its own `cortex-m-dwt` feature.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use embedded_time::{clocks::DwtClock, Clock as _};
+///
+/// let mut core_peripherals = cortex_m::Peripherals::take().unwrap();
+/// let clock = DwtClock::<64_000_000>::new(&mut core_peripherals.DCB, core_peripherals.DWT);
+///
+/// let start = clock.try_now().unwrap();
+/// ```
+pub struct DwtClock<const CPU_HZ: u32> {
+    dwt: DWT,
+}
+
+impl<const CPU_HZ: u32> DwtClock<CPU_HZ> {
+    /// Take ownership of the DWT peripheral, enable tracing and start the cycle counter
+    pub fn new(dcb: &mut DCB, mut dwt: DWT) -> Self {
+        dcb.enable_trace();
+        DWT::unlock();
+        dwt.enable_cycle_counter();
+
+        Self { dwt }
+    }
+
+    /// Stop the cycle counter and return the DWT peripheral
+    pub fn into_inner(mut self) -> DWT {
+        self.dwt.disable_cycle_counter();
+        self.dwt
+    }
+}
+
+impl<const CPU_HZ: u32> fmt::Debug for DwtClock<CPU_HZ> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DwtClock").field("cpu_hz", &CPU_HZ).finish()
+    }
+}
+
+impl<const CPU_HZ: u32> crate::Clock for DwtClock<CPU_HZ> {
+    type T = u32;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, CPU_HZ);
+
+    /// Get the current Instant
+    ///
+    /// # Errors
+    ///
+    /// - [`clock::Error::NotRunning`] : The cycle counter has been disabled (eg. by a debugger)
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        if !DWT::cycle_counter_enabled() {
+            return Err(clock::Error::NotRunning);
+        }
+
+        Ok(Instant::new(DWT::cycle_count()))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{clock, fraction::Fraction, Instant};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A [`Clock`](crate::Clock) whose time only advances when the embedding environment says so
+///
+/// The clock counts ticks of `NUMERATOR` / `DENOMINATOR` seconds (the _scaling factor_), starting
+/// at `0`, and only moves when [`advance()`](Self::advance) is called. This lets a C scheduler, a
+/// hardware-in-the-loop rig or a discrete-event simulator drive the timing logic in lockstep with
+/// its own notion of time: everything built on the clock ([`Instant`]s, [`Timer`](crate::Timer)s,
+/// ...) observes exactly the ticks injected so far.
+///
+/// The counter is atomic, so the clock can be a `static` advanced from the environment's callback
+/// while the code under simulation reads it.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{clocks::InjectedClock, duration::*, Clock as _, Instant};
+///
+/// // 1 µs ticks
+/// static CLOCK: InjectedClock<1, 1_000_000> = InjectedClock::new();
+///
+/// let timer = CLOCK.new_timer(Milliseconds(2_u32)).start().unwrap();
+///
+/// // the simulator steps by 1.5 ms
+/// CLOCK.advance(1_500);
+/// assert_eq!(CLOCK.try_now(), Ok(Instant::new(1_500)));
+/// assert!(!timer.is_expired().unwrap());
+///
+/// CLOCK.advance(500);
+/// assert!(timer.is_expired().unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct InjectedClock<const NUMERATOR: u32, const DENOMINATOR: u32> {
+    ticks: AtomicU64,
+}
+
+impl<const NUMERATOR: u32, const DENOMINATOR: u32> InjectedClock<NUMERATOR, DENOMINATOR> {
+    /// Construct a clock at `0` (usable in `static`s)
+    pub const fn new() -> Self {
+        Self {
+            ticks: AtomicU64::new(0),
+        }
+    }
+
+    /// Advance the clock by the given number of ticks (wrapping around at [`u64::MAX`])
+    pub fn advance(&self, ticks: u64) {
+        self.ticks.fetch_add(ticks, Ordering::AcqRel);
+    }
+}
+
+impl<const NUMERATOR: u32, const DENOMINATOR: u32> crate::Clock
+    for InjectedClock<NUMERATOR, DENOMINATOR>
+{
+    type T = u64;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(NUMERATOR, DENOMINATOR);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(self.ticks.load(Ordering::Acquire)))
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    clock,
+    duration::{Duration, Nanoseconds},
+    fixed_point::FixedPoint,
+    fraction::Fraction,
+    Instant,
+};
+use core::{cell::Cell, convert::TryFrom};
+#[cfg(feature = "async")]
+use core::{cell::RefCell, task::Waker};
+
+/// The number of wakers a [`MockClock`] can hold
+#[cfg(feature = "async")]
+const ALARMS: usize = 8;
+
+/// A registered waker and its expiration
+#[cfg(feature = "async")]
+type Alarm = Option<(Instant<MockClock>, Waker)>;
+
+/// A deterministic [`Clock`](crate::Clock) for unit tests, time only moves when told to
+///
+/// The clock counts nanoseconds, starting at `0`. It is moved forward with
+/// [`MockClock::advance()`] or set to any instant (even an earlier one, to test
+/// [`NonMonotonic`](clock::Error::NonMonotonic) handling) with [`MockClock::set()`].
+///
+/// Code that busy-waits on the clock (eg. [`Timer::wait()`](crate::Timer)) would never return,
+/// [`MockClock::set_auto_advance()`] makes every read advance the clock by a fixed step.
+///
+/// With the `async` feature, the clock is also a [`WakeHook`](crate::async_delay::WakeHook):
+/// wakers registered by pending [`DelayFuture`](crate::async_delay::DelayFuture)s are woken when
+/// the clock is moved past their expiration. If more than 8 wakers are pending, further wakers are
+/// woken immediately (so the executor polls them again).
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{clocks::MockClock, duration::*, Clock as _, Instant};
+///
+/// let clock = MockClock::new();
+/// let timer = clock.new_timer(Milliseconds(10_u32)).start().unwrap();
+///
+/// clock.advance(Milliseconds(9_u32));
+/// assert!(!timer.is_expired().unwrap());
+///
+/// clock.advance(Milliseconds(1_u32));
+/// assert!(timer.is_expired().unwrap());
+///
+/// clock.set(Instant::new(5));
+/// assert_eq!(clock.try_now(), Ok(Instant::new(5)));
+/// ```
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: Cell<u64>,
+    auto_advance: Cell<u64>,
+    #[cfg(feature = "async")]
+    alarms: RefCell<[Alarm; ALARMS]>,
+}
+
+impl MockClock {
+    /// Construct a clock at `0`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by the given duration
+    ///
+    /// # Panics
+    ///
+    /// If the duration doesn't fit in [`Nanoseconds<u64>`]
+    pub fn advance<Dur>(&self, duration: Dur)
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+    {
+        let ticks = nanos(duration);
+        self.set(Instant::new(self.now.get().wrapping_add(ticks)));
+    }
+
+    /// Set the clock to the given instant
+    pub fn set(&self, instant: Instant<Self>) {
+        self.now.set(instant.duration_since_epoch().integer());
+
+        #[cfg(feature = "async")]
+        self.wake_expired();
+    }
+
+    /// Advance the clock by the given duration every time it is read (`0` disables it)
+    ///
+    /// # Panics
+    ///
+    /// If the duration doesn't fit in [`Nanoseconds<u64>`]
+    pub fn set_auto_advance<Dur>(&self, duration: Dur)
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+    {
+        self.auto_advance.set(nanos(duration));
+    }
+
+    #[cfg(feature = "async")]
+    fn wake_expired(&self) {
+        let now = Instant::<Self>::new(self.now.get());
+
+        // wake outside of the borrow so a waker may register again
+        let mut expired: [Option<Waker>; ALARMS] = Default::default();
+        for (alarm, expired) in self.alarms.borrow_mut().iter_mut().zip(expired.iter_mut()) {
+            if matches!(alarm, Some((expiration, _)) if now >= *expiration) {
+                *expired = alarm.take().map(|(_, waker)| waker);
+            }
+        }
+
+        expired.iter().flatten().for_each(Waker::wake_by_ref);
+    }
+}
+
+fn nanos<Dur>(duration: Dur) -> u64
+where
+    Dur: Duration + FixedPoint,
+    u64: TryFrom<Dur::T>,
+{
+    match duration.into_ticks(Nanoseconds::<u64>::SCALING_FACTOR) {
+        Ok(ticks) => ticks,
+        Err(_) => panic!("Duration conversion failed"),
+    }
+}
+
+impl crate::Clock for MockClock {
+    type T = u64;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let auto_advance = self.auto_advance.get();
+        if auto_advance > 0 {
+            self.set(Instant::new(self.now.get().wrapping_add(auto_advance)));
+        }
+
+        Ok(Instant::new(self.now.get()))
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::async_delay::WakeHook<MockClock> for MockClock {
+    fn register(&self, expiration: &Instant<MockClock>, waker: &Waker) {
+        if Instant::new(self.now.get()) >= *expiration {
+            waker.wake_by_ref();
+            return;
+        }
+
+        let mut alarms = self.alarms.borrow_mut();
+
+        // a re-registering task replaces its previous alarm
+        let slot = alarms
+            .iter()
+            .position(|alarm| matches!(alarm, Some((_, registered)) if registered.will_wake(waker)))
+            .or_else(|| alarms.iter().position(Option::is_none));
+
+        match slot {
+            Some(slot) => alarms[slot] = Some((*expiration, waker.clone())),
+            None => waker.wake_by_ref(),
+        }
+    }
+}
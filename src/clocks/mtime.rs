@@ -0,0 +1,123 @@
+use crate::{clock, fraction::Fraction, Instant};
+use core::ptr;
+
+/// A [`Clock`](crate::Clock) backed by the RISC-V machine timer (`mtime`)
+///
+/// `mtime` is a memory-mapped, 64-bit counter incrementing at the platform's timebase frequency,
+/// `HZ`. Its address (and that of the hart's `mtimecmp` compare register) is platform specific, it
+/// is usually part of the CLINT (or ACLINT MTIMER) peripheral.
+///
+/// The registers are accessed as pairs of 32-bit words so the same code works on RV32 and RV64.
+/// The 64-bit counter is read as high/low/high (retrying if the high word changed in between), so a
+/// carry between the two halves can't produce a torn reading. `mtimecmp` is written low word
+/// first, after setting the low word to its maximum, so no spurious interrupt is raised while it is
+/// being updated.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use embedded_time::{clocks::MtimeClock, Clock as _};
+///
+/// // a 10 MHz timebase with the CLINT at 0x0200_0000 (hart 0)
+/// let clock = unsafe { MtimeClock::<10_000_000>::new(0x0200_bff8, 0x0200_4000) };
+///
+/// let start = clock.try_now().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MtimeClock<const HZ: u32> {
+    mtime: *mut u32,
+    mtimecmp: *mut u32,
+}
+
+impl<const HZ: u32> MtimeClock<HZ> {
+    /// Construct a clock from the addresses of the `mtime` and `mtimecmp` registers
+    ///
+    /// # Safety
+    ///
+    /// The addresses must be those of the (8-byte aligned) `mtime` register and of the current
+    /// hart's `mtimecmp` register. Nothing else may write to `mtimecmp` while the clock exists.
+    #[allow(unsafe_code)]
+    pub const unsafe fn new(mtime: usize, mtimecmp: usize) -> Self {
+        Self {
+            mtime: mtime as *mut u32,
+            mtimecmp: mtimecmp as *mut u32,
+        }
+    }
+
+    /// Set the compare value, the machine timer interrupt is pending once the clock reaches
+    /// `instant`
+    pub fn set_compare(&mut self, instant: Instant<Self>) {
+        let ticks = instant.duration_since_epoch().integer();
+
+        // keep the register above `mtime` while the high word is written
+        self.write_mtimecmp(u32::MAX, (ticks >> 32) as u32, ticks as u32);
+    }
+
+    /// Set the compare value to its maximum, clearing a pending machine timer interrupt
+    pub fn clear_compare(&mut self) {
+        self.write_mtimecmp(u32::MAX, u32::MAX, u32::MAX);
+    }
+
+    /// Reset the counter to `0`
+    ///
+    /// `mtime` is shared by all harts, so this affects their clocks too.
+    pub fn reset(&mut self) {
+        // NOTE(unsafe) the address is valid per the contract of `new()`
+        #[allow(unsafe_code)]
+        unsafe {
+            ptr::write_volatile(self.mtime, 0);
+            ptr::write_volatile(self.mtime.add(1), 0);
+            ptr::write_volatile(self.mtime, 0);
+        }
+    }
+
+    fn write_mtimecmp(&mut self, low_first: u32, high: u32, low: u32) {
+        // NOTE(unsafe) the address is valid and exclusively owned per the contract of `new()`
+        #[allow(unsafe_code)]
+        unsafe {
+            ptr::write_volatile(self.mtimecmp, low_first);
+            ptr::write_volatile(self.mtimecmp.add(1), high);
+            ptr::write_volatile(self.mtimecmp, low);
+        }
+    }
+
+    fn read_mtime(&self) -> u64 {
+        // NOTE(unsafe) volatile reads of a valid address per the contract of `new()`
+        #[allow(unsafe_code)]
+        let read = |offset| unsafe { ptr::read_volatile(self.mtime.add(offset)) };
+
+        loop {
+            let high = read(1);
+            let low = read(0);
+
+            if read(1) == high {
+                return (u64::from(high) << 32) | u64::from(low);
+            }
+        }
+    }
+}
+
+impl<const HZ: u32> crate::Clock for MtimeClock<HZ> {
+    type T = u64;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, HZ);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(self.read_mtime()))
+    }
+}
+
+#[cfg(feature = "rtic")]
+impl<const HZ: u32> crate::rtic::Compare for MtimeClock<HZ> {
+    fn set_compare(&mut self, instant: Instant<Self>) {
+        MtimeClock::set_compare(self, instant);
+    }
+
+    fn clear_compare_flag(&mut self) {
+        self.clear_compare();
+    }
+
+    fn reset(&mut self) {
+        MtimeClock::reset(self);
+    }
+}
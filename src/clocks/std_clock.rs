@@ -0,0 +1,63 @@
+use crate::{clock, fraction::Fraction, Instant};
+use core::convert::TryFrom;
+
+/// A [`Clock`](crate::Clock) backed by [`std::time::Instant`], for running tests on the host
+///
+/// The clock counts nanoseconds since it was constructed. [`std::time::Instant`] is monotonic, so
+/// the clock never goes backwards.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{clocks::StdClock, duration::*, Clock as _};
+///
+/// let clock = StdClock::new();
+///
+/// let start = clock.try_now().unwrap();
+/// std::thread::sleep(std::time::Duration::from_millis(5));
+/// let elapsed = clock.try_now().unwrap().checked_duration_since(&start).unwrap();
+///
+/// assert!(Milliseconds::<u64>::try_from(elapsed).unwrap() >= Milliseconds(5_u64));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StdClock {
+    epoch: std::time::Instant,
+}
+
+impl StdClock {
+    /// Construct a clock whose epoch is now
+    pub fn new() -> Self {
+        Self {
+            epoch: std::time::Instant::now(),
+        }
+    }
+
+    /// Returns the [`std::time::Instant`] corresponding to `0` ticks
+    pub fn epoch(&self) -> std::time::Instant {
+        self.epoch
+    }
+}
+
+impl Default for StdClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Clock for StdClock {
+    type T = u64;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000_000);
+
+    /// Get the current Instant
+    ///
+    /// # Errors
+    ///
+    /// - [`clock::Error::Unspecified`] : More than 2^64 ns (~584 years) have passed since the epoch
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        u64::try_from(self.epoch.elapsed().as_nanos())
+            .map(Instant::new)
+            .map_err(|_| clock::Error::Unspecified)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{clock, fraction::Fraction, Instant};
+use core::fmt;
+use cortex_m::peripheral::{syst::SystClkSource, SYST};
+
+/// The maximum (and reset) value of the 24-bit SysTick counter
+const RELOAD: u32 = 0x00ff_ffff;
+
+/// A [`Clock`](crate::Clock) backed by the Cortex-M SysTick timer
+///
+/// The SysTick counter is clocked from the core clock, whose frequency (in Hz) is given by
+/// `CORE_HZ`. As the _scaling factor_ of a clock is a constant, the frequency is part of the type.
+///
+/// The counter is 24 bits wide, so it wraps every `2^24` core cycles (eg. ~350 ms at 48 MHz).
+/// [`Instant`] comparisons assume a full-width counter, so for anything but very short intervals
+/// the clock should be wrapped in an [`ExtendedClock`] with `BITS = 24`, calling
+/// [`ExtendedClock::on_overflow()`] from the SysTick exception handler.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use embedded_time::{clocks::SysTickClock, extended_clock::ExtendedClock};
+///
+/// let mut clock = SysTickClock::<48_000_000>::new(core_peripherals.SYST);
+/// clock.enable_interrupt();
+/// let clock = ExtendedClock::<_, 24>::new(clock);
+///
+/// // in the SysTick exception handler
+/// clock.on_overflow();
+/// ```
+///
+/// [`ExtendedClock`]: crate::extended_clock::ExtendedClock
+/// [`ExtendedClock::on_overflow()`]: crate::extended_clock::ExtendedClock::on_overflow()
+pub struct SysTickClock<const CORE_HZ: u32> {
+    syst: SYST,
+}
+
+impl<const CORE_HZ: u32> SysTickClock<CORE_HZ> {
+    /// Take ownership of the SysTick peripheral, configure it to count core clock cycles over its
+    /// full 24-bit range and start it
+    ///
+    /// The SysTick exception is left disabled.
+    pub fn new(mut syst: SYST) -> Self {
+        syst.disable_counter();
+        syst.set_clock_source(SystClkSource::Core);
+        syst.set_reload(RELOAD);
+        syst.clear_current();
+        syst.enable_counter();
+
+        Self { syst }
+    }
+
+    /// Enable the SysTick exception, which fires each time the counter wraps
+    pub fn enable_interrupt(&mut self) {
+        self.syst.enable_interrupt();
+    }
+
+    /// Disable the SysTick exception
+    pub fn disable_interrupt(&mut self) {
+        self.syst.disable_interrupt();
+    }
+
+    /// Stop the counter and return the SysTick peripheral
+    pub fn into_inner(mut self) -> SYST {
+        self.syst.disable_interrupt();
+        self.syst.disable_counter();
+        self.syst
+    }
+}
+
+impl<const CORE_HZ: u32> fmt::Debug for SysTickClock<CORE_HZ> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SysTickClock")
+            .field("core_hz", &CORE_HZ)
+            .finish()
+    }
+}
+
+impl<const CORE_HZ: u32> crate::Clock for SysTickClock<CORE_HZ> {
+    type T = u32;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, CORE_HZ);
+
+    /// Get the current Instant
+    ///
+    /// The SysTick counter counts down, so it is inverted to give an increasing tick count.
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(RELOAD - (SYST::get_current() & RELOAD)))
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{clock, fraction::Fraction, Instant};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+macro_rules! impl_tick_clock {
+    ($(#[$attr:meta])* $name:ident, $atomic:ty, $int:ty, $example:literal) => {
+        /// A [`Clock`](crate::Clock) counting the ticks of a periodic interrupt
+        ///
+        /// [`tick()`](Self::tick) is called from the interrupt handler, each call being one tick
+        /// of `NUMERATOR` / `DENOMINATOR` seconds (the _scaling factor_). The counter is atomic, so
+        /// the clock can be a `static` shared between the interrupt handler and the rest of the
+        /// application.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        #[doc = $example]
+        /// use embedded_time::{Clock as _, Instant};
+        ///
+        /// // a 1 kHz tick
+        /// static CLOCK: Clock<1, 1_000> = Clock::new();
+        ///
+        /// // in the interrupt handler
+        /// CLOCK.tick();
+        ///
+        /// assert_eq!(CLOCK.try_now(), Ok(Instant::new(1)));
+        /// ```
+        $(#[$attr])*
+        #[derive(Debug, Default)]
+        pub struct $name<const NUMERATOR: u32, const DENOMINATOR: u32> {
+            ticks: $atomic,
+        }
+
+        $(#[$attr])*
+        impl<const NUMERATOR: u32, const DENOMINATOR: u32> $name<NUMERATOR, DENOMINATOR> {
+            /// Construct a clock at `0` (usable in `static`s)
+            pub const fn new() -> Self {
+                Self {
+                    ticks: <$atomic>::new(0),
+                }
+            }
+
+            /// Advance the clock by one tick
+            ///
+            /// This is intended to be called from a single interrupt handler.
+            pub fn tick(&self) {
+                // load + store rather than `fetch_add()` so this works on targets without atomic
+                // read-modify-write instructions (there is a single writer)
+                self.ticks.store(
+                    self.ticks.load(Ordering::Acquire).wrapping_add(1),
+                    Ordering::Release,
+                );
+            }
+        }
+
+        $(#[$attr])*
+        impl<const NUMERATOR: u32, const DENOMINATOR: u32> crate::Clock
+            for $name<NUMERATOR, DENOMINATOR>
+        {
+            type T = $int;
+            type Error = clock::Error;
+            const SCALING_FACTOR: Fraction = Fraction::new(NUMERATOR, DENOMINATOR);
+
+            fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+                Ok(Instant::new(self.ticks.load(Ordering::Acquire)))
+            }
+        }
+    };
+}
+
+impl_tick_clock!(
+    TickClock,
+    AtomicU32,
+    u32,
+    "use embedded_time::clocks::TickClock as Clock;"
+);
+impl_tick_clock!(
+    #[cfg(target_has_atomic = "64")]
+    TickClock64,
+    AtomicU64,
+    u64,
+    "use embedded_time::clocks::TickClock64 as Clock;"
+);
@@ -0,0 +1,97 @@
+//! Standard periods, rates and _scaling factors_
+//!
+//! Typed values for numbers that otherwise appear as magic numbers in firmware.
+//!
+//! ```rust
+//! use embedded_time::{consts, duration::*, rate::*};
+//!
+//! // the duration of one bit at 115200 baud
+//! assert_eq!(consts::baud::B_115200.to_duration(), Ok(Nanoseconds(8_680_u32)));
+//!
+//! assert_eq!(Kilohertz::<u32>::try_from(consts::crystal::F_8_MHZ), Ok(Kilohertz(8_000_u32)));
+//! # use core::convert::TryFrom;
+//! ```
+
+use crate::{
+    duration::Milliseconds,
+    fraction::Fraction,
+    rate::{Baud, Hertz},
+};
+
+/// _Scaling factors_ of common clocks (for use as [`Clock::SCALING_FACTOR`])
+///
+/// [`Clock::SCALING_FACTOR`]: crate::Clock::SCALING_FACTOR
+pub mod scaling_factor {
+    use super::*;
+
+    /// One tick of a 32.768 kHz (watch crystal) clock
+    pub const TICK_32_768_KHZ: Fraction = Fraction::new(1, 32_768);
+    /// One tick of a 1 kHz (eg. 1 ms SysTick) clock
+    pub const TICK_1_KHZ: Fraction = Fraction::new(1, 1_000);
+    /// One tick of a 1 MHz clock
+    pub const TICK_1_MHZ: Fraction = Fraction::new(1, 1_000_000);
+}
+
+/// Common periods
+pub mod period {
+    use super::*;
+
+    /// The usual SysTick interrupt period
+    pub const SYSTICK: Milliseconds<u32> = Milliseconds(1);
+}
+
+/// Common crystal/oscillator frequencies
+pub mod crystal {
+    use super::*;
+
+    /// 32.768 kHz (watch crystal)
+    pub const F_32_768_KHZ: Hertz<u32> = Hertz(32_768);
+    /// 4 MHz
+    pub const F_4_MHZ: Hertz<u32> = Hertz(4_000_000);
+    /// 8 MHz
+    pub const F_8_MHZ: Hertz<u32> = Hertz(8_000_000);
+    /// 12 MHz
+    pub const F_12_MHZ: Hertz<u32> = Hertz(12_000_000);
+    /// 16 MHz
+    pub const F_16_MHZ: Hertz<u32> = Hertz(16_000_000);
+    /// 20 MHz
+    pub const F_20_MHZ: Hertz<u32> = Hertz(20_000_000);
+    /// 24 MHz
+    pub const F_24_MHZ: Hertz<u32> = Hertz(24_000_000);
+    /// 25 MHz
+    pub const F_25_MHZ: Hertz<u32> = Hertz(25_000_000);
+    /// 26 MHz
+    pub const F_26_MHZ: Hertz<u32> = Hertz(26_000_000);
+    /// 32 MHz
+    pub const F_32_MHZ: Hertz<u32> = Hertz(32_000_000);
+    /// 40 MHz
+    pub const F_40_MHZ: Hertz<u32> = Hertz(40_000_000);
+}
+
+/// Standard serial baud rates
+pub mod baud {
+    use super::*;
+
+    /// 1200 baud
+    pub const B_1200: Baud<u32> = Baud(1_200);
+    /// 2400 baud
+    pub const B_2400: Baud<u32> = Baud(2_400);
+    /// 4800 baud
+    pub const B_4800: Baud<u32> = Baud(4_800);
+    /// 9600 baud
+    pub const B_9600: Baud<u32> = Baud(9_600);
+    /// 19200 baud
+    pub const B_19200: Baud<u32> = Baud(19_200);
+    /// 38400 baud
+    pub const B_38400: Baud<u32> = Baud(38_400);
+    /// 57600 baud
+    pub const B_57600: Baud<u32> = Baud(57_600);
+    /// 115200 baud
+    pub const B_115200: Baud<u32> = Baud(115_200);
+    /// 230400 baud
+    pub const B_230400: Baud<u32> = Baud(230_400);
+    /// 460800 baud
+    pub const B_460800: Baud<u32> = Baud(460_800);
+    /// 921600 baud
+    pub const B_921600: Baud<u32> = Baud(921_600);
+}
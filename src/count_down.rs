@@ -0,0 +1,131 @@
+//! An `embedded-hal` (0.2) `CountDown` timer backed by a [`Clock`](crate::Clock)
+
+use crate::{clock, duration, time_int::TimeInt, Instant};
+use core::convert::TryFrom;
+use embedded_hal::timer::{CountDown, Periodic};
+use num::traits::WrappingAdd;
+use void::Void;
+
+/// Implements the `embedded-hal` (0.2) [`CountDown`] and [`Periodic`] traits using any
+/// [`Clock`](crate::Clock)
+///
+/// This allows drivers written against `embedded-hal` to be driven by any `embedded-time` clock
+/// implementation. The timer automatically restarts each time it expires (without accumulating
+/// the latency of the [`CountDown::wait()`] calls).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{count_down::CountDownTimer, duration::*, Instant};
+/// # use embedded_hal::timer::CountDown;
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let mut timer = CountDownTimer::new(Clock(Cell::new(0)));
+/// timer.start(Milliseconds(10_u32));
+///
+/// timer.inner().0.set(9);
+/// assert!(timer.wait().is_err());
+///
+/// timer.inner().0.set(10);
+/// assert!(timer.wait().is_ok());
+/// ```
+#[derive(Debug)]
+pub struct CountDownTimer<Clock: crate::Clock> {
+    clock: Clock,
+    period: Clock::T,
+    start: Option<Instant<Clock>>,
+}
+
+impl<Clock: crate::Clock> CountDownTimer<Clock> {
+    /// Construct a new, stopped `CountDownTimer`
+    pub fn new(clock: Clock) -> Self {
+        Self {
+            clock,
+            period: Clock::T::from(0),
+            start: None,
+        }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the underlying clock
+    pub fn into_inner(self) -> Clock {
+        self.clock
+    }
+}
+
+impl<Clock: crate::Clock> CountDown for CountDownTimer<Clock> {
+    type Time = duration::Generic<u32>;
+
+    /// Start (or restart) the timer
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read or the count can't be represented in ticks of the clock
+    fn start<T>(&mut self, count: T)
+    where
+        T: Into<Self::Time>,
+    {
+        let count = count.into();
+        let period = count
+            .scaling_factor()
+            .checked_div(&Clock::SCALING_FACTOR)
+            .and_then(|conversion_factor| {
+                Clock::T::try_from(count.integer())
+                    .ok()?
+                    .checked_mul_fraction(&conversion_factor)
+            });
+
+        match (period, clock::try_now(&self.clock)) {
+            (Some(period), Ok(now)) => {
+                self.period = period;
+                self.start = Some(now);
+            }
+            _ => panic!("Timer start failed"),
+        }
+    }
+
+    /// Returns `Ok` once the timer has expired, restarting it
+    ///
+    /// A timer that hasn't been started never expires.
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    fn wait(&mut self) -> nb::Result<(), Void> {
+        let start = self.start.ok_or(nb::Error::WouldBlock)?;
+        let now = match clock::try_now(&self.clock) {
+            Ok(now) => now,
+            Err(_) => panic!("Clock read failed"),
+        };
+
+        match now.checked_duration_since(&start) {
+            Some(elapsed) if elapsed.integer() >= self.period => {
+                self.start = Some(Instant::new(
+                    start
+                        .duration_since_epoch()
+                        .integer()
+                        .wrapping_add(&self.period),
+                ));
+                Ok(())
+            }
+            _ => Err(nb::Error::WouldBlock),
+        }
+    }
+}
+
+impl<Clock: crate::Clock> Periodic for CountDownTimer<Clock> {}
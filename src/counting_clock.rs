@@ -0,0 +1,128 @@
+//! Instrumentation of clock reads
+
+use crate::{duration, fraction::Fraction, Instant};
+use core::cell::Cell;
+
+/// A [`Clock`](crate::Clock) wrapper counting reads and tracking the intervals between them
+///
+/// This helps find busy-wait hot spots and check that drivers aren't reading the timer (eg. over a
+/// slow bus) more often than expected. The interval between two reads is the difference of their
+/// readings, reads that fail or go backwards are counted but not used as intervals.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{counting_clock::CountingClock, duration::*, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     self.0.set(self.0.get() + 2);
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// // (each read of `Clock` advances it by 2 ms)
+/// let clock = CountingClock::new(Clock(Cell::new(0)));
+/// for _ in 0..3 {
+///     clock.try_now().unwrap();
+/// }
+///
+/// assert_eq!(clock.reads(), 3);
+/// assert_eq!(clock.min_interval(), Some(Generic::new(2_u32, Fraction::new(1, 1_000))));
+/// ```
+#[derive(Debug)]
+pub struct CountingClock<Clock: crate::Clock> {
+    clock: Clock,
+    reads: Cell<u32>,
+    latest: Cell<Option<Clock::T>>,
+    min_interval: Cell<Option<Clock::T>>,
+    max_interval: Cell<Option<Clock::T>>,
+}
+
+impl<Clock: crate::Clock> CountingClock<Clock> {
+    /// Wrap a clock
+    pub fn new(clock: Clock) -> Self {
+        Self {
+            clock,
+            reads: Cell::new(0),
+            latest: Cell::new(None),
+            min_interval: Cell::new(None),
+            max_interval: Cell::new(None),
+        }
+    }
+
+    /// Returns the number of reads (saturating at [`u32::MAX`])
+    pub fn reads(&self) -> u32 {
+        self.reads.get()
+    }
+
+    /// Returns the shortest interval between two consecutive reads
+    pub fn min_interval(&self) -> Option<duration::Generic<Clock::T>> {
+        self.min_interval
+            .get()
+            .map(|ticks| duration::Generic::new(ticks, Clock::SCALING_FACTOR))
+    }
+
+    /// Returns the longest interval between two consecutive reads
+    pub fn max_interval(&self) -> Option<duration::Generic<Clock::T>> {
+        self.max_interval
+            .get()
+            .map(|ticks| duration::Generic::new(ticks, Clock::SCALING_FACTOR))
+    }
+
+    /// Clear the read count and intervals
+    pub fn reset(&self) {
+        self.reads.set(0);
+        self.latest.set(None);
+        self.min_interval.set(None);
+        self.max_interval.set(None);
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the underlying clock
+    pub fn into_inner(self) -> Clock {
+        self.clock
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for CountingClock<Clock> {
+    type T = Clock::T;
+    type Error = Clock::Error;
+    const SCALING_FACTOR: Fraction = Clock::SCALING_FACTOR;
+
+    /// Get the current Instant, recording the read
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the underlying clock
+    fn try_now(&self) -> Result<Instant<Self>, Clock::Error> {
+        self.reads.set(self.reads.get().saturating_add(1));
+
+        let now = Instant::<Self>::new(self.clock.try_now()?.duration_since_epoch().integer());
+
+        if let Some(latest) = self.latest.get().map(Instant::<Self>::new) {
+            if let Some(interval) = now.checked_duration_since(&latest) {
+                let interval = interval.integer();
+                if self.min_interval.get().is_none_or(|min| interval < min) {
+                    self.min_interval.set(Some(interval));
+                }
+                if self.max_interval.get().is_none_or(|max| interval > max) {
+                    self.max_interval.set(Some(interval));
+                }
+            }
+        }
+
+        self.latest.set(Some(now.duration_since_epoch().integer()));
+        Ok(now)
+    }
+}
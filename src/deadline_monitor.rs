@@ -0,0 +1,160 @@
+//! Software watchdog monitoring periodic task check-ins
+
+use crate::{clock, duration::Duration, fixed_point::FixedPoint, Instant, TimeError};
+use core::convert::TryFrom;
+
+/// A software watchdog for up to `N` tasks
+///
+/// Each task is registered with the maximum interval allowed between its check-ins. The tasks
+/// then periodically call [`DeadlineMonitor::check_in()`] while a supervisor calls
+/// [`DeadlineMonitor::poll()`] to find out which tasks have missed their deadline.
+///
+/// Task ids are indices in the range `0..N`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, duration::*, deadline_monitor::DeadlineMonitor, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let clock = Clock(Cell::new(0));
+/// let mut monitor = DeadlineMonitor::<_, 2>::new(&clock);
+///
+/// monitor.register(0, Milliseconds(100_u32)).unwrap();
+/// monitor.register(1, Seconds(1_u32)).unwrap();
+///
+/// // only task 0 has missed its deadline
+/// assert!(monitor.poll(Instant::new(150)).eq(core::iter::once(0)));
+///
+/// // until it checks in again
+/// clock.0.set(150);
+/// monitor.check_in(0).unwrap();
+/// assert_eq!(monitor.poll(Instant::new(150)).next(), None);
+/// ```
+#[derive(Debug)]
+pub struct DeadlineMonitor<'a, Clock: crate::Clock, const N: usize> {
+    clock: &'a Clock,
+    tasks: [Option<Task<Clock>>; N],
+}
+
+#[derive(Debug)]
+struct Task<Clock: crate::Clock> {
+    interval: Clock::T,
+    last_check_in: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Copy for Task<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Task<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> Task<Clock> {
+    fn is_missed(&self, now: Instant<Clock>) -> bool {
+        match now.checked_duration_since(&self.last_check_in) {
+            Some(elapsed) => elapsed.integer() > self.interval,
+            None => false,
+        }
+    }
+}
+
+impl<'a, Clock: crate::Clock, const N: usize> DeadlineMonitor<'a, Clock, N> {
+    /// Construct a new `DeadlineMonitor` with no registered tasks
+    pub fn new(clock: &'a Clock) -> Self {
+        Self {
+            clock,
+            tasks: [None; N],
+        }
+    }
+
+    /// Start monitoring the task with the given id
+    ///
+    /// The first deadline is `interval` from now. Registering an already registered task replaces
+    /// its interval and restarts its deadline.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    /// - [`TimeError::ConversionFailure`], [`TimeError::Overflow`], [`TimeError::Unspecified`] :
+    ///   The interval can't be represented in ticks of the clock
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not less than `N`
+    pub fn register<Dur>(&mut self, id: usize, interval: Dur) -> Result<(), TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let interval = interval.into_ticks(Clock::SCALING_FACTOR)?;
+        self.tasks[id] = Some(Task {
+            interval,
+            last_check_in: clock::try_now(self.clock)?,
+        });
+
+        Ok(())
+    }
+
+    /// Stop monitoring the task with the given id
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not less than `N`
+    pub fn deregister(&mut self, id: usize) {
+        self.tasks[id] = None;
+    }
+
+    /// Record that the task with the given id is alive, starting its next interval
+    ///
+    /// Checking in an unregistered task has no effect.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not less than `N`
+    pub fn check_in(&mut self, id: usize) -> Result<(), TimeError> {
+        if let Some(task) = &mut self.tasks[id] {
+            task.last_check_in = clock::try_now(self.clock)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the task with the given id has missed its deadline as of `now`
+    ///
+    /// Unregistered tasks never miss a deadline.
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not less than `N`
+    pub fn is_missed(&self, id: usize, now: Instant<Clock>) -> bool {
+        self.tasks[id].is_some_and(|task| task.is_missed(now))
+    }
+
+    /// Returns the ids of all tasks that have missed their deadline as of `now`
+    pub fn poll(&self, now: Instant<Clock>) -> impl Iterator<Item = usize> + '_ {
+        self.tasks
+            .iter()
+            .enumerate()
+            .filter_map(move |(id, task)| match task {
+                Some(task) if task.is_missed(now) => Some(id),
+                _ => None,
+            })
+    }
+}
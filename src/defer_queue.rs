@@ -0,0 +1,155 @@
+//! Delayed delivery of items
+
+use crate::{duration::Duration, fixed_point::FixedPoint, Instant};
+use core::{convert::TryFrom, iter};
+
+/// A failure to push an item, which is handed back
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Error<T> {
+    /// The queue is full
+    Full(T),
+    /// The delay is longer than half the range of the clock (see [`Instant::checked_add()`])
+    Overflow(T),
+}
+
+/// A queue of up to `N` items, each delivered once its delay has elapsed
+///
+/// Items are kept in deadline order and items with the same deadline are delivered in the order
+/// they were pushed. Deadlines are compared with wrap-aware math, so all the pending deadlines
+/// must be within half the range of the clock of each other.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{defer_queue::DeferQueue, duration::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Message {
+///     LedOff,
+///     Retransmit,
+/// }
+///
+/// let mut queue = DeferQueue::<Clock, Message, 4>::new();
+/// queue.push(Instant::new(0), Milliseconds(500_u32), Message::LedOff).unwrap();
+/// queue.push(Instant::new(0), Milliseconds(200_u32), Message::Retransmit).unwrap();
+/// assert_eq!(queue.next_deadline(), Some(Instant::new(200)));
+///
+/// assert_eq!(queue.pop_ready(Instant::new(100)).next(), None);
+/// assert!(queue.pop_ready(Instant::new(600)).eq([Message::Retransmit, Message::LedOff]));
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct DeferQueue<Clock: crate::Clock, T, const N: usize> {
+    // sorted by deadline, the first `len` slots are occupied
+    items: [Option<(Instant<Clock>, T)>; N],
+    len: usize,
+}
+
+impl<Clock: crate::Clock, T, const N: usize> DeferQueue<Clock, T, N> {
+    /// Construct an empty `DeferQueue`
+    pub fn new() -> Self {
+        Self {
+            items: core::array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    /// Push an item to be delivered `delay` after `now`
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Full`] : The queue is full
+    /// - [`Error::Overflow`] : The delay can't be added to `now`
+    pub fn push<Dur>(&mut self, now: Instant<Clock>, delay: Dur, item: T) -> Result<(), Error<T>>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        match now.checked_add(delay) {
+            Some(deadline) => self.push_at(deadline, item),
+            None => Err(Error::Overflow(item)),
+        }
+    }
+
+    /// Push an item to be delivered at the `deadline`
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Full`] : The queue is full
+    pub fn push_at(&mut self, deadline: Instant<Clock>, item: T) -> Result<(), Error<T>> {
+        if self.len == N {
+            return Err(Error::Full(item));
+        }
+
+        // after the items with the same deadline
+        let index = self.items[..self.len]
+            .iter()
+            .flatten()
+            .position(|(pending, _)| deadline.checked_duration_since(pending).is_none())
+            .unwrap_or(self.len);
+
+        self.items[self.len] = Some((deadline, item));
+        self.items[index..=self.len].rotate_right(1);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Returns an iterator removing the items whose deadline is at or before `now`, in deadline
+    /// order
+    ///
+    /// Items not consumed from the iterator remain in the queue.
+    pub fn pop_ready(&mut self, now: Instant<Clock>) -> impl Iterator<Item = T> + '_ {
+        iter::from_fn(move || match self.next_deadline() {
+            Some(deadline) if now.checked_duration_since(&deadline).is_some() => self.pop(),
+            _ => None,
+        })
+    }
+
+    /// Returns the earliest pending deadline
+    pub fn next_deadline(&self) -> Option<Instant<Clock>> {
+        self.items
+            .first()
+            .and_then(Option::as_ref)
+            .map(|(deadline, _)| *deadline)
+    }
+
+    /// Returns the number of pending items
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no pending items
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Discard all pending items
+    pub fn clear(&mut self) {
+        self.items.iter_mut().for_each(|item| *item = None);
+        self.len = 0;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let (_, item) = self.items.first_mut()?.take()?;
+        self.items[..self.len].rotate_left(1);
+        self.len -= 1;
+
+        Some(item)
+    }
+}
+
+impl<Clock: crate::Clock, T, const N: usize> Default for DeferQueue<Clock, T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,151 @@
+//! An `embedded-hal` (1.0) `DelayNs` implementation backed by a [`Clock`](crate::Clock)
+
+use crate::{clock, fraction::Fraction, Instant};
+use core::convert::TryFrom;
+use embedded_hal_1::delay::DelayNs;
+
+/// The longest wait (in clock ticks) before re-reading the start instant
+///
+/// Elapsed time is only valid for less than half the range of the clock's integer type, so longer
+/// delays are made of several waits. Clocks with a narrower integer type use a shorter limit (see
+/// `Delay::max_wait_ticks()`).
+const MAX_WAIT_TICKS: u64 = 1 << 30;
+
+/// Implements the `embedded-hal` (1.0) [`DelayNs`] trait by busy-waiting on a
+/// [`Clock`](crate::Clock)
+///
+/// This allows a single clock to serve both scheduling and blocking delays. Delays are rounded up
+/// to a whole number of clock ticks and are never shorter than requested.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{delay::Delay, fraction::Fraction, Instant};
+/// # use embedded_hal_1::delay::DelayNs;
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     // each read advances the clock by 1 μs
+/// #     self.0.set(self.0.get() + 1);
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let mut delay = Delay::new(Clock(Cell::new(0)));
+/// delay.delay_ms(2);
+///
+/// assert!(delay.inner().0.get() >= 2_000);
+/// ```
+#[derive(Debug)]
+pub struct Delay<Clock: crate::Clock> {
+    clock: Clock,
+}
+
+impl<Clock: crate::Clock> Delay<Clock> {
+    /// Wrap a clock
+    pub fn new(clock: Clock) -> Self {
+        Self { clock }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the underlying clock
+    pub fn into_inner(self) -> Clock {
+        self.clock
+    }
+
+    /// Block for `value` × `unit` seconds
+    fn delay(&self, value: u32, unit: Fraction) {
+        // u32 × u32 × u32 always fits in a u128
+        let numerator = u128::from(value)
+            * u128::from(*unit.numerator())
+            * u128::from(*Clock::SCALING_FACTOR.denominator());
+        let denominator =
+            u128::from(*unit.denominator()) * u128::from(*Clock::SCALING_FACTOR.numerator());
+
+        let mut ticks = u64::try_from(numerator.div_ceil(denominator)).unwrap_or(u64::MAX);
+
+        let max_wait = Self::max_wait_ticks();
+
+        while ticks > 0 {
+            let wait = ticks.min(max_wait);
+            self.wait_ticks(wait);
+            ticks -= wait;
+        }
+    }
+
+    /// The longest wait (in clock ticks) that is less than half the range of the clock's integer
+    /// type
+    fn max_wait_ticks() -> u64 {
+        let mut max_wait = MAX_WAIT_TICKS;
+
+        while Clock::T::try_from((max_wait * 2) as u32).is_err() {
+            max_wait /= 2;
+        }
+
+        max_wait
+    }
+
+    /// Block until more than `ticks` ticks have elapsed
+    ///
+    /// Waiting for _more_ than `ticks` ensures the delay is not shortened by reading the start
+    /// instant late in a tick.
+    fn wait_ticks(&self, ticks: u64) {
+        // the max_wait_ticks() limit ensures this fits in the clock's integer type
+        let ticks = match Clock::T::try_from(ticks as u32) {
+            Ok(ticks) => ticks,
+            Err(_) => return,
+        };
+        let start = self.now();
+
+        while !matches!(
+            self.now().checked_duration_since(&start),
+            Some(elapsed) if elapsed.integer() > ticks
+        ) {}
+    }
+
+    fn now(&self) -> Instant<Clock> {
+        match clock::try_now(&self.clock) {
+            Ok(now) => now,
+            Err(_) => panic!("Clock read failed"),
+        }
+    }
+}
+
+impl<Clock: crate::Clock> DelayNs for Delay<Clock> {
+    /// Delays for (at least) the given number of nanoseconds
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    fn delay_ns(&mut self, ns: u32) {
+        self.delay(ns, Fraction::new(1, 1_000_000_000));
+    }
+
+    /// Delays for (at least) the given number of microseconds
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    fn delay_us(&mut self, us: u32) {
+        self.delay(us, Fraction::new(1, 1_000_000));
+    }
+
+    /// Delays for (at least) the given number of milliseconds
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    fn delay_ms(&mut self, ms: u32) {
+        self.delay(ms, Fraction::new(1, 1_000));
+    }
+}
@@ -0,0 +1,90 @@
+//! Inter-arrival durations of a sequence of instants
+
+use crate::{duration, Instant, TimeError};
+
+/// Returns an iterator over the durations between consecutive [`Instant`]s
+///
+/// Each duration is the time elapsed from one instant to the next, accounting for counter
+/// wrap-around (see [`Instant::checked_duration_since()`]). A sequence of `n` instants yields
+/// `n - 1` durations.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{deltas::deltas, duration::*, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // the counter wraps between the second and third instants
+/// let instants = [Instant::<Clock>::new(u32::MAX - 2), Instant::new(u32::MAX), Instant::new(4)];
+/// let mut deltas = deltas(instants.iter().copied())
+///     .map(|delta| Milliseconds::<u32>::try_from(delta.unwrap()).unwrap());
+///
+/// assert_eq!(deltas.next(), Some(Milliseconds(2_u32)));
+/// assert_eq!(deltas.next(), Some(Milliseconds(5_u32)));
+/// assert_eq!(deltas.next(), None);
+/// ```
+///
+/// # Errors
+///
+/// Each item is a [`TimeError::NegDuration`] if an instant is earlier than the one before it.
+pub fn deltas<Clock, Instants>(instants: Instants) -> Deltas<Clock, Instants::IntoIter>
+where
+    Clock: crate::Clock,
+    Instants: IntoIterator<Item = Instant<Clock>>,
+{
+    Deltas {
+        instants: instants.into_iter(),
+        previous: None,
+    }
+}
+
+/// An iterator over the durations between consecutive [`Instant`]s
+///
+/// Created by [`deltas()`].
+#[derive(Debug)]
+pub struct Deltas<Clock: crate::Clock, Instants> {
+    instants: Instants,
+    previous: Option<Instant<Clock>>,
+}
+
+impl<Clock, Instants> Iterator for Deltas<Clock, Instants>
+where
+    Clock: crate::Clock,
+    Instants: Iterator<Item = Instant<Clock>>,
+{
+    type Item = Result<duration::Generic<Clock::T>, TimeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous {
+            Some(previous) => previous,
+            None => self.instants.next()?,
+        };
+        let next = self.instants.next()?;
+        self.previous = Some(next);
+
+        Some(
+            next.checked_duration_since(&previous)
+                .ok_or(TimeError::NegDuration),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.instants.size_hint();
+        match self.previous {
+            Some(_) => (lower, upper),
+            None => (
+                lower.saturating_sub(1),
+                upper.map(|upper| upper.saturating_sub(1)),
+            ),
+        }
+    }
+}
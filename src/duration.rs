@@ -2,20 +2,23 @@
 
 pub use crate::fraction::Fraction;
 use crate::{
+    duty_cycle::DutyCycle,
     fixed_point::{self, FixedPoint},
     rate,
     time_int::TimeInt,
-    ConversionError,
+    ConversionError, ParseError,
 };
 use core::{
     convert::TryFrom,
+    fmt,
     hash::{Hash, Hasher},
+    iter,
     mem::size_of,
-    prelude::v1::*,
+    ops,
 };
 #[doc(hidden)]
 pub use fixed_point::FixedPoint as _;
-use num::{CheckedDiv, CheckedMul};
+use num::{CheckedAdd, CheckedDiv, CheckedMul, Integer};
 #[doc(inline)]
 pub use units::*;
 
@@ -33,6 +36,61 @@ pub use units::*;
 /// let millis = 5_u32.milliseconds();
 /// ```
 ///
+/// The `ZERO`, `MIN` and `MAX` constants are available for each _integer_ type:
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// const TIMEOUTS: [Milliseconds<u16>; 2] = [Milliseconds::<u16>::ZERO, Milliseconds::<u16>::MAX];
+///
+/// assert_eq!(TIMEOUTS, [Milliseconds(0_u16), Milliseconds(u16::MAX)]);
+/// assert_eq!(Seconds::<u64>::MIN, Seconds(0_u64));
+/// ```
+///
+/// The constructors are `const fn`, so durations can live in `static` configuration tables:
+///
+/// ```rust
+/// use embedded_time::{duration::*, fraction::Fraction};
+///
+/// static DEBOUNCE: [Milliseconds; 2] = [Milliseconds::new(5), Milliseconds::new(50)];
+/// static TICK: Generic<u32> = Generic::new(1, Fraction::new(1, 32_768));
+///
+/// assert_eq!(DEBOUNCE[1], Milliseconds(50_u32));
+/// assert_eq!(TICK.integer(), 1);
+/// ```
+///
+/// Values of a wider (or any) primitive integer type (read from a register or a protocol, for
+/// example) are range-checked rather than cast:
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+/// use core::convert::TryFrom;
+///
+/// assert_eq!(Milliseconds::<u16>::try_from_u32(1_000), Ok(Milliseconds(1_000_u16)));
+/// assert_eq!(Milliseconds::<u16>::try_from_u32(70_000), Err(ConversionError::ConversionFailure));
+/// assert_eq!(Seconds::<u8>::try_from(300_u64), Err(ConversionError::ConversionFailure));
+/// ```
+///
+/// # Constructing from another unit
+///
+/// A value in seconds, milliseconds, microseconds or nanoseconds (from a config value, for example)
+/// can be converted directly into any duration type. The scaling is done with checked math and
+/// the result is either truncated (rounded toward `0`) or rounded to the nearest integer (halves
+/// round up).
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+///
+/// assert_eq!(Milliseconds::<u32>::try_from_secs(23_u32), Ok(Milliseconds(23_000_u32)));
+/// assert_eq!(Seconds::<u32>::try_from_millis(2_600_u64), Ok(Seconds(2_u32)));
+/// assert_eq!(Seconds::<u32>::try_from_millis_rounded(2_600_u64), Ok(Seconds(3_u32)));
+///
+/// assert_eq!(
+///     Nanoseconds::<u32>::try_from_secs(5_u32),
+///     Err(ConversionError::ConversionFailure)
+/// );
+/// ```
+///
 /// # Get the integer part
 ///
 /// ```rust
@@ -53,6 +111,41 @@ pub use units::*;
 /// assert_eq!(format!("{}", Seconds(123_u32)), "123");
 /// ```
 ///
+/// [`Duration::display_hms()`] formats any duration as `HH:MM:SS.fraction`:
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(format!("{}", Milliseconds(5_025_678_u32).display_hms()), "01:23:45.678");
+/// ```
+///
+/// [`Duration::display_uptime()`] formats it as `Nd HH:MM:SS`:
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(format!("{}", Seconds(274_376_u32).display_uptime()), "3d 04:12:56");
+/// ```
+///
+/// # Parsing
+///
+/// Durations are parsed from a number followed by a unit (`ns`, `us` or `µs`, `ms`, `s`, `m` or
+/// `min`, `h`, `d`, `w`), or from several of them (eg. `"1h30m"`), which are added. The numbers may
+/// have decimals and whitespace is allowed around the units. The result must be a whole number of
+/// ticks.
+///
+/// ```rust
+/// use embedded_time::{duration::*, ParseError};
+///
+/// assert_eq!("250ms".parse(), Ok(Milliseconds(250_u32)));
+/// assert_eq!("1h30m".parse(), Ok(Minutes(90_u32)));
+/// assert_eq!("2.5 s".parse(), Ok(Milliseconds(2_500_u32)));
+///
+/// assert_eq!("2.5s".parse::<Seconds<u32>>(), Err(ParseError::Inexact));
+/// assert_eq!("2.5 fortnights".parse::<Seconds<u32>>(), Err(ParseError::UnknownUnit));
+/// assert_eq!("300s".parse::<Seconds<u8>>(), Err(ParseError::Overflow));
+/// ```
+///
 /// # Getting H:M:S.MS... Components
 ///
 /// ```rust
@@ -240,6 +333,10 @@ pub use units::*;
 ///
 /// See [`Duration::to_rate()`]
 ///
+/// # Creating a custom `Duration`
+///
+/// See [`duration_unit!`](crate::duration_unit!)
+///
 /// # Add/Sub
 ///
 /// The result of the operation is the LHS type
@@ -256,6 +353,18 @@ pub use units::*;
 ///     Milliseconds(1_001_u32));
 /// ```
 ///
+/// A [`Generic`] `Duration` may have any `Duration` added or subtracted, the result has the
+/// _scaling factor_ of the LHS:
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// let duration = Generic::new(3_u32, Fraction::new(1, 2));
+///
+/// assert_eq!(duration + Seconds(1_u32), Generic::new(5_u32, Fraction::new(1, 2)));
+/// assert_eq!(duration - Milliseconds(500_u32), Seconds(1_u32).into());
+/// ```
+///
 /// ## Panics
 ///
 /// The same reason the integer operation would panic. Namely, if the result overflows the type.
@@ -266,11 +375,44 @@ pub use units::*;
 /// let _ = Seconds(u32::MAX) + Seconds(1_u32);
 /// ```
 ///
+/// The checked equivalents, [`FixedPoint::checked_add()`] and [`FixedPoint::checked_sub()`],
+/// return a [`ConversionError`] instead.
+///
+/// # Sum
+///
+/// Iterators of durations (or references to them) can be summed, the result is of the item type.
+/// As with addition, the sum panics upon overflow. [`Duration::try_sum()`] returns a
+/// [`ConversionError`] instead.
+///
+/// ```rust
+/// use embedded_time::{duration::*, ConversionError};
+///
+/// let latencies = [Microseconds(250_u32), Microseconds(1_000), Microseconds(4)];
+///
+/// assert_eq!(latencies.iter().sum::<Microseconds<u32>>(), Microseconds(1_254_u32));
+/// assert_eq!(
+///     Microseconds::try_sum(latencies.iter().copied().chain([Microseconds(u32::MAX)])),
+///     Err(ConversionError::Overflow)
+/// );
+/// ```
+///
 /// # Mul/Div
 ///
 /// Durations may also be multiplied and divided by integers. The result is of the LHS type. Both
 /// _panicky_ and _checked_ operations are available.
 ///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(10_u32.milliseconds() * 3, Milliseconds(30_u32));
+/// assert_eq!(Seconds(10_u32) / 4, Seconds(2_u32));
+/// assert_eq!(Generic::new(10_u32, Fraction::new(1, 1_000)) * 3, Milliseconds(30_u32).into());
+///
+/// assert_eq!(Seconds(u32::MAX).checked_mul(&2), Err(ConversionError::Overflow));
+/// assert_eq!(Seconds(10_u32).checked_div(&0), Err(ConversionError::DivByZero));
+/// # use embedded_time::{fixed_point::FixedPoint, ConversionError};
+/// ```
+///
 /// # Comparisons
 ///
 /// ```rust
@@ -283,12 +425,42 @@ pub use units::*;
 /// assert!(Seconds(2_u32) > Milliseconds(1_999_u32));
 /// ```
 ///
+/// [`Generic`] `Duration`s are compared by value, whatever their _scaling factors_:
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// assert_eq!(Generic::new(3_u32, Fraction::new(1, 2)), Generic::new(1_500_u32, Fraction::new(1, 1_000)));
+/// assert!(Generic::new(1_u32, Fraction::new(1, 3)) > Generic::new(333_u32, Fraction::new(1, 1_000)));
+/// ```
+///
+/// # Min/Max/Clamp
+///
+/// The _named_ `Duration` types have `min()`, `max()` and `clamp()` methods accepting any `Duration`
+/// type that they can be compared to. They shadow the [`Ord`] methods (which only accept the same
+/// type) and the result is of the LHS type (truncated when converted from a finer unit). As with
+/// Add/Sub, they panic if the result doesn't fit in the LHS type.
+///
+/// ```rust
+/// use embedded_time::duration::*;
+///
+/// let timeout = Milliseconds(5_u32);
+/// assert_eq!(timeout.clamp(Milliseconds(10_u32), Seconds(1_u32)), Milliseconds(10_u32));
+/// assert_eq!(Milliseconds(1_500_u32).clamp(10_u32.milliseconds(), 1_u32.seconds()), Milliseconds(1_000_u32));
+///
+/// assert_eq!(Seconds(2_u32).min(Milliseconds(1_999_u32)), Seconds(1_u32));
+/// assert_eq!(Milliseconds(2_u32).max(Microseconds(2_001_u32)), Milliseconds(2_u32));
+///
+/// assert!(Nanoseconds(0_u64).is_zero());
+/// ```
+///
 /// # Remainder
 ///
 /// ```rust
 /// use embedded_time::duration::*;
 ///
 /// assert_eq!(Minutes(62_u32) % Hours(1_u32), Minutes(2_u32));
+/// assert_eq!(Generic::new(62_u32, Fraction::new(60, 1)) % Hours(1_u32), Minutes(2_u32).into());
 /// ```
 pub trait Duration: Sized + Copy {
     /// Construct a `Generic` `Duration` from a _named_ `Duration` (eg.
@@ -335,11 +507,12 @@ pub trait Duration: Sized + Copy {
     /// assert_eq!(Seconds(u32::MAX as u64 + 1).to_generic::<u32>(Fraction::new(1, 1)),
     ///     Err(ConversionError::ConversionFailure));
     /// ```
-    fn to_generic<DestInt: TimeInt>(
+    fn to_generic<DestInt>(
         self,
         scaling_factor: Fraction,
     ) -> Result<Generic<DestInt>, ConversionError>
     where
+        DestInt: TimeInt,
         Self: FixedPoint,
         DestInt: TryFrom<Self::T>,
     {
@@ -393,24 +566,26 @@ pub trait Duration: Sized + Copy {
     ///     Err(ConversionError::DivByZero)
     /// );
     /// ```
-    fn to_rate<Rate: rate::Rate>(&self) -> Result<Rate, ConversionError>
+    fn to_rate<Rate>(&self) -> Result<Rate, ConversionError>
     where
-        Rate: FixedPoint,
+        Rate: rate::Rate + FixedPoint,
         Self: FixedPoint,
         Rate::T: TryFrom<Self::T>,
     {
-        let conversion_factor = Self::SCALING_FACTOR
-            .checked_mul(&Rate::SCALING_FACTOR)
-            .ok_or(ConversionError::Unspecified)?
-            .recip();
+        let (numerator, denominator) =
+            fixed_point::reciprocal_product(Self::SCALING_FACTOR, Rate::SCALING_FACTOR);
 
         if size_of::<Self::T>() >= size_of::<Rate::T>() {
             fixed_point::FixedPoint::from_ticks(
-                Self::T::from(*conversion_factor.numerator())
+                fixed_point::int_from_u64::<Self::T>(numerator)
+                    .ok_or(ConversionError::Overflow)?
                     .checked_div(
                         &self
                             .integer()
-                            .checked_mul(&Self::T::from(*conversion_factor.denominator()))
+                            .checked_mul(
+                                &fixed_point::int_from_u64::<Self::T>(denominator)
+                                    .ok_or(ConversionError::Overflow)?,
+                            )
                             .ok_or(ConversionError::Overflow)?,
                     )
                     .ok_or(ConversionError::DivByZero)?,
@@ -418,11 +593,15 @@ pub trait Duration: Sized + Copy {
             )
         } else {
             fixed_point::FixedPoint::from_ticks(
-                Rate::T::from(*conversion_factor.numerator())
+                fixed_point::int_from_u64::<Rate::T>(numerator)
+                    .ok_or(ConversionError::Overflow)?
                     .checked_div(
                         &Rate::T::try_from(self.integer())
                             .map_err(|_| ConversionError::Overflow)?
-                            .checked_mul(&Rate::T::from(*conversion_factor.denominator()))
+                            .checked_mul(
+                                &fixed_point::int_from_u64::<Rate::T>(denominator)
+                                    .ok_or(ConversionError::Overflow)?,
+                            )
                             .ok_or(ConversionError::Overflow)?,
                     )
                     .ok_or(ConversionError::DivByZero)?,
@@ -430,6 +609,192 @@ pub trait Duration: Sized + Copy {
             )
         }
     }
+
+    /// Returns the exact (reduced) ratio of this duration to another, of any _scaling factor_
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, ConversionError};
+    ///
+    /// // how many 20 ms periods fit in 1.23 seconds
+    /// let ratio = Microseconds(1_230_000_u32).div_duration(Milliseconds(20_u32)).unwrap();
+    ///
+    /// assert_eq!(ratio, Fraction::new(123, 2));
+    /// assert_eq!(ratio.to_integer(), 61);
+    ///
+    /// assert_eq!(Seconds(1_u32).div_duration(Seconds(0_u32)), Err(ConversionError::DivByZero));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : `other` is zero
+    /// - [`ConversionError::Overflow`] : The ratio doesn't fit in a [`Fraction`]
+    fn div_duration<Other>(&self, other: Other) -> Result<Fraction, ConversionError>
+    where
+        Other: Duration + FixedPoint,
+        Self: FixedPoint,
+        u128: From<Self::T> + From<Other::T>,
+    {
+        fixed_point::ratio(self, &other)
+    }
+
+    /// Returns the number of cycles of `rate` that occur in this duration (truncated)
+    ///
+    /// This is eg. the number of samples to fill a buffer with or the reload value of a timer.
+    /// The intermediate math is widened to [`u128`]. The same product as [`Rate::count_in()`],
+    /// called on the duration.
+    ///
+    /// [`Rate::count_in()`]: rate::Rate::count_in()
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, rate::*, ConversionError};
+    ///
+    /// // 20 ms of audio sampled at 44.1 kHz
+    /// assert_eq!(Milliseconds(20_u32).cycles_at(Hertz(44_100_u32)), Ok(882));
+    /// assert_eq!(Microseconds(1_u32).cycles_at(Hertz(999_999_u32)), Ok(0));
+    ///
+    /// assert_eq!(
+    ///     Hours(u64::MAX).cycles_at(Megahertz(u32::MAX)),
+    ///     Err(ConversionError::Overflow)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The count doesn't fit in a [`u64`]
+    fn cycles_at<Rate>(&self, rate: Rate) -> Result<u64, ConversionError>
+    where
+        Rate: rate::Rate + FixedPoint<Dimension = fixed_point::dimension::Frequency>,
+        Self: FixedPoint<Dimension = fixed_point::dimension::Time>,
+        u128: From<Self::T> + From<Rate::T>,
+    {
+        self.checked_product(&rate).ok_or(ConversionError::Overflow)
+    }
+
+    /// Checked sum of durations, `0` if there are none
+    ///
+    /// See [Sum](trait.Duration.html#sum)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The sum doesn't fit in the _integer_ type
+    fn try_sum<Durations>(durations: Durations) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Durations: IntoIterator<Item = Self>,
+    {
+        durations
+            .into_iter()
+            .try_fold(Self::new(Self::T::from(0)), |sum, duration| {
+                sum.integer()
+                    .checked_add(&duration.integer())
+                    .map(Self::new)
+                    .ok_or(ConversionError::Overflow)
+            })
+    }
+
+    /// Returns whether the duration is zero
+    ///
+    /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+    fn is_zero(&self) -> bool
+    where
+        Self: FixedPoint,
+    {
+        self.integer() == Self::T::from(0)
+    }
+
+    /// Returns an adapter [`Display`](fmt::Display)ing the duration as `HH:MM:SS.fraction`
+    ///
+    /// See [`Hms`]
+    fn display_hms(&self) -> Hms
+    where
+        Self: FixedPoint,
+        u128: From<Self::T>,
+    {
+        Hms::new(u128::from(self.integer()), Self::SCALING_FACTOR)
+    }
+
+    /// Returns an adapter [`Display`](fmt::Display)ing the duration as an uptime, `Nd HH:MM:SS`
+    ///
+    /// See [`Uptime`]
+    fn display_uptime(&self) -> Uptime
+    where
+        Self: FixedPoint,
+        u128: From<Self::T>,
+    {
+        Uptime::new(u128::from(self.integer()), Self::SCALING_FACTOR)
+    }
+
+    /// Returns the duration as a floating-point number of seconds
+    ///
+    /// The result is rounded to the nearest representable [`f64`], so it's exact for up to
+    /// 2<sup>53</sup> ticks of a whole-number _scaling factor_.
+    ///
+    /// ```rust
+    /// # use embedded_time::duration::*;
+    /// assert_eq!(Milliseconds(2_500_u32).to_secs_f64(), 2.5);
+    /// assert_eq!(Milliseconds::<u32>::try_from_secs_f32(0.25), Ok(Milliseconds(250_u32)));
+    /// ```
+    #[cfg(feature = "float")]
+    fn to_secs_f64(&self) -> f64
+    where
+        Self: FixedPoint,
+        u128: From<Self::T>,
+    {
+        fixed_point::to_f64(u128::from(self.integer()), Self::SCALING_FACTOR)
+    }
+
+    /// Returns the duration as a floating-point number of seconds
+    ///
+    /// The result is rounded to the nearest representable [`f32`] (by way of an [`f64`]).
+    #[cfg(feature = "float")]
+    fn to_secs_f32(&self) -> f32
+    where
+        Self: FixedPoint,
+        u128: From<Self::T>,
+    {
+        self.to_secs_f64() as f32
+    }
+
+    /// Construct from a floating-point number of seconds
+    ///
+    /// The value is truncated to whole ticks (rounded toward `0`).
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`] : The value is negative, NaN or doesn't fit in the
+    /// _integer_ type
+    #[cfg(feature = "float")]
+    fn try_from_secs_f64(secs: f64) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = fixed_point::from_f64(secs, Self::SCALING_FACTOR)?;
+
+        <Self::T as TryFrom<u128>>::try_from(ticks)
+            .map(Self::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+
+    /// Construct from a floating-point number of seconds
+    ///
+    /// The [`f32`] is converted exactly to an [`f64`], see [`Duration::try_from_secs_f64()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Duration::try_from_secs_f64()`]
+    #[cfg(feature = "float")]
+    fn try_from_secs_f32(secs: f32) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        Self::try_from_secs_f64(f64::from(secs))
+    }
 }
 
 /// The `Generic` `Duration` type allows an arbitrary _scaling factor_ to be used without having to
@@ -437,19 +802,51 @@ pub trait Duration: Sized + Copy {
 ///
 /// The purpose of this type is to allow a simple `Duration` object that can be defined at run-time.
 /// It does this by replacing the `const` _scaling factor_ with a struct field.
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Default)]
 pub struct Generic<T> {
     integer: T,
     scaling_factor: Fraction,
 }
 
+/// Serialized as an `(integer, numerator, denominator)` tuple
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Generic<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            &self.integer,
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator(),
+        )
+            .serialize(serializer)
+    }
+}
+
+/// Deserialized from an `(integer, numerator, denominator)` tuple, a denominator of `0` is
+/// rejected
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Generic<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (integer, numerator, denominator) = <(T, u32, u32)>::deserialize(deserializer)?;
+        if denominator == 0 {
+            return Err(serde::de::Error::custom("Invalid scaling factor"));
+        }
+
+        Ok(Self {
+            integer,
+            scaling_factor: Fraction::new(numerator, denominator),
+        })
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<T: TimeInt> PartialOrd<Generic<T>> for Generic<T> {
     /// See [Comparisons](trait.Duration.html#comparisons)
     fn partial_cmp(&self, rhs: &Generic<T>) -> Option<core::cmp::Ordering> {
-        Some(
-            self.integer
-                .checked_mul_fraction(&self.scaling_factor)?
-                .cmp(&rhs.integer.checked_mul_fraction(&rhs.scaling_factor)?),
+        fixed_point::compare(
+            self.integer,
+            &self.scaling_factor,
+            rhs.integer,
+            &rhs.scaling_factor,
         )
     }
 }
@@ -483,7 +880,7 @@ impl<T: TimeInt + Hash> Hash for Generic<T> {
 
 impl<T: TimeInt> Generic<T> {
     /// Constructs a new fixed-point `Generic` `Duration` value
-    pub fn new(integer: T, scaling_factor: Fraction) -> Self {
+    pub const fn new(integer: T, scaling_factor: Fraction) -> Self {
         Self {
             integer,
             scaling_factor,
@@ -499,40 +896,1043 @@ impl<T: TimeInt> Generic<T> {
     pub fn scaling_factor(&self) -> &Fraction {
         &self.scaling_factor
     }
-}
 
-impl<T: TimeInt> Duration for Generic<T> {}
+    /// Returns whether the duration is zero
+    pub fn is_zero(&self) -> bool {
+        self.integer == T::from(0)
+    }
 
-/// Duration units
-#[doc(hidden)]
-pub mod units {
-    use super::*;
-    use crate::{
-        fixed_point::{self, FixedPoint},
-        fraction::Fraction,
-        time_int::TimeInt,
-        ConversionError,
-    };
-    use core::{
-        cmp,
-        convert::{TryFrom, TryInto},
-        fmt::{self, Formatter},
-        ops,
-    };
-    #[doc(hidden)]
-    pub use Extensions as _;
+    /// Returns an adapter [`Display`](fmt::Display)ing the duration as `HH:MM:SS.fraction`
+    ///
+    /// See [`Hms`]
+    pub fn display_hms(&self) -> Hms
+    where
+        u128: From<T>,
+    {
+        Hms::new(u128::from(self.integer), self.scaling_factor)
+    }
+
+    /// Returns an adapter [`Display`](fmt::Display)ing the duration as an uptime, `Nd HH:MM:SS`
+    ///
+    /// See [`Uptime`]
+    pub fn display_uptime(&self) -> Uptime
+    where
+        u128: From<T>,
+    {
+        Uptime::new(u128::from(self.integer), self.scaling_factor)
+    }
+
+    /// Returns the duration as a floating-point number of seconds
+    ///
+    /// See [`Duration::to_secs_f64()`]
+    #[cfg(feature = "float")]
+    pub fn to_secs_f64(&self) -> f64
+    where
+        u128: From<T>,
+    {
+        fixed_point::to_f64(u128::from(self.integer), self.scaling_factor)
+    }
+
+    /// Returns the duration as a floating-point number of seconds
+    ///
+    /// See [`Duration::to_secs_f32()`]
+    #[cfg(feature = "float")]
+    pub fn to_secs_f32(&self) -> f32
+    where
+        u128: From<T>,
+    {
+        self.to_secs_f64() as f32
+    }
+
+    /// Convert to a _named_ duration type, rounded as given
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::RoundingMode};
+    /// let duration = Generic::new(2_500_u32, Fraction::new(1, 1_000));
+    ///
+    /// assert_eq!(duration.to_rounded(RoundingMode::Floor), Ok(Seconds(2_u32)));
+    /// assert_eq!(duration.to_rounded(RoundingMode::Ceil), Ok(Seconds(3_u32)));
+    /// assert_eq!(duration.to_rounded(RoundingMode::Nearest), Ok(Seconds(3_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`FixedPoint::try_convert_from_with()`]
+    pub fn to_rounded<Dest>(
+        &self,
+        rounding: fixed_point::RoundingMode,
+    ) -> Result<Dest, ConversionError>
+    where
+        Dest: FixedPoint<Dimension = fixed_point::dimension::Time>,
+        u128: From<T>,
+        Dest::T: TryFrom<u128>,
+    {
+        let ticks = fixed_point::rescale(
+            u128::from(self.integer),
+            self.scaling_factor,
+            Dest::SCALING_FACTOR,
+            rounding,
+        )?;
+
+        Dest::T::try_from(ticks)
+            .map(Dest::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+
+    /// Checked sum of durations
+    ///
+    /// As with [`Generic::checked_add()`], the durations are converted to the _scaling factor_ of
+    /// the first one, which is that of the sum. The sum of no durations is `0` seconds.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, ConversionError};
+    /// let durations = [
+    ///     Generic::new(1_500_u32, Fraction::new(1, 1_000)),
+    ///     Generic::new(2_u32, Fraction::new(1, 1)),
+    /// ];
+    ///
+    /// assert_eq!(Generic::try_sum(durations), Ok(Generic::new(3_500_u32, Fraction::new(1, 1_000))));
+    /// assert_eq!(durations.iter().sum::<Generic<u32>>(), Milliseconds(3_500_u32).into());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`Generic::checked_add()`]
+    pub fn try_sum<Durations>(durations: Durations) -> Result<Self, ConversionError>
+    where
+        Durations: IntoIterator<Item = Self>,
+    {
+        let mut durations = durations.into_iter();
+
+        match durations.next() {
+            Some(first) => durations.try_fold(first, |sum, duration| sum.checked_add(&duration)),
+            None => Ok(Self::new(T::from(0), Fraction::default())),
+        }
+    }
+
+    /// Checked addition
+    ///
+    /// The `rhs` is first converted to the _scaling factor_ of `self`, the result has the _scaling
+    /// factor_ of `self`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Unspecified`] : The _scaling factor_ conversion failed
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ConversionError> {
+        let rhs = rhs.integer_with(&self.scaling_factor)?;
+
+        Ok(Self::new(
+            self.integer
+                .checked_add(&rhs)
+                .ok_or(ConversionError::Overflow)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Checked subtraction
+    ///
+    /// The `rhs` is first converted to the _scaling factor_ of `self`, the result has the _scaling
+    /// factor_ of `self`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Unspecified`] : The _scaling factor_ conversion failed
+    /// - [`ConversionError::Overflow`] : The result is negative
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, ConversionError> {
+        let rhs = rhs.integer_with(&self.scaling_factor)?;
+
+        Ok(Self::new(
+            self.integer
+                .checked_sub(&rhs)
+                .ok_or(ConversionError::Overflow)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Checked multiplication
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+    pub fn checked_mul(&self, rhs: &T) -> Result<Self, ConversionError> {
+        Ok(Self::new(
+            self.integer
+                .checked_mul(rhs)
+                .ok_or(ConversionError::Overflow)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Checked division
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::DivByZero`] : `rhs` is `0`
+    pub fn checked_div(&self, rhs: &T) -> Result<Self, ConversionError> {
+        Ok(Self::new(
+            self.integer
+                .checked_div(rhs)
+                .ok_or(ConversionError::DivByZero)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Returns the _integer_ converted to the given _scaling factor_ (truncated)
+    fn integer_with(&self, scaling_factor: &Fraction) -> Result<T, ConversionError> {
+        self.integer
+            .checked_mul_fraction(
+                &self
+                    .scaling_factor
+                    .checked_div(scaling_factor)
+                    .ok_or(ConversionError::Unspecified)?,
+            )
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+impl<T: TimeInt + TryFrom<u128>> Generic<T> {
+    /// Parse a decimal duration with a unit suffix (eg. `"2.5ms"` or `"1h30m"`)
+    ///
+    /// The grammar is that of [`str::parse()`](trait.Duration.html#parsing). The value is
+    /// represented exactly, as a reduced _integer_ and a _scaling factor_ of `1 / n`.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fraction::Fraction, ParseError};
+    /// let duration = Generic::<u32>::parse("2.5ms").unwrap();
+    /// assert_eq!(duration.integer(), 1);
+    /// assert_eq!(duration.scaling_factor(), &Fraction::new(1, 400));
+    ///
+    /// assert_eq!(Generic::<u32>::parse("1h30m"), Ok(Minutes(90_u32).into()));
+    /// assert_eq!(Generic::<u32>::parse("5 fortnights"), Err(ParseError::UnknownUnit));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseError::InvalidNumber`] : A number is missing or malformed
+    /// - [`ParseError::UnknownUnit`] : A unit is missing or unknown
+    /// - [`ParseError::Overflow`] : The _integer_ doesn't fit in the _integer_ type or the
+    ///   _scaling factor_ doesn't fit in a [`Fraction`]
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let (integer, scaling_factor) = crate::parse::duration(s)?.generic()?;
+
+        Ok(Self::new(integer, scaling_factor))
+    }
+}
+
+impl<T: TimeInt + TryFrom<u128>> TryFrom<&str> for Generic<T> {
+    type Error = ParseError;
+
+    /// See [`Generic::parse()`]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl<T: TimeInt> Duration for Generic<T> {}
+
+impl<T: TimeInt + TryFrom<u128>> core::str::FromStr for Generic<T> {
+    type Err = crate::ParseError;
+
+    /// See [`Generic::parse()`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<T: TimeInt> iter::Sum for Generic<T> {
+    /// See [`Generic::try_sum()`]
+    ///
+    /// # Panics
+    ///
+    /// If the sum overflows
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        match Self::try_sum(iter) {
+            Ok(sum) => sum,
+            Err(_) => panic!("Add failed"),
+        }
+    }
+}
+
+impl<'a, T: TimeInt> iter::Sum<&'a Self> for Generic<T> {
+    /// See [`Generic::try_sum()`]
+    ///
+    /// # Panics
+    ///
+    /// If the sum overflows
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T: TimeInt> ops::Mul<T> for Generic<T> {
+    type Output = Self;
+
+    /// See [Mul/Div](trait.Duration.html#muldiv)
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.integer * rhs, self.scaling_factor)
+    }
+}
+
+impl<T: TimeInt> ops::Mul<DutyCycle> for Generic<T>
+where
+    u128: From<T>,
+    T: TryFrom<u128>,
+{
+    type Output = Self;
+
+    /// Returns the on-time of a signal of this period (truncated), see [`DutyCycle`]
+    fn mul(self, rhs: DutyCycle) -> Self::Output {
+        Self::new(rhs.scale(self.integer), self.scaling_factor)
+    }
+}
+
+impl<T: TimeInt> ops::Div<T> for Generic<T> {
+    type Output = Self;
+
+    /// See [Mul/Div](trait.Duration.html#muldiv)
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.integer / rhs, self.scaling_factor)
+    }
+}
+
+impl<T: TimeInt, Rhs: Duration + Into<Self>> ops::Add<Rhs> for Generic<T> {
+    type Output = Self;
+
+    /// See [`Generic::checked_add()`]
+    ///
+    /// # Panics
+    ///
+    /// If the sum overflows
+    fn add(self, rhs: Rhs) -> Self::Output {
+        match self.checked_add(&rhs.into()) {
+            Ok(sum) => sum,
+            Err(_) => panic!("Add failed"),
+        }
+    }
+}
+
+impl<T: TimeInt, Rhs: Duration + Into<Self>> ops::Sub<Rhs> for Generic<T> {
+    type Output = Self;
+
+    /// See [`Generic::checked_sub()`]
+    ///
+    /// # Panics
+    ///
+    /// If the difference overflows
+    fn sub(self, rhs: Rhs) -> Self::Output {
+        match self.checked_sub(&rhs.into()) {
+            Ok(difference) => difference,
+            Err(_) => panic!("Sub failed"),
+        }
+    }
+}
+
+impl<T: TimeInt, Rhs: Duration + Into<Self>> ops::Rem<Rhs> for Generic<T> {
+    type Output = Self;
+
+    /// The `rhs` is first converted to the _scaling factor_ of `self`, the result has the _scaling
+    /// factor_ of `self`
+    ///
+    /// See [Remainder](trait.Duration.html#remainder)
+    fn rem(self, rhs: Rhs) -> Self::Output {
+        match rhs.into().integer_with(&self.scaling_factor) {
+            Ok(rhs) if rhs > T::from(0) => Self::new(self.integer % rhs, self.scaling_factor),
+            Ok(_) => Self::new(T::from(0), self.scaling_factor),
+            Err(_) => self,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Generic<T> {
+    /// Includes the _scaling factor_ and unit
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::Generic};
+    /// assert_eq!(format!("{:?}", Generic::new(246_u32, Fraction::new(1, 2))), "246 × 1/2 s");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.integer, f)?;
+        write!(
+            f,
+            " × {}/{} s",
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Generic<T> {
+    /// Includes the _scaling factor_ and unit, the formatting options apply to the _integer_
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::Generic};
+    /// assert_eq!(format!("{:>4}", Generic::new(25_u32, Fraction::new(1, 10))), "  25 × 1/10 s");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.integer, f)?;
+        write!(
+            f,
+            " × {}/{} s",
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
+}
+
+/// Includes the _scaling factor_ and unit, as [`Debug`](fmt::Debug) does
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for Generic<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{} × {}/{} s",
+            self.integer,
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
+}
+
+/// A signed duration with an arbitrary _scaling factor_
+///
+/// [`Duration`]s are unsigned, but the difference between two timestamps (eg. of two sensors, or
+/// see [`Instant::signed_duration_since()`](crate::Instant::signed_duration_since())) can
+/// legitimately be negative. The _integer_ is a signed integer type (eg. [`i64`]).
+///
+/// Non-negative values convert to and from [`Generic`] (and from there to and from the named
+/// units) with `try_from()`/`try_into()`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, ConversionError};
+/// # use core::convert::TryFrom;
+/// let offset = SignedGeneric::new(-250_i64, Fraction::new(1, 1_000));
+/// assert!(offset.is_negative());
+///
+/// assert_eq!(Generic::<u32>::try_from(offset), Err(ConversionError::NegDuration));
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(Generic::<u32>::try_from(-offset).unwrap()),
+///     Ok(Milliseconds(250_u32))
+/// );
+///
+/// assert_eq!(
+///     SignedGeneric::<i64>::try_from(Generic::new(5_u32, Fraction::new(1, 1_000))),
+///     Ok(SignedGeneric::new(5_i64, Fraction::new(1, 1_000)))
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct SignedGeneric<T> {
+    integer: T,
+    scaling_factor: Fraction,
+}
+
+/// Serialized as an `(integer, numerator, denominator)` tuple
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for SignedGeneric<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            &self.integer,
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator(),
+        )
+            .serialize(serializer)
+    }
+}
+
+/// Deserialized from an `(integer, numerator, denominator)` tuple, a denominator of `0` is
+/// rejected
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for SignedGeneric<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (integer, numerator, denominator) = <(T, u32, u32)>::deserialize(deserializer)?;
+        if denominator == 0 {
+            return Err(serde::de::Error::custom("Invalid scaling factor"));
+        }
+
+        Ok(Self {
+            integer,
+            scaling_factor: Fraction::new(numerator, denominator),
+        })
+    }
+}
+
+impl<T: Copy> SignedGeneric<T> {
+    /// Constructs a new fixed-point `SignedGeneric` duration value
+    pub fn new(integer: T, scaling_factor: Fraction) -> Self {
+        Self {
+            integer,
+            scaling_factor,
+        }
+    }
+
+    /// Returns the _integer_ part
+    pub fn integer(&self) -> T {
+        self.integer
+    }
+
+    /// Returns the _scaling factor_ [`Fraction`] part
+    pub fn scaling_factor(&self) -> &Fraction {
+        &self.scaling_factor
+    }
+}
+
+impl<T: num::Signed + Copy> SignedGeneric<T> {
+    /// Returns `true` if the duration is negative
+    pub fn is_negative(&self) -> bool {
+        self.integer.is_negative()
+    }
+}
+
+impl<T: ops::Neg<Output = T>> ops::Neg for SignedGeneric<T> {
+    type Output = Self;
+
+    /// Negate the duration
+    ///
+    /// # Panics
+    ///
+    /// If the _integer_ is the minimum value of its type (and overflow checks are enabled)
+    fn neg(self) -> Self::Output {
+        Self {
+            integer: -self.integer,
+            scaling_factor: self.scaling_factor,
+        }
+    }
+}
+
+impl<Signed, Unsigned> TryFrom<Generic<Unsigned>> for SignedGeneric<Signed>
+where
+    Unsigned: TimeInt,
+    Signed: TryFrom<Unsigned>,
+{
+    type Error = ConversionError;
+
+    /// Convert an unsigned duration
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The _integer_ doesn't fit in the signed type
+    fn try_from(duration: Generic<Unsigned>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            integer: Signed::try_from(duration.integer)
+                .map_err(|_| ConversionError::ConversionFailure)?,
+            scaling_factor: duration.scaling_factor,
+        })
+    }
+}
+
+impl<Signed, Unsigned> TryFrom<SignedGeneric<Signed>> for Generic<Unsigned>
+where
+    Signed: num::Signed + Copy,
+    Unsigned: TimeInt + TryFrom<Signed>,
+{
+    type Error = ConversionError;
+
+    /// Convert a non-negative signed duration
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::NegDuration`] : The duration is negative
+    /// - [`ConversionError::ConversionFailure`] : The _integer_ doesn't fit in the unsigned type
+    fn try_from(duration: SignedGeneric<Signed>) -> Result<Self, Self::Error> {
+        if duration.is_negative() {
+            return Err(ConversionError::NegDuration);
+        }
+
+        Ok(Self {
+            integer: Unsigned::try_from(duration.integer)
+                .map_err(|_| ConversionError::ConversionFailure)?,
+            scaling_factor: duration.scaling_factor,
+        })
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SignedGeneric<T> {
+    /// Includes the _scaling factor_ and unit
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::SignedGeneric};
+    /// assert_eq!(format!("{:?}", SignedGeneric::new(-246_i32, Fraction::new(1, 2))), "-246 × 1/2 s");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.integer, f)?;
+        write!(
+            f,
+            " × {}/{} s",
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
+}
+
+/// Includes the _scaling factor_ and unit, as [`Debug`](fmt::Debug) does
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for SignedGeneric<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{} × {}/{} s",
+            self.integer,
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
+}
+
+/// A duration [`Display`](fmt::Display)ed as `HH:MM:SS.fraction`
+///
+/// Created by [`Duration::display_hms()`] or [`Generic::display_hms()`] (eg. of
+/// [`Instant::duration_since_epoch()`](crate::Instant::duration_since_epoch())).
+///
+/// The hours are padded to 2 digits but aren't wrapped into days. By default, the fraction of a
+/// second has as many digits as the _scaling factor_ needs (none for whole seconds, 3 for
+/// milliseconds, ...), up to 9. A precision (eg. `{:.3}`) overrides this, also up to 9. The
+/// fraction is truncated, and durations of more than [`u128::MAX`] seconds saturate.
+///
+/// ```rust
+/// # use embedded_time::duration::*;
+/// assert_eq!(Milliseconds(5_025_678_u32).display_hms().to_string(), "01:23:45.678");
+/// assert_eq!(Seconds(90_u32).display_hms().to_string(), "00:01:30");
+/// assert_eq!(Hours(100_u32).display_hms().to_string(), "100:00:00");
+///
+/// assert_eq!(format!("{:.1}", Microseconds(1_999_999_u32).display_hms()), "00:00:01.9");
+/// assert_eq!(
+///     format!("{}", Generic::new(3_u32, Fraction::new(1, 32_768)).display_hms()),
+///     "00:00:00.000091552"
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Hms {
+    seconds: u128,
+    // the fraction of a second is `remainder / denominator`
+    remainder: u128,
+    denominator: u128,
+    digits: usize,
+}
+
+impl Hms {
+    fn new(integer: u128, scaling_factor: Fraction) -> Self {
+        let numerator = u128::from(*scaling_factor.numerator());
+        let denominator = u128::from(*scaling_factor.denominator());
+
+        // integer × numerator / denominator without overflowing the remainder
+        let seconds = (integer / denominator)
+            .checked_mul(numerator)
+            .and_then(|seconds| {
+                seconds.checked_add(integer % denominator * numerator / denominator)
+            });
+        let remainder = integer % denominator * numerator % denominator;
+
+        // the digits needed to represent the _scaling factor_ exactly
+        let reduced_denominator = denominator / numerator.gcd(&denominator);
+        let digits = (0..=9)
+            .find(|digits| 10_u128.pow(*digits) % reduced_denominator == 0)
+            .unwrap_or(9) as usize;
+
+        match seconds {
+            Some(seconds) => Self {
+                seconds,
+                remainder,
+                denominator,
+                digits,
+            },
+            None => Self {
+                seconds: u128::MAX,
+                remainder: 0,
+                denominator,
+                digits,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Hms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.seconds / 3_600,
+            self.seconds / 60 % 60,
+            self.seconds % 60
+        )?;
+
+        let digits = f.precision().unwrap_or(self.digits).min(9);
+        if digits > 0 {
+            // remainder < denominator <= u32::MAX, so this can't overflow
+            let fraction = self.remainder * 10_u128.pow(digits as u32) / self.denominator;
+            write!(f, ".{:0width$}", fraction, width = digits)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A duration [`Display`](fmt::Display)ed as an uptime, `Nd HH:MM:SS`
+///
+/// Created by [`Duration::display_uptime()`] or [`Generic::display_uptime()`] (eg. of
+/// [`Instant::duration_since_epoch()`](crate::Instant::duration_since_epoch())).
+///
+/// The days are omitted for durations of less than a day. The fraction of a second is truncated,
+/// and durations of more than [`u128::MAX`] seconds saturate, as with [`Hms`].
+///
+/// ```rust
+/// # use embedded_time::duration::*;
+/// assert_eq!(Seconds(274_376_u32).display_uptime().to_string(), "3d 04:12:56");
+/// assert_eq!(Milliseconds(5_025_678_u32).display_uptime().to_string(), "01:23:45");
+/// assert_eq!(Milliseconds(u32::MAX).display_uptime().to_string(), "49d 17:02:47");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Uptime {
+    seconds: u128,
+}
+
+impl Uptime {
+    fn new(integer: u128, scaling_factor: Fraction) -> Self {
+        Self {
+            seconds: Hms::new(integer, scaling_factor).seconds,
+        }
+    }
+}
+
+impl fmt::Display for Uptime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.seconds / 86_400;
+        if days > 0 {
+            write!(f, "{}d ", days)?;
+        }
+
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.seconds / 3_600 % 24,
+            self.seconds / 60 % 60,
+            self.seconds % 60
+        )
+    }
+}
+
+/// Duration units
+#[doc(hidden)]
+pub mod units {
+    use super::*;
+    use crate::{
+        fixed_point::{self, FixedPoint},
+        fraction::Fraction,
+        time_int::TimeInt,
+        ConversionError,
+    };
+    use core::{
+        cmp,
+        convert::{TryFrom, TryInto},
+        fmt::{self, Formatter},
+        ops,
+    };
+    #[doc(hidden)]
+    pub use Extensions as _;
+
+    /// Converts `value` ticks of `scaling_factor` into ticks of the `Dest` _scaling factor_
+    ///
+    /// The intermediate math is done in `u128` so it can't overflow.
+    fn scale<Dest: FixedPoint>(
+        value: u64,
+        scaling_factor: Fraction,
+        rounded: bool,
+    ) -> Result<Dest, ConversionError>
+    where
+        Dest::T: TryFrom<u64>,
+    {
+        let numerator = u128::from(value)
+            * u128::from(*scaling_factor.numerator())
+            * u128::from(*Dest::SCALING_FACTOR.denominator());
+        let denominator = u128::from(*scaling_factor.denominator())
+            * u128::from(*Dest::SCALING_FACTOR.numerator());
+
+        let ticks = if rounded {
+            (numerator + denominator / 2) / denominator
+        } else {
+            numerator / denominator
+        };
+
+        u64::try_from(ticks)
+            .ok()
+            .and_then(|ticks| Dest::T::try_from(ticks).ok())
+            .map(Dest::new)
+            .ok_or(ConversionError::ConversionFailure)
+    }
+
+    macro_rules! impl_consts {
+        ($name:ident, $($int:ty),+) => {
+            $(
+                impl $name<$int> {
+                    /// The zero duration
+                    pub const ZERO: Self = Self(0);
+                    /// The smallest duration of the _integer_ type (zero)
+                    pub const MIN: Self = Self(<$int>::MIN);
+                    /// The largest duration of the _integer_ type
+                    pub const MAX: Self = Self(<$int>::MAX);
+                }
+            )+
+        };
+    }
+
+    macro_rules! impl_try_from_int {
+        ($name:ident, $($method:ident: $int:ty),+) => {
+            impl<T: TimeInt> $name<T> {
+                $(
+                    #[doc = concat!("Construct from a [`", stringify!($int), "`], checking that it fits in the _integer_ type")]
+                    ///
+                    /// # Errors
+                    ///
+                    /// [`ConversionError::ConversionFailure`] : The value doesn't fit in the _integer_
+                    /// type
+                    pub fn $method(integer: $int) -> Result<Self, ConversionError>
+                    where
+                        T: TryFrom<$int>,
+                    {
+                        <T as TryFrom<$int>>::try_from(integer)
+                            .map(Self)
+                            .map_err(|_| ConversionError::ConversionFailure)
+                    }
+                )+
+            }
+        };
+    }
+
+    macro_rules! impl_try_from_wider_int {
+        ($name:ident, $narrow:ty, $($wide:ty),+) => {
+            $(
+                impl TryFrom<$wide> for $name<$narrow> {
+                    type Error = ConversionError;
+
+                    /// Checks that the value fits in the _integer_ type
+                    fn try_from(integer: $wide) -> Result<Self, Self::Error> {
+                        <$narrow>::try_from(integer)
+                            .map(Self)
+                            .map_err(|_| ConversionError::ConversionFailure)
+                    }
+                }
+            )+
+        };
+    }
+
+    macro_rules! impl_duration {
+        ( $name:ident, ($numer:expr, $denom:expr) ) => {
+            /// A duration unit type
+            #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+            pub struct $name<T: TimeInt = u32>(pub T);
+
+            impl_consts![$name, u8, u16, u32, u64, u128];
+            impl_try_from_int![
+                $name,
+                try_from_u8: u8,
+                try_from_u16: u16,
+                try_from_u32: u32,
+                try_from_u64: u64,
+                try_from_u128: u128
+            ];
+            impl_try_from_wider_int![$name, u8, u16, u32, u64, u128];
+            impl_try_from_wider_int![$name, u16, u32, u64, u128];
+            impl_try_from_wider_int![$name, u32, u64, u128];
+            impl_try_from_wider_int![$name, u64, u128];
+
+            impl<T: TimeInt> $name<T> {
+                /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
+                pub const fn new(value: T) -> Self {
+                    Self(value)
+                }
+
+                /// Returns the lesser of `self` and `other`, as the type of `self`
+                ///
+                /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+                pub fn min<Rhs: Duration>(self, other: Rhs) -> Self
+                where
+                    Self: PartialOrd<Rhs> + TryFrom<Rhs>,
+                {
+                    if self > other {
+                        Self::convert(other)
+                    } else {
+                        self
+                    }
+                }
+
+                /// Returns the greater of `self` and `other`, as the type of `self`
+                ///
+                /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+                pub fn max<Rhs: Duration>(self, other: Rhs) -> Self
+                where
+                    Self: PartialOrd<Rhs> + TryFrom<Rhs>,
+                {
+                    if self < other {
+                        Self::convert(other)
+                    } else {
+                        self
+                    }
+                }
+
+                /// Returns `self` restricted to `min..=max`, as the type of `self`
+                ///
+                /// See [Min/Max/Clamp](trait.Duration.html#minmaxclamp)
+                pub fn clamp<Min: Duration, Max: Duration>(self, min: Min, max: Max) -> Self
+                where
+                    Self: PartialOrd<Min> + PartialOrd<Max> + TryFrom<Min> + TryFrom<Max>,
+                {
+                    if self < min {
+                        Self::convert(min)
+                    } else if self > max {
+                        Self::convert(max)
+                    } else {
+                        self
+                    }
+                }
+
+                fn convert<Rhs>(rhs: Rhs) -> Self
+                where
+                    Self: TryFrom<Rhs>,
+                {
+                    if let Ok(v) = Self::try_from(rhs) {
+                        v
+                    } else {
+                        panic!("Conversion failed")
+                    }
+                }
+
+                /// Construct from a number of seconds (truncated)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_secs<Int: Into<u64>>(secs: Int) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(secs.into(), Fraction::new(1, 1), false)
+                }
+
+                /// Construct from a number of seconds (rounded to the nearest integer)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_secs_rounded<Int: Into<u64>>(
+                    secs: Int,
+                ) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(secs.into(), Fraction::new(1, 1), true)
+                }
+
+                /// Construct from a number of milliseconds (truncated)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_millis<Int: Into<u64>>(millis: Int) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(millis.into(), Fraction::new(1, 1_000), false)
+                }
+
+                /// Construct from a number of milliseconds (rounded to the nearest integer)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_millis_rounded<Int: Into<u64>>(
+                    millis: Int,
+                ) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(millis.into(), Fraction::new(1, 1_000), true)
+                }
+
+                /// Construct from a number of microseconds (truncated)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_micros<Int: Into<u64>>(micros: Int) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(micros.into(), Fraction::new(1, 1_000_000), false)
+                }
+
+                /// Construct from a number of microseconds (rounded to the nearest integer)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_micros_rounded<Int: Into<u64>>(
+                    micros: Int,
+                ) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(micros.into(), Fraction::new(1, 1_000_000), true)
+                }
 
-    macro_rules! impl_duration {
-        ( $name:ident, ($numer:expr, $denom:expr) ) => {
-            /// A duration unit type
-            #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
-            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct $name<T: TimeInt = u32>(pub T);
+                /// Construct from a number of nanoseconds (truncated)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_nanos<Int: Into<u64>>(nanos: Int) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(nanos.into(), Fraction::new(1, 1_000_000_000), false)
+                }
 
-            impl<T: TimeInt> $name<T> {
-                /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
-                pub fn new(value: T) -> Self {
-                    Self(value)
+                /// Construct from a number of nanoseconds (rounded to the nearest integer)
+                ///
+                /// See [Constructing from another
+                /// unit](trait.Duration.html#constructing-from-another-unit)
+                ///
+                /// # Errors
+                ///
+                /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_
+                /// type
+                pub fn try_from_nanos_rounded<Int: Into<u64>>(
+                    nanos: Int,
+                ) -> Result<Self, ConversionError>
+                where
+                    T: TryFrom<u64>,
+                {
+                    scale(nanos.into(), Fraction::new(1, 1_000_000_000), true)
                 }
             }
 
@@ -540,6 +1940,7 @@ pub mod units {
 
             impl<T: TimeInt> FixedPoint for $name<T> {
                 type T = T;
+                type Dimension = fixed_point::dimension::Time;
                 const SCALING_FACTOR: Fraction = Fraction::new($numer, $denom);
 
                 /// See [Constructing a duration](trait.Duration.html#constructing-a-duration)
@@ -560,9 +1961,18 @@ pub mod units {
                 }
             }
 
+            impl<T: TimeInt + TryFrom<u128>> core::str::FromStr for $name<T> {
+                type Err = crate::ParseError;
+
+                /// See [Parsing](trait.Duration.html#parsing)
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    crate::parse::duration(s)?.ticks(Self::SCALING_FACTOR).map(Self)
+                }
+            }
+
             impl<T: TimeInt, Rhs: Duration> ops::Add<Rhs> for $name<T>
             where
-                Rhs: FixedPoint,
+                Rhs: FixedPoint<Dimension = fixed_point::dimension::Time>,
                 Self: TryFrom<Rhs>,
             {
                 type Output = Self;
@@ -573,10 +1983,24 @@ pub mod units {
                 }
             }
 
+            impl<T: TimeInt> iter::Sum for $name<T> {
+                /// See [Sum](trait.Duration.html#sum)
+                fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                    iter.fold(Self(T::from(0)), |sum, duration| sum + duration)
+                }
+            }
+
+            impl<'a, T: TimeInt> iter::Sum<&'a Self> for $name<T> {
+                /// See [Sum](trait.Duration.html#sum)
+                fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                    iter.copied().sum()
+                }
+            }
+
             impl<T: TimeInt, Rhs: Duration> ops::Sub<Rhs> for $name<T>
             where
                 Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
+                Rhs: FixedPoint<Dimension = fixed_point::dimension::Time>,
             {
                 type Output = Self;
 
@@ -620,10 +2044,23 @@ pub mod units {
                 }
             }
 
+            impl<T: TimeInt> ops::Mul<DutyCycle> for $name<T>
+            where
+                u128: From<T>,
+                T: TryFrom<u128>,
+            {
+                type Output = Self;
+
+                /// Returns the on-time of a signal of this period (truncated), see [`DutyCycle`]
+                fn mul(self, rhs: DutyCycle) -> Self::Output {
+                    rhs.on_time(self)
+                }
+            }
+
             impl<T: TimeInt, Rhs: Duration> ops::Rem<Rhs> for $name<T>
             where
                 Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
+                Rhs: FixedPoint<Dimension = fixed_point::dimension::Time>,
             {
                 type Output = Self;
 
@@ -729,6 +2166,8 @@ pub mod units {
             }
         };
     }
+    impl_duration![Weeks, (604_800, 1), ge_secs];
+    impl_duration![Days, (86_400, 1), ge_secs];
     impl_duration![Hours, (3600, 1), ge_secs];
     impl_duration![Minutes, (60, 1), ge_secs];
     impl_duration![Seconds, (1, 1), ge_secs];
@@ -752,6 +2191,8 @@ pub mod units {
             }
         };
     }
+    impl_partial_eq![Weeks];
+    impl_partial_eq![Days];
     impl_partial_eq![Hours];
     impl_partial_eq![Minutes];
     impl_partial_eq![Seconds];
@@ -781,6 +2222,8 @@ pub mod units {
         };
     }
     impl_big_partial_eq_small![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
@@ -817,7 +2260,9 @@ pub mod units {
         Milliseconds,
         Seconds,
         Minutes,
-        Hours
+        Hours,
+        Days,
+        Weeks
     ];
 
     macro_rules! impl_partial_ord {
@@ -836,6 +2281,8 @@ pub mod units {
             }
         };
     }
+    impl_partial_ord![Weeks];
+    impl_partial_ord![Days];
     impl_partial_ord![Hours];
     impl_partial_ord![Minutes];
     impl_partial_ord![Seconds];
@@ -865,6 +2312,8 @@ pub mod units {
         };
     }
     impl_big_partial_ord_small![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
@@ -901,7 +2350,9 @@ pub mod units {
         Milliseconds,
         Seconds,
         Minutes,
-        Hours
+        Hours,
+        Days,
+        Weeks
     ];
 
     macro_rules! impl_from {
@@ -928,6 +2379,8 @@ pub mod units {
             }
         };
     }
+    impl_from![Weeks];
+    impl_from![Days];
     impl_from![Hours];
     impl_from![Minutes];
     impl_from![Seconds];
@@ -935,6 +2388,46 @@ pub mod units {
     impl_from![Microseconds];
     impl_from![Nanoseconds];
 
+    macro_rules! impl_widen_from {
+        ($name:ident, $($narrow:ty => $($wide:ty),+);+) => {
+            $($(
+                impl From<$name<$narrow>> for $name<$wide> {
+                    /// See [Converting between
+                    /// `Duration`s](trait.Duration.html#converting-between-durations)
+                    fn from(source: $name<$narrow>) -> Self {
+                        Self::new(<$wide>::from(source.integer()))
+                    }
+                }
+
+                impl TryFrom<$name<$wide>> for $name<$narrow> {
+                    type Error = ConversionError;
+
+                    /// See [Converting between
+                    /// `Duration`s](trait.Duration.html#converting-between-durations)
+                    fn try_from(source: $name<$wide>) -> Result<Self, Self::Error> {
+                        fixed_point::FixedPoint::from_ticks(
+                            source.integer(),
+                            $name::<$wide>::SCALING_FACTOR,
+                        )
+                    }
+                }
+            )+)+
+        };
+        ($($name:ident),+) => {
+            $(impl_widen_from![$name, u8 => u16, u32, u64, u128; u16 => u32, u64, u128; u32 => u128; u64 => u128];)+
+        };
+    }
+    impl_widen_from![
+        Weeks,
+        Days,
+        Hours,
+        Minutes,
+        Seconds,
+        Milliseconds,
+        Microseconds,
+        Nanoseconds
+    ];
+
     macro_rules! impl_from_smaller {
         ($name:ident) => {};
         ($big:ident, $($small:ident),+) => {
@@ -982,6 +2475,8 @@ pub mod units {
 
     }
     impl_from_smaller![
+        Weeks,
+        Days,
         Hours,
         Minutes,
         Seconds,
@@ -994,18 +2489,6 @@ pub mod units {
         ($small:ident) => {};
         ($small:ident, $($big:ident),+) => {
             $(
-                impl From<$big<u32>> for $small<u64>
-                {
-                    /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
-                    fn from(big: $big<u32>) -> Self {
-                        if let Ok(v) = fixed_point::FixedPoint::from_ticks(big.integer(), $big::<u32>::SCALING_FACTOR) {
-                            v
-                        } else {
-                            panic!("From failed")
-                        }
-                    }
-                }
-
                 impl<T: TimeInt> TryFrom<$big<T>> for $small<T>
                 {
                     type Error = ConversionError;
@@ -1043,9 +2526,234 @@ pub mod units {
         Milliseconds,
         Seconds,
         Minutes,
-        Hours
+        Hours,
+        Days,
+        Weeks
+    ];
+
+    macro_rules! impl_widen_from_bigger {
+        ($($big:ident => $($small:ident),+);+) => {
+            $($(
+                impl From<$big<u32>> for $small<u64>
+                {
+                    /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+                    fn from(big: $big<u32>) -> Self {
+                        if let Ok(v) = fixed_point::FixedPoint::from_ticks(big.integer(), $big::<u32>::SCALING_FACTOR) {
+                            v
+                        } else {
+                            panic!("From failed")
+                        }
+                    }
+                }
+            )+)+
+        };
+    }
+
+    macro_rules! impl_try_widen_from_bigger {
+        ($($big:ident => $($small:ident),+);+) => {
+            $($(
+                impl TryFrom<$big<u32>> for $small<u64>
+                {
+                    type Error = ConversionError;
+
+                    /// See [Converting between `Duration`s](trait.Duration.html#converting-between-durations)
+                    fn try_from(big: $big<u32>) -> Result<Self, Self::Error> {
+                        fixed_point::FixedPoint::from_ticks(
+                            big.integer(),
+                            $big::<u32>::SCALING_FACTOR,
+                        )
+                    }
+                }
+            )+)+
+        };
+    }
+
+    // any u32 of the bigger unit fits in a u64 of the smaller one
+    impl_widen_from_bigger![
+        Weeks => Days, Hours, Minutes, Seconds, Milliseconds;
+        Days => Hours, Minutes, Seconds, Milliseconds;
+        Hours => Minutes, Seconds, Milliseconds, Microseconds;
+        Minutes => Seconds, Milliseconds, Microseconds;
+        Seconds => Milliseconds, Microseconds, Nanoseconds;
+        Milliseconds => Microseconds, Nanoseconds;
+        Microseconds => Nanoseconds
+    ];
+
+    // larger u32 values of the bigger unit overflow a u64 of the smaller one
+    impl_try_widen_from_bigger![
+        Weeks => Microseconds, Nanoseconds;
+        Days => Microseconds, Nanoseconds;
+        Hours => Nanoseconds;
+        Minutes => Nanoseconds
     ];
 
+    /// A duration of picoseconds, for high-speed interface timing (DDR timing parameters, delay
+    /// lines, time-to-digital converter results, ...)
+    ///
+    /// A picosecond can't be expressed as a [`u32`]/[`u32`] _scaling factor_, so unlike the other
+    /// units, `Picoseconds` isn't a [`Duration`] and is converted explicitly:
+    ///
+    /// - from [`Nanoseconds`] and [`core::time::Duration`] exactly, failing if the result doesn't
+    ///   fit in the _integer_ type
+    /// - to [`Nanoseconds`] (of the same _integer_ type, which can't fail) and
+    ///   [`core::time::Duration`], truncating the fraction of a nanosecond
+    ///   ([`Picoseconds::to_nanoseconds_rounded()`] rounds it to the nearest nanosecond instead)
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, ConversionError};
+    /// # use core::convert::{TryFrom, TryInto};
+    /// let tdc = Picoseconds(2_999_u64);
+    /// assert_eq!(Nanoseconds::from(tdc), Nanoseconds(2_u64));
+    /// assert_eq!(tdc.to_nanoseconds_rounded(), Nanoseconds(3_u64));
+    /// assert_eq!(
+    ///     core::time::Duration::try_from(tdc),
+    ///     Ok(core::time::Duration::from_nanos(2))
+    /// );
+    ///
+    /// assert_eq!(Picoseconds::try_from(Nanoseconds(3_u32)), Ok(Picoseconds(3_000_u64)));
+    /// assert_eq!(
+    ///     Picoseconds::<u32>::try_from(Nanoseconds(5_000_000_u32)),
+    ///     Err(ConversionError::ConversionFailure)
+    /// );
+    /// assert_eq!(
+    ///     core::time::Duration::from_micros(1).try_into(),
+    ///     Ok(Picoseconds(1_000_000_u64))
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub struct Picoseconds<T: TimeInt = u64>(pub T);
+
+    impl<T: TimeInt> Picoseconds<T> {
+        /// Construct from a number of picoseconds
+        pub const fn new(value: T) -> Self {
+            Self(value)
+        }
+
+        /// Returns the _integer_ value
+        pub fn integer(&self) -> T {
+            self.0
+        }
+
+        /// Checked addition
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The sum doesn't fit in the _integer_ type
+        pub fn checked_add(self, rhs: Self) -> Result<Self, ConversionError> {
+            self.0
+                .checked_add(&rhs.0)
+                .map(Self)
+                .ok_or(ConversionError::Overflow)
+        }
+
+        /// Checked subtraction
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The result is negative
+        pub fn checked_sub(self, rhs: Self) -> Result<Self, ConversionError> {
+            self.0
+                .checked_sub(&rhs.0)
+                .map(Self)
+                .ok_or(ConversionError::Overflow)
+        }
+
+        /// Convert to [`Nanoseconds`], rounding to the nearest nanosecond (half a nanosecond
+        /// rounds up)
+        pub fn to_nanoseconds_rounded(self) -> Nanoseconds<T> {
+            let truncated = Nanoseconds::from(self).0;
+
+            // a `u8` can't hold the picoseconds of 1 ns, so they are always less than half a
+            // nanosecond
+            let rounds_up =
+                T::try_from(1_000).is_ok_and(|per_nano| self.0 % per_nano >= per_nano / T::from(2));
+            if rounds_up {
+                // `truncated` is at most `T::MAX / 1_000`, so this can't overflow
+                Nanoseconds(truncated + T::from(1))
+            } else {
+                Nanoseconds(truncated)
+            }
+        }
+    }
+
+    impl<T: TimeInt> fmt::Display for Picoseconds<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(&self.0, f)
+        }
+    }
+
+    impl<T: TimeInt> From<Picoseconds<T>> for Nanoseconds<T> {
+        /// Truncates the fraction of a nanosecond
+        fn from(picoseconds: Picoseconds<T>) -> Self {
+            // a `u8` can't hold the picoseconds of 1 ns, so they are always less than a nanosecond
+            Self(
+                T::try_from(1_000).map_or_else(|_| T::from(0), |per_nano| picoseconds.0 / per_nano),
+            )
+        }
+    }
+
+    impl<SourceInt: TimeInt, DestInt: TimeInt> TryFrom<Nanoseconds<SourceInt>> for Picoseconds<DestInt>
+    where
+        u128: From<SourceInt>,
+        DestInt: TryFrom<u128>,
+    {
+        type Error = ConversionError;
+
+        /// # Errors
+        ///
+        /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_ type
+        fn try_from(nanoseconds: Nanoseconds<SourceInt>) -> Result<Self, Self::Error> {
+            u128::from(nanoseconds.0)
+                .checked_mul(1_000)
+                .and_then(|picoseconds| DestInt::try_from(picoseconds).ok())
+                .map(Self)
+                .ok_or(ConversionError::ConversionFailure)
+        }
+    }
+
+    impl<T: TimeInt> TryFrom<Picoseconds<T>> for core::time::Duration
+    where
+        u128: From<T>,
+    {
+        type Error = ConversionError;
+
+        /// Truncates the fraction of a nanosecond
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::ConversionFailure`] : The duration is longer than [`u64::MAX`]
+        /// seconds
+        fn try_from(picoseconds: Picoseconds<T>) -> Result<Self, Self::Error> {
+            let picoseconds = u128::from(picoseconds.0);
+            let seconds = u64::try_from(picoseconds / 1_000_000_000_000)
+                .map_err(|_| ConversionError::ConversionFailure)?;
+            let nanos = (picoseconds % 1_000_000_000_000 / 1_000) as u32;
+
+            Ok(Self::new(seconds, nanos))
+        }
+    }
+
+    impl<T: TimeInt> TryFrom<core::time::Duration> for Picoseconds<T>
+    where
+        T: TryFrom<u128>,
+    {
+        type Error = ConversionError;
+
+        /// # Errors
+        ///
+        /// [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_ type
+        fn try_from(core_duration: core::time::Duration) -> Result<Self, Self::Error> {
+            core_duration
+                .as_nanos()
+                .checked_mul(1_000)
+                .and_then(|picoseconds| T::try_from(picoseconds).ok())
+                .map(Self)
+                .ok_or(ConversionError::ConversionFailure)
+        }
+    }
+
     /// Create duration-based extensions from primitive numeric types.
     ///
     /// ```rust
@@ -1057,6 +2765,8 @@ pub mod units {
     /// assert_eq!(5.seconds(), Seconds(5_u32));
     /// assert_eq!(5.minutes(), Minutes(5_u32));
     /// assert_eq!(5.hours(), Hours(5_u32));
+    /// assert_eq!(5.days(), Days(5_u32));
+    /// assert_eq!(5.weeks(), Weeks(5_u32));
     /// ```
     pub trait Extensions: TimeInt {
         /// nanoseconds
@@ -1083,12 +2793,340 @@ pub mod units {
         fn hours(self) -> Hours<Self> {
             Hours::new(self)
         }
+        /// days
+        fn days(self) -> Days<Self> {
+            Days::new(self)
+        }
+        /// weeks
+        fn weeks(self) -> Weeks<Self> {
+            Weeks::new(self)
+        }
     }
 
     impl Extensions for u32 {}
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// A duration constant from a literal, parsed at compile time
+///
+/// `duration!("literal")` evaluates to a [`Generic`] `Duration` with a `u32` _integer_ and the
+/// exact _scaling factor_ of the literal (equal to the value of [`Generic::parse()`]).
+/// `duration!("literal", Unit<Int>)` evaluates to the _named_ `Duration` type. The units are `ns`,
+/// `us` (or `µs`), `ms`, `s`, `min`, `h`, `d` and `w`, optionally separated from the number by a
+/// space.
+///
+/// Literals that don't fit in the chosen type, or that aren't a whole number of its unit, are
+/// rejected at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration, duration::*};
+///
+/// const TIMEOUT: Generic<u32> = duration!("1.5ms");
+/// assert_eq!(TIMEOUT, Generic::new(15_u32, Fraction::new(1, 10_000)));
+///
+/// const POLL: Microseconds<u32> = duration!("1.5ms", Microseconds<u32>);
+/// assert_eq!(POLL, Microseconds(1_500_u32));
+/// assert_eq!(duration!("2 min", Seconds<u8>), Seconds(120_u8));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use embedded_time::{duration, duration::*};
+/// // not a whole number of milliseconds
+/// const TIMEOUT: Milliseconds<u32> = duration!("1.5ms", Milliseconds<u32>);
+/// ```
+#[macro_export]
+macro_rules! duration {
+    ($literal:literal) => {{
+        const DURATION: $crate::duration::Generic<u32> = {
+            let literal = $crate::literal::duration($literal);
+            $crate::duration::Generic::new(
+                $crate::literal::to_u32(literal.integer),
+                $crate::fraction::Fraction::new(
+                    $crate::literal::to_u32(literal.numerator),
+                    $crate::literal::to_u32(literal.denominator),
+                ),
+            )
+        };
+        DURATION
+    }};
+    ($literal:literal, $unit:ident < $int:ty >) => {{
+        const INTEGER: $int = {
+            let integer = $crate::literal::integer(
+                $crate::literal::duration($literal),
+                <$crate::duration::$unit<$int> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+            );
+            if integer > <$int>::MAX as u128 {
+                panic!("Literal doesn't fit in the integer type");
+            }
+            integer as $int
+        };
+        $crate::duration::$unit::<$int>(INTEGER)
+    }};
+}
+
+/// Define a custom _named_ `Duration` type
+///
+/// `duration_unit!(Name, (numerator, denominator))` defines `Name<T: TimeInt = u32>(pub T)` with
+/// the given _scaling factor_ (in seconds). As with the built-in units (eg. [`Milliseconds`]), it
+/// implements [`Duration`] and [`FixedPoint`](crate::fixed_point::FixedPoint), so it can be used
+/// with clocks, instants and timers. It can be:
+///
+/// - converted from and to the built-in units (with the same _integer_ type) and [`Generic`]
+/// - compared with itself and the built-in units (with the same _integer_ type)
+/// - added to, subtracted from and taken the remainder of by any `Duration` it can be converted
+///   from, the result is of the LHS type
+/// - multiplied and divided by integers
+///
+/// Attributes (eg. doc comments) and a visibility may precede the name.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{duration::*, duration_unit};
+///
+/// duration_unit!(
+///     /// Two weeks
+///     pub Fortnights, (1_209_600, 1)
+/// );
+/// duration_unit!(
+///     /// Ticks of a 32.768 kHz RTC
+///     pub RtcTicks, (1, 32_768)
+/// );
+///
+/// assert_eq!(Seconds::try_from(Fortnights(1_u32)), Ok(Seconds(1_209_600_u32)));
+/// assert_eq!(RtcTicks::try_from(Milliseconds(500_u32)), Ok(RtcTicks(16_384_u32)));
+///
+/// assert_eq!(RtcTicks(32_768_u32), Seconds(1_u32));
+/// assert!(Hours(400_u32) > Fortnights(1_u32));
+///
+/// assert_eq!(RtcTicks(10_u32) + Seconds(1_u32), RtcTicks(32_778_u32));
+/// assert_eq!(RtcTicks(32_778_u32) % Seconds(1_u32), RtcTicks(10_u32));
+/// assert_eq!(Fortnights(3_u32) * 2, Fortnights(6_u32));
+/// ```
+#[macro_export]
+macro_rules! duration_unit {
+    ($(#[$attr:meta])* $vis:vis $name:ident, ($numer:expr, $denom:expr)) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+        $vis struct $name<T: $crate::TimeInt = u32>(pub T);
+
+        impl<T: $crate::TimeInt> $name<T> {
+            /// Construct a duration of the given _integer_
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T: $crate::TimeInt> $crate::duration::Duration for $name<T> {}
+
+        impl<T: $crate::TimeInt> $crate::fixed_point::FixedPoint for $name<T> {
+            type T = T;
+            type Dimension = $crate::fixed_point::dimension::Time;
+            const SCALING_FACTOR: $crate::fraction::Fraction =
+                $crate::fraction::Fraction::new($numer, $denom);
+
+            fn new(value: Self::T) -> Self {
+                Self(value)
+            }
+
+            fn integer(&self) -> Self::T {
+                self.0
+            }
+        }
+
+        impl<T: $crate::TimeInt> core::fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> core::cmp::PartialEq<$name<RhsInt>>
+            for $name<T>
+        where
+            T: core::convert::TryFrom<RhsInt>,
+        {
+            fn eq(&self, rhs: &$name<RhsInt>) -> bool {
+                match <T as core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => self.0 == rhs_integer,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> core::cmp::PartialOrd<$name<RhsInt>>
+            for $name<T>
+        where
+            T: core::convert::TryFrom<RhsInt>,
+        {
+            fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<core::cmp::Ordering> {
+                match <T as core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => Some(self.0.cmp(&rhs_integer)),
+                    Err(_) => Some(core::cmp::Ordering::Less),
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::duration::Duration> core::ops::Add<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint<
+                Dimension = $crate::fixed_point::dimension::Time,
+            >,
+            Self: core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::add(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::duration::Duration> core::ops::Sub<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint<
+                Dimension = $crate::fixed_point::dimension::Time,
+            >,
+            Self: core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            fn sub(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::sub(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::duration::Duration> core::ops::Rem<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint<
+                Dimension = $crate::fixed_point::dimension::Time,
+            >,
+            Self: core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            fn rem(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::rem(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> core::ops::Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::mul(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> core::ops::Div<T> for $name<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::div(self, rhs)
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+            core::convert::TryFrom<$crate::duration::Generic<SourceInt>> for $name<DestInt>
+        where
+            DestInt: core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            fn try_from(
+                generic_duration: $crate::duration::Generic<SourceInt>,
+            ) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_ticks(
+                    generic_duration.integer(),
+                    *generic_duration.scaling_factor(),
+                )
+            }
+        }
+
+        impl<T: $crate::TimeInt> From<$name<T>> for $crate::duration::Generic<T> {
+            fn from(duration: $name<T>) -> Self {
+                Self::new(
+                    duration.0,
+                    <$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )
+            }
+        }
+
+        $crate::duration_unit!(
+            @builtin $name,
+            Weeks,
+            Days,
+            Hours,
+            Minutes,
+            Seconds,
+            Milliseconds,
+            Microseconds,
+            Nanoseconds
+        );
+    };
+    (@builtin $name:ident, $($unit:ident),+) => {
+        $(
+            impl<T: $crate::TimeInt> core::convert::TryFrom<$crate::duration::$unit<T>>
+                for $name<T>
+            {
+                type Error = $crate::ConversionError;
+
+                fn try_from(duration: $crate::duration::$unit<T>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_ticks(
+                        duration.0,
+                        <$crate::duration::$unit<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                    )
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::convert::TryFrom<$name<T>>
+                for $crate::duration::$unit<T>
+            {
+                type Error = $crate::ConversionError;
+
+                fn try_from(duration: $name<T>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_ticks(
+                        duration.0,
+                        <$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                    )
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialEq<$crate::duration::$unit<T>> for $name<T> {
+                fn eq(&self, rhs: &$crate::duration::$unit<T>) -> bool {
+                    $crate::duration::Generic::from(*self) == $crate::duration::Generic::from(*rhs)
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialEq<$name<T>> for $crate::duration::$unit<T> {
+                fn eq(&self, rhs: &$name<T>) -> bool {
+                    $crate::duration::Generic::from(*self) == $crate::duration::Generic::from(*rhs)
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialOrd<$crate::duration::$unit<T>>
+                for $name<T>
+            {
+                fn partial_cmp(
+                    &self,
+                    rhs: &$crate::duration::$unit<T>,
+                ) -> Option<core::cmp::Ordering> {
+                    $crate::duration::Generic::from(*self)
+                        .partial_cmp(&$crate::duration::Generic::from(*rhs))
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialOrd<$name<T>>
+                for $crate::duration::$unit<T>
+            {
+                fn partial_cmp(&self, rhs: &$name<T>) -> Option<core::cmp::Ordering> {
+                    $crate::duration::Generic::from(*self)
+                        .partial_cmp(&$crate::duration::Generic::from(*rhs))
+                }
+            }
+        )+
+    };
 }
+
+#[cfg(test)]
+mod tests {}
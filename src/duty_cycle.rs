@@ -0,0 +1,229 @@
+//! Duty cycles of periodic signals
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, fraction::Fraction, rate::Rate, time_int::TimeInt,
+    ConversionError,
+};
+use core::{convert::TryFrom, fmt};
+use num::Integer;
+
+/// The fraction of a period a signal is active (on), in parts per million
+///
+/// A duty cycle ranges from [`DutyCycle::ZERO`] (always off) to [`DutyCycle::FULL`] (always on).
+/// It is computed from an on-time and a period with [`DutyCycle::from_durations()`], and applied
+/// to a period to get the on-time, either by multiplying a duration by it or with
+/// [`DutyCycle::on_time()`]. The results are truncated.
+///
+/// It converts into a [`Fraction`] (eg. for [`SoftPwm`](crate::soft_pwm::SoftPwm)) and is
+/// [`Display`](fmt::Display)ed as a percentage.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, duty_cycle::DutyCycle, rate::*};
+///
+/// let duty = DutyCycle::from_durations(Microseconds(250_u32), Milliseconds(1_u32)).unwrap();
+/// assert_eq!(duty, DutyCycle::from_percent(25).unwrap());
+/// assert_eq!(duty.to_string(), "25%");
+///
+/// assert_eq!(Milliseconds(20_u32) * duty, Milliseconds(5_u32));
+/// assert_eq!(duty.off_time(Milliseconds(20_u32)), Milliseconds(15_u32));
+/// assert_eq!(duty.on_time_at(Kilohertz(2_u32)), Ok(Microseconds(125_u32)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DutyCycle(u32);
+
+impl DutyCycle {
+    /// Parts per million of a full duty cycle
+    const PPM: u32 = 1_000_000;
+
+    /// Always off
+    pub const ZERO: Self = Self(0);
+    /// Always on
+    pub const FULL: Self = Self(Self::PPM);
+
+    /// Construct from parts per million
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`] : `ppm` is more than 1,000,000
+    pub const fn from_ppm(ppm: u32) -> Result<Self, ConversionError> {
+        if ppm <= Self::PPM {
+            Ok(Self(ppm))
+        } else {
+            Err(ConversionError::ConversionFailure)
+        }
+    }
+
+    /// Construct from parts per thousand
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`] : `permille` is more than 1,000
+    pub const fn from_permille(permille: u16) -> Result<Self, ConversionError> {
+        Self::from_ppm(permille as u32 * 1_000)
+    }
+
+    /// Construct from a percentage
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`] : `percent` is more than 100
+    pub const fn from_percent(percent: u8) -> Result<Self, ConversionError> {
+        Self::from_ppm(percent as u32 * 10_000)
+    }
+
+    /// Construct from the time a signal is on during each period (truncated to parts per million)
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : `on_time` is longer than `period`
+    /// - [`ConversionError::DivByZero`] : `period` is `0`
+    /// - [`ConversionError::Overflow`] : The intermediate math overflowed
+    pub fn from_durations<On, Period>(on_time: On, period: Period) -> Result<Self, ConversionError>
+    where
+        On: Duration + FixedPoint,
+        Period: Duration + FixedPoint,
+        u128: From<On::T> + From<Period::T>,
+    {
+        // both durations in a common unit, their _scaling factors_ reduced so that same-unit
+        // durations aren't scaled
+        let numerators = On::SCALING_FACTOR
+            .numerator()
+            .gcd(Period::SCALING_FACTOR.numerator());
+        let denominators = On::SCALING_FACTOR
+            .denominator()
+            .gcd(Period::SCALING_FACTOR.denominator());
+        let on_time = u128::from(on_time.integer())
+            .checked_mul(<u128 as From<u32>>::from(
+                *On::SCALING_FACTOR.numerator() / numerators,
+            ))
+            .and_then(|ticks| {
+                ticks.checked_mul(<u128 as From<u32>>::from(
+                    *Period::SCALING_FACTOR.denominator() / denominators,
+                ))
+            })
+            .ok_or(ConversionError::Overflow)?;
+        let period = u128::from(period.integer())
+            .checked_mul(<u128 as From<u32>>::from(
+                *Period::SCALING_FACTOR.numerator() / numerators,
+            ))
+            .and_then(|ticks| {
+                ticks.checked_mul(<u128 as From<u32>>::from(
+                    *On::SCALING_FACTOR.denominator() / denominators,
+                ))
+            })
+            .ok_or(ConversionError::Overflow)?;
+
+        if period == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+        if on_time > period {
+            return Err(ConversionError::ConversionFailure);
+        }
+
+        // drop low bits of huge durations so that the product can't overflow
+        let (mut on_time, mut period) = (on_time, period);
+        while on_time
+            .checked_mul(<u128 as From<u32>>::from(Self::PPM))
+            .is_none()
+        {
+            on_time >>= 1;
+            period >>= 1;
+        }
+        // on_time <= period, so this is at most `PPM`
+        Ok(Self(
+            (on_time * <u128 as From<u32>>::from(Self::PPM) / period) as u32,
+        ))
+    }
+
+    /// Returns the parts per million
+    pub const fn ppm(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the duty cycle of the inverted signal (`FULL - self`)
+    pub const fn complement(&self) -> Self {
+        Self(Self::PPM - self.0)
+    }
+
+    /// Returns the time the signal is on during the given period (truncated)
+    pub fn on_time<Dur>(&self, period: Dur) -> Dur
+    where
+        Dur: Duration + FixedPoint,
+        u128: From<Dur::T>,
+        Dur::T: TryFrom<u128>,
+    {
+        Dur::new(self.scale(period.integer()))
+    }
+
+    /// Returns the time the signal is off during the given period (`period - on_time`)
+    pub fn off_time<Dur>(&self, period: Dur) -> Dur
+    where
+        Dur: Duration + FixedPoint,
+        u128: From<Dur::T>,
+        Dur::T: TryFrom<u128>,
+    {
+        // the on-time is at most the period
+        Dur::new(period.integer() - self.scale(period.integer()))
+    }
+
+    /// Returns the time the signal is on during each period of the given rate
+    ///
+    /// The period is first truncated to the `Dur` type.
+    ///
+    /// # Errors
+    ///
+    /// See [`Rate::to_duration()`]
+    pub fn on_time_at<Dur, R>(&self, rate: R) -> Result<Dur, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        R: Rate + FixedPoint,
+        Dur::T: TryFrom<R::T> + TryFrom<u128>,
+        u128: From<Dur::T>,
+    {
+        Ok(self.on_time(rate.to_duration::<Dur>()?))
+    }
+
+    /// Returns `integer × self` (truncated), which is at most `integer`
+    pub(crate) fn scale<T>(&self, integer: T) -> T
+    where
+        T: TimeInt + TryFrom<u128>,
+        u128: From<T>,
+    {
+        let ppm = <u128 as From<u32>>::from(Self::PPM);
+        let value = u128::from(integer);
+        // split so that `u128` integers can't overflow
+        let scaled = value / ppm * <u128 as From<u32>>::from(self.0)
+            + value % ppm * <u128 as From<u32>>::from(self.0) / ppm;
+
+        T::try_from(scaled).unwrap_or(integer)
+    }
+}
+
+impl From<DutyCycle> for Fraction {
+    fn from(duty: DutyCycle) -> Self {
+        Fraction::new(duty.0, DutyCycle::PPM)
+    }
+}
+
+impl fmt::Display for DutyCycle {
+    /// Formats the duty cycle as a percentage (eg. `12.5%`), without trailing zeros
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (percent, mut fraction) = (self.0 / 10_000, self.0 % 10_000);
+        write!(f, "{}", percent)?;
+
+        if fraction != 0 {
+            let mut digits = 4;
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{:0width$}", fraction, width = digits)?;
+        }
+
+        f.write_str("%")
+    }
+}
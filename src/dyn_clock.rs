@@ -0,0 +1,66 @@
+//! Dynamic dispatch over clocks
+
+use crate::{clock, duration, fraction::Fraction, time_int::TimeInt};
+
+/// A dyn-safe facade over [`Clock`](crate::Clock)
+///
+/// [`Clock`](crate::Clock) has an associated constant and returns instants typed by the clock, so
+/// it can't be made into a trait object. Every [`Clock`](crate::Clock) implements `DynClock`
+/// instead, returning its instants as a [`duration::Generic`] since the clock's epoch. Drivers can
+/// then hold a `&dyn DynClock<T>` and have the clock swapped at runtime, without heap allocation.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, dyn_clock::DynClock, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct MilliClock;
+/// impl embedded_time::Clock for MilliClock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(5))}
+/// }
+/// # #[derive(Debug)]
+/// struct MicroClock;
+/// impl embedded_time::Clock for MicroClock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::new(7))}
+/// }
+///
+/// let mut clock: &dyn DynClock<u32> = &MilliClock;
+/// assert_eq!(clock.try_now_generic(), Ok(Generic::new(5, Fraction::new(1, 1_000))));
+///
+/// clock = &MicroClock;
+/// assert_eq!(clock.scaling_factor(), Fraction::new(1, 1_000_000));
+/// assert_eq!(
+///     Microseconds::<u32>::try_from(clock.try_now_generic().unwrap()),
+///     Ok(Microseconds(7))
+/// );
+/// ```
+pub trait DynClock<T: TimeInt> {
+    /// Returns the duration of one clock tick in seconds
+    fn scaling_factor(&self) -> Fraction;
+
+    /// Get the current instant as the duration since the clock's epoch
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the clock
+    fn try_now_generic(&self) -> Result<duration::Generic<T>, clock::Error>;
+}
+
+impl<Clock: crate::Clock> DynClock<Clock::T> for Clock {
+    fn scaling_factor(&self) -> Fraction {
+        Clock::SCALING_FACTOR
+    }
+
+    fn try_now_generic(&self) -> Result<duration::Generic<Clock::T>, clock::Error> {
+        clock::try_now(self).map(|instant| instant.duration_since_epoch())
+    }
+}
@@ -0,0 +1,101 @@
+//! An [`embassy_time_driver::Driver`] backed by a [`Clock`](crate::Clock)
+
+use crate::{async_delay::WakeHook, clock, Instant};
+use core::{convert::TryFrom, task::Waker};
+use embassy_time_driver::{Driver, TICK_HZ};
+use num::Bounded;
+
+/// Implements [`embassy_time_driver::Driver`] using any [`Clock`](crate::Clock)
+///
+/// This lets projects mixing embassy tasks with `embedded-time`-based HALs use a single time
+/// source. Clock ticks are converted to and from embassy ticks ([`TICK_HZ`]) using the clock's
+/// _scaling factor_. Wakeups are scheduled through the given [`WakeHook`], which would typically
+/// program a compare/alarm interrupt that wakes the registered wakers.
+///
+/// embassy requires a time that never wraps, so the clock should have a 64-bit counter.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// embassy_time_driver::time_driver_impl!(
+///     static DRIVER: EmbassyDriver<SysClock, AlarmHook> = EmbassyDriver::new(SysClock, AlarmHook)
+/// );
+/// ```
+#[derive(Debug)]
+pub struct EmbassyDriver<Clock: crate::Clock, Hook> {
+    clock: Clock,
+    hook: Hook,
+}
+
+impl<Clock: crate::Clock, Hook> EmbassyDriver<Clock, Hook> {
+    /// Construct a driver (usable in `static`s)
+    pub const fn new(clock: Clock, hook: Hook) -> Self {
+        Self { clock, hook }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns a reference to the wake hook
+    pub fn hook(&self) -> &Hook {
+        &self.hook
+    }
+}
+
+impl<Clock: crate::Clock, Hook> EmbassyDriver<Clock, Hook>
+where
+    u64: TryFrom<Clock::T>,
+{
+    /// Converts clock ticks to embassy ticks (truncated)
+    fn to_embassy_ticks(instant: &Instant<Clock>) -> u64 {
+        let ticks = u64::try_from(instant.duration_since_epoch().integer()).unwrap_or(u64::MAX);
+        let ticks = u128::from(ticks)
+            * u128::from(*Clock::SCALING_FACTOR.numerator())
+            * u128::from(TICK_HZ)
+            / u128::from(*Clock::SCALING_FACTOR.denominator());
+
+        <u64 as TryFrom<u128>>::try_from(ticks).unwrap_or(u64::MAX)
+    }
+}
+
+impl<Clock, Hook> Driver for EmbassyDriver<Clock, Hook>
+where
+    Clock: crate::Clock + Send + Sync + 'static,
+    Hook: WakeHook<Clock> + Send + Sync + 'static,
+    u64: TryFrom<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    /// Returns the current time in embassy ticks
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    fn now(&self) -> u64 {
+        match clock::try_now(&self.clock) {
+            Ok(now) => Self::to_embassy_ticks(&now),
+            Err(_) => panic!("Clock read failed"),
+        }
+    }
+
+    /// Registers `waker` with the [`WakeHook`] to be woken at (or after) `at` embassy ticks
+    ///
+    /// The waker is woken immediately if `at` has already passed.
+    fn schedule_wake(&self, at: u64, waker: &Waker) {
+        if at <= self.now() {
+            waker.wake_by_ref();
+            return;
+        }
+
+        // rounded up so the wakeup is never early
+        let ticks = (u128::from(at) * u128::from(*Clock::SCALING_FACTOR.denominator()))
+            .div_ceil(u128::from(*Clock::SCALING_FACTOR.numerator()) * u128::from(TICK_HZ));
+        let ticks = <u64 as TryFrom<u128>>::try_from(ticks)
+            .ok()
+            .and_then(|ticks| Clock::T::try_from(ticks).ok())
+            .unwrap_or_else(Clock::T::max_value);
+
+        self.hook.register(&Instant::new(ticks), waker);
+    }
+}
@@ -0,0 +1,333 @@
+//! Wall-clock (Unix-epoch) timestamps
+
+use crate::{
+    duration::{self, Duration},
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::Fraction,
+    Instant, TimeInt,
+};
+use core::{convert::TryFrom, ops};
+
+#[cfg(feature = "std")]
+use crate::ConversionError;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+const NANOSECOND: Fraction = Fraction::new(1, 1_000_000_000);
+
+/// A wall-clock time: the seconds and nanoseconds since the Unix epoch (1970-01-01 00:00:00 UTC)
+///
+/// Unlike an [`Instant`], which counts ticks since the (arbitrary) start of a clock, a `UnixTime`
+/// is meaningful outside of the device (eg. to timestamp telemetry). Once the Unix time of a
+/// clock's tick `0` is known (eg. from a network time sync or an RTC), the clock's instants convert
+/// to and from `UnixTime`s with [`from_instant()`](UnixTime::from_instant()) and
+/// [`to_instant()`](UnixTime::to_instant()).
+///
+/// With the `std` feature, it converts to and from `std::time::SystemTime`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, epoch::UnixTime, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // the clock started at 1_600_000_000.5
+/// let clock_epoch = UnixTime::new(1_600_000_000, 500_000_000).unwrap();
+///
+/// let sampled_at = UnixTime::from_instant(&Instant::<Clock>::new(1_750), clock_epoch).unwrap();
+/// assert_eq!(sampled_at, UnixTime::new(1_600_000_002, 250_000_000).unwrap());
+/// assert_eq!(sampled_at.to_instant(clock_epoch), Some(Instant::<Clock>::new(1_750)));
+///
+/// assert_eq!(
+///     sampled_at + Milliseconds(750_u32),
+///     UnixTime::from_secs(1_600_000_003)
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnixTime {
+    secs: u64,
+    nanos: u32,
+}
+
+impl UnixTime {
+    /// The Unix epoch (1970-01-01 00:00:00 UTC)
+    pub const EPOCH: Self = Self { secs: 0, nanos: 0 };
+
+    /// Construct a `UnixTime` from whole seconds and nanoseconds since the Unix epoch
+    ///
+    /// Returns [`None`] if `nanos` isn't less than a second.
+    pub const fn new(secs: u64, nanos: u32) -> Option<Self> {
+        if nanos < NANOS_PER_SEC {
+            Some(Self { secs, nanos })
+        } else {
+            None
+        }
+    }
+
+    /// Construct a `UnixTime` from whole seconds since the Unix epoch
+    pub const fn from_secs(secs: u64) -> Self {
+        Self { secs, nanos: 0 }
+    }
+
+    /// Returns the whole seconds since the Unix epoch
+    pub const fn secs(&self) -> u64 {
+        self.secs
+    }
+
+    /// Returns the fractional part, in nanoseconds
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Returns the `UnixTime` of an [`Instant`], given the `UnixTime` of the clock's tick `0`
+    /// (`epoch`)
+    ///
+    /// The time since the clock's epoch is truncated to nanoseconds. Returns [`None`] if the result
+    /// overflows.
+    pub fn from_instant<Clock: crate::Clock>(
+        instant: &Instant<Clock>,
+        epoch: UnixTime,
+    ) -> Option<Self> {
+        epoch.checked_add_nanos(duration_nanos(
+            instant.duration_since_epoch().integer(),
+            Clock::SCALING_FACTOR,
+        )?)
+    }
+
+    /// Returns the [`Instant`] of this `UnixTime`, given the `UnixTime` of the clock's tick `0`
+    /// (`epoch`)
+    ///
+    /// The time since the clock's epoch is truncated to whole ticks. Returns [`None`] if this is
+    /// before the `epoch` or the ticks don't fit the clock's integer type.
+    pub fn to_instant<Clock: crate::Clock>(&self, epoch: UnixTime) -> Option<Instant<Clock>>
+    where
+        Clock::T: TryFrom<u128>,
+    {
+        let nanos = self.as_nanos().checked_sub(epoch.as_nanos())?;
+        let ticks = fixed_point::rescale(
+            nanos,
+            NANOSECOND,
+            Clock::SCALING_FACTOR,
+            RoundingMode::Floor,
+        )
+        .ok()?;
+
+        Clock::T::try_from(ticks).ok().map(Instant::new)
+    }
+
+    /// This `UnixTime` + [`Duration`] (or [`Generic`](duration::Generic) duration) = later
+    /// `UnixTime`
+    ///
+    /// The duration is truncated to nanoseconds. Returns [`None`] if the result overflows.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, epoch::UnixTime};
+    /// assert_eq!(
+    ///     UnixTime::from_secs(1).checked_add(Microseconds(1_u32)),
+    ///     UnixTime::new(1, 1_000)
+    /// );
+    /// assert_eq!(UnixTime::from_secs(u64::MAX).checked_add(Seconds(1_u32)), None);
+    /// ```
+    pub fn checked_add<T: TimeInt>(
+        self,
+        duration: impl Into<duration::Generic<T>>,
+    ) -> Option<Self> {
+        self.checked_add_nanos(generic_nanos(duration.into())?)
+    }
+
+    /// This `UnixTime` - [`Duration`] (or [`Generic`](duration::Generic) duration) = earlier
+    /// `UnixTime`
+    ///
+    /// The duration is truncated to nanoseconds. Returns [`None`] if the result is before the Unix
+    /// epoch.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, epoch::UnixTime};
+    /// assert_eq!(
+    ///     UnixTime::from_secs(1).checked_sub(Microseconds(1_u32)),
+    ///     UnixTime::new(0, 999_999_000)
+    /// );
+    /// assert_eq!(UnixTime::EPOCH.checked_sub(Nanoseconds(1_u32)), None);
+    /// ```
+    pub fn checked_sub<T: TimeInt>(
+        self,
+        duration: impl Into<duration::Generic<T>>,
+    ) -> Option<Self> {
+        Self::from_nanos(
+            self.as_nanos()
+                .checked_sub(generic_nanos(duration.into())?)?,
+        )
+    }
+
+    /// Returns the amount of time elapsed from another `UnixTime` to this one, in nanoseconds
+    ///
+    /// Returns [`None`] if `other` is later than this or the nanoseconds overflow a [`u64`] (about
+    /// 584 years).
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, epoch::UnixTime};
+    /// # use core::convert::TryFrom;
+    /// let earlier = UnixTime::new(10, 900_000_000).unwrap();
+    /// let later = UnixTime::from_secs(12);
+    ///
+    /// let elapsed = later.checked_duration_since(&earlier).unwrap();
+    /// assert_eq!(Milliseconds::<u32>::try_from(elapsed), Ok(Milliseconds(1_100_u32)));
+    /// assert_eq!(earlier.checked_duration_since(&later), None);
+    /// ```
+    pub fn checked_duration_since(&self, other: &Self) -> Option<duration::Generic<u64>> {
+        let nanos = u64::try_from(self.as_nanos().checked_sub(other.as_nanos())?).ok()?;
+
+        Some(duration::Generic::new(nanos, NANOSECOND))
+    }
+
+    /// The nanoseconds since the Unix epoch
+    fn as_nanos(&self) -> u128 {
+        u128::from(self.secs) * u128::from(NANOS_PER_SEC) + u128::from(self.nanos)
+    }
+
+    fn from_nanos(nanos: u128) -> Option<Self> {
+        Some(Self {
+            secs: u64::try_from(nanos / u128::from(NANOS_PER_SEC)).ok()?,
+            nanos: (nanos % u128::from(NANOS_PER_SEC)) as u32,
+        })
+    }
+
+    fn checked_add_nanos(self, nanos: u128) -> Option<Self> {
+        Self::from_nanos(self.as_nanos().checked_add(nanos)?)
+    }
+}
+
+/// Returns the `duration` in nanoseconds (truncated)
+fn generic_nanos<T: TimeInt>(duration: duration::Generic<T>) -> Option<u128> {
+    duration_nanos(duration.integer(), *duration.scaling_factor())
+}
+
+/// Returns `integer` ticks of the `scaling_factor` in nanoseconds (truncated)
+fn duration_nanos<T: TimeInt>(integer: T, scaling_factor: Fraction) -> Option<u128> {
+    fixed_point::rescale(
+        integer.to_u128()?,
+        scaling_factor,
+        NANOSECOND,
+        RoundingMode::Floor,
+    )
+    .ok()
+}
+
+impl<Dur: Duration + FixedPoint> ops::Add<Dur> for UnixTime
+where
+    Dur: Into<duration::Generic<Dur::T>>,
+{
+    type Output = Self;
+
+    /// Add a [`Duration`] to a `UnixTime` resulting in a later `UnixTime`
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows. See [`UnixTime::checked_add()`].
+    fn add(self, rhs: Dur) -> Self::Output {
+        if let Some(v) = self.checked_add::<Dur::T>(rhs) {
+            v
+        } else {
+            panic!("Add failed")
+        }
+    }
+}
+
+impl<Dur: Duration + FixedPoint> ops::Sub<Dur> for UnixTime
+where
+    Dur: Into<duration::Generic<Dur::T>>,
+{
+    type Output = Self;
+
+    /// Subtract a [`Duration`] from a `UnixTime` resulting in an earlier `UnixTime`
+    ///
+    /// # Panics
+    ///
+    /// If the result is before the Unix epoch. See [`UnixTime::checked_sub()`].
+    fn sub(self, rhs: Dur) -> Self::Output {
+        if let Some(v) = self.checked_sub::<Dur::T>(rhs) {
+            v
+        } else {
+            panic!("Sub failed")
+        }
+    }
+}
+
+impl<T: TimeInt> ops::Add<duration::Generic<T>> for UnixTime {
+    type Output = Self;
+
+    /// Add a [`Generic`](duration::Generic) duration to a `UnixTime` resulting in a later `UnixTime`
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows. See [`UnixTime::checked_add()`].
+    fn add(self, rhs: duration::Generic<T>) -> Self::Output {
+        if let Some(v) = self.checked_add(rhs) {
+            v
+        } else {
+            panic!("Add failed")
+        }
+    }
+}
+
+impl<T: TimeInt> ops::Sub<duration::Generic<T>> for UnixTime {
+    type Output = Self;
+
+    /// Subtract a [`Generic`](duration::Generic) duration from a `UnixTime` resulting in an earlier `UnixTime`
+    ///
+    /// # Panics
+    ///
+    /// If the result is before the Unix epoch. See [`UnixTime::checked_sub()`].
+    fn sub(self, rhs: duration::Generic<T>) -> Self::Output {
+        if let Some(v) = self.checked_sub(rhs) {
+            v
+        } else {
+            panic!("Sub failed")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for UnixTime {
+    type Error = ConversionError;
+
+    /// Convert a [`SystemTime`](std::time::SystemTime)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::NegDuration`] : The time is before the Unix epoch
+    fn try_from(time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let since_epoch = time
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|_| ConversionError::NegDuration)?;
+
+        Ok(Self {
+            secs: since_epoch.as_secs(),
+            nanos: since_epoch.subsec_nanos(),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<UnixTime> for std::time::SystemTime {
+    type Error = ConversionError;
+
+    /// Convert to a [`SystemTime`](std::time::SystemTime)
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The time is beyond the range of the platform's `SystemTime`
+    fn try_from(time: UnixTime) -> Result<Self, Self::Error> {
+        std::time::UNIX_EPOCH
+            .checked_add(core::time::Duration::new(time.secs, time.nanos))
+            .ok_or(ConversionError::Overflow)
+    }
+}
@@ -0,0 +1,117 @@
+//! Extension of narrow hardware counters to 64 bits
+
+use crate::{clock, fraction::Fraction, Instant};
+use core::{
+    convert::TryFrom,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+/// A [`Clock`](crate::Clock) wrapper that widens a `BITS`-bit hardware counter to 64 bits
+///
+/// Many MCUs only have 16- or 32-bit timers, which wrap after seconds or minutes. The wrapper counts
+/// counter overflows, [`ExtendedClock::on_overflow()`] must be called (typically from the overflow
+/// interrupt handler) each time the underlying counter wraps. The overflow count becomes the upper
+/// bits of a 64-bit, effectively non-wrapping [`Instant`].
+///
+/// Only the lower `BITS` bits of the underlying clock's readings are used.
+///
+/// If the clock is read after the counter has wrapped but before the overflow has been handled (eg.
+/// from a higher-priority interrupt or a critical section), the reading is one counter period
+/// early. The overflow interrupt should therefore be serviced promptly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{extended_clock::ExtendedClock, fraction::Fraction, Clock as _, Instant};
+/// # use core::sync::atomic::{AtomicU32, Ordering};
+/// # #[derive(Debug)]
+/// /// A 16-bit timer
+/// struct Timer(AtomicU32);
+/// impl embedded_time::Clock for Timer {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.load(Ordering::SeqCst)))
+/// # }
+/// }
+///
+/// static CLOCK: ExtendedClock<Timer, 16> = ExtendedClock::new(Timer(AtomicU32::new(0)));
+///
+/// // in the timer overflow interrupt handler
+/// CLOCK.on_overflow();
+///
+/// # CLOCK.inner().0.store(5, Ordering::SeqCst);
+/// assert_eq!(CLOCK.try_now(), Ok(Instant::new(65_536 + 5)));
+/// ```
+#[derive(Debug)]
+pub struct ExtendedClock<Clock: crate::Clock, const BITS: u32> {
+    clock: Clock,
+    overflows: AtomicU32,
+}
+
+impl<Clock: crate::Clock, const BITS: u32> ExtendedClock<Clock, BITS> {
+    /// Wrap a clock whose counter is `BITS` bits wide
+    ///
+    /// # Panics
+    ///
+    /// If `BITS` is not in the range 1..=32
+    pub const fn new(clock: Clock) -> Self {
+        assert!(BITS >= 1 && BITS <= 32, "Invalid counter width");
+
+        Self {
+            clock,
+            overflows: AtomicU32::new(0),
+        }
+    }
+
+    /// Record an overflow (wrap-around) of the underlying counter
+    ///
+    /// This must be called exactly once per overflow, typically from the overflow interrupt
+    /// handler.
+    pub fn on_overflow(&self) {
+        // load + store rather than `fetch_add()` so this works on targets without atomic
+        // read-modify-write instructions (there is a single writer)
+        self.overflows.store(
+            self.overflows.load(Ordering::Acquire).wrapping_add(1),
+            Ordering::Release,
+        );
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+}
+
+impl<Clock: crate::Clock, const BITS: u32> crate::Clock for ExtendedClock<Clock, BITS>
+where
+    u64: TryFrom<Clock::T>,
+{
+    type T = u64;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Clock::SCALING_FACTOR;
+
+    /// Get the current, 64-bit Instant
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the underlying clock
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        // re-read if an overflow was handled while reading the counter
+        loop {
+            let overflows = self.overflows.load(Ordering::Acquire);
+            let ticks = clock::try_now(&self.clock)?
+                .duration_since_epoch()
+                .integer();
+
+            if self.overflows.load(Ordering::Acquire) == overflows {
+                let ticks = u64::try_from(ticks).map_err(|_| clock::Error::Unspecified)?
+                    & (u64::MAX >> (64 - BITS));
+
+                return Ok(Instant::new((u64::from(overflows) << BITS) | ticks));
+            }
+        }
+    }
+}
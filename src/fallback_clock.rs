@@ -0,0 +1,206 @@
+//! Fallback to a secondary clock
+
+use crate::{clock, fraction::Fraction, Instant};
+use core::{cell::Cell, convert::TryFrom};
+use num::traits::WrappingAdd;
+
+/// A [`Clock`](crate::Clock) that switches to a secondary clock once the primary one fails
+///
+/// The `FallbackClock` has the integer type and _scaling factor_ of the primary clock. Until the
+/// primary clock returns an error, its readings are returned unchanged. From then on, the secondary
+/// clock (eg. a timer running from a less accurate internal oscillator) is used: the time elapsed
+/// on it since the switchover is added to the latest primary reading (or to the epoch if there
+/// was none), so the time continues rather than jumping to the secondary clock's count.
+///
+/// The switchover is recorded ([`FallbackClock::switchover()`]), so timestamps taken after it can
+/// be explained. The time elapsed between the latest primary reading and the failure is lost. Each
+/// reading moves the reference point on the secondary clock forward (keeping the remainder of the
+/// tick conversion, so nothing drifts), so the secondary clock must be read at least once every
+/// half of its range.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fallback_clock::FallbackClock, fraction::Fraction, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Crystal(Cell<Option<u32>>);
+/// impl embedded_time::Clock for Crystal {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     self.0.get().map(Instant::new).ok_or(embedded_time::clock::Error::NotRunning)
+/// # }
+/// }
+/// # #[derive(Debug)]
+/// struct Lsi(Cell<u16>);
+/// impl embedded_time::Clock for Lsi {
+///     type T = u16;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let clock = FallbackClock::new(Crystal(Cell::new(Some(65_536))), Lsi(Cell::new(500)));
+/// assert_eq!(clock.try_now(), Ok(Instant::new(65_536)));
+///
+/// // the crystal stops
+/// clock.primary().0.set(None);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(65_536)));
+/// assert_eq!(clock.switchover(), Some(Instant::new(65_536)));
+///
+/// // 250 ms later
+/// clock.secondary().0.set(750);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(65_536 + 8_192)));
+/// ```
+#[derive(Debug)]
+pub struct FallbackClock<Primary: crate::Clock, Secondary: crate::Clock> {
+    primary: Primary,
+    secondary: Secondary,
+    latest: Cell<Option<Primary::T>>,
+    switchover: Cell<Option<Primary::T>>,
+    fallback: Cell<Option<Fallback<Primary::T, Secondary::T>>>,
+}
+
+/// The latest reading since the switchover
+#[derive(Copy, Clone, Debug)]
+struct Fallback<P, S> {
+    /// The reading of the `FallbackClock`
+    primary: P,
+    /// The reading of the secondary clock
+    secondary: S,
+    /// The remainder of the conversion of secondary clock ticks to primary clock ticks (in units
+    /// of the conversion's denominator)
+    remainder: u128,
+}
+
+impl<Primary, Secondary> FallbackClock<Primary, Secondary>
+where
+    Primary: crate::Clock,
+    Secondary: crate::Clock,
+    u128: From<Secondary::T>,
+    Primary::T: TryFrom<u128>,
+{
+    /// Wrap a primary and a secondary clock
+    pub fn new(primary: Primary, secondary: Secondary) -> Self {
+        Self {
+            primary,
+            secondary,
+            latest: Cell::new(None),
+            switchover: Cell::new(None),
+            fallback: Cell::new(None),
+        }
+    }
+
+    /// Returns a reference to the primary clock
+    pub fn primary(&self) -> &Primary {
+        &self.primary
+    }
+
+    /// Returns a reference to the secondary clock
+    pub fn secondary(&self) -> &Secondary {
+        &self.secondary
+    }
+
+    /// Returns the instant (of this clock) from which the secondary clock is used, or `None` if the
+    /// primary clock hasn't failed
+    pub fn switchover(&self) -> Option<Instant<Self>> {
+        self.switchover.get().map(Instant::new)
+    }
+
+    /// Returns whether the secondary clock is in use
+    pub fn is_fallback(&self) -> bool {
+        self.switchover.get().is_some()
+    }
+
+    /// Advances the latest fallback reading by the time elapsed on the secondary clock since then
+    fn advance(
+        &self,
+        latest: Fallback<Primary::T, Secondary::T>,
+    ) -> Result<Primary::T, clock::Error> {
+        let now = clock::try_now(&self.secondary)?;
+        let elapsed = now
+            .checked_duration_since(&Instant::new(latest.secondary))
+            .ok_or(clock::Error::NonMonotonic)?
+            .integer();
+
+        // elapsed × secondary scaling factor / primary scaling factor
+        let numerator = <u128 as From<u32>>::from(*Secondary::SCALING_FACTOR.numerator())
+            * <u128 as From<u32>>::from(*Primary::SCALING_FACTOR.denominator());
+        let denominator = <u128 as From<u32>>::from(*Secondary::SCALING_FACTOR.denominator())
+            * <u128 as From<u32>>::from(*Primary::SCALING_FACTOR.numerator());
+
+        let scaled = u128::from(elapsed)
+            .checked_mul(numerator)
+            .and_then(|scaled| scaled.checked_add(latest.remainder))
+            .ok_or(clock::Error::Unspecified)?;
+        let ticks = scaled
+            .checked_div(denominator)
+            .and_then(|ticks| Primary::T::try_from(ticks).ok())
+            .ok_or(clock::Error::Unspecified)?;
+
+        let primary = latest.primary.wrapping_add(&ticks);
+        self.fallback.set(Some(Fallback {
+            primary,
+            secondary: now.duration_since_epoch().integer(),
+            remainder: scaled % denominator,
+        }));
+
+        Ok(primary)
+    }
+}
+
+impl<Primary, Secondary> crate::Clock for FallbackClock<Primary, Secondary>
+where
+    Primary: crate::Clock,
+    Secondary: crate::Clock,
+    u128: From<Secondary::T>,
+    Primary::T: TryFrom<u128>,
+{
+    type T = Primary::T;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Primary::SCALING_FACTOR;
+
+    /// Get the current Instant of the primary clock, or continued with the secondary clock once
+    /// the primary clock has failed
+    ///
+    /// # Errors
+    ///
+    /// - [`clock::Error::NonMonotonic`] : The secondary clock jumped backwards (or wasn't read
+    ///   for more than half of its range since the previous reading)
+    /// - [`clock::Error::Unspecified`] : The time elapsed on the secondary clock doesn't fit in the
+    ///   primary clock's integer type
+    /// - Any error returned by the secondary clock
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let fallback = match self.fallback.get() {
+            Some(fallback) => fallback,
+            None => match clock::try_now(&self.primary) {
+                Ok(now) => {
+                    let now = now.duration_since_epoch().integer();
+                    self.latest.set(Some(now));
+                    return Ok(Instant::new(now));
+                }
+                Err(_) => {
+                    let primary = self.latest.get().unwrap_or_else(|| Primary::T::from(0));
+                    let fallback = Fallback {
+                        primary,
+                        secondary: clock::try_now(&self.secondary)?
+                            .duration_since_epoch()
+                            .integer(),
+                        remainder: 0,
+                    };
+                    self.switchover.set(Some(primary));
+                    self.fallback.set(Some(fallback));
+                    fallback
+                }
+            },
+        };
+
+        self.advance(fallback).map(Instant::new)
+    }
+}
@@ -1,7 +1,65 @@
 //! Fixed-point values
-use crate::{fraction::Fraction, time_int::TimeInt, ConversionError};
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
-use num::{Bounded, CheckedDiv, CheckedMul};
+use crate::{
+    fraction::{Fraction, Fraction64},
+    time_int::TimeInt,
+    ConversionError,
+};
+use core::{cmp::Ordering, convert::TryFrom, mem::size_of};
+use dimension::Dimension;
+use num::{
+    traits::{WrappingAdd, WrappingSub},
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Integer,
+};
+
+/// Physical dimensions of fixed-point values
+///
+/// Every [`FixedPoint`] type has a [`Dimension`], so the arithmetic that only makes sense between
+/// values of the same dimension is rejected at compile time, even in generic code:
+///
+/// ```rust,compile_fail
+/// # use embedded_time::{duration::*, fixed_point::FixedPoint, rate::*};
+/// fn sum<A: FixedPoint, B: FixedPoint>(a: A, b: B) -> A
+/// where
+///     A: core::convert::TryFrom<B>,
+/// {
+///     a.add(b)
+/// }
+/// ```
+///
+/// The meaningful cross-dimension operations are still available: the reciprocal
+/// ([`Duration::to_rate()`](crate::duration::Duration::to_rate()),
+/// [`Rate::to_duration()`](crate::rate::Rate::to_duration())) and the dimensionless product
+/// ([`FixedPoint::checked_product()`]).
+pub mod dimension {
+    mod private {
+        pub trait Sealed {}
+    }
+
+    /// The physical dimension of a [`FixedPoint`](super::FixedPoint) value
+    pub trait Dimension: private::Sealed {
+        /// The dimension of the reciprocal of a value of this dimension
+        type Reciprocal: Dimension<Reciprocal = Self>;
+    }
+
+    /// Time (seconds), the dimension of [`Duration`](crate::duration::Duration)s
+    #[derive(Debug)]
+    pub enum Time {}
+
+    /// Frequency (1/seconds), the dimension of [`Rate`](crate::rate::Rate)s
+    #[derive(Debug)]
+    pub enum Frequency {}
+
+    impl private::Sealed for Time {}
+    impl private::Sealed for Frequency {}
+
+    impl Dimension for Time {
+        type Reciprocal = Frequency;
+    }
+
+    impl Dimension for Frequency {
+        type Reciprocal = Time;
+    }
+}
 
 /// Fixed-point value type
 ///
@@ -10,6 +68,9 @@ pub trait FixedPoint: Sized + Copy {
     /// The _integer_ (magnitude) type
     type T: TimeInt;
 
+    /// The physical dimension
+    type Dimension: Dimension;
+
     /// The fractional _scaling factor_
     const SCALING_FACTOR: Fraction;
 
@@ -63,20 +124,21 @@ pub trait FixedPoint: Sized + Copy {
 
     #[doc(hidden)]
     fn convert_ticks<T: TimeInt>(ticks: T, scaling_factor: Fraction) -> Option<T> {
-        if (scaling_factor >= Fraction::new(1, 1) && Self::SCALING_FACTOR <= Fraction::new(1, 1))
-            || (scaling_factor <= Fraction::new(1, 1)
-                && Self::SCALING_FACTOR >= Fraction::new(1, 1))
-        {
-            TimeInt::checked_div_fraction(
-                &TimeInt::checked_mul_fraction(&ticks, &scaling_factor)?,
-                &Self::SCALING_FACTOR,
-            )
-        } else {
-            TimeInt::checked_mul_fraction(
-                &ticks,
-                &scaling_factor.checked_div(&Self::SCALING_FACTOR)?,
-            )
-        }
+        // compared with 1 without the division of `Fraction`'s `Ord`
+        let (from, to) = (
+            scaling_factor.numerator().cmp(scaling_factor.denominator()),
+            Self::SCALING_FACTOR
+                .numerator()
+                .cmp(Self::SCALING_FACTOR.denominator()),
+        );
+        let factors = conversion_factors(
+            scaling_factor,
+            Self::SCALING_FACTOR,
+            (from != Ordering::Less && to != Ordering::Greater)
+                || (from != Ordering::Greater && to != Ordering::Less),
+        )?;
+
+        mul_fractions(ticks, factors)
     }
 
     /// Returns the _integer_ of the fixed-point value after converting to the _scaling factor_
@@ -98,48 +160,31 @@ pub trait FixedPoint: Sized + Copy {
     /// [`ConversionError::ConversionFailure`] : The _integer_ type cast to that of the destination
     /// fails.
     #[doc(hidden)]
-    fn into_ticks<T: TimeInt>(self, fraction: Fraction) -> Result<T, ConversionError>
+    fn into_ticks<T>(self, fraction: Fraction) -> Result<T, ConversionError>
     where
         Self::T: TimeInt,
-        T: TryFrom<Self::T>,
+        T: TimeInt + TryFrom<Self::T>,
     {
         if size_of::<T>() > size_of::<Self::T>() {
             let ticks =
                 T::try_from(self.integer()).map_err(|_| ConversionError::ConversionFailure)?;
+            let factors = conversion_factors(
+                Self::SCALING_FACTOR,
+                fraction,
+                fraction.numerator() > fraction.denominator(),
+            )
+            .ok_or(ConversionError::Unspecified)?;
 
-            if fraction > Fraction::new(1, 1) {
-                TimeInt::checked_div_fraction(
-                    &TimeInt::checked_mul_fraction(&ticks, &Self::SCALING_FACTOR)
-                        .ok_or(ConversionError::Unspecified)?,
-                    &fraction,
-                )
-                .ok_or(ConversionError::Unspecified)
-            } else {
-                TimeInt::checked_mul_fraction(
-                    &ticks,
-                    &Self::SCALING_FACTOR
-                        .checked_div(&fraction)
-                        .ok_or(ConversionError::Unspecified)?,
-                )
-                .ok_or(ConversionError::Unspecified)
-            }
+            mul_fractions(ticks, factors).ok_or(ConversionError::Unspecified)
         } else {
-            let ticks = if Self::SCALING_FACTOR > Fraction::new(1, 1) {
-                TimeInt::checked_div_fraction(
-                    &TimeInt::checked_mul_fraction(&self.integer(), &Self::SCALING_FACTOR)
-                        .ok_or(ConversionError::Unspecified)?,
-                    &fraction,
-                )
-                .ok_or(ConversionError::Unspecified)?
-            } else {
-                TimeInt::checked_mul_fraction(
-                    &self.integer(),
-                    &Self::SCALING_FACTOR
-                        .checked_div(&fraction)
-                        .ok_or(ConversionError::Unspecified)?,
-                )
-                .ok_or(ConversionError::Unspecified)?
-            };
+            let factors = conversion_factors(
+                Self::SCALING_FACTOR,
+                fraction,
+                Self::SCALING_FACTOR.numerator() > Self::SCALING_FACTOR.denominator(),
+            )
+            .ok_or(ConversionError::Unspecified)?;
+            let ticks =
+                mul_fractions(self.integer(), factors).ok_or(ConversionError::Unspecified)?;
 
             T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)
         }
@@ -147,7 +192,7 @@ pub trait FixedPoint: Sized + Copy {
 
     /// Panicky addition
     #[doc(hidden)]
-    fn add<Rhs: FixedPoint>(self, rhs: Rhs) -> Self
+    fn add<Rhs: FixedPoint<Dimension = Self::Dimension>>(self, rhs: Rhs) -> Self
     where
         Self: TryFrom<Rhs>,
     {
@@ -161,7 +206,7 @@ pub trait FixedPoint: Sized + Copy {
 
     /// Panicky subtraction
     #[doc(hidden)]
-    fn sub<Rhs: FixedPoint>(self, rhs: Rhs) -> Self
+    fn sub<Rhs: FixedPoint<Dimension = Self::Dimension>>(self, rhs: Rhs) -> Self
     where
         Self: TryFrom<Rhs>,
     {
@@ -174,15 +219,146 @@ pub trait FixedPoint: Sized + Copy {
         Self::new(self.integer() - v.integer())
     }
 
+    /// Checked addition
+    ///
+    /// The `rhs` is first converted to the type of `self`, the result is of the LHS type.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint, rate::*, ConversionError};
+    /// assert_eq!(Milliseconds(1_u32).checked_add(&Seconds(1_u32)), Ok(Milliseconds(1_001_u32)));
+    /// assert_eq!(Hertz(u32::MAX).checked_add(&Hertz(1_u32)), Err(ConversionError::Overflow));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+    /// - Any error converting `rhs` to the type of `self`
+    fn checked_add<Rhs: FixedPoint<Dimension = Self::Dimension>>(
+        &self,
+        rhs: &Rhs,
+    ) -> Result<Self, ConversionError>
+    where
+        Self::T: TryFrom<Rhs::T>,
+    {
+        let rhs = Self::from_ticks(rhs.integer(), Rhs::SCALING_FACTOR)?;
+
+        Ok(Self::new(
+            self.integer()
+                .checked_add(&rhs.integer())
+                .ok_or(ConversionError::Overflow)?,
+        ))
+    }
+
+    /// Checked subtraction
+    ///
+    /// The `rhs` is first converted to the type of `self`, the result is of the LHS type.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint, ConversionError};
+    /// assert_eq!(Milliseconds(2_001_u32).checked_sub(&Seconds(1_u32)), Ok(Milliseconds(1_001_u32)));
+    /// assert_eq!(Seconds(1_u32).checked_sub(&Milliseconds(2_000_u32)), Err(ConversionError::Overflow));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The result is negative
+    /// - Any error converting `rhs` to the type of `self`
+    fn checked_sub<Rhs: FixedPoint<Dimension = Self::Dimension>>(
+        &self,
+        rhs: &Rhs,
+    ) -> Result<Self, ConversionError>
+    where
+        Self::T: TryFrom<Rhs::T>,
+    {
+        let rhs = Self::from_ticks(rhs.integer(), Rhs::SCALING_FACTOR)?;
+
+        Ok(Self::new(
+            self.integer()
+                .checked_sub(&rhs.integer())
+                .ok_or(ConversionError::Overflow)?,
+        ))
+    }
+
+    /// Saturating addition
+    ///
+    /// The `rhs` is first converted to the type of `self`, the result is of the LHS type. Saturates
+    /// at the maximum _integer_ value, including when `rhs` is too large to be converted.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint, rate::*};
+    /// assert_eq!(Milliseconds(1_u32).saturating_add(&Seconds(1_u32)), Milliseconds(1_001_u32));
+    /// assert_eq!(Hertz(u32::MAX - 1).saturating_add(&Kilohertz(1_u32)), Hertz(u32::MAX));
+    /// assert_eq!(Seconds(1_u32).saturating_add(&Seconds(u64::MAX)), Seconds(u32::MAX));
+    /// ```
+    fn saturating_add<Rhs: FixedPoint<Dimension = Self::Dimension>>(&self, rhs: &Rhs) -> Self
+    where
+        Self::T: TryFrom<Rhs::T>,
+    {
+        self.checked_add(rhs)
+            .unwrap_or_else(|_| Self::new(Self::T::max_value()))
+    }
+
+    /// Saturating subtraction
+    ///
+    /// The `rhs` is first converted to the type of `self`, the result is of the LHS type. Saturates
+    /// at zero, including when `rhs` is too large to be converted.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint};
+    /// assert_eq!(Milliseconds(2_001_u32).saturating_sub(&Seconds(1_u32)), Milliseconds(1_001_u32));
+    /// assert_eq!(Seconds(1_u32).saturating_sub(&Milliseconds(5_000_u32)), Seconds(0_u32));
+    /// ```
+    fn saturating_sub<Rhs: FixedPoint<Dimension = Self::Dimension>>(&self, rhs: &Rhs) -> Self
+    where
+        Self::T: TryFrom<Rhs::T>,
+    {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|_| Self::new(Self::T::from(0)))
+    }
+
+    /// Wrapping addition
+    ///
+    /// Unlike the other additions, the `rhs` must be of the same type as the wraparound is that of
+    /// the _integer_ type.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint};
+    /// assert_eq!(Milliseconds(u32::MAX).wrapping_add(&Milliseconds(3_u32)), Milliseconds(2_u32));
+    /// ```
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        Self::new(self.integer().wrapping_add(&rhs.integer()))
+    }
+
+    /// Wrapping subtraction
+    ///
+    /// Unlike the other subtractions, the `rhs` must be of the same type as the wraparound is that
+    /// of the _integer_ type.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint};
+    /// assert_eq!(Milliseconds(2_u32).wrapping_sub(&Milliseconds(3_u32)), Milliseconds(u32::MAX));
+    /// ```
+    fn wrapping_sub(&self, rhs: &Self) -> Self {
+        Self::new(self.integer().wrapping_sub(&rhs.integer()))
+    }
+
     /// Panicky multiplication
     #[doc(hidden)]
     fn mul(self, rhs: Self::T) -> Self {
         Self::new(self.integer() * rhs)
     }
 
-    /// Multiply with overflow checking
-    fn checked_mul(&self, rhs: &Self::T) -> Option<Self> {
-        Some(Self::new((self.integer()).checked_mul(rhs)?))
+    /// Checked multiplication
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+    fn checked_mul(&self, rhs: &Self::T) -> Result<Self, ConversionError> {
+        Ok(Self::new(
+            self.integer()
+                .checked_mul(rhs)
+                .ok_or(ConversionError::Overflow)?,
+        ))
     }
 
     /// Panicky division
@@ -191,14 +367,22 @@ pub trait FixedPoint: Sized + Copy {
         Self::new(self.integer() / rhs)
     }
 
-    /// Multiply with overflow checking
-    fn checked_div(&self, rhs: &Self::T) -> Option<Self> {
-        Some(Self::new((self.integer()).checked_div(rhs)?))
+    /// Checked division
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::DivByZero`] : `rhs` is `0`
+    fn checked_div(&self, rhs: &Self::T) -> Result<Self, ConversionError> {
+        Ok(Self::new(
+            self.integer()
+                .checked_div(rhs)
+                .ok_or(ConversionError::DivByZero)?,
+        ))
     }
 
     /// Panicky remainder
     #[doc(hidden)]
-    fn rem<Rhs: FixedPoint>(self, rhs: Rhs) -> Self
+    fn rem<Rhs: FixedPoint<Dimension = Self::Dimension>>(self, rhs: Rhs) -> Self
     where
         Self: TryFrom<Rhs>,
     {
@@ -214,6 +398,176 @@ pub trait FixedPoint: Sized + Copy {
         }
     }
 
+    /// Returns the dimensionless product with a value of the reciprocal dimension (truncated), or
+    /// `None` upon overflow
+    ///
+    /// This is eg. the number of cycles of a rate within a duration.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::FixedPoint, rate::*};
+    /// assert_eq!(Milliseconds(250_u32).checked_product(&Kilohertz(3_u32)), Some(750));
+    /// assert_eq!(Hertz(10_u32).checked_product(&Microseconds(50_000_u32)), Some(0));
+    /// ```
+    fn checked_product<Rhs>(&self, rhs: &Rhs) -> Option<u64>
+    where
+        Rhs: FixedPoint<Dimension = <Self::Dimension as Dimension>::Reciprocal>,
+        u128: From<Self::T> + From<Rhs::T>,
+    {
+        let numerator = u128::from(self.integer())
+            .checked_mul(u128::from(rhs.integer()))?
+            .checked_mul(u128::from(*Self::SCALING_FACTOR.numerator()))?
+            .checked_mul(u128::from(*Rhs::SCALING_FACTOR.numerator()))?;
+        let denominator = u128::from(*Self::SCALING_FACTOR.denominator())
+            * u128::from(*Rhs::SCALING_FACTOR.denominator());
+
+        u64::try_from(numerator.checked_div(denominator)?).ok()
+    }
+
+    /// Construct from a value of any _scaling factor_ of the same dimension, rounded as given
+    ///
+    /// All other conversions truncate, which is usually the wrong choice for timeouts (rounding
+    /// up) or display (rounding to the nearest tick).
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::{FixedPoint, RoundingMode}, rate::*};
+    /// assert_eq!(
+    ///     Seconds::<u32>::try_convert_from_with(Milliseconds(1_999_u32), RoundingMode::Floor),
+    ///     Ok(Seconds(1_u32))
+    /// );
+    /// assert_eq!(
+    ///     Seconds::<u32>::try_convert_from_with(Milliseconds(1_001_u32), RoundingMode::Ceil),
+    ///     Ok(Seconds(2_u32))
+    /// );
+    /// assert_eq!(
+    ///     Kilohertz::<u32>::try_convert_from_with(Hertz(2_500_u32), RoundingMode::Nearest),
+    ///     Ok(Kilohertz(3_u32))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The intermediate math overflowed
+    /// - [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_ type
+    fn try_convert_from_with<Source>(
+        source: Source,
+        rounding: RoundingMode,
+    ) -> Result<Self, ConversionError>
+    where
+        Source: FixedPoint<Dimension = Self::Dimension>,
+        u128: From<Source::T>,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = rescale(
+            <u128 as From<Source::T>>::from(source.integer()),
+            Source::SCALING_FACTOR,
+            Self::SCALING_FACTOR,
+            rounding,
+        )?;
+
+        <Self::T as TryFrom<u128>>::try_from(ticks)
+            .map(Self::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+
+    /// Convert to a value of any _scaling factor_ of the same dimension, rounded as given
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::{FixedPoint, RoundingMode}};
+    /// let duration = Microseconds(1_500_u32);
+    ///
+    /// assert_eq!(duration.to_rounded(RoundingMode::Floor), Ok(Milliseconds(1_u32)));
+    /// assert_eq!(duration.to_rounded(RoundingMode::Ceil), Ok(Milliseconds(2_u32)));
+    /// assert_eq!(duration.to_rounded(RoundingMode::Nearest), Ok(Milliseconds(2_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`FixedPoint::try_convert_from_with()`]
+    fn to_rounded<Dest>(&self, rounding: RoundingMode) -> Result<Dest, ConversionError>
+    where
+        Dest: FixedPoint<Dimension = Self::Dimension>,
+        u128: From<Self::T>,
+        Dest::T: TryFrom<u128>,
+    {
+        Dest::try_convert_from_with(*self, rounding)
+    }
+
+    /// Construct from ticks of a wide ([`Fraction64`]) _scaling factor_, rounded as given
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::{FixedPoint, RoundingMode}, fraction::Fraction64};
+    /// // 3 / 25 MHz ticks
+    /// let tick = Fraction64::new(3, 25_000_000);
+    ///
+    /// assert_eq!(
+    ///     Nanoseconds::<u32>::try_from_ticks_with(1_000_u32, tick, RoundingMode::Floor),
+    ///     Ok(Nanoseconds(120_000_u32))
+    /// );
+    /// assert_eq!(
+    ///     Microseconds::<u64>::try_from_ticks_with(5_u64, tick, RoundingMode::Ceil),
+    ///     Ok(Microseconds(1_u64))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Overflow`] : The intermediate math overflowed
+    /// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` numerator or denominator
+    /// - [`ConversionError::ConversionFailure`] : The result doesn't fit in the _integer_ type
+    fn try_from_ticks_with<SourceInt>(
+        ticks: SourceInt,
+        scaling_factor: Fraction64,
+        rounding: RoundingMode,
+    ) -> Result<Self, ConversionError>
+    where
+        u128: From<SourceInt>,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = rescale(
+            <u128 as From<SourceInt>>::from(ticks),
+            scaling_factor,
+            Self::SCALING_FACTOR,
+            rounding,
+        )?;
+
+        <Self::T as TryFrom<u128>>::try_from(ticks)
+            .map(Self::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+
+    /// Returns the number of ticks of a wide ([`Fraction64`]) _scaling factor_, rounded as given
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, fixed_point::{FixedPoint, RoundingMode}, fraction::Fraction64};
+    /// // 3 / 25 MHz ticks
+    /// let tick = Fraction64::new(3, 25_000_000);
+    ///
+    /// assert_eq!(Milliseconds(3_u32).to_ticks_with(tick, RoundingMode::Floor), Ok(25_000_u32));
+    /// assert_eq!(Nanoseconds(100_u32).to_ticks_with(tick, RoundingMode::Ceil), Ok(1_u8));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`FixedPoint::try_from_ticks_with()`]
+    fn to_ticks_with<T>(
+        &self,
+        scaling_factor: Fraction64,
+        rounding: RoundingMode,
+    ) -> Result<T, ConversionError>
+    where
+        u128: From<Self::T>,
+        T: TryFrom<u128>,
+    {
+        let ticks = rescale(
+            <u128 as From<Self::T>>::from(self.integer()),
+            Self::SCALING_FACTOR,
+            scaling_factor,
+            rounding,
+        )?;
+
+        T::try_from(ticks).map_err(|_| ConversionError::ConversionFailure)
+    }
+
     /// Returns the minimum integer value
     fn min_value() -> Self::T {
         Self::T::min_value()
@@ -225,9 +579,330 @@ pub trait FixedPoint: Sized + Copy {
     }
 }
 
+/// The rounding of a conversion between _scaling factors_
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RoundingMode {
+    /// Round down (toward `0`), as all other conversions do
+    Floor,
+    /// Round up
+    Ceil,
+    /// Round to the nearest integer (halves round up)
+    Nearest,
+}
+
+/// Returns the two factors converting ticks of the `from` _scaling factor_ into ticks of the `to`
+/// _scaling factor_ when applied in turn (each truncating), or [`None`] if they don't fit a
+/// [`Fraction`]
+///
+/// `in_two_steps` multiplies by `from`, then divides by `to`, which avoids overflowing the
+/// [`Fraction`] when only one of them is less than `1`. Otherwise, the ticks are only multiplied
+/// by `from / to`.
+///
+/// The conversions of every unit and integer type share this (rather than each having its own copy
+/// of the _scaling factor_ math), leaving only [`mul_fractions()`] generic.
+pub(crate) fn conversion_factors(
+    from: Fraction,
+    to: Fraction,
+    in_two_steps: bool,
+) -> Option<(Fraction, Option<Fraction>)> {
+    if in_two_steps {
+        Some((from, Some(to.checked_recip()?)))
+    } else {
+        Some((from.checked_div(&to)?, None))
+    }
+}
+
+/// Returns `ticks` multiplied by the [`conversion_factors()`] (truncated)
+pub(crate) fn mul_fractions<T: TimeInt>(
+    ticks: T,
+    (first, second): (Fraction, Option<Fraction>),
+) -> Option<T> {
+    let ticks = ticks.checked_mul_fraction(&first)?;
+
+    match second {
+        Some(second) => ticks.checked_mul_fraction(&second),
+        None => Some(ticks),
+    }
+}
+
+/// Converts `ticks` of the `from` _scaling factor_ into ticks of the `to` _scaling factor_
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The intermediate math overflowed
+/// - [`ConversionError::DivByZero`] : A _scaling factor_ has a `0` numerator or denominator
+pub(crate) fn rescale(
+    ticks: u128,
+    from: impl Into<Fraction64>,
+    to: impl Into<Fraction64>,
+    rounding: RoundingMode,
+) -> Result<u128, ConversionError> {
+    let (from, to) = (from.into(), to.into());
+    let numerator = ticks
+        .checked_mul(u128::from(*from.numerator()))
+        .and_then(|ticks| ticks.checked_mul(u128::from(*to.denominator())))
+        .ok_or(ConversionError::Overflow)?;
+    let denominator = u128::from(*from.denominator()) * u128::from(*to.numerator());
+
+    if denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+    let round_up = match rounding {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => remainder != 0,
+        RoundingMode::Nearest => remainder >= denominator - denominator / 2,
+    };
+
+    if round_up {
+        quotient.checked_add(1).ok_or(ConversionError::Overflow)
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// Returns the reciprocal of the product of two _scaling factors_, reduced, as a
+/// `(numerator, denominator)` pair
+///
+/// This is the factor converting between rates and durations. Unlike [`Fraction`]
+/// multiplication, it can't overflow (eg. 1/1_000_000 × 1/1_000_000 from `Microhertz` to
+/// `Microseconds`).
+pub(crate) fn reciprocal_product(lhs: Fraction, rhs: Fraction) -> (u64, u64) {
+    let numerator = u64::from(*lhs.numerator()) * u64::from(*rhs.numerator());
+    let denominator = u64::from(*lhs.denominator()) * u64::from(*rhs.denominator());
+    let gcd = numerator.gcd(&denominator);
+
+    (denominator / gcd, numerator / gcd)
+}
+
+/// Converts a [`u64`] into the integer type, [`None`] if it doesn't fit
+pub(crate) fn int_from_u64<T: TimeInt>(value: u64) -> Option<T> {
+    let high = T::try_from((value >> 32) as u32).ok()?;
+    let low = T::try_from(value as u32).ok()?;
+
+    if high == T::from(0) {
+        Some(low)
+    } else {
+        let half = T::try_from(1 << 16).ok()?;
+        high.checked_mul(&half)?
+            .checked_mul(&half)?
+            .checked_add(&low)
+    }
+}
+
+/// Compares two values of any _scaling factors_ exactly
+///
+/// Both are converted to the greatest _scaling factor_ that they are integer multiples of. As with
+/// the _named_ types, a value that doesn't fit the integer type once converted is the larger one.
+/// If neither fits, or if there is no such _scaling factor_ that fits a [`Fraction`], the values
+/// are compared as wider products instead. Returns [`None`] only for integer types that aren't
+/// primitive integers.
+pub(crate) fn compare<T: TimeInt>(
+    lhs: T,
+    lhs_scaling_factor: &Fraction,
+    rhs: T,
+    rhs_scaling_factor: &Fraction,
+) -> Option<Ordering> {
+    compare_common(lhs, lhs_scaling_factor, rhs, rhs_scaling_factor).or_else(|| {
+        Some(compare_wide(
+            lhs.to_u128()?,
+            lhs_scaling_factor,
+            rhs.to_u128()?,
+            rhs_scaling_factor,
+        ))
+    })
+}
+
+/// Compares `lhs` × `lhs_scaling_factor` with `rhs` × `rhs_scaling_factor` as 192-bit products
+///
+/// (Both sides are multiplied by the product of the denominators.)
+fn compare_wide(
+    lhs: u128,
+    lhs_scaling_factor: &Fraction,
+    rhs: u128,
+    rhs_scaling_factor: &Fraction,
+) -> Ordering {
+    /// `value × factor` as the high 128 and low 64 bits
+    fn widening_mul(value: u128, factor: u64) -> (u128, u64) {
+        let low = (value as u64 as u128) * u128::from(factor);
+        // at most (2^64 - 1)^2 + 2^64 - 1, which is less than 2^128
+        let high = (value >> 64) * u128::from(factor) + (low >> 64);
+
+        (high, low as u64)
+    }
+
+    widening_mul(
+        lhs,
+        u64::from(*lhs_scaling_factor.numerator()) * u64::from(*rhs_scaling_factor.denominator()),
+    )
+    .cmp(&widening_mul(
+        rhs,
+        u64::from(*rhs_scaling_factor.numerator()) * u64::from(*lhs_scaling_factor.denominator()),
+    ))
+}
+
+/// Compares two values converted to their common _scaling factor_ (see [`compare()`])
+fn compare_common<T: TimeInt>(
+    lhs: T,
+    lhs_scaling_factor: &Fraction,
+    rhs: T,
+    rhs_scaling_factor: &Fraction,
+) -> Option<Ordering> {
+    if lhs == T::from(0) || rhs == T::from(0) {
+        return Some(lhs.cmp(&rhs));
+    }
+
+    let scaling_factor = Fraction::new(
+        lhs_scaling_factor
+            .numerator()
+            .gcd(rhs_scaling_factor.numerator()),
+        u32::try_from(
+            u64::from(*lhs_scaling_factor.denominator())
+                .lcm(&u64::from(*rhs_scaling_factor.denominator())),
+        )
+        .ok()?,
+    );
+    let lhs = lhs_scaling_factor
+        .checked_div(&scaling_factor)
+        .and_then(|factor| lhs.checked_mul_fraction(&factor));
+    let rhs = rhs_scaling_factor
+        .checked_div(&scaling_factor)
+        .and_then(|factor| rhs.checked_mul_fraction(&factor));
+
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(lhs.cmp(&rhs)),
+        (None, Some(_)) => Some(Ordering::Greater),
+        (Some(_), None) => Some(Ordering::Less),
+        (None, None) => None,
+    }
+}
+
+/// Converts `integer` ticks of the _scaling factor_ into a floating-point number of units
+///
+/// The result is rounded to the nearest representable value.
+#[cfg(feature = "float")]
+pub(crate) fn to_f64(integer: u128, scaling_factor: Fraction) -> f64 {
+    integer as f64 * f64::from(*scaling_factor.numerator())
+        / f64::from(*scaling_factor.denominator())
+}
+
+/// Converts a floating-point number of units into ticks of the _scaling factor_ (truncated)
+///
+/// # Errors
+///
+/// [`ConversionError::ConversionFailure`] : `value` is negative, NaN or too large
+#[cfg(feature = "float")]
+pub(crate) fn from_f64(value: f64, scaling_factor: Fraction) -> Result<u128, ConversionError> {
+    let ticks =
+        value * f64::from(*scaling_factor.denominator()) / f64::from(*scaling_factor.numerator());
+
+    // up to 2^128, NaN isn't contained either
+    if !(0.0..340_282_366_920_938_463_463_374_607_431_768_211_456.0).contains(&ticks) {
+        return Err(ConversionError::ConversionFailure);
+    }
+
+    Ok(ticks as u128)
+}
+
+/// Returns the exact ratio of two values of any _scaling factors_, reduced
+///
+/// # Errors
+///
+/// - [`ConversionError::DivByZero`] : `rhs` is zero
+/// - [`ConversionError::Overflow`] : The reduced ratio doesn't fit in a [`Fraction`]
+pub(crate) fn ratio<Lhs, Rhs>(lhs: &Lhs, rhs: &Rhs) -> Result<Fraction, ConversionError>
+where
+    Lhs: FixedPoint,
+    Rhs: FixedPoint,
+    u128: From<Lhs::T> + From<Rhs::T>,
+{
+    // both values in units of 1 / (lhs denominator × rhs denominator)
+    let numerator = u128::from(lhs.integer())
+        .checked_mul(<u128 as From<u32>>::from(*Lhs::SCALING_FACTOR.numerator()))
+        .and_then(|ticks| {
+            ticks.checked_mul(<u128 as From<u32>>::from(
+                *Rhs::SCALING_FACTOR.denominator(),
+            ))
+        })
+        .ok_or(ConversionError::Overflow)?;
+    let denominator = u128::from(rhs.integer())
+        .checked_mul(<u128 as From<u32>>::from(*Rhs::SCALING_FACTOR.numerator()))
+        .and_then(|ticks| {
+            ticks.checked_mul(<u128 as From<u32>>::from(
+                *Lhs::SCALING_FACTOR.denominator(),
+            ))
+        })
+        .ok_or(ConversionError::Overflow)?;
+
+    if denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let gcd = numerator.gcd(&denominator);
+    Ok(Fraction::new(
+        u32::try_from(numerator / gcd).map_err(|_| ConversionError::Overflow)?,
+        u32::try_from(denominator / gcd).map_err(|_| ConversionError::Overflow)?,
+    ))
+}
+
+/// Converts a _named_ `Duration` or `Rate` constant to another unit at compile time
+///
+/// `const_convert!(value => Unit<Int>)` evaluates to `value` (eg. `Seconds(2_u32)`) converted to
+/// `Unit<Int>` of the same dimension, truncated as with [`TryFrom`]. Unlike a runtime conversion,
+/// which returns a [`ConversionError`], a conversion that doesn't fit in the destination type is a
+/// compile error. The value must be a constant expression (with a suffixed _integer_) and `Unit`
+/// must be in scope.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{const_convert, duration::*, rate::*};
+///
+/// const TIMEOUT: Nanoseconds<u32> = const_convert!(Seconds(2_u32) => Nanoseconds<u32>);
+/// assert_eq!(TIMEOUT, Nanoseconds(2_000_000_000_u32));
+///
+/// static BAUD_CLOCKS: [Hertz<u32>; 2] = [
+///     const_convert!(Kilohertz(16_u32) => Hertz<u32>),
+///     const_convert!(Megahertz(4_u8) => Hertz<u32>),
+/// ];
+/// assert_eq!(BAUD_CLOCKS[1], Hertz(4_000_000_u32));
+///
+/// // truncated
+/// assert_eq!(const_convert!(Milliseconds(1_999_u32) => Seconds<u8>), Seconds(1_u8));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use embedded_time::{const_convert, duration::*};
+/// // 5 s doesn't fit in a `u32` of nanoseconds
+/// const TIMEOUT: Nanoseconds<u32> = const_convert!(Seconds(5_u32) => Nanoseconds<u32>);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use embedded_time::{const_convert, duration::*, rate::*};
+/// // not the same dimension
+/// const PERIOD: Seconds<u32> = const_convert!(Hertz(5_u32) => Seconds<u32>);
+/// ```
+///
+/// [`TryFrom`]: core::convert::TryFrom
+#[macro_export]
+macro_rules! const_convert {
+    ($value:expr => $unit:ident < $int:ty >) => {{
+        const INTEGER: $int = {
+            let value = $value;
+            let (from, to) = $crate::literal::scaling_factors::<_, $unit<$int>>(&value);
+            let integer = $crate::literal::convert(value.0 as u128, from, to);
+            if integer > <$int>::MAX as u128 {
+                panic!("Converted value doesn't fit in the integer type");
+            }
+            integer as $int
+        };
+        $unit::<$int>(INTEGER)
+    }};
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::duration::*;
     use crate::fixed_point;
 
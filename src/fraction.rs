@@ -1,6 +1,6 @@
 //! Fractional/Rational values
 use crate::ConversionError;
-use core::ops;
+use core::{cmp::Ordering, convert::TryFrom, ops};
 use num::{rational::Ratio, CheckedDiv, CheckedMul, Zero};
 
 /// A fractional value
@@ -15,6 +15,36 @@ use num::{rational::Ratio, CheckedDiv, CheckedMul, Zero};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Fraction(Ratio<u32>);
 
+/// Formatted as `numerator/denominator`
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fraction {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}/{}", self.numerator(), self.denominator())
+    }
+}
+
+/// Serialized as a `(numerator, denominator)` tuple
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.numerator(), self.denominator()).serialize(serializer)
+    }
+}
+
+/// Deserialized from a `(numerator, denominator)` tuple without reduction, a denominator of `0` is
+/// rejected
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (numerator, denominator) = <(u32, u32)>::deserialize(deserializer)?;
+        if denominator == 0 {
+            return Err(serde::de::Error::custom("Invalid fraction"));
+        }
+
+        Ok(Self::new(numerator, denominator))
+    }
+}
+
 impl Fraction {
     /// Construct a new `Fraction`.
     ///
@@ -43,35 +73,83 @@ impl Fraction {
     /// # Errors
     ///
     /// [`ConversionError::DivByZero`] : A `0` denominator was detected
-    // TODO: add example
-    pub fn new_reduce(numerator: u32, denominator: u32) -> Result<Self, ConversionError> {
-        if !denominator.is_zero() {
-            Ok(Self(Ratio::new(numerator, denominator)))
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, ConversionError};
+    /// #
+    /// assert_eq!(Fraction::new_reduce(6, 3), Ok(Fraction::new(2, 1)));
+    /// assert_eq!(Fraction::new_reduce(6, 0), Err(ConversionError::DivByZero));
+    /// ```
+    pub const fn new_reduce(numerator: u32, denominator: u32) -> Result<Self, ConversionError> {
+        if denominator != 0 {
+            Ok(Self::new(numerator, denominator).reduce())
         } else {
             Err(ConversionError::DivByZero)
         }
     }
 
+    /// Returns the fraction in lowest terms
+    ///
+    /// A fraction with a `0` denominator is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// const SCALING_FACTOR: Fraction = Fraction::new(8, 48_000_000).reduce();
+    ///
+    /// assert_eq!(*SCALING_FACTOR.numerator(), 1);
+    /// assert_eq!(*SCALING_FACTOR.denominator(), 6_000_000);
+    /// ```
+    pub const fn reduce(self) -> Self {
+        let (numerator, denominator) = (*self.numerator(), *self.denominator());
+        let gcd = gcd(numerator, denominator);
+        if denominator == 0 || gcd == 0 {
+            self
+        } else {
+            Self::new(numerator / gcd, denominator / gcd)
+        }
+    }
+
     /// Returns the value truncated to an integer
-    pub fn to_integer(&self) -> u32 {
-        self.0.to_integer()
+    pub const fn to_integer(&self) -> u32 {
+        *self.numerator() / *self.denominator()
     }
 
     /// Constructs a `Fraction` from an integer.
     ///
     /// Equivalent to `Fraction::new(value,1)`.
-    pub fn from_integer(value: u32) -> Self {
-        Self(Ratio::from_integer(value))
+    pub const fn from_integer(value: u32) -> Self {
+        Self::new(value, 1)
     }
 
     /// Returns the reciprocal of the fraction
-    pub fn recip(self) -> Self {
-        Self(self.0.recip())
+    ///
+    /// # Panics
+    ///
+    /// The numerator is `0`. See [`Fraction::checked_recip()`] for a non-panicking version.
+    pub const fn recip(self) -> Self {
+        match self.checked_recip() {
+            Some(recip) => recip,
+            None => panic!("division by zero"),
+        }
+    }
+
+    /// Returns the reciprocal of the fraction, or [`None`] if the numerator is `0`
+    pub const fn checked_recip(self) -> Option<Self> {
+        if *self.numerator() == 0 {
+            None
+        } else {
+            Some(Self::new(*self.denominator(), *self.numerator()))
+        }
     }
 
     /// Checked `Fraction` × `Fraction` = `Fraction`
     ///
-    /// Returns [`None`] for any errors
+    /// The result is reduced. Returns [`None`] on overflow or a `0` denominator.
     ///
     /// # Examples
     ///
@@ -83,14 +161,41 @@ impl Fraction {
     ///
     /// assert_eq!(Fraction::new(u32::MAX, 1).checked_mul(&Fraction::new(2,1)),
     ///     None);
+    ///
+    /// // a 72 MHz timer clock prescaled by 36
+    /// const TICK: Fraction = match Fraction::new(1, 72_000_000).checked_mul(&Fraction::new(36, 1)) {
+    ///     Some(tick) => tick,
+    ///     None => panic!("overflow"),
+    /// };
+    /// assert_eq!(*TICK.denominator(), 2_000_000);
     /// ```
-    pub fn checked_mul(&self, v: &Self) -> Option<Self> {
-        self.0.checked_mul(&v.0).map(Self)
+    pub const fn checked_mul(&self, v: &Self) -> Option<Self> {
+        let (lhs_numerator, lhs_denominator) = (*self.numerator(), *self.denominator());
+        let (rhs_numerator, rhs_denominator) = (*v.numerator(), *v.denominator());
+        if lhs_denominator == 0 || rhs_denominator == 0 {
+            return None;
+        }
+
+        // cross-reduce first so that fewer products overflow (the denominators aren't `0`, so
+        // neither divisor is)
+        let gcd_ad = gcd(lhs_numerator, rhs_denominator);
+        let gcd_bc = gcd(lhs_denominator, rhs_numerator);
+
+        let numerator = match (lhs_numerator / gcd_ad).checked_mul(rhs_numerator / gcd_bc) {
+            Some(numerator) => numerator,
+            None => return None,
+        };
+        let denominator = match (lhs_denominator / gcd_bc).checked_mul(rhs_denominator / gcd_ad) {
+            Some(denominator) => denominator,
+            None => return None,
+        };
+
+        Some(Self::new(numerator, denominator).reduce())
     }
 
     /// Checked `Fraction` / `Fraction` = `Fraction`
     ///
-    /// Returns [`None`] for any errors
+    /// The result is reduced. Returns [`None`] on overflow, a `0` denominator or division by `0`.
     ///
     /// # Examples
     ///
@@ -103,11 +208,297 @@ impl Fraction {
     /// assert_eq!(Fraction::new(1, u32::MAX).checked_div(&Fraction::new(2,1)),
     ///     None);
     /// ```
+    pub const fn checked_div(&self, v: &Self) -> Option<Self> {
+        if *v.denominator() == 0 {
+            return None;
+        }
+
+        match v.checked_recip() {
+            Some(recip) => self.checked_mul(&recip),
+            None => None,
+        }
+    }
+
+    /// Checked `Fraction` + `Fraction` = `Fraction`
+    ///
+    /// The result is reduced. Returns [`None`] on overflow or a `0` denominator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// assert_eq!(Fraction::new(1, 6).checked_add(&Fraction::new(1, 3)),
+    ///     Some(Fraction::new(1, 2)));
+    ///
+    /// assert_eq!(Fraction::new(u32::MAX, 1).checked_add(&Fraction::new(1, 1)),
+    ///     None);
+    /// ```
+    pub const fn checked_add(&self, v: &Self) -> Option<Self> {
+        let (lhs_numerator, lhs_denominator) = (*self.numerator(), *self.denominator());
+        let (rhs_numerator, rhs_denominator) = (*v.numerator(), *v.denominator());
+        if lhs_denominator == 0 || rhs_denominator == 0 {
+            return None;
+        }
+
+        // over the least common denominator
+        let denominator = match (lhs_denominator / gcd(lhs_denominator, rhs_denominator))
+            .checked_mul(rhs_denominator)
+        {
+            Some(denominator) => denominator,
+            None => return None,
+        };
+        let lhs = match lhs_numerator.checked_mul(denominator / lhs_denominator) {
+            Some(lhs) => lhs,
+            None => return None,
+        };
+        let rhs = match rhs_numerator.checked_mul(denominator / rhs_denominator) {
+            Some(rhs) => rhs,
+            None => return None,
+        };
+
+        match lhs.checked_add(rhs) {
+            Some(numerator) => Some(Self::new(numerator, denominator).reduce()),
+            None => None,
+        }
+    }
+
+    /// Compares the values of two fractions (usable in `const` contexts, unlike [`Ord`])
+    ///
+    /// Fractions with a `0` denominator compare by their cross products.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::cmp::Ordering;
+    /// # use embedded_time::fraction::Fraction;
+    /// #
+    /// const FASTER: bool = matches!(
+    ///     Fraction::new(1, 32_768).compare(&Fraction::new(1, 1_000)),
+    ///     Ordering::Less
+    /// );
+    /// assert!(FASTER);
+    /// assert_eq!(Fraction::new(2, 4).compare(&Fraction::new(1, 2)), Ordering::Equal);
+    /// ```
+    pub const fn compare(&self, other: &Self) -> Ordering {
+        let lhs = *self.numerator() as u64 * *other.denominator() as u64;
+        let rhs = *other.numerator() as u64 * *self.denominator() as u64;
+
+        if lhs < rhs {
+            Ordering::Less
+        } else if lhs > rhs {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// Greatest common divisor (`0` only if both are `0`)
+///
+/// Binary (Stein's) algorithm, so that reducing a _scaling factor_ doesn't divide (a library call
+/// on targets without a hardware divider)
+const fn gcd(a: u32, b: u32) -> u32 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+    // most _scaling factors_ have a numerator of `1`
+    if a == 1 || b == 1 {
+        return 1;
+    }
+
+    let shift = (a | b).trailing_zeros();
+    let (mut a, mut b) = (a >> a.trailing_zeros(), b);
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            let swap = a;
+            a = b;
+            b = swap;
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// A fractional value with 64-bit numerator and denominator
+///
+/// For _scaling factors_ a [`Fraction`] can't represent exactly, eg. the period of a 25 MHz / 3
+/// clock combined with a large multiplier, or very fine resolutions. It's accepted by the
+/// [`FixedPoint`](crate::fixed_point::FixedPoint) tick conversions
+/// ([`try_from_ticks_with()`](crate::fixed_point::FixedPoint::try_from_ticks_with) and
+/// [`to_ticks_with()`](crate::fixed_point::FixedPoint::to_ticks_with)).
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::convert::TryFrom;
+/// # use embedded_time::{fraction::{Fraction, Fraction64}, ConversionError};
+/// #
+/// assert_eq!(Fraction64::from(Fraction::new(1, 1_000)), Fraction64::new(1, 1_000));
+///
+/// assert_eq!(Fraction::try_from(Fraction64::new(6_000_000_000, 2)), Ok(Fraction::new(3_000_000_000, 1)));
+/// assert_eq!(
+///     Fraction::try_from(Fraction64::new(1, 10_000_000_000)),
+///     Err(ConversionError::ConversionFailure)
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Fraction64(Ratio<u64>);
+
+/// Formatted as `numerator/denominator`
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fraction64 {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}/{}", self.numerator(), self.denominator())
+    }
+}
+
+/// Serialized as a `(numerator, denominator)` tuple
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fraction64 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.numerator(), self.denominator()).serialize(serializer)
+    }
+}
+
+/// Deserialized from a `(numerator, denominator)` tuple without reduction, a denominator of `0` is
+/// rejected
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fraction64 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (numerator, denominator) = <(u64, u64)>::deserialize(deserializer)?;
+        if denominator == 0 {
+            return Err(serde::de::Error::custom("Invalid fraction"));
+        }
+
+        Ok(Self::new(numerator, denominator))
+    }
+}
+
+impl Fraction64 {
+    /// Construct a new `Fraction64`.
+    ///
+    /// A reduction is **not** performed. Also there is no check for a denominator of `0`. If these
+    /// features are needed, use [`Fraction64::new_reduce()`]
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
+        Self(Ratio::new_raw(numerator, denominator))
+    }
+
+    /// Return the numerator of the fraction
+    pub const fn numerator(&self) -> &u64 {
+        self.0.numer()
+    }
+
+    /// Return the denominator of the fraction
+    pub const fn denominator(&self) -> &u64 {
+        self.0.denom()
+    }
+}
+
+impl Fraction64 {
+    /// Construct a new `Fraction64`.
+    ///
+    /// A reduction and `denominator == 0` check **are** performed.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::DivByZero`] : A `0` denominator was detected
+    pub fn new_reduce(numerator: u64, denominator: u64) -> Result<Self, ConversionError> {
+        if !denominator.is_zero() {
+            Ok(Self(Ratio::new(numerator, denominator)))
+        } else {
+            Err(ConversionError::DivByZero)
+        }
+    }
+
+    /// Returns the value truncated to an integer
+    pub fn to_integer(&self) -> u64 {
+        self.0.to_integer()
+    }
+
+    /// Constructs a `Fraction64` from an integer.
+    ///
+    /// Equivalent to `Fraction64::new(value,1)`.
+    pub fn from_integer(value: u64) -> Self {
+        Self(Ratio::from_integer(value))
+    }
+
+    /// Returns the reciprocal of the fraction
+    pub fn recip(self) -> Self {
+        Self(self.0.recip())
+    }
+
+    /// Checked `Fraction64` × `Fraction64` = `Fraction64`
+    ///
+    /// Returns [`None`] for any errors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::fraction::Fraction64;
+    /// #
+    /// assert_eq!(Fraction64::new(25_000_000, 3).checked_mul(&Fraction64::new(1_000_000, 1)),
+    ///     Some(Fraction64::new(25_000_000_000_000, 3)));
+    ///
+    /// assert_eq!(Fraction64::new(u64::MAX, 1).checked_mul(&Fraction64::new(2,1)),
+    ///     None);
+    /// ```
+    pub fn checked_mul(&self, v: &Self) -> Option<Self> {
+        self.0.checked_mul(&v.0).map(Self)
+    }
+
+    /// Checked `Fraction64` / `Fraction64` = `Fraction64`
+    ///
+    /// Returns [`None`] for any errors
     pub fn checked_div(&self, v: &Self) -> Option<Self> {
         self.0.checked_div(&v.0).map(Self)
     }
 }
 
+impl From<Fraction> for Fraction64 {
+    /// Lossless widening, not reduced
+    fn from(fraction: Fraction) -> Self {
+        Self::new(
+            (*fraction.numerator()).into(),
+            (*fraction.denominator()).into(),
+        )
+    }
+}
+
+impl TryFrom<Fraction64> for Fraction {
+    type Error = ConversionError;
+
+    /// Narrowing, reduced first
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : The denominator is `0`
+    /// - [`ConversionError::ConversionFailure`] : The reduced fraction doesn't fit in `u32`s
+    fn try_from(fraction: Fraction64) -> Result<Self, Self::Error> {
+        let fraction = Fraction64::new_reduce(*fraction.numerator(), *fraction.denominator())?;
+
+        match (
+            u32::try_from(*fraction.numerator()),
+            u32::try_from(*fraction.denominator()),
+        ) {
+            (Ok(numerator), Ok(denominator)) => Ok(Self::new(numerator, denominator)),
+            _ => Err(ConversionError::ConversionFailure),
+        }
+    }
+}
+
+impl Default for Fraction64 {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
 impl ops::Mul<Fraction> for u32 {
     type Output = Self;
 
@@ -146,6 +537,55 @@ impl ops::Div<Fraction> for u64 {
     }
 }
 
+impl ops::Mul<Fraction> for u128 {
+    type Output = Self;
+
+    /// Panicky u128 × `Fraction` = u128
+    fn mul(self, rhs: Fraction) -> Self::Output {
+        (Ratio::new_raw((*rhs.numerator()).into(), (*rhs.denominator()).into()) * self).to_integer()
+    }
+}
+
+impl ops::Div<Fraction> for u128 {
+    type Output = Self;
+
+    /// Panicky u128 / `Fraction` = u128
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Fraction) -> Self::Output {
+        (Ratio::new_raw((*rhs.denominator()).into(), (*rhs.numerator()).into()) * self).to_integer()
+    }
+}
+
+macro_rules! impl_narrow_ops {
+    ($($int:ty),*) => {$(
+        impl ops::Mul<Fraction> for $int {
+            type Output = Self;
+
+            #[doc = concat!("Panicky ", stringify!($int), " × `Fraction` = ", stringify!($int))]
+            fn mul(self, rhs: Fraction) -> Self::Output {
+                match Self::try_from(u64::from(self) * rhs) {
+                    Ok(product) => product,
+                    Err(_) => panic!("Mul failed"),
+                }
+            }
+        }
+
+        impl ops::Div<Fraction> for $int {
+            type Output = Self;
+
+            #[doc = concat!("Panicky ", stringify!($int), " / `Fraction` = ", stringify!($int))]
+            fn div(self, rhs: Fraction) -> Self::Output {
+                match Self::try_from(u64::from(self) / rhs) {
+                    Ok(quotient) => quotient,
+                    Err(_) => panic!("Div failed"),
+                }
+            }
+        }
+    )*};
+}
+
+impl_narrow_ops!(u8, u16);
+
 impl ops::Mul for Fraction {
     type Output = Self;
 
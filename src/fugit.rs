@@ -0,0 +1,173 @@
+//! Conversions to and from [`fugit`] durations and rates
+//!
+//! A [`fugit::Duration`]/[`fugit::Rate`] is a [`u32`] or [`u64`] number of ticks with a
+//! (const-generic) `NOM / DENOM` scaling factor, so it is equivalent to a [`duration::Generic`] or
+//! [`rate::Generic`] of the same integer type:
+//!
+//! - `fugit` values convert to a `Generic` with `From`.
+//! - `Generic` values and the _named_ units (the frequency ones for rates) convert to and from
+//!   `fugit` values with `TryFrom`. The conversions are lossless: a value that isn't a whole number
+//!   of ticks of the destination fails ([`ConversionError::ConversionFailure`]) rather than being
+//!   truncated.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use embedded_time::{duration::*, rate::*, ConversionError};
+//! # use core::convert::TryFrom;
+//! let timeout = fugit::MillisDurationU32::millis(1_500);
+//! assert_eq!(Milliseconds::<u32>::try_from(timeout), Ok(Milliseconds(1_500_u32)));
+//! assert_eq!(Seconds::<u32>::try_from(timeout), Err(ConversionError::ConversionFailure));
+//! assert_eq!(
+//!     Generic::from(timeout),
+//!     Generic::new(1_500_u32, Fraction::new(1, 1_000))
+//! );
+//!
+//! assert_eq!(
+//!     fugit::HertzU32::try_from(Kilohertz(48_u32)),
+//!     Ok(fugit::HertzU32::kHz(48))
+//! );
+//! ```
+
+use crate::{
+    duration,
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::Fraction,
+    rate, ConversionError,
+};
+use core::convert::TryFrom;
+
+/// Converts `ticks` of the `from` _scaling factor_ into ticks of the `to` _scaling factor_,
+/// failing unless the result is exact
+///
+/// # Errors
+///
+/// - [`ConversionError::ConversionFailure`] : The result isn't a whole number of ticks
+/// - [`ConversionError::Overflow`] : The intermediate math overflowed
+/// - [`ConversionError::DivByZero`] : A _scaling factor_ has a `0` numerator or denominator
+fn rescale_exact(ticks: u128, from: Fraction, to: Fraction) -> Result<u128, ConversionError> {
+    let floor = fixed_point::rescale(ticks, from, to, RoundingMode::Floor)?;
+
+    if floor == fixed_point::rescale(ticks, from, to, RoundingMode::Ceil)? {
+        Ok(floor)
+    } else {
+        Err(ConversionError::ConversionFailure)
+    }
+}
+
+macro_rules! impl_fugit {
+    (
+        $fugit:ident, $from_ticks:ident, $ticks:ident, $module:ident,
+        $int:ty, [$($name:ident),*]
+    ) => {
+        impl<const NOM: u32, const DENOM: u32> From<fugit::$fugit<$int, NOM, DENOM>>
+            for $module::Generic<$int>
+        {
+            fn from(value: fugit::$fugit<$int, NOM, DENOM>) -> Self {
+                Self::new(value.$ticks(), Fraction::new(NOM, DENOM))
+            }
+        }
+
+        impl<const NOM: u32, const DENOM: u32> TryFrom<$module::Generic<$int>>
+            for fugit::$fugit<$int, NOM, DENOM>
+        {
+            type Error = ConversionError;
+
+            /// Lossless conversion
+            ///
+            /// # Errors
+            ///
+            /// - [`ConversionError::ConversionFailure`] : The value isn't a whole number of ticks
+            ///   or the ticks don't fit the integer type
+            /// - [`ConversionError::Overflow`] : The intermediate math overflowed
+            /// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+            fn try_from(value: $module::Generic<$int>) -> Result<Self, Self::Error> {
+                let ticks = rescale_exact(
+                    u128::from(value.integer()),
+                    *value.scaling_factor(),
+                    Fraction::new(NOM, DENOM),
+                )?;
+
+                <$int>::try_from(ticks)
+                    .map(Self::$from_ticks)
+                    .map_err(|_| ConversionError::ConversionFailure)
+            }
+        }
+
+        $(
+            impl<const NOM: u32, const DENOM: u32> TryFrom<fugit::$fugit<$int, NOM, DENOM>>
+                for $module::$name<$int>
+            {
+                type Error = ConversionError;
+
+                /// Lossless conversion
+                ///
+                /// # Errors
+                ///
+                /// - [`ConversionError::ConversionFailure`] : The value isn't a whole number of
+                ///   units or the units don't fit the integer type
+                /// - [`ConversionError::Overflow`] : The intermediate math overflowed
+                fn try_from(value: fugit::$fugit<$int, NOM, DENOM>) -> Result<Self, Self::Error> {
+                    let integer = rescale_exact(
+                        u128::from(value.$ticks()),
+                        Fraction::new(NOM, DENOM),
+                        Self::SCALING_FACTOR,
+                    )?;
+
+                    <$int>::try_from(integer)
+                        .map(Self::new)
+                        .map_err(|_| ConversionError::ConversionFailure)
+                }
+            }
+
+            impl<const NOM: u32, const DENOM: u32> TryFrom<$module::$name<$int>>
+                for fugit::$fugit<$int, NOM, DENOM>
+            {
+                type Error = ConversionError;
+
+                /// Lossless conversion
+                ///
+                /// # Errors
+                ///
+                /// - [`ConversionError::ConversionFailure`] : The value isn't a whole number of
+                ///   ticks or the ticks don't fit the integer type
+                /// - [`ConversionError::Overflow`] : The intermediate math overflowed
+                fn try_from(value: $module::$name<$int>) -> Result<Self, Self::Error> {
+                    let ticks = rescale_exact(
+                        u128::from(value.integer()),
+                        $module::$name::<$int>::SCALING_FACTOR,
+                        Fraction::new(NOM, DENOM),
+                    )?;
+
+                    <$int>::try_from(ticks)
+                        .map(Self::$from_ticks)
+                        .map_err(|_| ConversionError::ConversionFailure)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fugit_duration {
+    ( $($int:ty),* ) => {$(
+        impl_fugit!(
+            Duration, from_ticks, ticks, duration, $int,
+            [Weeks, Days, Hours, Minutes, Seconds, Milliseconds, Microseconds, Nanoseconds]
+        );
+    )*};
+}
+
+macro_rules! impl_fugit_rate {
+    ( $($int:ty),* ) => {$(
+        impl_fugit!(
+            Rate, from_raw, raw, rate, $int,
+            [
+                Mebihertz, Megahertz, Kibihertz, Kilohertz, Hertz, Decihertz, Centihertz,
+                Millihertz, Microhertz, Rpm
+            ]
+        );
+    )*};
+}
+
+impl_fugit_duration![u32, u64];
+impl_fugit_rate![u32, u64];
@@ -0,0 +1,186 @@
+//! A globally registered default clock
+//!
+//! Applications register their clock once with [`set_global_clock()`], then use [`now()`],
+//! [`delay()`] and [`timeout()`] from anywhere instead of threading a clock reference through
+//! every constructor. The registration is guarded by a [`critical_section`], so the application
+//! (or its HAL) must provide a `critical-section` implementation.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! static CLOCK: SysClock = SysClock::new();
+//!
+//! fn main() {
+//!     embedded_time::global::set_global_clock(&CLOCK);
+//!
+//!     let timeout = embedded_time::global::timeout(Milliseconds(100_u32)).unwrap();
+//!     while !timeout.is_expired().unwrap() {
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use crate::{
+    clock,
+    duration::{self, Duration},
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::Fraction,
+    TimeError,
+};
+use core::{cell::Cell, convert::TryFrom, fmt};
+use critical_section::Mutex;
+use num::Bounded;
+
+/// A [`Clock`](crate::Clock) with its ticks widened to `u64`, so it can be a trait object
+trait Source: Sync {
+    fn scaling_factor(&self) -> Fraction;
+
+    fn try_now_ticks(&self) -> Result<u64, clock::Error>;
+
+    /// The largest tick count, after which the clock wraps to `0`
+    fn max_ticks(&self) -> u64;
+}
+
+impl<Clock: crate::Clock + Sync> Source for Clock
+where
+    u64: From<Clock::T>,
+{
+    fn scaling_factor(&self) -> Fraction {
+        Clock::SCALING_FACTOR
+    }
+
+    fn try_now_ticks(&self) -> Result<u64, clock::Error> {
+        clock::try_now(self).map(|instant| u64::from(instant.duration_since_epoch().integer()))
+    }
+
+    fn max_ticks(&self) -> u64 {
+        u64::from(Clock::T::max_value())
+    }
+}
+
+static CLOCK: Mutex<Cell<Option<&'static dyn Source>>> = Mutex::new(Cell::new(None));
+
+/// Register the global clock, replacing any previously registered one
+///
+/// [`Timeout`]s already started keep using the clock they were started with.
+pub fn set_global_clock<Clock>(clock: &'static Clock)
+where
+    Clock: crate::Clock + Sync,
+    u64: From<Clock::T>,
+{
+    critical_section::with(|cs| CLOCK.borrow(cs).set(Some(clock)));
+}
+
+/// Returns the registered clock
+fn clock() -> Result<&'static dyn Source, TimeError> {
+    critical_section::with(|cs| CLOCK.borrow(cs).get())
+        .ok_or(TimeError::Clock(clock::Error::NotRunning))
+}
+
+/// Returns the time since the epoch of the global clock, with the clock's _scaling factor_
+///
+/// # Errors
+///
+/// - [`TimeError::Clock`] : No clock has been registered ([`clock::Error::NotRunning`]) or the clock
+///   failed
+pub fn now() -> Result<duration::Generic<u64>, TimeError> {
+    let clock = clock()?;
+
+    Ok(duration::Generic::new(
+        clock.try_now_ticks()?,
+        clock.scaling_factor(),
+    ))
+}
+
+/// Busy-wait for (at least) the given duration using the global clock
+///
+/// # Errors
+///
+/// See [`timeout()`]
+pub fn delay<Dur>(duration: Dur) -> Result<(), TimeError>
+where
+    Dur: Duration + FixedPoint,
+    u128: From<Dur::T>,
+{
+    let timeout = timeout(duration)?;
+    while !timeout.is_expired()? {}
+
+    Ok(())
+}
+
+/// Start a [`Timeout`] of (at least) the given duration using the global clock
+///
+/// # Errors
+///
+/// - [`TimeError::Clock`] : No clock has been registered ([`clock::Error::NotRunning`]) or the clock
+///   failed
+/// - [`TimeError::Overflow`] : The duration is longer than the clock's wrap-around period
+pub fn timeout<Dur>(duration: Dur) -> Result<Timeout, TimeError>
+where
+    Dur: Duration + FixedPoint,
+    u128: From<Dur::T>,
+{
+    let clock = clock()?;
+    let ticks = fixed_point::rescale(
+        u128::from(duration.integer()),
+        Dur::SCALING_FACTOR,
+        clock.scaling_factor(),
+        RoundingMode::Ceil,
+    )?;
+    let ticks = u64::try_from(ticks)
+        .ok()
+        .filter(|&ticks| ticks <= clock.max_ticks())
+        .ok_or(TimeError::Overflow)?;
+
+    Ok(Timeout {
+        clock,
+        start: clock.try_now_ticks()?,
+        ticks,
+    })
+}
+
+/// A timeout started with [`timeout()`]
+///
+/// As with [`Timer`](crate::Timer)s, it must be checked at least once per wrap-around period of
+/// the clock to be reliable.
+pub struct Timeout {
+    clock: &'static dyn Source,
+    start: u64,
+    ticks: u64,
+}
+
+impl Timeout {
+    /// Returns whether the timeout has expired
+    ///
+    /// # Errors
+    ///
+    /// [`TimeError::Clock`] : The clock failed
+    pub fn is_expired(&self) -> Result<bool, TimeError> {
+        Ok(self.elapsed_ticks()? >= self.ticks)
+    }
+
+    /// Returns the time remaining until expiration (`0` once expired)
+    ///
+    /// # Errors
+    ///
+    /// [`TimeError::Clock`] : The clock failed
+    pub fn remaining(&self) -> Result<duration::Generic<u64>, TimeError> {
+        Ok(duration::Generic::new(
+            self.ticks.saturating_sub(self.elapsed_ticks()?),
+            self.clock.scaling_factor(),
+        ))
+    }
+
+    fn elapsed_ticks(&self) -> Result<u64, TimeError> {
+        Ok(self.clock.try_now_ticks()?.wrapping_sub(self.start) & self.clock.max_ticks())
+    }
+}
+
+impl fmt::Debug for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("start", &self.start)
+            .field("ticks", &self.ticks)
+            .finish()
+    }
+}
@@ -3,10 +3,13 @@
 use crate::{
     duration::{self, Duration},
     fixed_point::FixedPoint,
+    time_int::TimeInt,
+    ConversionError,
 };
 use core::{
     cmp::Ordering,
     convert::TryFrom,
+    fmt,
     hash::{Hash, Hasher},
     ops,
 };
@@ -24,6 +27,7 @@ use num::traits::{WrappingAdd, WrappingSub};
 /// # struct SomeClock;
 /// # impl embedded_time::Clock for SomeClock {
 /// #     type T = u32;
+/// #     type Error = embedded_time::clock::Error;
 /// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
 /// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {Ok(Instant::<Self>::new(23))}
 /// # }
@@ -40,12 +44,12 @@ use num::traits::{WrappingAdd, WrappingSub};
 /// # struct SomeClock;
 /// # impl embedded_time::Clock for SomeClock {
 /// #     type T = u32;
+/// #     type Error = embedded_time::clock::Error;
 /// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
 /// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
 /// # }
 /// Instant::<SomeClock>::new(23);
 /// ```
-#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Instant<Clock: crate::Clock> {
     ticks: Clock::T,
@@ -53,7 +57,7 @@ pub struct Instant<Clock: crate::Clock> {
 
 impl<Clock: crate::Clock> Instant<Clock> {
     /// Construct a new Instant from the provided [`Clock`](clock/trait.Clock.html)
-    pub fn new(ticks: Clock::T) -> Self {
+    pub const fn new(ticks: Clock::T) -> Self {
         Self { ticks }
     }
 
@@ -70,6 +74,7 @@ impl<Clock: crate::Clock> Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -111,6 +116,7 @@ impl<Clock: crate::Clock> Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -139,6 +145,53 @@ impl<Clock: crate::Clock> Instant<Clock> {
         }
     }
 
+    /// Returns the signed amount of time elapsed from another instant to this one, negative if the
+    /// other instant is later
+    ///
+    /// Returns [`None`] if the difference doesn't fit in the signed integer type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// let earlier = Instant::<Clock>::new(5);
+    /// let later = Instant::<Clock>::new(7);
+    ///
+    /// assert_eq!(
+    ///     later.signed_duration_since::<i64>(&earlier),
+    ///     Some(SignedGeneric::new(2_i64, Fraction::new(1, 1_000)))
+    /// );
+    /// assert_eq!(
+    ///     earlier.signed_duration_since::<i64>(&later),
+    ///     Some(SignedGeneric::new(-2_i64, Fraction::new(1, 1_000)))
+    /// );
+    /// ```
+    pub fn signed_duration_since<Signed>(
+        &self,
+        other: &Self,
+    ) -> Option<duration::SignedGeneric<Signed>>
+    where
+        Signed: TryFrom<Clock::T> + ops::Neg<Output = Signed> + Copy,
+    {
+        let integer = if self >= other {
+            Signed::try_from(self.ticks.wrapping_sub(&other.ticks)).ok()?
+        } else {
+            -Signed::try_from(other.ticks.wrapping_sub(&self.ticks)).ok()?
+        };
+
+        Some(duration::SignedGeneric::new(integer, Clock::SCALING_FACTOR))
+    }
+
     /// Returns the [`Duration`] (in the provided units) since the beginning of time (the
     /// [`Clock`](clock/trait.Clock.html)'s 0)
     pub fn duration_since_epoch(&self) -> duration::Generic<Clock::T> {
@@ -157,6 +210,7 @@ impl<Clock: crate::Clock> Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -172,9 +226,9 @@ impl<Clock: crate::Clock> Instant<Clock> {
     ///     None
     /// );
     /// ```
-    pub fn checked_add<Dur: Duration>(self, duration: Dur) -> Option<Self>
+    pub fn checked_add<Dur>(self, duration: Dur) -> Option<Self>
     where
-        Dur: FixedPoint,
+        Dur: Duration + FixedPoint,
         Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
     {
         let add_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR).ok()?;
@@ -199,6 +253,7 @@ impl<Clock: crate::Clock> Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -210,9 +265,9 @@ impl<Clock: crate::Clock> Instant<Clock> {
     /// assert_eq!(Instant::<Clock>::new(u32::MAX).checked_sub(Milliseconds(u32::MAX/2 + 1)),
     ///     None);
     /// ```
-    pub fn checked_sub<Dur: Duration>(self, duration: Dur) -> Option<Self>
+    pub fn checked_sub<Dur>(self, duration: Dur) -> Option<Self>
     where
-        Dur: FixedPoint,
+        Dur: Duration + FixedPoint,
         Clock::T: TryFrom<Dur::T> + core::ops::Div<Output = Clock::T>,
     {
         let sub_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR).ok()?;
@@ -224,13 +279,132 @@ impl<Clock: crate::Clock> Instant<Clock> {
             None
         }
     }
+
+    /// This `Instant` + [`Duration`] with the wraparound of the clock's integer type
+    ///
+    /// Unlike [`Instant::checked_add()`], the duration may be longer than half the range of the
+    /// clock, the result is that of free-running counter math.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(10).wrapping_add(Milliseconds(u32::MAX)),
+    ///     Ok(Instant::<Clock>::new(9))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Any error converting the duration into clock ticks
+    pub fn wrapping_add<Dur>(self, duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let add_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR)?;
+
+        Ok(Self {
+            ticks: self.ticks.wrapping_add(&add_ticks),
+        })
+    }
+
+    /// This `Instant` - [`Duration`] with the wraparound of the clock's integer type
+    ///
+    /// Unlike [`Instant::checked_sub()`], the duration may be longer than half the range of the
+    /// clock, the result is that of free-running counter math.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, duration::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Instant::<Clock>::new(10).wrapping_sub(Seconds(5_u32)),
+    ///     Ok(Instant::<Clock>::new(u32::MAX - 4_989))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Any error converting the duration into clock ticks
+    pub fn wrapping_sub<Dur>(self, duration: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let sub_ticks: Clock::T = duration.into_ticks(Clock::SCALING_FACTOR)?;
+
+        Ok(Self {
+            ticks: self.ticks.wrapping_sub(&sub_ticks),
+        })
+    }
+}
+
+impl<Clock: crate::Clock> fmt::Debug for Instant<Clock> {
+    /// Includes the clock's _scaling factor_
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// assert_eq!(format!("{:?}", Instant::<Clock>::new(23)), "Instant(23 × 1/1000 s)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Instant")
+            .field(&duration::Generic::new(self.ticks, Clock::SCALING_FACTOR))
+            .finish()
+    }
+}
+
+/// Includes the clock's _scaling factor_, as [`Debug`](fmt::Debug) does
+#[cfg(feature = "defmt")]
+impl<Clock: crate::Clock> defmt::Format for Instant<Clock>
+where
+    Clock::T: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "Instant({})",
+            duration::Generic::new(self.ticks, Clock::SCALING_FACTOR)
+        )
+    }
 }
 
 impl<Clock: crate::Clock> Copy for Instant<Clock> {}
 
 impl<Clock: crate::Clock> Clone for Instant<Clock> {
     fn clone(&self) -> Self {
-        Self { ticks: self.ticks }
+        *self
     }
 }
 
@@ -251,6 +425,7 @@ impl<Clock: crate::Clock> PartialOrd for Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -261,7 +436,7 @@ impl<Clock: crate::Clock> PartialOrd for Instant<Clock> {
     /// assert!(Instant::<Clock>::new(u32::MAX) < Instant::<Clock>::new(u32::MIN));
     /// ```
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
@@ -295,6 +470,7 @@ where
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -324,6 +500,7 @@ where
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -357,6 +534,7 @@ where
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -386,6 +564,7 @@ where
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -402,6 +581,68 @@ where
     }
 }
 
+impl<Clock: crate::Clock> ops::Add<duration::Generic<Clock::T>> for Instant<Clock> {
+    type Output = Self;
+
+    /// Add a [`duration::Generic`] (eg. the difference between two `Instant`s)
+    ///
+    /// # Panics
+    ///
+    /// If the duration can't be represented in ticks of the clock or is longer than half the
+    /// range of the clock
+    fn add(self, rhs: duration::Generic<Clock::T>) -> Self::Output {
+        if let Some(ticks) = Self::generic_ticks(&rhs) {
+            Self {
+                ticks: self.ticks.wrapping_add(&ticks),
+            }
+        } else {
+            panic!("Add failed")
+        }
+    }
+}
+
+impl<Clock: crate::Clock> ops::Sub<duration::Generic<Clock::T>> for Instant<Clock> {
+    type Output = Self;
+
+    /// Subtract a [`duration::Generic`] (eg. the difference between two `Instant`s)
+    ///
+    /// # Panics
+    ///
+    /// If the duration can't be represented in ticks of the clock or is longer than half the
+    /// range of the clock
+    fn sub(self, rhs: duration::Generic<Clock::T>) -> Self::Output {
+        if let Some(ticks) = Self::generic_ticks(&rhs) {
+            Self {
+                ticks: self.ticks.wrapping_sub(&ticks),
+            }
+        } else {
+            panic!("Sub failed")
+        }
+    }
+}
+
+impl<Clock: crate::Clock> Instant<Clock> {
+    /// Returns the number of clock ticks in a [`duration::Generic`] if it is no longer than half
+    /// the range of the clock
+    fn generic_ticks(duration: &duration::Generic<Clock::T>) -> Option<Clock::T> {
+        let ticks = if *duration.scaling_factor() == Clock::SCALING_FACTOR {
+            duration.integer()
+        } else {
+            duration.integer().checked_mul_fraction(
+                &duration
+                    .scaling_factor()
+                    .checked_div(&Clock::SCALING_FACTOR)?,
+            )?
+        };
+
+        if ticks <= <Clock::T as num::Bounded>::max_value() / Clock::T::from(2) {
+            Some(ticks)
+        } else {
+            None
+        }
+    }
+}
+
 impl<Clock: crate::Clock> ops::Sub<Instant<Clock>> for Instant<Clock> {
     type Output = duration::Generic<Clock::T>;
 
@@ -415,6 +656,7 @@ impl<Clock: crate::Clock> ops::Sub<Instant<Clock>> for Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -435,6 +677,7 @@ impl<Clock: crate::Clock> ops::Sub<Instant<Clock>> for Instant<Clock> {
     /// struct Clock;
     /// impl embedded_time::Clock for Clock {
     ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
     ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
     ///     // ...
     /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
@@ -0,0 +1,109 @@
+//! Bounded random jitter of delays
+//!
+//! Nodes that retry or poll on the same schedule after a common event (eg. a mesh network coming
+//! back up) collide again and again. Jittering each delay by a random amount spreads them out.
+//!
+//! The randomness comes from a user-supplied, uniformly distributed `u32` (eg.
+//! `rng.next_u32()`), so any RNG (a hardware TRNG, a PRNG seeded from a unique ID, ...) can be
+//! used. The jitter also integrates with [`RetryBudget::next_attempt_jittered()`] and
+//! [`Periodic::jittered_deadline()`].
+//!
+//! [`RetryBudget::next_attempt_jittered()`]: crate::retry::RetryBudget::next_attempt_jittered()
+//! [`Periodic::jittered_deadline()`]: crate::periodic::Periodic::jittered_deadline()
+
+use crate::{duration, duration::Duration, fixed_point::FixedPoint, fraction::Fraction};
+use core::convert::TryFrom;
+use num::Bounded;
+
+/// A bounded jitter, as a fraction of the delay being jittered
+///
+/// A jittered delay is uniformly distributed from `delay × (1 - spread)` up to (but excluding)
+/// `delay × (1 + spread)`, truncated to whole ticks. Results too large for the _integer_ type
+/// saturate.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, jitter::Jitter};
+/// // ±10%
+/// let jitter = Jitter::new(Fraction::new(1, 10));
+///
+/// assert_eq!(jitter.apply(Milliseconds(1_000_u32), 0), Milliseconds(900_u32));
+/// assert_eq!(jitter.apply(Milliseconds(1_000_u32), u32::MAX / 2), Milliseconds(999_u32));
+/// assert_eq!(jitter.apply(Milliseconds(1_000_u32), u32::MAX), Milliseconds(1_099_u32));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Jitter {
+    spread: Fraction,
+}
+
+impl Jitter {
+    /// Construct a jitter of up to ± `spread` × the delay
+    ///
+    /// # Panics
+    ///
+    /// If `spread` is greater than `1` or has a denominator of `0`
+    pub fn new(spread: Fraction) -> Self {
+        assert!(
+            *spread.denominator() != 0 && spread <= Fraction::new(1, 1),
+            "Invalid spread"
+        );
+
+        Self { spread }
+    }
+
+    /// Returns the spread
+    pub fn spread(&self) -> Fraction {
+        self.spread
+    }
+
+    /// Returns the duration jittered by the `random` value
+    pub fn apply<Dur>(&self, duration: Dur, random: u32) -> Dur
+    where
+        Dur: Duration + FixedPoint,
+        u128: From<Dur::T>,
+        Dur::T: TryFrom<u128>,
+    {
+        Dur::new(self.apply_ticks(duration.integer(), random))
+    }
+
+    /// Returns the [`duration::Generic`] jittered by the `random` value
+    pub fn apply_generic<T>(
+        &self,
+        duration: duration::Generic<T>,
+        random: u32,
+    ) -> duration::Generic<T>
+    where
+        T: crate::TimeInt,
+        u128: From<T>,
+        T: TryFrom<u128>,
+    {
+        duration::Generic::new(
+            self.apply_ticks(duration.integer(), random),
+            *duration.scaling_factor(),
+        )
+    }
+
+    /// Returns the _integer_ jittered by the `random` value
+    pub(crate) fn apply_ticks<T>(&self, ticks: T, random: u32) -> T
+    where
+        T: Bounded + TryFrom<u128>,
+        u128: From<T>,
+    {
+        let ticks = u128::from(ticks);
+        let numerator = <u128 as From<u32>>::from(*self.spread.numerator());
+        let denominator = <u128 as From<u32>>::from(*self.spread.denominator());
+
+        // ticks × spread without overflowing, spread <= 1 so this is no greater than ticks
+        let max_deviation =
+            ticks / denominator * numerator + ticks % denominator * numerator / denominator;
+
+        // span × random / 2^32 without overflowing
+        let span = max_deviation.saturating_mul(2);
+        let random = <u128 as From<u32>>::from(random);
+        let offset = (span >> 32) * random + (((span & 0xFFFF_FFFF) * random) >> 32);
+
+        T::try_from((ticks - max_deviation).saturating_add(offset))
+            .unwrap_or_else(|_| T::max_value())
+    }
+}
@@ -0,0 +1,232 @@
+//! Interrupt latency measurement
+
+use crate::{
+    clock,
+    duration::{Duration, Nanoseconds},
+    fixed_point::FixedPoint,
+    stats::Stats,
+    Instant, TimeError,
+};
+use core::convert::TryFrom;
+
+/// The number of back-to-back clock reads used to measure the measurement overhead
+const CALIBRATION_SAMPLES: u32 = 16;
+
+/// Latency summary returned by [`LatencyMeter::stats()`]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LatencySummary {
+    /// The number of latencies measured
+    pub count: u32,
+    /// The shortest latency
+    pub min: Nanoseconds<u64>,
+    /// The longest latency
+    pub max: Nanoseconds<u64>,
+    /// The average latency (truncated)
+    pub mean: Nanoseconds<u64>,
+}
+
+/// Measures interrupt latency (the time from a trigger to its handling) using a
+/// [`Clock`](crate::Clock)
+///
+/// [`LatencyMeter::trigger()`] timestamps the triggering event (eg. just before a GPIO is toggled
+/// or a software interrupt is pended) and [`LatencyMeter::handled()`] timestamps its handling
+/// (eg. on entry to the interrupt handler). The latencies are accumulated into a
+/// [`LatencySummary`] and a histogram of `BUCKETS` buckets of equal width, the last bucket also
+/// counting all longer latencies.
+///
+/// Reading the clock takes time itself. [`LatencyMeter::calibrate()`] measures this overhead, which
+/// is then subtracted from every measured latency.
+///
+/// As the meter is used from both thread and interrupt context, it would typically be shared
+/// through a critical-section mutex.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, latency::LatencyMeter, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+/// let clock = Clock(Cell::new(0));
+///
+/// // 4 buckets, 10 µs wide
+/// let mut meter = LatencyMeter::<_, 4>::new(&clock, Microseconds(10_u32)).unwrap();
+///
+/// meter.trigger().unwrap();
+/// clock.0.set(12);
+/// assert_eq!(meter.handled(), Ok(Some(Nanoseconds(12_000_u64))));
+///
+/// assert_eq!(meter.histogram(), &[0, 1, 0, 0]);
+/// assert_eq!(meter.stats().unwrap().max, Nanoseconds(12_000_u64));
+/// ```
+#[derive(Debug)]
+pub struct LatencyMeter<'a, Clock: crate::Clock, const BUCKETS: usize> {
+    clock: &'a Clock,
+    bucket_width: u64,
+    overhead: u64,
+    trigger: Option<Instant<Clock>>,
+    histogram: [u32; BUCKETS],
+    count: u32,
+    min: u64,
+    max: u64,
+    latencies: Stats<Nanoseconds<u64>>,
+}
+
+impl<'a, Clock, const BUCKETS: usize> LatencyMeter<'a, Clock, BUCKETS>
+where
+    Clock: crate::Clock,
+    u64: TryFrom<Clock::T>,
+{
+    /// Construct a meter with histogram buckets of the given width
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::DivByZero`] : The bucket width is zero
+    /// - [`TimeError::Overflow`], [`TimeError::ConversionFailure`], [`TimeError::Unspecified`] :
+    ///   The bucket width doesn't fit in [`Nanoseconds<u64>`]
+    pub fn new<Dur>(clock: &'a Clock, bucket_width: Dur) -> Result<Self, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+    {
+        let bucket_width: u64 = bucket_width.into_ticks(Nanoseconds::<u64>::SCALING_FACTOR)?;
+        if bucket_width == 0 {
+            return Err(TimeError::DivByZero);
+        }
+
+        Ok(Self {
+            clock,
+            bucket_width,
+            overhead: 0,
+            trigger: None,
+            histogram: [0; BUCKETS],
+            count: 0,
+            min: u64::MAX,
+            max: 0,
+            latencies: Stats::new(),
+        })
+    }
+
+    /// Measure the overhead of timestamping, which is subtracted from all subsequently measured
+    /// latencies, and return it
+    ///
+    /// This should be called with interrupts disabled so the measurement isn't inflated.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    /// - [`TimeError::NegDuration`] : The clock went backwards
+    /// - [`TimeError::Overflow`], [`TimeError::ConversionFailure`], [`TimeError::Unspecified`] :
+    ///   The overhead doesn't fit in [`Nanoseconds<u64>`]
+    pub fn calibrate(&mut self) -> Result<Nanoseconds<u64>, TimeError> {
+        let mut overhead = u64::MAX;
+
+        for _ in 0..CALIBRATION_SAMPLES {
+            let trigger = clock::try_now(self.clock)?;
+            let handled = clock::try_now(self.clock)?;
+            overhead = overhead.min(nanos_between(&trigger, &handled)?);
+        }
+
+        self.overhead = overhead;
+        Ok(Nanoseconds(overhead))
+    }
+
+    /// Timestamp a triggering event
+    ///
+    /// A previous trigger that hasn't been handled is discarded.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    pub fn trigger(&mut self) -> Result<(), TimeError> {
+        self.trigger = Some(clock::try_now(self.clock)?);
+        Ok(())
+    }
+
+    /// Timestamp the handling of the latest trigger, record and return its latency
+    ///
+    /// Returns `None` if there is no pending trigger.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    /// - [`TimeError::NegDuration`] : The clock went backwards
+    /// - [`TimeError::Overflow`], [`TimeError::ConversionFailure`], [`TimeError::Unspecified`] :
+    ///   The latency doesn't fit in [`Nanoseconds<u64>`]
+    pub fn handled(&mut self) -> Result<Option<Nanoseconds<u64>>, TimeError> {
+        let now = clock::try_now(self.clock)?;
+        let trigger = match self.trigger.take() {
+            Some(trigger) => trigger,
+            None => return Ok(None),
+        };
+
+        let latency = nanos_between(&trigger, &now)?.saturating_sub(self.overhead);
+        self.record(latency);
+
+        Ok(Some(Nanoseconds(latency)))
+    }
+
+    /// Returns the latency statistics, or `None` if no latency has been measured
+    pub fn stats(&self) -> Option<LatencySummary> {
+        Some(LatencySummary {
+            count: self.count,
+            min: Nanoseconds(self.min),
+            max: Nanoseconds(self.max),
+            mean: self.latencies.mean()?,
+        })
+    }
+
+    /// Returns the number of latencies in each histogram bucket
+    ///
+    /// Bucket `i` counts latencies from `i` up to (but excluding) `i + 1` bucket widths.
+    pub fn histogram(&self) -> &[u32; BUCKETS] {
+        &self.histogram
+    }
+
+    /// Clear the statistics, histogram and any pending trigger (the calibration is kept)
+    pub fn reset(&mut self) {
+        self.trigger = None;
+        self.histogram = [0; BUCKETS];
+        self.count = 0;
+        self.min = u64::MAX;
+        self.max = 0;
+        self.latencies = Stats::new();
+    }
+
+    fn record(&mut self, latency: u64) {
+        self.count = self.count.saturating_add(1);
+        self.min = self.min.min(latency);
+        self.max = self.max.max(latency);
+        self.latencies.add(Nanoseconds(latency));
+
+        let bucket = usize::try_from(latency / self.bucket_width).unwrap_or(usize::MAX);
+        if let Some(count) = self
+            .histogram
+            .get_mut(bucket.min(BUCKETS.saturating_sub(1)))
+        {
+            *count = count.saturating_add(1);
+        }
+    }
+}
+
+/// Returns the time elapsed from `earlier` to `later` in nanoseconds
+fn nanos_between<Clock>(earlier: &Instant<Clock>, later: &Instant<Clock>) -> Result<u64, TimeError>
+where
+    Clock: crate::Clock,
+    u64: TryFrom<Clock::T>,
+{
+    let elapsed = later
+        .checked_duration_since(earlier)
+        .ok_or(TimeError::NegDuration)?;
+
+    Ok(Nanoseconds::<u64>::try_from(elapsed)?.integer())
+}
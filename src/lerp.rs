@@ -0,0 +1,71 @@
+//! Linear interpolation over time
+
+use crate::{duration::Duration, fixed_point::FixedPoint, ConversionError};
+use core::convert::TryFrom;
+use num::Integer;
+
+/// Returns the value `elapsed` of the way through a linear ramp from `start` to `end` taking
+/// `total`
+///
+/// This is eg. the brightness of an LED part way through a fade or the position of an axis part way
+/// through a motion ramp. The durations may be of different units, the scaling is done with checked
+/// integer math. The result is truncated toward `start` and is `end` once `elapsed` reaches
+/// `total`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, lerp::lerp};
+/// // fade from 0 to 255 over 2 s
+/// assert_eq!(lerp(0_u8, 255, Milliseconds(500_u32), Seconds(2_u32)), Ok(63));
+/// assert_eq!(lerp(0_u8, 255, Seconds(3_u32), Seconds(2_u32)), Ok(255));
+///
+/// // ramp down
+/// assert_eq!(lerp(1_000_i32, -1_000, Microseconds(1_500_u64), Milliseconds(2_u32)), Ok(-500));
+/// ```
+///
+/// # Errors
+///
+/// - [`ConversionError::DivByZero`] : `total` is zero
+/// - [`ConversionError::Overflow`] : An intermediate value of the scaling overflowed
+pub fn lerp<Value, Elapsed, Total>(
+    start: Value,
+    end: Value,
+    elapsed: Elapsed,
+    total: Total,
+) -> Result<Value, ConversionError>
+where
+    Value: Copy + Into<i128> + TryFrom<i128>,
+    Elapsed: Duration + FixedPoint,
+    Total: Duration + FixedPoint,
+    u128: From<Elapsed::T> + From<Total::T>,
+{
+    // both durations in units of 1 / (elapsed denominator × total denominator) seconds
+    let elapsed = u128::from(elapsed.integer())
+        .checked_mul(u128::from(*Elapsed::SCALING_FACTOR.numerator()))
+        .and_then(|ticks| ticks.checked_mul(u128::from(*Total::SCALING_FACTOR.denominator())))
+        .ok_or(ConversionError::Overflow)?;
+    let total = u128::from(total.integer())
+        .checked_mul(u128::from(*Total::SCALING_FACTOR.numerator()))
+        .and_then(|ticks| ticks.checked_mul(u128::from(*Elapsed::SCALING_FACTOR.denominator())))
+        .ok_or(ConversionError::Overflow)?;
+
+    if total == 0 {
+        return Err(ConversionError::DivByZero);
+    } else if elapsed >= total {
+        return Ok(end);
+    }
+
+    let gcd = elapsed.gcd(&total);
+    let (elapsed, total) = (elapsed / gcd, total / gcd);
+
+    let (start, end) = (start.into(), end.into());
+    let offset = end
+        .checked_sub(start)
+        .and_then(|span| span.checked_mul(i128::try_from(elapsed).ok()?))
+        .ok_or(ConversionError::Overflow)?
+        / i128::try_from(total).map_err(|_| ConversionError::Overflow)?;
+
+    // the result is between start and end
+    Value::try_from(start + offset).map_err(|_| ConversionError::Overflow)
+}
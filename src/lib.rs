@@ -80,6 +80,7 @@
 //!
 //! [`Seconds`]: duration::units::Seconds
 //! [`Milliseconds`]: duration::units::Milliseconds
+//! [`Picoseconds`]: duration::units::Picoseconds
 //! [`Clock`]: clock::Clock
 //! [`Instant`]: instant::Instant
 //! [`Rate`]: rate::Rate
@@ -124,6 +125,8 @@
 //!
 //! | Units        | Extension    |
 //! | :----------- | :----------- |
+//! | Weeks        | weeks        |
+//! | Days         | days         |
 //! | Hours        | hours        |
 //! | Minutes      | minutes      |
 //! | Seconds      | seconds      |
@@ -131,6 +134,9 @@
 //! | Microseconds | microseconds |
 //! | Nanoseconds  | nanoseconds  |
 //!
+//! [`Picoseconds`] are also available for high-speed interface timing, but aren't a `Duration` (a
+//! picosecond can't be expressed as a `u32`/`u32` scaling factor) and are converted explicitly.
+//!
 //! - Conversion from `Rate` types
 //! ```rust
 //! use embedded_time::{duration::*, rate::*};
@@ -194,6 +200,7 @@
 //! | Kibihertz         | KiHz      |
 //! | Kilohertz         | kHz       |
 //! | Hertz             | Hz        |
+//! | Rpm               | rpm       |
 //!
 //! ## Data Rate
 //! | Units             | Extension |
@@ -219,6 +226,18 @@
 //! | Kilobaud          | kBd       |
 //! | Baud              | Bd        |
 //!
+//! ## Sample Rate
+//! | Units                | Extension |
+//! | :------------------- | :-------- |
+//! | KilosamplesPerSecond | ksps      |
+//! | SamplesPerSecond     | sps       |
+//!
+//! ## Frame Rate
+//! | Units                | Extension |
+//! | :------------------- | :-------- |
+//! | KiloframesPerSecond  | kfps      |
+//! | FramesPerSecond      | fps       |
+//!
 //! - Conversion from/to all other rate types within the same class (frequency, data rate, etc.) and
 //!   _base_ (mega, mebi, kilo, kibi). For example, MiBps (mebibytes per second) --> Kibps (kibibits
 //!   per second) and MBps (megabytes per second) --> kbps (kilobits per second).
@@ -264,8 +283,34 @@
 //!
 //! - Extensive tests
 //! - Thorough documentation with examples
+//! - Builds on stable Rust, with `const fn` constructors for the units, the `Generic` types,
+//!   `Instant` and `Fraction` (usable in `static` configuration tables)
 //! - Example for the nRF52_DK board
 //!
+//! # Panic-free Use
+//!
+//! The arithmetic operators and `Sum` panic on overflow (as the primitive integer ones do), and
+//! the cross-unit `min()`, `max()` and `clamp()` panic if the result doesn't fit the type of
+//! `self`, for convenience. Every one of them has a checked counterpart returning a `Result` or an
+//! `Option`, so code that must be shown not to panic can use those exclusively:
+//!
+//! | Panicking                                       | Checked                                                      |
+//! |-------------------------------------------------|--------------------------------------------------------------|
+//! | `duration + duration`, `rate - rate`, ...       | `FixedPoint::checked_add()`, `FixedPoint::checked_sub()`     |
+//! | `duration * integer`, `rate / integer`          | `FixedPoint::checked_mul()`, `FixedPoint::checked_div()`     |
+//! | `generic + generic`, `generic * integer`, ...   | `Generic::checked_add()`, `Generic::checked_mul()`, ...      |
+//! | `durations.sum()`                               | `Duration::try_sum()`, `Generic::try_sum()`                  |
+//! | `instant + duration`, `instant - duration`      | `Instant::checked_add()`, `Instant::checked_sub()`           |
+//! | `instant - instant`                             | `Instant::checked_duration_since()`                          |
+//! | `unix_time + duration`, `unix_time - duration`  | `UnixTime::checked_add()`, `UnixTime::checked_sub()`         |
+//! | `integer * fraction`, `fraction / fraction`     | `TimeInt::checked_mul_fraction()`, `Fraction::checked_div()` |
+//! | `duration.max(other)`, `rate.clamp(min, max)`   | Comparison (`<`, `>`), then `TryFrom`                        |
+//!
+//! The `From` conversions between the built-in types don't panic: conversions that can overflow
+//! (eg. `Hours<u32>` to `Nanoseconds<u64>` or `Hertz` to `Millihertz`) are only `TryFrom`.
+//! Comparisons (including those of `Generic` values of any _scaling factors_) and the checked
+//! operations themselves don't panic either.
+//!
 //! # Notes
 //!
 //! Some parts of this crate were derived from various sources:
@@ -278,14 +323,76 @@
 #![warn(missing_docs)]
 #![deny(broken_intra_doc_links)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod accumulator;
+#[cfg(feature = "async")]
+pub mod async_delay;
+pub mod batch;
+pub mod calendar;
+pub mod characterization;
+#[cfg(feature = "chrono")]
+pub mod chrono;
 pub mod clock;
+pub mod clock_handle;
+pub mod clocks;
+pub mod consts;
+#[cfg(feature = "embedded-hal")]
+pub mod count_down;
+pub mod counting_clock;
+pub mod deadline_monitor;
+pub mod defer_queue;
+#[cfg(feature = "embedded-hal-1")]
+pub mod delay;
+pub mod deltas;
 pub mod duration;
+pub mod duty_cycle;
+pub mod dyn_clock;
+#[cfg(feature = "embassy")]
+pub mod embassy;
+pub mod epoch;
+pub mod extended_clock;
+pub mod fallback_clock;
 pub mod fixed_point;
 pub mod fraction;
+#[cfg(feature = "fugit")]
+pub mod fugit;
+#[cfg(feature = "global")]
+pub mod global;
 mod instant;
+pub mod jitter;
+pub mod latency;
+pub mod lerp;
+#[doc(hidden)]
+pub mod literal;
+pub mod long_delay;
+pub mod monotonic_guard;
+pub mod ntp;
+mod parse;
+pub mod periodic;
+pub mod ptp;
+pub mod quantized;
 pub mod rate;
+pub mod rate_watch;
+pub mod retry;
+pub mod rtc_alarm;
+#[cfg(feature = "rtic")]
+pub mod rtic;
+pub mod sequence;
+pub mod slew_limiter;
+pub mod soft_pwm;
+pub mod spin_delay;
+pub mod stats;
+pub mod superframe;
+#[cfg(feature = "time")]
+pub mod time;
 mod time_int;
+pub mod time_slicer;
+pub mod time_transfer;
+pub mod timeline;
 pub mod timer;
+pub mod timestamp;
 
 pub use clock::Clock;
 pub use instant::Instant;
@@ -294,9 +401,11 @@ pub use timer::Timer;
 
 /// Crate errors
 #[non_exhaustive]
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TimeError {
     /// Exact cause of failure is unknown
+    #[default]
     Unspecified,
     /// Attempted type conversion failed
     ConversionFailure,
@@ -316,17 +425,13 @@ impl From<clock::Error> for TimeError {
     }
 }
 
-impl Default for TimeError {
-    fn default() -> Self {
-        Self::Unspecified
-    }
-}
-
 /// Conversion errors
 #[non_exhaustive]
-#[derive(Debug, Eq, PartialEq, Hash)]
+#[derive(Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ConversionError {
     /// Exact cause of failure is unknown
+    #[default]
     Unspecified,
     /// Attempted type conversion failed
     ConversionFailure,
@@ -338,6 +443,25 @@ pub enum ConversionError {
     NegDuration,
 }
 
+/// String parsing errors
+///
+/// Returned when parsing a duration or rate string (eg. `"250ms"`) with
+/// [`str::parse()`](https://doc.rust-lang.org/core/primitive.str.html#method.parse).
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseError {
+    /// A number is missing or malformed
+    #[default]
+    InvalidNumber,
+    /// A unit is missing or unknown
+    UnknownUnit,
+    /// The value doesn't fit in the _integer_ type
+    Overflow,
+    /// The value isn't a whole number of ticks of the destination type
+    Inexact,
+}
+
 impl From<ConversionError> for TimeError {
     fn from(error: ConversionError) -> Self {
         match error {
@@ -350,11 +474,5 @@ impl From<ConversionError> for TimeError {
     }
 }
 
-impl Default for ConversionError {
-    fn default() -> Self {
-        Self::Unspecified
-    }
-}
-
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,256 @@
+//! Compile-time parsing of the [`duration!`](crate::duration!) and [`rate!`](crate::rate!)
+//! literals, and the conversions of [`const_convert!`](crate::const_convert!)
+//!
+//! Everything is `const fn` so that invalid literals (and overflowing conversions) are rejected (by
+//! panicking) during constant evaluation.
+
+use crate::{fixed_point::FixedPoint, fraction::Fraction};
+
+/// A parsed literal: `integer` × `numerator` / `denominator` seconds (or hertz)
+///
+/// The number is kept as written (with trailing decimal zeros removed) and the fraction is
+/// reduced.
+#[derive(Copy, Clone, Debug)]
+pub struct Literal {
+    /// The digits of the number
+    pub integer: u128,
+    /// The numerator of the unit divided by 10 for each decimal
+    pub numerator: u128,
+    /// The denominator of the unit multiplied by 10 for each decimal
+    pub denominator: u128,
+}
+
+/// Parse a duration literal (`ns`, `us`/`µs`, `ms`, `s`, `min`, `h`, `d` or `w`)
+pub const fn duration(literal: &str) -> Literal {
+    let (integer, decimals, unit) = number(literal.as_bytes());
+
+    let (numerator, denominator) = if eq(unit, b"ns") {
+        (1, 1_000_000_000)
+    } else if eq(unit, b"us") || eq(unit, "µs".as_bytes()) {
+        (1, 1_000_000)
+    } else if eq(unit, b"ms") {
+        (1, 1_000)
+    } else if eq(unit, b"s") {
+        (1, 1)
+    } else if eq(unit, b"min") {
+        (60, 1)
+    } else if eq(unit, b"h") {
+        (3_600, 1)
+    } else if eq(unit, b"d") {
+        (86_400, 1)
+    } else if eq(unit, b"w") {
+        (604_800, 1)
+    } else {
+        panic!("Unknown duration unit")
+    };
+
+    reduce(integer, decimals, numerator, denominator)
+}
+
+/// Parse a rate literal (`µHz`/`uHz` to `MiHz`, `bps` to `Mibps`, `Bps` to `MiBps`, `Bd` to
+/// `MiBd`, `rpm`, `sps`/`ksps` and `fps`/`kfps`)
+pub const fn rate(literal: &str) -> Literal {
+    let (integer, decimals, unit) = number(literal.as_bytes());
+
+    let (numerator, denominator) = if eq(unit, b"MiHz") || eq(unit, b"Mibps") || eq(unit, b"MiBd") {
+        (1_048_576, 1)
+    } else if eq(unit, b"MHz") || eq(unit, b"Mbps") || eq(unit, b"MBd") {
+        (1_000_000, 1)
+    } else if eq(unit, b"KiHz") || eq(unit, b"Kibps") || eq(unit, b"KiBd") {
+        (1_024, 1)
+    } else if eq(unit, b"kHz") || eq(unit, b"kbps") || eq(unit, b"kBd") {
+        (1_000, 1)
+    } else if eq(unit, b"Hz") || eq(unit, b"bps") || eq(unit, b"Bd") {
+        (1, 1)
+    } else if eq(unit, b"dHz") {
+        (1, 10)
+    } else if eq(unit, b"cHz") {
+        (1, 100)
+    } else if eq(unit, b"mHz") {
+        (1, 1_000)
+    } else if eq(unit, b"uHz") || eq(unit, "µHz".as_bytes()) {
+        (1, 1_000_000)
+    } else if eq(unit, b"rpm") {
+        (1, 60)
+    } else if eq(unit, b"MiBps") {
+        (1_048_576 * 8, 1)
+    } else if eq(unit, b"MBps") {
+        (1_000_000 * 8, 1)
+    } else if eq(unit, b"KiBps") {
+        (1_024 * 8, 1)
+    } else if eq(unit, b"kBps") {
+        (1_000 * 8, 1)
+    } else if eq(unit, b"Bps") {
+        (8, 1)
+    } else if eq(unit, b"ksps") || eq(unit, b"kfps") {
+        (1_000, 1)
+    } else if eq(unit, b"sps") || eq(unit, b"fps") {
+        (1, 1)
+    } else {
+        panic!("Unknown rate unit")
+    };
+
+    reduce(integer, decimals, numerator, denominator)
+}
+
+/// Returns the literal as a whole number of units of `scaling_factor`
+///
+/// # Panics
+///
+/// If the literal isn't a whole number of units or the number overflows
+pub const fn integer(literal: Literal, scaling_factor: Fraction) -> u128 {
+    let numerator = match literal.integer.checked_mul(literal.numerator) {
+        Some(numerator) => match numerator.checked_mul(*scaling_factor.denominator() as u128) {
+            Some(numerator) => numerator,
+            None => panic!("Literal overflows"),
+        },
+        None => panic!("Literal overflows"),
+    };
+    let denominator = match literal
+        .denominator
+        .checked_mul(*scaling_factor.numerator() as u128)
+    {
+        Some(denominator) => denominator,
+        None => panic!("Literal overflows"),
+    };
+
+    if denominator == 0 || !numerator.is_multiple_of(denominator) {
+        panic!("Literal isn't a whole number of units");
+    }
+
+    numerator / denominator
+}
+
+/// Returns the _scaling factors_ of the `value` type and the `Dest` type, which must have the same
+/// dimension
+pub const fn scaling_factors<Source, Dest>(_value: &Source) -> (Fraction, Fraction)
+where
+    Source: FixedPoint,
+    Dest: FixedPoint<Dimension = Source::Dimension>,
+{
+    (Source::SCALING_FACTOR, Dest::SCALING_FACTOR)
+}
+
+/// Returns `ticks` of the `from` _scaling factor_ as (truncated) ticks of the `to` _scaling
+/// factor_
+///
+/// # Panics
+///
+/// If the conversion overflows, or a _scaling factor_ has a `0` numerator or denominator
+pub const fn convert(ticks: u128, from: Fraction, to: Fraction) -> u128 {
+    let numerator = match ticks.checked_mul(*from.numerator() as u128) {
+        Some(numerator) => match numerator.checked_mul(*to.denominator() as u128) {
+            Some(numerator) => numerator,
+            None => panic!("Conversion overflows"),
+        },
+        None => panic!("Conversion overflows"),
+    };
+    let denominator = *from.denominator() as u128 * *to.numerator() as u128;
+
+    if denominator == 0 {
+        panic!("Scaling factor of 0");
+    }
+
+    numerator / denominator
+}
+
+/// Returns the value as a `u32`
+///
+/// # Panics
+///
+/// If the value doesn't fit
+pub const fn to_u32(value: u128) -> u32 {
+    if value > u32::MAX as u128 {
+        panic!("Literal doesn't fit in a u32");
+    }
+
+    value as u32
+}
+
+/// Returns the digits, the number of decimals and the unit
+const fn number(literal: &[u8]) -> (u128, u32, &[u8]) {
+    let mut integer = 0_u128;
+    let mut digits = 0;
+    let mut decimals = 0;
+    let mut point = false;
+
+    let mut i = 0;
+    while i < literal.len() {
+        match literal[i] {
+            digit @ b'0'..=b'9' => {
+                integer = match integer.checked_mul(10) {
+                    Some(integer) => match integer.checked_add((digit - b'0') as u128) {
+                        Some(integer) => integer,
+                        None => panic!("Literal overflows"),
+                    },
+                    None => panic!("Literal overflows"),
+                };
+                digits += 1;
+                if point {
+                    decimals += 1;
+                }
+            }
+            b'.' if !point => point = true,
+            _ => break,
+        }
+        i += 1;
+    }
+
+    if digits == 0 {
+        panic!("Literal has no digits");
+    }
+
+    // the unit may be separated by a space
+    let (_, mut unit) = literal.split_at(i);
+    if let [b' ', rest @ ..] = unit {
+        unit = rest;
+    }
+
+    // trailing decimal zeros don't change the value
+    while decimals > 0 && integer.is_multiple_of(10) {
+        integer /= 10;
+        decimals -= 1;
+    }
+
+    (integer, decimals, unit)
+}
+
+const fn reduce(integer: u128, decimals: u32, numerator: u128, denominator: u128) -> Literal {
+    let denominator = match 10_u128.checked_pow(decimals) {
+        Some(scale) => denominator * scale,
+        None => panic!("Literal overflows"),
+    };
+    let gcd = gcd(numerator, denominator);
+
+    Literal {
+        integer,
+        numerator: numerator / gcd,
+        denominator: denominator / gcd,
+    }
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    a
+}
+
+const fn eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
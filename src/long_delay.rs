@@ -0,0 +1,127 @@
+//! Splitting of long durations into timer-sized chunks
+
+use crate::{duration, duration::Duration, fixed_point::FixedPoint, ConversionError};
+use core::{convert::TryFrom, marker::PhantomData};
+use num::Bounded;
+
+/// Returns an iterator over chunks of a duration, each no longer than `max_ticks` ticks of the
+/// clock
+///
+/// Hardware timers can only be programmed with an interval up to some maximum (eg. the range of a
+/// 16-bit compare register). A longer sleep is done as a sequence of shorter ones, the chunks
+/// yielded by the iterator.
+///
+/// The duration is rounded up to whole ticks of the clock, so the sum of the chunks is never
+/// shorter than the requested duration. `max_ticks` is also limited to half the range of the
+/// clock, the longest interval an [`Instant`](crate::Instant) can span.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, long_delay, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // a 16-bit timer clocked at 32.768 kHz can't be programmed for more than 2 seconds
+/// let mut chunks = long_delay::chunks::<Clock, _>(Seconds(5_u32), u32::from(u16::MAX)).unwrap();
+///
+/// assert_eq!(chunks.next().map(|chunk| chunk.integer()), Some(65_535));
+/// assert_eq!(chunks.next().map(|chunk| chunk.integer()), Some(65_535));
+/// assert_eq!(chunks.next().map(|chunk| chunk.integer()), Some(32_770));
+/// assert_eq!(chunks.next(), None);
+/// ```
+///
+/// # Errors
+///
+/// - [`ConversionError::ConversionFailure`] : The duration is longer than [`u64::MAX`] ticks of
+///   the clock
+/// - [`ConversionError::DivByZero`] : `max_ticks` is zero
+pub fn chunks<Clock, Dur>(
+    duration: Dur,
+    max_ticks: Clock::T,
+) -> Result<Chunks<Clock>, ConversionError>
+where
+    Clock: crate::Clock,
+    Dur: Duration + FixedPoint,
+    u64: TryFrom<Dur::T> + TryFrom<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    let max_ticks = max_ticks.min(Clock::T::max_value() / Clock::T::from(2));
+    let max_ticks = <u64 as TryFrom<Clock::T>>::try_from(max_ticks)
+        .map_err(|_| ConversionError::Unspecified)?;
+    if max_ticks == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let integer = <u64 as TryFrom<Dur::T>>::try_from(duration.integer())
+        .map_err(|_| ConversionError::ConversionFailure)?;
+
+    // duration × duration scaling factor / clock scaling factor, rounded up
+    let numerator = u128::from(integer)
+        * u128::from(*Dur::SCALING_FACTOR.numerator())
+        * u128::from(*Clock::SCALING_FACTOR.denominator());
+    let denominator = u128::from(*Dur::SCALING_FACTOR.denominator())
+        * u128::from(*Clock::SCALING_FACTOR.numerator());
+    if denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let remaining = <u64 as TryFrom<u128>>::try_from(numerator.div_ceil(denominator))
+        .map_err(|_| ConversionError::ConversionFailure)?;
+
+    Ok(Chunks {
+        remaining,
+        max_ticks,
+        clock: PhantomData,
+    })
+}
+
+/// An iterator over timer-sized chunks of a duration
+///
+/// Created by [`chunks()`].
+#[derive(Debug)]
+pub struct Chunks<Clock: crate::Clock> {
+    remaining: u64,
+    max_ticks: u64,
+    clock: PhantomData<Clock>,
+}
+
+impl<Clock: crate::Clock> Chunks<Clock> {
+    /// Returns the number of clock ticks not yet yielded
+    pub fn remaining_ticks(&self) -> u64 {
+        self.remaining
+    }
+}
+
+impl<Clock> Iterator for Chunks<Clock>
+where
+    Clock: crate::Clock,
+    Clock::T: TryFrom<u64>,
+{
+    type Item = duration::Generic<Clock::T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let chunk = self.remaining.min(self.max_ticks);
+        self.remaining -= chunk;
+
+        // the chunk is no larger than `max_ticks`, which came from a `Clock::T`
+        let chunk = Clock::T::try_from(chunk).ok()?;
+        Some(duration::Generic::new(chunk, Clock::SCALING_FACTOR))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = usize::try_from(self.remaining.div_ceil(self.max_ticks)).ok();
+        (chunks.unwrap_or(usize::MAX), chunks)
+    }
+}
@@ -0,0 +1,107 @@
+//! Detection of backwards-jumping clocks
+
+use crate::{clock, fraction::Fraction, Instant};
+use core::cell::Cell;
+
+/// The action taken by a [`MonotonicGuard`] when the underlying clock jumps backwards
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Policy {
+    /// Return the latest reading again instead of the earlier one
+    Clamp,
+    /// Return [`clock::Error::NonMonotonic`]
+    Error,
+}
+
+/// A [`Clock`](crate::Clock) wrapper that guarantees readings never go backwards
+///
+/// A broken counter, a mis-set prescaler or a race in counter-extension logic can make the
+/// underlying clock jump backwards. Rather than producing absurd elapsed times, the guard either
+/// clamps the reading to the latest one seen or returns an error, depending on its [`Policy`].
+///
+/// As with [`Instant`] comparisons, a reading is considered to be earlier than the previous one if
+/// it is behind it by less than half the counter range, so counter wrap-around is handled.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, monotonic_guard::{MonotonicGuard, Policy}, Clock as _, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Counter(Cell<u32>);
+/// impl embedded_time::Clock for Counter {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let clock = MonotonicGuard::new(Counter(Cell::new(100)), Policy::Clamp);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+///
+/// // the counter jumps backwards
+/// clock.inner().0.set(90);
+/// assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+/// ```
+#[derive(Debug)]
+pub struct MonotonicGuard<Clock: crate::Clock> {
+    clock: Clock,
+    policy: Policy,
+    latest: Cell<Option<Clock::T>>,
+}
+
+impl<Clock: crate::Clock> MonotonicGuard<Clock> {
+    /// Wrap a clock
+    pub fn new(clock: Clock, policy: Policy) -> Self {
+        Self {
+            clock,
+            policy,
+            latest: Cell::new(None),
+        }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the underlying clock
+    pub fn into_inner(self) -> Clock {
+        self.clock
+    }
+}
+
+impl<Clock: crate::Clock> crate::Clock for MonotonicGuard<Clock> {
+    type T = Clock::T;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Clock::SCALING_FACTOR;
+
+    /// Get the current Instant, guarded against backwards jumps
+    ///
+    /// # Errors
+    ///
+    /// - [`clock::Error::NonMonotonic`] : The underlying clock jumped backwards and the policy is
+    ///   [`Policy::Error`]
+    /// - Any error returned by the underlying clock
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        let now = Instant::new(
+            clock::try_now(&self.clock)?
+                .duration_since_epoch()
+                .integer(),
+        );
+
+        if let Some(latest) = self.latest.get().map(Instant::<Self>::new) {
+            if now < latest {
+                return match self.policy {
+                    Policy::Clamp => Ok(latest),
+                    Policy::Error => Err(clock::Error::NonMonotonic),
+                };
+            }
+        }
+
+        self.latest.set(Some(now.duration_since_epoch().integer()));
+        Ok(now)
+    }
+}
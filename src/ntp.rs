@@ -0,0 +1,368 @@
+//! NTP timestamp (32.32) and short (16.16) formats
+//!
+//! The fixed-point formats of the Network Time Protocol ([RFC 5905]), for (S)NTP clients:
+//!
+//! - [`NtpTimestamp`]: seconds since 1900-01-01 00:00:00 UTC and a 32-bit binary fraction of a
+//!   second (the _transmit_, _receive_, ... timestamps of a packet)
+//! - [`NtpShort`]: a duration of seconds and a 16-bit binary fraction of a second (the _root delay_
+//!   and _root dispersion_ of a packet)
+//!
+//! # Eras
+//!
+//! The 32-bit seconds of an `NtpTimestamp` wrap every 2<sup>32</sup> seconds (about 136 years), the
+//! first wraparound (the start of era 1) being on 2036-02-07 06:28:16 UTC. A timestamp doesn't
+//! record its era:
+//!
+//! - Differences between timestamps ([`NtpTimestamp::signed_duration_since()`]) and offsets of
+//!   timestamps ([`NtpTimestamp::wrapping_add()`]) use wrapping math, so they are correct across
+//!   an era boundary as long as the timestamps are less than 68 years apart.
+//! - [`NtpTimestamp::to_unix_time()`] resolves the era as [RFC 4330] recommends: timestamps with
+//!   the most significant bit of the seconds set are in era 0 (1968 to 2036), the others in era 1
+//!   (2036 to 2104). [`NtpTimestamp::to_unix_time_in_era()`] takes an explicit era instead.
+//! - [`NtpTimestamp::from_unix_time()`] drops the era.
+//!
+//! # Examples
+//!
+//! A simple SNTP exchange: the client sends its transmit time (`t1`), the server replies with its
+//! receive (`t2`) and transmit (`t3`) times and the client records the reply's arrival (`t4`).
+//!
+//! ```rust
+//! # use embedded_time::{duration::*, epoch::UnixTime, ntp::NtpTimestamp};
+//! # use core::convert::TryFrom;
+//! let t1 = NtpTimestamp::from_unix_time(UnixTime::from_secs(1_600_000_000));
+//! let t2 = NtpTimestamp::from_unix_time(UnixTime::new(1_600_000_005, 10_000_000).unwrap());
+//! let t3 = NtpTimestamp::from_unix_time(UnixTime::new(1_600_000_005, 12_000_000).unwrap());
+//! let t4 = NtpTimestamp::from_unix_time(UnixTime::new(1_600_000_000, 30_000_000).unwrap());
+//!
+//! // offset = ((t2 - t1) + (t3 - t4)) / 2
+//! let offset = (t2.signed_duration_since(&t1).integer()
+//!     + t3.signed_duration_since(&t4).integer())
+//!     / 2;
+//! assert_eq!(offset, 4_996_000_000); // nanoseconds
+//!
+//! let now = t4.wrapping_add(Generic::new(offset as u64, Fraction::new(1, 1_000_000_000)));
+//! assert_eq!(
+//!     now.unwrap().to_unix_time(),
+//!     UnixTime::new(1_600_000_005, 25_999_999)
+//! );
+//! ```
+//!
+//! [RFC 5905]: https://www.rfc-editor.org/rfc/rfc5905
+//! [RFC 4330]: https://www.rfc-editor.org/rfc/rfc4330
+
+use crate::{
+    duration::{self, SignedGeneric},
+    epoch::UnixTime,
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::{Fraction, Fraction64},
+    ConversionError, Instant, TimeInt,
+};
+use core::convert::TryFrom;
+
+/// The seconds from the NTP epoch (1900-01-01) to the Unix epoch (1970-01-01)
+const UNIX_OFFSET: u64 = 2_208_988_800;
+const NANOSECOND: Fraction = Fraction::new(1, 1_000_000_000);
+/// The _scaling factor_ of the bits of an [`NtpTimestamp`]
+const TIMESTAMP_SCALING_FACTOR: Fraction64 = Fraction64::new(1, 1 << 32);
+/// The _scaling factor_ of the bits of an [`NtpShort`]
+const SHORT_SCALING_FACTOR: Fraction = Fraction::new(1, 1 << 16);
+
+/// An NTP timestamp: seconds since 1900-01-01 00:00:00 UTC (of an unrecorded era) in the upper 32
+/// bits and a binary fraction of a second in the lower 32 bits
+///
+/// See the [module documentation](self) for the handling of eras.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NtpTimestamp(u64);
+
+impl NtpTimestamp {
+    /// Construct a timestamp from its 64-bit wire representation
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Construct a timestamp from its seconds and fraction (of 2<sup>32</sup>) parts
+    pub const fn new(secs: u32, fraction: u32) -> Self {
+        Self((secs as u64) << 32 | fraction as u64)
+    }
+
+    /// Returns the 64-bit wire representation
+    pub const fn to_bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the seconds part
+    pub const fn secs(&self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Returns the fraction (of 2<sup>32</sup>) part
+    pub const fn fraction(&self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Returns the timestamp of a [`UnixTime`], dropping the era
+    ///
+    /// The nanoseconds are rounded up to the next fraction so that converting back with
+    /// [`to_unix_time()`](NtpTimestamp::to_unix_time()) is exact.
+    pub fn from_unix_time(time: UnixTime) -> Self {
+        let secs = time.secs().wrapping_add(UNIX_OFFSET) as u32;
+        // the fraction of 999_999_999ns rounds up to less than 1 second
+        let fraction = (u64::from(time.subsec_nanos()) << 32).div_ceil(1_000_000_000);
+
+        Self::new(secs, fraction as u32)
+    }
+
+    /// Returns the [`UnixTime`] of the timestamp, resolving the era as [RFC 4330] recommends
+    /// (1968 to 2104)
+    ///
+    /// The fraction is truncated to nanoseconds. Returns [`None`] before the Unix epoch.
+    ///
+    /// [RFC 4330]: https://www.rfc-editor.org/rfc/rfc4330
+    pub fn to_unix_time(&self) -> Option<UnixTime> {
+        self.to_unix_time_in_era(if self.secs() & (1 << 31) != 0 { 0 } else { 1 })
+    }
+
+    /// Returns the [`UnixTime`] of the timestamp in the given era (`0` being 1900 to 2036)
+    ///
+    /// The fraction is truncated to nanoseconds. Returns [`None`] before the Unix epoch.
+    pub fn to_unix_time_in_era(&self, era: u32) -> Option<UnixTime> {
+        let ntp_secs = (u64::from(era) << 32) + u64::from(self.secs());
+        let nanos = (u64::from(self.fraction()) * 1_000_000_000) >> 32;
+
+        UnixTime::new(ntp_secs.checked_sub(UNIX_OFFSET)?, nanos as u32)
+    }
+
+    /// Returns the timestamp of an [`Instant`], given the timestamp of the clock's tick `0`
+    /// (`epoch`)
+    ///
+    /// The time since the clock's epoch is truncated to a fraction.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The time since the clock's epoch is more than 2<sup>32</sup>
+    /// seconds
+    pub fn from_instant<Clock: crate::Clock>(
+        instant: &Instant<Clock>,
+        epoch: NtpTimestamp,
+    ) -> Result<Self, ConversionError> {
+        epoch.wrapping_add(instant.duration_since_epoch())
+    }
+
+    /// Returns the [`Instant`] of this timestamp, given the timestamp of the clock's tick `0`
+    /// (`epoch`)
+    ///
+    /// The time since the clock's epoch is truncated to whole ticks. Returns [`None`] if this is
+    /// more than 68 years before the `epoch` or the ticks don't fit the clock's integer type.
+    pub fn to_instant<Clock: crate::Clock>(&self, epoch: NtpTimestamp) -> Option<Instant<Clock>>
+    where
+        Clock::T: TryFrom<u128>,
+    {
+        let since_epoch = u64::try_from(self.0.wrapping_sub(epoch.0) as i64).ok()?;
+        let ticks = fixed_point::rescale(
+            u128::from(since_epoch),
+            TIMESTAMP_SCALING_FACTOR,
+            Clock::SCALING_FACTOR,
+            RoundingMode::Floor,
+        )
+        .ok()?;
+
+        Clock::T::try_from(ticks).ok().map(Instant::new)
+    }
+
+    /// This timestamp + duration, wrapping into the next era
+    ///
+    /// The duration is truncated to a fraction.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, ntp::NtpTimestamp};
+    /// assert_eq!(
+    ///     NtpTimestamp::new(u32::MAX, 0).wrapping_add(Milliseconds(1_500_u32)),
+    ///     Ok(NtpTimestamp::new(0, 1 << 31))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The duration is more than 2<sup>32</sup> seconds
+    pub fn wrapping_add<T: TimeInt>(
+        self,
+        duration: impl Into<duration::Generic<T>>,
+    ) -> Result<Self, ConversionError> {
+        Ok(Self(self.0.wrapping_add(timestamp_bits(duration.into())?)))
+    }
+
+    /// This timestamp - duration, wrapping into the previous era
+    ///
+    /// The duration is truncated to a fraction.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The duration is more than 2<sup>32</sup> seconds
+    pub fn wrapping_sub<T: TimeInt>(
+        self,
+        duration: impl Into<duration::Generic<T>>,
+    ) -> Result<Self, ConversionError> {
+        Ok(Self(self.0.wrapping_sub(timestamp_bits(duration.into())?)))
+    }
+
+    /// Returns the (possibly negative) time from `other` to this timestamp, in nanoseconds
+    /// (truncated toward `0`)
+    ///
+    /// The timestamps may be in adjacent eras, but must be less than 68 years apart.
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, ntp::NtpTimestamp};
+    /// let before_wraparound = NtpTimestamp::new(u32::MAX, 0);
+    /// let after_wraparound = NtpTimestamp::new(1, 0);
+    ///
+    /// assert_eq!(
+    ///     after_wraparound.signed_duration_since(&before_wraparound),
+    ///     SignedGeneric::new(2_000_000_000_i64, Fraction::new(1, 1_000_000_000))
+    /// );
+    /// assert_eq!(
+    ///     before_wraparound.signed_duration_since(&after_wraparound),
+    ///     SignedGeneric::new(-2_000_000_000_i64, Fraction::new(1, 1_000_000_000))
+    /// );
+    /// ```
+    pub fn signed_duration_since(&self, other: &Self) -> SignedGeneric<i64> {
+        let bits = i128::from(self.0.wrapping_sub(other.0) as i64);
+        // 2^63 / 2^32 seconds is less than 2^63 nanoseconds
+        let nanos = (bits.unsigned_abs() * 1_000_000_000) >> 32;
+        let nanos = if bits < 0 {
+            -(nanos as i64)
+        } else {
+            nanos as i64
+        };
+
+        SignedGeneric::new(nanos, NANOSECOND)
+    }
+}
+
+/// Returns the `duration` in 32.32 fixed-point seconds (truncated)
+fn timestamp_bits<T: TimeInt>(duration: duration::Generic<T>) -> Result<u64, ConversionError> {
+    let bits = fixed_point::rescale(
+        duration
+            .integer()
+            .to_u128()
+            .ok_or(ConversionError::Overflow)?,
+        *duration.scaling_factor(),
+        TIMESTAMP_SCALING_FACTOR,
+        RoundingMode::Floor,
+    )?;
+
+    u64::try_from(bits).map_err(|_| ConversionError::Overflow)
+}
+
+/// An NTP short format duration: seconds in the upper 16 bits and a binary fraction of a second in
+/// the lower 16 bits
+///
+/// It converts exactly to a [`Generic`](duration::Generic) duration (with a `1/65_536` _scaling
+/// factor_) and from any duration, truncated to a fraction.
+///
+/// ```rust
+/// # use embedded_time::{duration::*, ntp::NtpShort, ConversionError};
+/// # use core::convert::TryFrom;
+/// let root_delay = NtpShort::try_from(Milliseconds(250_u32)).unwrap();
+/// assert_eq!(root_delay.to_bits(), 0x0000_4000);
+/// assert_eq!(Milliseconds::<u32>::try_from(Generic::from(root_delay)), Ok(Milliseconds(250)));
+///
+/// assert_eq!(NtpShort::try_from(Hours(24_u32)), Err(ConversionError::Overflow));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NtpShort(u32);
+
+impl NtpShort {
+    /// Construct a short format duration from its 32-bit wire representation
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Construct a short format duration from its seconds and fraction (of 2<sup>16</sup>) parts
+    pub const fn new(secs: u16, fraction: u16) -> Self {
+        Self((secs as u32) << 16 | fraction as u32)
+    }
+
+    /// Returns the 32-bit wire representation
+    pub const fn to_bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the seconds part
+    pub const fn secs(&self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    /// Returns the fraction (of 2<sup>16</sup>) part
+    pub const fn fraction(&self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Converts ticks of the `scaling_factor` (truncated)
+    fn from_ticks(ticks: u128, scaling_factor: Fraction) -> Result<Self, ConversionError> {
+        let bits = fixed_point::rescale(
+            ticks,
+            scaling_factor,
+            SHORT_SCALING_FACTOR,
+            RoundingMode::Floor,
+        )?;
+
+        u32::try_from(bits)
+            .map(Self)
+            .map_err(|_| ConversionError::Overflow)
+    }
+}
+
+impl From<NtpShort> for duration::Generic<u32> {
+    fn from(short: NtpShort) -> Self {
+        Self::new(short.0, SHORT_SCALING_FACTOR)
+    }
+}
+
+/// Truncated to a fraction
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The duration is 65,536 seconds or more
+/// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+impl<T: TimeInt> TryFrom<duration::Generic<T>> for NtpShort {
+    type Error = ConversionError;
+
+    fn try_from(duration: duration::Generic<T>) -> Result<Self, Self::Error> {
+        let ticks = duration
+            .integer()
+            .to_u128()
+            .ok_or(ConversionError::Overflow)?;
+        Self::from_ticks(ticks, *duration.scaling_factor())
+    }
+}
+
+macro_rules! impl_ntp_short {
+    ( $($name:ident),* ) => {$(
+        /// Truncated to a fraction
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The duration is 65,536 seconds or more
+        impl<T: TimeInt> TryFrom<duration::$name<T>> for NtpShort {
+            type Error = ConversionError;
+
+            fn try_from(duration: duration::$name<T>) -> Result<Self, Self::Error> {
+                let ticks = duration.integer().to_u128().ok_or(ConversionError::Overflow)?;
+                Self::from_ticks(ticks, duration::$name::<T>::SCALING_FACTOR)
+            }
+        }
+    )*};
+}
+
+impl_ntp_short![
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds
+];
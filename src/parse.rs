@@ -0,0 +1,186 @@
+//! Run-time parsing of duration and rate strings
+
+use crate::{fraction::Fraction, ParseError};
+use core::convert::TryFrom;
+use num::Integer;
+
+/// An exact, reduced value: `numerator` / `denominator` seconds (or hertz)
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Value {
+    numerator: u128,
+    denominator: u128,
+}
+
+impl Value {
+    fn new(numerator: u128, denominator: u128) -> Self {
+        let gcd = numerator.gcd(&denominator);
+
+        Self {
+            numerator: numerator / gcd,
+            denominator: denominator / gcd,
+        }
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let gcd = self.denominator.gcd(&rhs.denominator);
+        let denominator = (self.denominator / gcd).checked_mul(rhs.denominator)?;
+        let numerator = self
+            .numerator
+            .checked_mul(denominator / self.denominator)?
+            .checked_add(rhs.numerator.checked_mul(denominator / rhs.denominator)?)?;
+
+        Some(Self::new(numerator, denominator))
+    }
+
+    /// Returns the value in ticks of the _scaling factor_
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseError::Inexact`] : The value isn't a whole number of ticks
+    /// - [`ParseError::Overflow`] : The ticks don't fit in the _integer_ type
+    pub(crate) fn ticks<T: TryFrom<u128>>(
+        &self,
+        scaling_factor: Fraction,
+    ) -> Result<T, ParseError> {
+        let numerator = self
+            .numerator
+            .checked_mul(u128::from(*scaling_factor.denominator()))
+            .ok_or(ParseError::Overflow)?;
+        let denominator = self
+            .denominator
+            .checked_mul(u128::from(*scaling_factor.numerator()))
+            .ok_or(ParseError::Overflow)?;
+
+        if denominator == 0 || numerator % denominator != 0 {
+            return Err(ParseError::Inexact);
+        }
+
+        T::try_from(numerator / denominator).map_err(|_| ParseError::Overflow)
+    }
+
+    /// Returns the value as an _integer_ and a _scaling factor_ of `1 / denominator`
+    ///
+    /// # Errors
+    ///
+    /// [`ParseError::Overflow`] : The numerator doesn't fit in the _integer_ type or the
+    /// denominator doesn't fit in a [`Fraction`]
+    pub(crate) fn generic<T: TryFrom<u128>>(&self) -> Result<(T, Fraction), ParseError> {
+        Ok((
+            T::try_from(self.numerator).map_err(|_| ParseError::Overflow)?,
+            Fraction::new(
+                1,
+                u32::try_from(self.denominator).map_err(|_| ParseError::Overflow)?,
+            ),
+        ))
+    }
+}
+
+/// Parse a duration, optionally made of several parts (eg. `"1h30m"` or `"1 min 2.5 s"`)
+///
+/// The units are `ns`, `us` (or `µs`), `ms`, `s`, `m` (or `min`), `h`, `d` and `w`.
+pub(crate) fn duration(s: &str) -> Result<Value, ParseError> {
+    let mut rest = s.trim();
+    let mut sum = Value::new(0, 1);
+
+    loop {
+        let (value, remainder) = part(rest, duration_unit)?;
+        sum = sum.checked_add(&value).ok_or(ParseError::Overflow)?;
+
+        rest = remainder.trim_start();
+        if rest.is_empty() {
+            return Ok(sum);
+        }
+    }
+}
+
+/// Parse a rate (eg. `"8MHz"` or `"115.2 kbps"`)
+///
+/// The units are those of the [`rate!`](crate::rate!) literals.
+pub(crate) fn rate(s: &str) -> Result<Value, ParseError> {
+    let (value, rest) = part(s.trim(), rate_unit)?;
+
+    if rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::UnknownUnit)
+    }
+}
+
+/// Parse a number followed by a unit, returning the value and the rest of the string
+fn part(s: &str, units: fn(&str) -> Option<(u128, u128)>) -> Result<(Value, &str), ParseError> {
+    let unit_start = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, rest) = s.split_at(unit_start);
+    let rest = rest.trim_start();
+    let unit_end = rest
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(rest.len());
+    let (unit, rest) = rest.split_at(unit_end);
+
+    let (whole, decimals) = match number.find('.') {
+        Some(point) => (&number[..point], &number[point + 1..]),
+        None => (number, ""),
+    };
+    if whole.len() + decimals.len() == 0 || decimals.contains('.') {
+        return Err(ParseError::InvalidNumber);
+    }
+
+    let mut integer = 0_u128;
+    for digit in whole.bytes().chain(decimals.bytes()) {
+        integer = integer
+            .checked_mul(10)
+            .and_then(|integer| integer.checked_add(u128::from(digit - b'0')))
+            .ok_or(ParseError::Overflow)?;
+    }
+    let scale = u32::try_from(decimals.len())
+        .ok()
+        .and_then(|decimals| 10_u128.checked_pow(decimals))
+        .ok_or(ParseError::Overflow)?;
+
+    let (numerator, denominator) = units(unit).ok_or(ParseError::UnknownUnit)?;
+    let value = integer
+        .checked_mul(numerator)
+        .zip(scale.checked_mul(denominator))
+        .map(|(numerator, denominator)| Value::new(numerator, denominator))
+        .ok_or(ParseError::Overflow)?;
+
+    Ok((value, rest))
+}
+
+fn duration_unit(unit: &str) -> Option<(u128, u128)> {
+    Some(match unit {
+        "ns" => (1, 1_000_000_000),
+        "us" | "µs" => (1, 1_000_000),
+        "ms" => (1, 1_000),
+        "s" => (1, 1),
+        "m" | "min" => (60, 1),
+        "h" => (3_600, 1),
+        "d" => (86_400, 1),
+        "w" => (604_800, 1),
+        _ => return None,
+    })
+}
+
+fn rate_unit(unit: &str) -> Option<(u128, u128)> {
+    Some(match unit {
+        "MiHz" | "Mibps" | "MiBd" => (1_048_576, 1),
+        "MHz" | "Mbps" | "MBd" => (1_000_000, 1),
+        "KiHz" | "Kibps" | "KiBd" => (1_024, 1),
+        "kHz" | "kbps" | "kBd" => (1_000, 1),
+        "Hz" | "bps" | "Bd" => (1, 1),
+        "dHz" => (1, 10),
+        "cHz" => (1, 100),
+        "mHz" => (1, 1_000),
+        "uHz" | "µHz" => (1, 1_000_000),
+        "rpm" => (1, 60),
+        "MiBps" => (1_048_576 * 8, 1),
+        "MBps" => (1_000_000 * 8, 1),
+        "KiBps" => (1_024 * 8, 1),
+        "kBps" => (1_000 * 8, 1),
+        "Bps" => (8, 1),
+        "ksps" | "kfps" => (1_000, 1),
+        "sps" | "fps" => (1, 1),
+        _ => return None,
+    })
+}
@@ -0,0 +1,221 @@
+//! Drift-free periodic deadlines
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, jitter::Jitter, rate::Rate, ConversionError,
+    Instant,
+};
+use core::convert::TryFrom;
+use num::{
+    integer::gcd,
+    traits::{WrappingAdd, WrappingSub},
+    Bounded,
+};
+
+/// Generates periodic deadlines whose long-run average period is exact
+///
+/// When the desired period isn't a whole number of clock ticks, each interval is quantized to
+/// either the truncated or the truncated + 1 number of ticks. The fractional part is accumulated
+/// (as in Bresenham's line algorithm) so that the quantization error never exceeds one tick and
+/// doesn't drift over time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, periodic::Periodic, rate::*, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // 3 Hz is a period of 333⅓ ms
+/// let mut periodic = Periodic::<Clock>::from_rate(Hertz(3_u32), Instant::new(0)).unwrap();
+///
+/// assert_eq!(periodic.next_deadline(), Instant::new(333));
+/// assert_eq!(periodic.advance(), Instant::new(666));
+/// assert_eq!(periodic.advance(), Instant::new(1_000));
+/// ```
+#[derive(Debug)]
+pub struct Periodic<Clock: crate::Clock> {
+    next: Instant<Clock>,
+    whole_ticks: Clock::T,
+    remainder: u64,
+    denominator: u64,
+    error: u64,
+}
+
+impl<Clock: crate::Clock> Periodic<Clock> {
+    /// Construct a `Periodic` with the given period, the first deadline being one period after
+    /// `start`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The period doesn't fit in the clock's integer type
+    /// - [`ConversionError::Overflow`] : The period is longer than half the range of the clock
+    /// - [`ConversionError::DivByZero`] : The period is zero
+    pub fn new<Dur>(period: Dur, start: Instant<Clock>) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+        Clock::T: TryFrom<u64>,
+    {
+        let integer =
+            u64::try_from(period.integer()).map_err(|_| ConversionError::ConversionFailure)?;
+
+        // period × duration scaling factor / clock scaling factor
+        Self::from_ticks(
+            u128::from(integer)
+                * u128::from(*Dur::SCALING_FACTOR.numerator())
+                * u128::from(*Clock::SCALING_FACTOR.denominator()),
+            u128::from(*Dur::SCALING_FACTOR.denominator())
+                * u128::from(*Clock::SCALING_FACTOR.numerator()),
+            start,
+        )
+    }
+
+    /// Construct a `Periodic` with the period of the given rate, the first deadline being one
+    /// period after `start`
+    ///
+    /// # Errors
+    ///
+    /// See [`Periodic::new()`]
+    pub fn from_rate<R>(rate: R, start: Instant<Clock>) -> Result<Self, ConversionError>
+    where
+        R: Rate + FixedPoint,
+        u64: TryFrom<R::T>,
+        Clock::T: TryFrom<u64>,
+    {
+        let integer =
+            u64::try_from(rate.integer()).map_err(|_| ConversionError::ConversionFailure)?;
+
+        // 1 / (rate × rate scaling factor) / clock scaling factor
+        Self::from_ticks(
+            u128::from(*R::SCALING_FACTOR.denominator())
+                * u128::from(*Clock::SCALING_FACTOR.denominator()),
+            u128::from(integer)
+                .checked_mul(u128::from(*R::SCALING_FACTOR.numerator()))
+                .and_then(|ticks| ticks.checked_mul(u128::from(*Clock::SCALING_FACTOR.numerator())))
+                .ok_or(ConversionError::Overflow)?,
+            start,
+        )
+    }
+
+    /// Construct from a period of `numerator` / `denominator` clock ticks
+    fn from_ticks(
+        numerator: u128,
+        denominator: u128,
+        start: Instant<Clock>,
+    ) -> Result<Self, ConversionError>
+    where
+        Clock::T: TryFrom<u64>,
+    {
+        if numerator == 0 || denominator == 0 {
+            return Err(ConversionError::DivByZero);
+        }
+
+        let whole_ticks = u64::try_from(numerator / denominator)
+            .ok()
+            .and_then(|ticks| Clock::T::try_from(ticks).ok())
+            .ok_or(ConversionError::ConversionFailure)?;
+        if whole_ticks > Clock::T::max_value() / Clock::T::from(2) {
+            return Err(ConversionError::Overflow);
+        }
+
+        let remainder = numerator % denominator;
+        let divisor = gcd(remainder, denominator);
+
+        let mut periodic = Self {
+            next: start,
+            whole_ticks,
+            remainder: u64::try_from(remainder / divisor).map_err(|_| ConversionError::Overflow)?,
+            denominator: u64::try_from(denominator / divisor)
+                .map_err(|_| ConversionError::Overflow)?,
+            error: 0,
+        };
+        periodic.advance();
+
+        Ok(periodic)
+    }
+
+    /// Returns the next deadline
+    pub fn next_deadline(&self) -> Instant<Clock> {
+        self.next
+    }
+
+    /// Returns the next deadline jittered by the `random` value
+    ///
+    /// The jitter is relative to the (whole ticks of the) period and doesn't affect the schedule,
+    /// so the deadlines don't drift no matter the jitter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{duration::*, jitter::Jitter, periodic::Periodic, Instant};
+    /// # #[derive(Debug)]
+    /// # struct Clock;
+    /// # impl embedded_time::Clock for Clock {
+    /// #     type T = u32;
+    /// #     type Error = embedded_time::clock::Error;
+    /// #     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    /// #     fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// # }
+    /// let mut periodic = Periodic::<Clock>::new(Seconds(1_u32), Instant::new(0)).unwrap();
+    /// let jitter = Jitter::new(Fraction::new(1, 4));
+    ///
+    /// assert_eq!(periodic.jittered_deadline(&jitter, 0), Instant::new(750));
+    /// assert_eq!(periodic.jittered_deadline(&jitter, u32::MAX), Instant::new(1_249));
+    ///
+    /// assert_eq!(periodic.advance(), Instant::new(2_000));
+    /// ```
+    pub fn jittered_deadline(&self, jitter: &Jitter, random: u32) -> Instant<Clock>
+    where
+        u128: From<Clock::T>,
+        Clock::T: TryFrom<u128>,
+    {
+        // the jittered period is no more than twice the period, which is no more than half the
+        // range of the clock
+        Instant::new(
+            self.next
+                .duration_since_epoch()
+                .integer()
+                .wrapping_add(&jitter.apply_ticks(self.whole_ticks, random))
+                .wrapping_sub(&self.whole_ticks),
+        )
+    }
+
+    /// Advance to the following deadline and return it
+    pub fn advance(&mut self) -> Instant<Clock> {
+        let mut ticks = self.whole_ticks;
+
+        // error + remainder >= denominator, without overflowing
+        if self.error >= self.denominator - self.remainder {
+            self.error -= self.denominator - self.remainder;
+            ticks = ticks + Clock::T::from(1);
+        } else {
+            self.error += self.remainder;
+        }
+
+        self.next = Instant::new(
+            self.next
+                .duration_since_epoch()
+                .integer()
+                .wrapping_add(&ticks),
+        );
+        self.next
+    }
+
+    /// Returns whether the next deadline has been reached as of `now`, advancing to the following
+    /// deadline if it has
+    pub fn period_complete(&mut self, now: Instant<Clock>) -> bool {
+        if now >= self.next {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! PTP (IEEE 1588) timestamps and correction fields
+//!
+//! Ethernet MACs with hardware timestamping (and PTP messages) represent time as 48-bit seconds and
+//! 32-bit nanoseconds ([`PtpTimestamp`]), and residence times as nanoseconds scaled by 2<sup>16</sup>
+//! ([`Correction`]).
+//!
+//! # Examples
+//!
+//! The delay request-response mechanism: the master sends a _sync_ at `t1`, received by the slave
+//! at `t2`, the slave sends a _delay request_ at `t3`, received by the master at `t4`.
+//!
+//! ```rust
+//! # use embedded_time::{duration::*, ptp::{Correction, PtpTimestamp}};
+//! let t1 = PtpTimestamp::new(1_000, 0).unwrap();
+//! let t2 = PtpTimestamp::new(1_000, 500_900).unwrap();
+//! let t3 = PtpTimestamp::new(1_000, 800_000).unwrap();
+//! let t4 = PtpTimestamp::new(1_000, 1_300_100).unwrap();
+//! // the time the sync spent in a transparent clock
+//! let correction = Correction::from_nanos(1_000).unwrap();
+//!
+//! let t2 = t2.checked_sub_correction(correction).unwrap();
+//! let ns = |duration: SignedGeneric<i128>| duration.integer();
+//!
+//! let mean_path_delay = (ns(t2 - t1) + ns(t4 - t3)) / 2;
+//! let offset_from_master = ns(t2 - t1) - mean_path_delay;
+//! assert_eq!((mean_path_delay, offset_from_master), (500_000, -100));
+//! ```
+
+use crate::{
+    duration::{self, Nanoseconds, SignedGeneric},
+    fixed_point::{self, RoundingMode},
+    fraction::{Fraction, Fraction64},
+    ConversionError, TimeInt,
+};
+use core::{convert::TryFrom, ops};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+const NANOSECOND: Fraction = Fraction::new(1, 1_000_000_000);
+/// The largest 48-bit seconds
+const MAX_SECS: u64 = (1 << 48) - 1;
+/// The _scaling factor_ of the bits of a [`Correction`]
+const CORRECTION_SCALING_FACTOR: Fraction64 = Fraction64::new(1, 1_000_000_000 << 16);
+
+/// A PTP timestamp: 48-bit seconds and 32-bit nanoseconds (of the PTP timescale, usually TAI since
+/// 1970-01-01)
+///
+/// It converts exactly to a [`Nanoseconds<u128>`] or [`Generic<u128>`](duration::Generic)
+/// duration (since the timescale's epoch) and from those (and any other duration, truncated to
+/// nanoseconds) if the seconds fit in 48 bits. The difference of two timestamps is a
+/// [`SignedGeneric<i128>`] number of nanoseconds.
+///
+/// ```rust
+/// # use embedded_time::{duration::*, ptp::PtpTimestamp, ConversionError};
+/// # use core::convert::TryFrom;
+/// let timestamp = PtpTimestamp::new(5, 250).unwrap();
+/// assert_eq!(Nanoseconds::<u128>::from(timestamp), Nanoseconds(5_000_000_250_u128));
+/// assert_eq!(PtpTimestamp::try_from(Nanoseconds(5_000_000_250_u128)), Ok(timestamp));
+/// assert_eq!(PtpTimestamp::try_from(Seconds(1_u64 << 48)), Err(ConversionError::Overflow));
+///
+/// let earlier = PtpTimestamp::new(6, 0).unwrap();
+/// assert_eq!(
+///     timestamp - earlier,
+///     SignedGeneric::new(-999_999_750_i128, Fraction::new(1, 1_000_000_000))
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PtpTimestamp {
+    secs: u64,
+    nanos: u32,
+}
+
+impl PtpTimestamp {
+    /// Construct a timestamp from its seconds and nanoseconds
+    ///
+    /// Returns [`None`] if `secs` doesn't fit in 48 bits or `nanos` isn't less than a second.
+    pub const fn new(secs: u64, nanos: u32) -> Option<Self> {
+        if secs <= MAX_SECS && nanos < NANOS_PER_SEC {
+            Some(Self { secs, nanos })
+        } else {
+            None
+        }
+    }
+
+    /// Construct a timestamp from its 10-byte (big-endian) wire representation
+    ///
+    /// Returns [`None`] if the nanoseconds aren't less than a second.
+    pub const fn from_bytes(bytes: [u8; 10]) -> Option<Self> {
+        let [s0, s1, s2, s3, s4, s5, n0, n1, n2, n3] = bytes;
+
+        Self::new(
+            u64::from_be_bytes([0, 0, s0, s1, s2, s3, s4, s5]),
+            u32::from_be_bytes([n0, n1, n2, n3]),
+        )
+    }
+
+    /// Returns the 10-byte (big-endian) wire representation
+    pub const fn to_bytes(&self) -> [u8; 10] {
+        let [_, _, s0, s1, s2, s3, s4, s5] = self.secs.to_be_bytes();
+        let [n0, n1, n2, n3] = self.nanos.to_be_bytes();
+
+        [s0, s1, s2, s3, s4, s5, n0, n1, n2, n3]
+    }
+
+    /// Returns the seconds part
+    pub const fn secs(&self) -> u64 {
+        self.secs
+    }
+
+    /// Returns the nanoseconds part
+    pub const fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Returns the (possibly negative) time from `other` to this timestamp, in nanoseconds
+    ///
+    /// The same as `self - other`.
+    pub fn signed_duration_since(&self, other: &Self) -> SignedGeneric<i128> {
+        // both are less than 2^78
+        SignedGeneric::new(
+            self.as_nanos() as i128 - other.as_nanos() as i128,
+            NANOSECOND,
+        )
+    }
+
+    /// This timestamp + a [`Correction`] (truncated toward `0` to nanoseconds)
+    ///
+    /// Returns [`None`] if the result is negative or its seconds don't fit in 48 bits.
+    pub fn checked_add_correction(self, correction: Correction) -> Option<Self> {
+        self.offset_nanos(correction.nanos())
+    }
+
+    /// This timestamp - a [`Correction`] (truncated toward `0` to nanoseconds)
+    ///
+    /// Returns [`None`] if the result is negative or its seconds don't fit in 48 bits.
+    pub fn checked_sub_correction(self, correction: Correction) -> Option<Self> {
+        self.offset_nanos(correction.nanos().checked_neg()?)
+    }
+
+    /// This timestamp + (possibly negative) `nanos`
+    fn offset_nanos(self, nanos: i64) -> Option<Self> {
+        let nanos = (self.as_nanos() as i128).checked_add(i128::from(nanos))?;
+
+        Self::from_nanos(u128::try_from(nanos).ok()?)
+    }
+
+    /// The nanoseconds since the epoch
+    fn as_nanos(&self) -> u128 {
+        u128::from(self.secs) * u128::from(NANOS_PER_SEC) + u128::from(self.nanos)
+    }
+
+    fn from_nanos(nanos: u128) -> Option<Self> {
+        Self::new(
+            u64::try_from(nanos / u128::from(NANOS_PER_SEC)).ok()?,
+            (nanos % u128::from(NANOS_PER_SEC)) as u32,
+        )
+    }
+}
+
+impl ops::Sub for PtpTimestamp {
+    type Output = SignedGeneric<i128>;
+
+    /// The (possibly negative) time between two timestamps, in nanoseconds
+    ///
+    /// See [`PtpTimestamp::signed_duration_since()`]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.signed_duration_since(&rhs)
+    }
+}
+
+impl From<PtpTimestamp> for Nanoseconds<u128> {
+    fn from(timestamp: PtpTimestamp) -> Self {
+        Self::new(timestamp.as_nanos())
+    }
+}
+
+impl From<PtpTimestamp> for duration::Generic<u128> {
+    fn from(timestamp: PtpTimestamp) -> Self {
+        Self::new(timestamp.as_nanos(), NANOSECOND)
+    }
+}
+
+/// Truncated to nanoseconds
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The seconds don't fit in 48 bits
+/// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+impl<T: TimeInt> TryFrom<duration::Generic<T>> for PtpTimestamp {
+    type Error = ConversionError;
+
+    fn try_from(duration: duration::Generic<T>) -> Result<Self, Self::Error> {
+        let nanos = fixed_point::rescale(
+            duration
+                .integer()
+                .to_u128()
+                .ok_or(ConversionError::Overflow)?,
+            *duration.scaling_factor(),
+            NANOSECOND,
+            RoundingMode::Floor,
+        )?;
+
+        Self::from_nanos(nanos).ok_or(ConversionError::Overflow)
+    }
+}
+
+macro_rules! impl_ptp_timestamp {
+    ( $($name:ident),* ) => {$(
+        /// Truncated to nanoseconds
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The seconds don't fit in 48 bits
+        impl<T: TimeInt> TryFrom<duration::$name<T>> for PtpTimestamp {
+            type Error = ConversionError;
+
+            fn try_from(duration: duration::$name<T>) -> Result<Self, Self::Error> {
+                Self::try_from(duration::Generic::from(duration))
+            }
+        }
+    )*};
+}
+
+impl_ptp_timestamp![
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds
+];
+
+/// A PTP correction field: a signed number of nanoseconds scaled by 2<sup>16</sup>
+///
+/// ```rust
+/// # use embedded_time::{duration::*, ptp::Correction};
+/// # use core::convert::TryFrom;
+/// let correction = Correction::from_bits(0x0002_8000); // 2.5ns
+/// assert_eq!(correction.nanos(), 2);
+///
+/// assert_eq!(
+///     Correction::try_from(SignedGeneric::new(-5_i32, Fraction::new(1, 2_000_000_000))),
+///     Ok(Correction::from_bits(-0x0002_8000))
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Correction(i64);
+
+impl Correction {
+    /// Construct a correction from its 64-bit wire representation
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Construct a correction of whole nanoseconds
+    ///
+    /// Returns [`None`] if the scaled nanoseconds overflow.
+    pub const fn from_nanos(nanos: i64) -> Option<Self> {
+        match nanos.checked_mul(1 << 16) {
+            Some(bits) => Some(Self(bits)),
+            None => None,
+        }
+    }
+
+    /// Returns the 64-bit wire representation
+    pub const fn to_bits(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns the whole nanoseconds (truncated toward `0`)
+    pub const fn nanos(&self) -> i64 {
+        self.0 / (1 << 16)
+    }
+
+    /// Returns the correction as a signed duration of whole nanoseconds (truncated toward `0`)
+    pub fn to_duration(&self) -> SignedGeneric<i64> {
+        SignedGeneric::new(self.nanos(), NANOSECOND)
+    }
+}
+
+/// Truncated toward `0` to 2<sup>-16</sup> nanoseconds
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The scaled nanoseconds don't fit an [`i64`]
+/// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+impl<T: Copy> TryFrom<SignedGeneric<T>> for Correction
+where
+    i128: From<T>,
+{
+    type Error = ConversionError;
+
+    fn try_from(duration: SignedGeneric<T>) -> Result<Self, Self::Error> {
+        let integer = i128::from(duration.integer());
+        let bits = fixed_point::rescale(
+            integer.unsigned_abs(),
+            *duration.scaling_factor(),
+            CORRECTION_SCALING_FACTOR,
+            RoundingMode::Floor,
+        )?;
+        let bits = i128::try_from(bits).map_err(|_| ConversionError::Overflow)?;
+
+        i64::try_from(if integer < 0 { -bits } else { bits })
+            .map(Self)
+            .map_err(|_| ConversionError::Overflow)
+    }
+}
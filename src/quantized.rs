@@ -0,0 +1,152 @@
+//! Durations that carry the resolution they were rounded to
+
+use crate::{
+    duration::{Duration, Seconds},
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::Fraction,
+    ConversionError,
+};
+use core::convert::TryFrom;
+
+/// A duration rounded to a whole number of quanta of a known resolution
+///
+/// The resolution (eg. the period of the timer that measured the duration) is kept with the
+/// duration so precision can't be silently lost afterwards:
+///
+/// - [`Quantized::try_convert()`] only converts to a duration type whose ticks divide the
+///   resolution, which is always exact
+/// - anything coarser must explicitly be rounded with [`Quantized::requantize()`]
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, fixed_point::RoundingMode, quantized::Quantized, ConversionError};
+/// // measured with a 10 ms tick
+/// let measured =
+///     Quantized::new(Microseconds(1_234_567_u32), Milliseconds(10_u32), RoundingMode::Nearest)
+///         .unwrap();
+/// assert_eq!(measured.duration(), Microseconds(1_230_000_u32));
+/// assert_eq!(measured.resolution(), Fraction::new(1, 100));
+///
+/// // exact
+/// assert_eq!(measured.try_convert::<Milliseconds<u32>>().unwrap().duration(), Milliseconds(1_230_u32));
+/// // would lose precision
+/// assert_eq!(measured.try_convert::<Seconds<u32>>(), Err(ConversionError::ConversionFailure));
+///
+/// let coarse = measured.requantize(Seconds(1_u32), RoundingMode::Ceil).unwrap();
+/// assert_eq!(coarse.try_convert::<Seconds<u32>>().unwrap().duration(), Seconds(2_u32));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Quantized<Dur> {
+    duration: Dur,
+    resolution: Fraction,
+}
+
+impl<Dur> Quantized<Dur>
+where
+    Dur: Duration + FixedPoint,
+    u128: From<Dur::T>,
+    Dur::T: TryFrom<u128>,
+{
+    /// Round the `duration` to a whole number of `resolution`s
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : The resolution is zero
+    /// - [`ConversionError::ConversionFailure`] : The resolution isn't a whole number of ticks of
+    ///   the duration type, or the rounded duration doesn't fit in it
+    /// - [`ConversionError::Overflow`] : The resolution can't be represented as a [`Fraction`] of
+    ///   seconds
+    pub fn new<Res>(
+        duration: Dur,
+        resolution: Res,
+        rounding: RoundingMode,
+    ) -> Result<Self, ConversionError>
+    where
+        Res: Duration + FixedPoint,
+        u128: From<Res::T>,
+    {
+        if resolution.integer() == Res::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+
+        // the resolution in ticks of the duration type
+        let quantum = fixed_point::ratio(&resolution, &Dur::new(Dur::T::from(1)))?;
+        if *quantum.denominator() != 1 {
+            return Err(ConversionError::ConversionFailure);
+        }
+        let quanta = fixed_point::rescale(
+            u128::from(duration.integer()),
+            Fraction::new(1, 1),
+            quantum,
+            rounding,
+        )?;
+
+        let ticks = quanta
+            .checked_mul(u128::from(*quantum.numerator()))
+            .and_then(|ticks| Dur::T::try_from(ticks).ok())
+            .ok_or(ConversionError::ConversionFailure)?;
+
+        Ok(Self {
+            duration: Dur::new(ticks),
+            resolution: fixed_point::ratio(&resolution, &Seconds(1_u32))?,
+        })
+    }
+
+    /// Returns the rounded duration
+    pub fn duration(&self) -> Dur {
+        self.duration
+    }
+
+    /// Returns the resolution in seconds
+    pub fn resolution(&self) -> Fraction {
+        self.resolution
+    }
+
+    /// Convert to another duration type without losing precision
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`] : The resolution isn't a whole number of ticks of
+    /// the destination type, or the duration doesn't fit in it
+    pub fn try_convert<Dest>(&self) -> Result<Quantized<Dest>, ConversionError>
+    where
+        Dest: Duration + FixedPoint<Dimension = Dur::Dimension>,
+        Dest::T: TryFrom<u128>,
+    {
+        let quantum = self
+            .resolution
+            .checked_div(&Dest::SCALING_FACTOR)
+            .ok_or(ConversionError::Overflow)?;
+        if *quantum.denominator() != 1 {
+            return Err(ConversionError::ConversionFailure);
+        }
+
+        Ok(Quantized {
+            duration: Dest::try_convert_from_with(self.duration, RoundingMode::Floor)?,
+            resolution: self.resolution,
+        })
+    }
+
+    /// Round to a whole number of another resolution, explicitly accepting any loss of precision
+    ///
+    /// # Errors
+    ///
+    /// See [`Quantized::new()`]
+    pub fn requantize<Res>(
+        &self,
+        resolution: Res,
+        rounding: RoundingMode,
+    ) -> Result<Self, ConversionError>
+    where
+        Res: Duration + FixedPoint,
+        u128: From<Res::T>,
+    {
+        Self::new(self.duration, resolution, rounding)
+    }
+
+    /// Returns the rounded duration, discarding the resolution
+    pub fn into_inner(self) -> Dur {
+        self.duration
+    }
+}
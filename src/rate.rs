@@ -5,9 +5,15 @@ use crate::{
     duration,
     fixed_point::{self, FixedPoint},
     time_int::TimeInt,
-    ConversionError,
+    ConversionError, Instant, TimeError,
+};
+use core::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    mem::size_of,
+    ops,
 };
-use core::{convert::TryFrom, mem::size_of, prelude::v1::*};
 #[doc(hidden)]
 pub use fixed_point::FixedPoint as _;
 use num::{CheckedDiv, CheckedMul};
@@ -28,6 +34,28 @@ pub use units::*;
 /// let _ = 5_u32.kHz();
 /// ```
 ///
+/// The `ZERO`, `MIN` and `MAX` constants are available for each _integer_ type:
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// const DISABLED: Hertz<u32> = Hertz::<u32>::ZERO;
+///
+/// assert_eq!(DISABLED, Hertz(0_u32));
+/// assert_eq!(Kilobaud::<u8>::MAX, Kilobaud(u8::MAX));
+/// ```
+///
+/// Values of a wider (or any) primitive integer type (read from a register or a protocol, for
+/// example) are range-checked rather than cast:
+///
+/// ```rust
+/// use embedded_time::{rate::*, ConversionError};
+/// use core::convert::TryFrom;
+///
+/// assert_eq!(Hertz::<u16>::try_from_u64(50), Ok(Hertz(50_u16)));
+/// assert_eq!(Kilohertz::<u8>::try_from(256_u32), Err(ConversionError::ConversionFailure));
+/// ```
+///
 /// # Get the integer part
 ///
 /// ```rust
@@ -46,6 +74,22 @@ pub use units::*;
 /// assert_eq!(format!("{}", Hertz(123_u32)), "123");
 /// ```
 ///
+/// # Parsing
+///
+/// Rates are parsed from a number followed by a unit (the units of the [`rate!`](crate::rate!)
+/// literals, eg. `MHz`, `kbps` or `Bd`). The number may have decimals and whitespace is allowed
+/// before the unit. The result must be a whole number of ticks.
+///
+/// ```rust
+/// use embedded_time::{rate::*, ParseError};
+///
+/// assert_eq!("8MHz".parse(), Ok(Kilohertz(8_000_u32)));
+/// assert_eq!("115.2 kbps".parse(), Ok(BitsPerSecond(115_200_u32)));
+///
+/// assert_eq!("1.5Hz".parse::<Hertz<u32>>(), Err(ParseError::Inexact));
+/// assert_eq!("MHz".parse::<Hertz<u32>>(), Err(ParseError::InvalidNumber));
+/// ```
+///
 /// # Converting between `Rate`s
 ///
 /// Many intra-rate conversions can be done using `From`/`Into`:
@@ -73,6 +117,23 @@ pub use units::*;
 /// assert_eq!(hertz.integer(), 23_000_u32);
 /// ```
 ///
+/// `From`/`Into` between bit and byte rates assume 8 bits per byte. As symbol, bit and byte rates
+/// otherwise depend on the line encoding, they are converted with explicit bits per symbol/word:
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// // 8N1 UART frames are 10 bits long
+/// let baud = Baud(115_200_u32);
+/// assert_eq!(
+///     baud.to_bits_per_second(1).and_then(|bits| bits.to_bytes_per_second(10)),
+///     Ok(BytesPerSecond(11_520_u32))
+/// );
+///
+/// // 4-FSK carries 2 bits per symbol
+/// assert_eq!(BitsPerSecond(9_600_u32).to_baud(2), Ok(Baud(4_800_u32)));
+/// ```
+///
 /// # Converting from a [`Generic`] `Rate`
 ///
 /// ## Examples
@@ -145,7 +206,7 @@ pub use units::*;
 ///
 /// # Creating a custom `Rate`
 ///
-///
+/// See [`rate_unit!`](crate::rate_unit!)
 ///
 /// # Add/Sub
 ///
@@ -163,6 +224,18 @@ pub use units::*;
 ///     Hertz(1_001_u32));
 /// ```
 ///
+/// A [`Generic`] `Rate` may have any `Rate` added or subtracted, the result has the _scaling
+/// factor_ of the LHS:
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// let rate = Generic::new(3_u32, Fraction::new(1, 2));
+///
+/// assert_eq!(rate + Hertz(1_u32), Generic::new(5_u32, Fraction::new(1, 2)));
+/// assert_eq!(rate - Millihertz(500_u32), Hertz(1_u32).into());
+/// ```
+///
 /// ## Panics
 ///
 /// The same reason the integer operation would panic. Namely, if the result overflows the type.
@@ -173,11 +246,26 @@ pub use units::*;
 /// let _ = Hertz(u32::MAX) + Hertz(1_u32);
 /// ```
 ///
+/// The checked equivalents, [`FixedPoint::checked_add()`] and [`FixedPoint::checked_sub()`],
+/// return a [`ConversionError`] instead.
+///
 /// # Mul/Div
 ///
 /// Rates may also be multiplied and divided by integers. The result is of the LHS type. Both
 /// _panicky_ and _checked_ operations are available.
 ///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// assert_eq!(Hertz(48_000_000_u32) / 8, Hertz(6_000_000_u32));
+/// assert_eq!(1_u32.kHz() * 3, Kilohertz(3_u32));
+/// assert_eq!(Generic::new(10_u32, Fraction::new(1_000, 1)) / 2, Kilohertz(5_u32).into());
+///
+/// assert_eq!(Hertz(u32::MAX).checked_mul(&2), Err(ConversionError::Overflow));
+/// assert_eq!(Hertz(10_u32).checked_div(&0), Err(ConversionError::DivByZero));
+/// # use embedded_time::{fixed_point::FixedPoint, ConversionError};
+/// ```
+///
 /// # Comparisons
 ///
 /// ```rust
@@ -190,12 +278,41 @@ pub use units::*;
 /// assert!(Kilohertz(2_u32) > Hertz(1_999_u32));
 /// ```
 ///
+/// [`Generic`] `Rate`s are compared by value, whatever their _scaling factors_:
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// assert_eq!(Generic::new(3_u32, Fraction::new(1, 2)), Generic::new(1_500_u32, Fraction::new(1, 1_000)));
+/// assert!(Generic::new(1_u32, Fraction::new(1, 3)) > Generic::new(333_u32, Fraction::new(1, 1_000)));
+/// ```
+///
+/// # Min/Max/Clamp
+///
+/// The _named_ `Rate` types have `min()`, `max()` and `clamp()` methods accepting any `Rate`
+/// type that they can be compared to. They shadow the [`Ord`] methods (which only accept the same
+/// type) and the result is of the LHS type (truncated when converted from a finer unit). As with
+/// Add/Sub, they panic if the result doesn't fit in the LHS type.
+///
+/// ```rust
+/// use embedded_time::rate::*;
+///
+/// let baud = Kilobaud(1_000_u32);
+/// assert_eq!(baud.clamp(Baud(9_600_u32), Kilobaud(115_u32)), Kilobaud(115_u32));
+///
+/// assert_eq!(Kilohertz(2_u32).min(Hertz(1_999_u32)), Kilohertz(1_u32));
+/// assert_eq!(Hertz(2_u32).max(Millihertz(2_001_u32)), Hertz(2_u32));
+///
+/// assert!(Hertz(0_u32).is_zero());
+/// ```
+///
 /// # Remainder
 ///
 /// ```rust
 /// use embedded_time::rate::*;
 ///
 /// assert_eq!(Hertz(2_037_u32) % Kilohertz(1_u32), Hertz(37_u32));
+/// assert_eq!(Generic::new(2_037_u32, Fraction::new(1, 1)) % Kilohertz(1_u32), Hertz(37_u32).into());
 /// ```
 pub trait Rate: Sized + Copy {
     /// Construct a `Generic` `Rate` from a _named_ `Rate` (eg. [`Kilohertz`])
@@ -243,11 +360,12 @@ pub trait Rate: Sized + Copy {
     ///     Err(ConversionError::ConversionFailure)
     /// );
     /// ```
-    fn to_generic<DestInt: TimeInt>(
+    fn to_generic<DestInt>(
         self,
         scaling_factor: Fraction,
     ) -> Result<Generic<DestInt>, ConversionError>
     where
+        DestInt: TimeInt,
         Self: FixedPoint,
         DestInt: TryFrom<Self::T>,
     {
@@ -270,6 +388,13 @@ pub trait Rate: Sized + Copy {
     ///     Kilohertz(500_u32).to_duration(),
     ///     Ok(Microseconds(2_u32))
     /// );
+    ///
+    /// // sub-hertz rates convert to long durations (a sensor sampled every 5 minutes)
+    /// assert_eq!(Microhertz(3_333_u32).to_duration(), Ok(Seconds(300_u32)));
+    /// assert_eq!(
+    ///     Millihertz(1_u32).to_duration(),
+    ///     Ok(Microseconds(1_000_000_000_u64))
+    /// );
     /// ```
     ///
     /// # Errors
@@ -301,24 +426,26 @@ pub trait Rate: Sized + Copy {
     ///     Err(ConversionError::DivByZero)
     /// );
     /// ```
-    fn to_duration<Duration: duration::Duration>(&self) -> Result<Duration, ConversionError>
+    fn to_duration<Duration>(&self) -> Result<Duration, ConversionError>
     where
-        Duration: FixedPoint,
+        Duration: duration::Duration + FixedPoint,
         Self: FixedPoint,
         Duration::T: TryFrom<Self::T>,
     {
-        let conversion_factor = Self::SCALING_FACTOR
-            .checked_mul(&Duration::SCALING_FACTOR)
-            .ok_or(ConversionError::Unspecified)?
-            .recip();
+        let (numerator, denominator) =
+            fixed_point::reciprocal_product(Self::SCALING_FACTOR, Duration::SCALING_FACTOR);
 
         if size_of::<Self::T>() >= size_of::<Duration::T>() {
             fixed_point::FixedPoint::from_ticks(
-                Self::T::from(*conversion_factor.numerator())
+                fixed_point::int_from_u64::<Self::T>(numerator)
+                    .ok_or(ConversionError::Overflow)?
                     .checked_div(
                         &self
                             .integer()
-                            .checked_mul(&Self::T::from(*conversion_factor.denominator()))
+                            .checked_mul(
+                                &fixed_point::int_from_u64::<Self::T>(denominator)
+                                    .ok_or(ConversionError::Overflow)?,
+                            )
                             .ok_or(ConversionError::Overflow)?,
                     )
                     .ok_or(ConversionError::DivByZero)?,
@@ -326,11 +453,15 @@ pub trait Rate: Sized + Copy {
             )
         } else {
             fixed_point::FixedPoint::from_ticks(
-                Duration::T::from(*conversion_factor.numerator())
+                fixed_point::int_from_u64::<Duration::T>(numerator)
+                    .ok_or(ConversionError::Overflow)?
                     .checked_div(
                         &Duration::T::try_from(self.integer())
                             .map_err(|_| ConversionError::Overflow)?
-                            .checked_mul(&Duration::T::from(*conversion_factor.denominator()))
+                            .checked_mul(
+                                &fixed_point::int_from_u64::<Duration::T>(denominator)
+                                    .ok_or(ConversionError::Overflow)?,
+                            )
                             .ok_or(ConversionError::Overflow)?,
                     )
                     .ok_or(ConversionError::DivByZero)?,
@@ -338,40 +469,682 @@ pub trait Rate: Sized + Copy {
             )
         }
     }
+
+    /// Returns the exact (reduced) ratio of this rate to another, of any _scaling factor_
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::{rate::*, ConversionError};
+    ///
+    /// assert_eq!(Megahertz(16_u32).div_rate(Kilohertz(32_u32)), Ok(Fraction::new(500, 1)));
+    /// assert_eq!(Hertz(3_u32).div_rate(Kilohertz(1_u32)), Ok(Fraction::new(3, 1_000)));
+    ///
+    /// assert_eq!(Hertz(1_u32).div_rate(Hertz(0_u32)), Err(ConversionError::DivByZero));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::DivByZero`] : `other` is zero
+    /// - [`ConversionError::Overflow`] : The ratio doesn't fit in a [`Fraction`]
+    fn div_rate<Other>(&self, other: Other) -> Result<Fraction, ConversionError>
+    where
+        Other: Rate + FixedPoint,
+        Self: FixedPoint,
+        u128: From<Self::T> + From<Other::T>,
+    {
+        fixed_point::ratio(self, &other)
+    }
+
+    /// Returns the number of events of this rate that occur in `duration` (truncated)
+    ///
+    /// This is eg. the length of a DMA transfer or the reload value of a timer. The intermediate
+    /// math is widened to [`u128`]. The same product as [`Duration::cycles_at()`], called on the
+    /// rate.
+    ///
+    /// [`Duration::cycles_at()`]: duration::Duration::cycles_at()
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_time::{duration::*, rate::*, ConversionError};
+    ///
+    /// // a 72 MHz timer reloading every 250 µs
+    /// assert_eq!(Megahertz(72_u32).count_in(Microseconds(250_u32)), Ok(18_000));
+    /// assert_eq!(Hertz(3_u32).count_in(Milliseconds(999_u32)), Ok(2));
+    ///
+    /// assert_eq!(
+    ///     Megahertz(u32::MAX).count_in(Hours(u64::MAX)),
+    ///     Err(ConversionError::Overflow)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The count doesn't fit in a [`u64`]
+    fn count_in<Duration>(&self, duration: Duration) -> Result<u64, ConversionError>
+    where
+        Duration: duration::Duration + FixedPoint<Dimension = fixed_point::dimension::Time>,
+        Self: FixedPoint<Dimension = fixed_point::dimension::Frequency>,
+        u128: From<Self::T> + From<Duration::T>,
+    {
+        self.checked_product(&duration)
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns whether the rate is zero
+    ///
+    /// See [Min/Max/Clamp](trait.Rate.html#minmaxclamp)
+    fn is_zero(&self) -> bool
+    where
+        Self: FixedPoint,
+    {
+        self.integer() == Self::T::from(0)
+    }
+
+    /// Returns the rate as a floating-point number of hertz
+    ///
+    /// The result is rounded to the nearest representable [`f64`], so it's exact for up to
+    /// 2<sup>53</sup> ticks of a whole-number _scaling factor_.
+    ///
+    /// ```rust
+    /// # use embedded_time::rate::*;
+    /// assert_eq!(Kilohertz(32_u32).to_hz_f64(), 32_000.0);
+    /// assert_eq!(Hertz::<u32>::try_from_hz_f32(50.5), Ok(Hertz(50_u32)));
+    /// ```
+    #[cfg(feature = "float")]
+    fn to_hz_f64(&self) -> f64
+    where
+        Self: FixedPoint,
+        u128: From<Self::T>,
+    {
+        fixed_point::to_f64(u128::from(self.integer()), Self::SCALING_FACTOR)
+    }
+
+    /// Returns the rate as a floating-point number of hertz
+    ///
+    /// The result is rounded to the nearest representable [`f32`] (by way of an [`f64`]).
+    #[cfg(feature = "float")]
+    fn to_hz_f32(&self) -> f32
+    where
+        Self: FixedPoint,
+        u128: From<Self::T>,
+    {
+        self.to_hz_f64() as f32
+    }
+
+    /// Construct from a floating-point number of hertz
+    ///
+    /// The value is truncated to whole ticks (rounded toward `0`).
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::ConversionFailure`] : The value is negative, NaN or doesn't fit in the
+    /// _integer_ type
+    #[cfg(feature = "float")]
+    fn try_from_hz_f64(hz: f64) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        let ticks = fixed_point::from_f64(hz, Self::SCALING_FACTOR)?;
+
+        <Self::T as TryFrom<u128>>::try_from(ticks)
+            .map(Self::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+
+    /// Construct from a floating-point number of hertz
+    ///
+    /// The [`f32`] is converted exactly to an [`f64`], see [`Rate::try_from_hz_f64()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Rate::try_from_hz_f64()`]
+    #[cfg(feature = "float")]
+    fn try_from_hz_f32(hz: f32) -> Result<Self, ConversionError>
+    where
+        Self: FixedPoint,
+        Self::T: TryFrom<u128>,
+    {
+        Self::try_from_hz_f64(f64::from(hz))
+    }
+
+    /// Construct the average rate of `count` events occurring between two [`Instant`]s
+    ///
+    /// The result is rounded to the nearest integer (halves round up).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use embedded_time::{rate::*, Instant};
+    /// # #[derive(Debug)]
+    /// struct Clock;
+    /// impl embedded_time::Clock for Clock {
+    ///     type T = u32;
+    /// # type Error = embedded_time::clock::Error;
+    ///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+    ///     // ...
+    /// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+    /// }
+    ///
+    /// // 3_000 packets received in 1.5 seconds
+    /// let start = Instant::<Clock>::new(500);
+    /// let end = Instant::<Clock>::new(2_000);
+    ///
+    /// assert_eq!(Hertz::<u32>::from_events(3_000, &start, &end), Ok(Hertz(2_000_u32)));
+    /// assert_eq!(Kilohertz::<u32>::from_events(3_000, &start, &end), Ok(Kilohertz(2_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::NegDuration`] : `end` is earlier than `start`
+    /// - [`TimeError::DivByZero`] : `start` and `end` are the same instant
+    /// - [`TimeError::ConversionFailure`] : The rate doesn't fit in the _integer_ type
+    fn from_events<Clock>(
+        count: u32,
+        start: &Instant<Clock>,
+        end: &Instant<Clock>,
+    ) -> Result<Self, TimeError>
+    where
+        Clock: crate::Clock,
+        Self: FixedPoint,
+        Self::T: TryFrom<u64>,
+        u64: TryFrom<Clock::T>,
+    {
+        let ticks = end
+            .checked_duration_since(start)
+            .ok_or(TimeError::NegDuration)?
+            .integer();
+        let ticks = u64::try_from(ticks).map_err(|_| TimeError::ConversionFailure)?;
+        if ticks == 0 {
+            return Err(TimeError::DivByZero);
+        }
+
+        // count / (ticks × clock scaling factor) / rate scaling factor, neither product can
+        // overflow a u128
+        let numerator = u128::from(count)
+            * u128::from(*Clock::SCALING_FACTOR.denominator())
+            * u128::from(*Self::SCALING_FACTOR.denominator());
+        let denominator = u128::from(ticks)
+            * u128::from(*Clock::SCALING_FACTOR.numerator())
+            * u128::from(*Self::SCALING_FACTOR.numerator());
+
+        let rate = (numerator + denominator / 2) / denominator;
+
+        <u64 as TryFrom<u128>>::try_from(rate)
+            .ok()
+            .and_then(|rate| Self::T::try_from(rate).ok())
+            .map(Self::new)
+            .ok_or(TimeError::ConversionFailure)
+    }
+}
+
+/// The `Generic` `Rate` type allows an arbitrary _scaling factor_ to be used without having to
+/// impl `FixedPoint`.
+///
+/// The purpose of this type is to allow a simple `Rate` object that can be defined at run-time.
+/// It does this by replacing the `const` _scaling factor_ with a struct field.
+#[derive(Copy, Clone, Default)]
+pub struct Generic<T> {
+    integer: T,
+    scaling_factor: Fraction,
+}
+
+/// Serialized as an `(integer, numerator, denominator)` tuple
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Generic<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            &self.integer,
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator(),
+        )
+            .serialize(serializer)
+    }
+}
+
+/// Deserialized from an `(integer, numerator, denominator)` tuple, a denominator of `0` is
+/// rejected
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Generic<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (integer, numerator, denominator) = <(T, u32, u32)>::deserialize(deserializer)?;
+        if denominator == 0 {
+            return Err(serde::de::Error::custom("Invalid scaling factor"));
+        }
+
+        Ok(Self {
+            integer,
+            scaling_factor: Fraction::new(numerator, denominator),
+        })
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl<T: TimeInt> PartialOrd<Generic<T>> for Generic<T> {
+    /// See [Comparisons](trait.Rate.html#comparisons)
+    fn partial_cmp(&self, rhs: &Generic<T>) -> Option<core::cmp::Ordering> {
+        fixed_point::compare(
+            self.integer,
+            &self.scaling_factor,
+            rhs.integer,
+            &rhs.scaling_factor,
+        )
+    }
+}
+
+impl<T: TimeInt> Ord for Generic<T> {
+    fn cmp(&self, rhs: &Generic<T>) -> core::cmp::Ordering {
+        if let Some(v) = self.partial_cmp(rhs) {
+            v
+        } else {
+            panic!("Cmp failed")
+        }
+    }
+}
+
+impl<T: TimeInt> PartialEq<Generic<T>> for Generic<T> {
+    /// See [Comparisons](trait.Rate.html#comparisons)
+    fn eq(&self, rhs: &Generic<T>) -> bool {
+        self.partial_cmp(rhs) == Some(core::cmp::Ordering::Equal)
+    }
+}
+
+impl<T: TimeInt> Eq for Generic<T> {}
+
+impl<T: TimeInt + Hash> Hash for Generic<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if let Some(v) = self.integer.checked_mul_fraction(&self.scaling_factor) {
+            v.hash(state);
+        }
+    }
+}
+
+impl<T: TimeInt> Generic<T> {
+    /// Constructs a new fixed-point `Generic` `Rate` value
+    pub const fn new(integer: T, scaling_factor: Fraction) -> Self {
+        Self {
+            integer,
+            scaling_factor,
+        }
+    }
+
+    /// Returns the _integer_ part
+    pub fn integer(&self) -> T {
+        self.integer
+    }
+
+    /// Returns the _scaling factor_ [`Fraction`] part
+    pub fn scaling_factor(&self) -> &Fraction {
+        &self.scaling_factor
+    }
+
+    /// Returns whether the rate is zero
+    pub fn is_zero(&self) -> bool {
+        self.integer == T::from(0)
+    }
+
+    /// Returns the rate as a floating-point number of hertz
+    ///
+    /// See [`Rate::to_hz_f64()`]
+    #[cfg(feature = "float")]
+    pub fn to_hz_f64(&self) -> f64
+    where
+        u128: From<T>,
+    {
+        fixed_point::to_f64(u128::from(self.integer), self.scaling_factor)
+    }
+
+    /// Returns the rate as a floating-point number of hertz
+    ///
+    /// See [`Rate::to_hz_f32()`]
+    #[cfg(feature = "float")]
+    pub fn to_hz_f32(&self) -> f32
+    where
+        u128: From<T>,
+    {
+        self.to_hz_f64() as f32
+    }
+
+    /// Convert to a _named_ rate type, rounded as given
+    ///
+    /// ```rust
+    /// # use embedded_time::{fixed_point::RoundingMode, rate::*};
+    /// let rate = Generic::new(1_499_u32, Fraction::new(1, 1));
+    ///
+    /// assert_eq!(rate.to_rounded(RoundingMode::Floor), Ok(Kilohertz(1_u32)));
+    /// assert_eq!(rate.to_rounded(RoundingMode::Ceil), Ok(Kilohertz(2_u32)));
+    /// assert_eq!(rate.to_rounded(RoundingMode::Nearest), Ok(Kilohertz(1_u32)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`FixedPoint::try_convert_from_with()`]
+    pub fn to_rounded<Dest>(
+        &self,
+        rounding: fixed_point::RoundingMode,
+    ) -> Result<Dest, ConversionError>
+    where
+        Dest: FixedPoint<Dimension = fixed_point::dimension::Frequency>,
+        u128: From<T>,
+        Dest::T: TryFrom<u128>,
+    {
+        let ticks = fixed_point::rescale(
+            u128::from(self.integer),
+            self.scaling_factor,
+            Dest::SCALING_FACTOR,
+            rounding,
+        )?;
+
+        Dest::T::try_from(ticks)
+            .map(Dest::new)
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+
+    /// Checked addition
+    ///
+    /// The `rhs` is first converted to the _scaling factor_ of `self`, the result has the _scaling
+    /// factor_ of `self`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Unspecified`] : The _scaling factor_ conversion failed
+    /// - [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ConversionError> {
+        let rhs = rhs.integer_with(&self.scaling_factor)?;
+
+        Ok(Self::new(
+            self.integer
+                .checked_add(&rhs)
+                .ok_or(ConversionError::Overflow)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Checked subtraction
+    ///
+    /// The `rhs` is first converted to the _scaling factor_ of `self`, the result has the _scaling
+    /// factor_ of `self`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::Unspecified`] : The _scaling factor_ conversion failed
+    /// - [`ConversionError::Overflow`] : The result is negative
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, ConversionError> {
+        let rhs = rhs.integer_with(&self.scaling_factor)?;
+
+        Ok(Self::new(
+            self.integer
+                .checked_sub(&rhs)
+                .ok_or(ConversionError::Overflow)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Checked multiplication
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+    pub fn checked_mul(&self, rhs: &T) -> Result<Self, ConversionError> {
+        Ok(Self::new(
+            self.integer
+                .checked_mul(rhs)
+                .ok_or(ConversionError::Overflow)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Checked division
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::DivByZero`] : `rhs` is `0`
+    pub fn checked_div(&self, rhs: &T) -> Result<Self, ConversionError> {
+        Ok(Self::new(
+            self.integer
+                .checked_div(rhs)
+                .ok_or(ConversionError::DivByZero)?,
+            self.scaling_factor,
+        ))
+    }
+
+    /// Returns the _integer_ converted to the given _scaling factor_ (truncated)
+    fn integer_with(&self, scaling_factor: &Fraction) -> Result<T, ConversionError> {
+        self.integer
+            .checked_mul_fraction(
+                &self
+                    .scaling_factor
+                    .checked_div(scaling_factor)
+                    .ok_or(ConversionError::Unspecified)?,
+            )
+            .ok_or(ConversionError::Overflow)
+    }
+}
+
+impl<T: TimeInt> Rate for Generic<T> {}
+
+impl<T: TimeInt + TryFrom<u128>> core::str::FromStr for Generic<T> {
+    type Err = crate::ParseError;
+
+    /// The value is represented exactly, with a _scaling factor_ of `1 / n`
+    ///
+    /// See [Parsing](trait.Rate.html#parsing)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (integer, scaling_factor) = crate::parse::rate(s)?.generic()?;
+
+        Ok(Self::new(integer, scaling_factor))
+    }
+}
+
+impl<T: TimeInt> ops::Mul<T> for Generic<T> {
+    type Output = Self;
+
+    /// See [Mul/Div](trait.Rate.html#muldiv)
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.integer * rhs, self.scaling_factor)
+    }
+}
+
+impl<T: TimeInt> ops::Div<T> for Generic<T> {
+    type Output = Self;
+
+    /// See [Mul/Div](trait.Rate.html#muldiv)
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.integer / rhs, self.scaling_factor)
+    }
+}
+
+impl<T: TimeInt, Rhs: Rate + Into<Self>> ops::Add<Rhs> for Generic<T> {
+    type Output = Self;
+
+    /// See [`Generic::checked_add()`]
+    ///
+    /// # Panics
+    ///
+    /// If the sum overflows
+    fn add(self, rhs: Rhs) -> Self::Output {
+        match self.checked_add(&rhs.into()) {
+            Ok(sum) => sum,
+            Err(_) => panic!("Add failed"),
+        }
+    }
+}
+
+impl<T: TimeInt, Rhs: Rate + Into<Self>> ops::Sub<Rhs> for Generic<T> {
+    type Output = Self;
+
+    /// See [`Generic::checked_sub()`]
+    ///
+    /// # Panics
+    ///
+    /// If the difference overflows
+    fn sub(self, rhs: Rhs) -> Self::Output {
+        match self.checked_sub(&rhs.into()) {
+            Ok(difference) => difference,
+            Err(_) => panic!("Sub failed"),
+        }
+    }
+}
+
+impl<T: TimeInt, Rhs: Rate + Into<Self>> ops::Rem<Rhs> for Generic<T> {
+    type Output = Self;
+
+    /// The `rhs` is first converted to the _scaling factor_ of `self`, the result has the _scaling
+    /// factor_ of `self`
+    ///
+    /// See [Remainder](trait.Rate.html#remainder)
+    fn rem(self, rhs: Rhs) -> Self::Output {
+        match rhs.into().integer_with(&self.scaling_factor) {
+            Ok(rhs) if rhs > T::from(0) => Self::new(self.integer % rhs, self.scaling_factor),
+            Ok(_) => Self::new(T::from(0), self.scaling_factor),
+            Err(_) => self,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Generic<T> {
+    /// Includes the _scaling factor_ and unit
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, rate::Generic};
+    /// assert_eq!(format!("{:?}", Generic::new(246_u32, Fraction::new(1, 2))), "246 × 1/2 Hz");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.integer, f)?;
+        write!(
+            f,
+            " × {}/{} Hz",
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
 }
 
-/// The `Generic` `Rate` type allows an arbitrary _scaling factor_ to be used without having to
-/// impl `FixedPoint`.
-///
-/// The purpose of this type is to allow a simple `Rate` object that can be defined at run-time.
-/// It does this by replacing the `const` _scaling factor_ with a struct field.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
-pub struct Generic<T> {
-    integer: T,
-    scaling_factor: Fraction,
+impl<T: fmt::Display> fmt::Display for Generic<T> {
+    /// Includes the _scaling factor_ and unit, the formatting options apply to the _integer_
+    ///
+    /// ```rust
+    /// # use embedded_time::{fraction::Fraction, rate::Generic};
+    /// assert_eq!(format!("{:>4}", Generic::new(25_u32, Fraction::new(1, 10))), "  25 × 1/10 Hz");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.integer, f)?;
+        write!(
+            f,
+            " × {}/{} Hz",
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
+    }
 }
 
-impl<T: TimeInt> Generic<T> {
-    /// Constructs a new fixed-point `Generic` `Rate` value
-    pub fn new(integer: T, scaling_factor: Fraction) -> Self {
-        Self {
-            integer,
-            scaling_factor,
-        }
+/// Includes the _scaling factor_ and unit, as [`Debug`](fmt::Debug) does
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for Generic<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{} × {}/{} Hz",
+            self.integer,
+            self.scaling_factor.numerator(),
+            self.scaling_factor.denominator()
+        )
     }
+}
 
-    /// Returns the _integer_ part
-    pub fn integer(&self) -> T {
-        self.integer
+/// Returns the prescaler and reload values of a hardware timer with the period closest to `target`
+///
+/// The timer counts `timer_clock` cycles, divided by the prescaler, up to the reload value: its
+/// period is `prescaler × reload` cycles. Both are counts (at least `1`) and the reload is at most
+/// `max_arr`, the register values are usually one less (eg. a 16-bit auto-reload register holds
+/// reloads of up to `65_536` cycles, a `max_arr` of `65_536`).
+///
+/// The smallest prescaler gives the finest resolution, but a larger one may divide the period
+/// exactly. The prescalers up to twice the smallest one (at most 65,536 of them) are searched for
+/// the one with the smallest error, the smallest prescaler winning ties.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{duration::*, rate::{self, *}, ConversionError};
+///
+/// // a 72 MHz timer with a 16-bit auto-reload register, 72_000_000 = 1_125 × 64_000
+/// assert_eq!(
+///     rate::prescaler_for(Hertz(72_000_000), Seconds(1_u32), 65_536),
+///     Ok((1_125, 64_000))
+/// );
+/// assert_eq!(
+///     rate::prescaler_for(Hertz(72_000_000), Microseconds(250_u32), 65_536),
+///     Ok((1, 18_000))
+/// );
+///
+/// assert_eq!(
+///     rate::prescaler_for(Hertz(72_000_000), Nanoseconds(1_u32), 65_536),
+///     Err(ConversionError::ConversionFailure)
+/// );
+/// ```
+///
+/// # Errors
+///
+/// - [`ConversionError::ConversionFailure`] : `target` is shorter than half a cycle
+/// - [`ConversionError::Overflow`] : `target` needs a prescaler larger than [`u32::MAX`]
+/// - [`ConversionError::DivByZero`] : `max_arr` is zero
+pub fn prescaler_for<Dur>(
+    timer_clock: units::Hertz<u32>,
+    target: Dur,
+    max_arr: u32,
+) -> Result<(u32, u32), ConversionError>
+where
+    Dur: duration::Duration + FixedPoint,
+    u128: From<Dur::T>,
+{
+    if max_arr == 0 {
+        return Err(ConversionError::DivByZero);
     }
 
-    /// Returns the _scaling factor_ [`Fraction`] part
-    pub fn scaling_factor(&self) -> &Fraction {
-        &self.scaling_factor
+    // the period is `cycles / scale` timer cycles
+    let cycles = u128::from(target.integer())
+        .checked_mul(<u128 as From<u32>>::from(*Dur::SCALING_FACTOR.numerator()))
+        .and_then(|cycles| cycles.checked_mul(<u128 as From<u32>>::from(timer_clock.0)))
+        .ok_or(ConversionError::Overflow)?;
+    let scale = <u128 as From<u32>>::from(*Dur::SCALING_FACTOR.denominator());
+    if scale == 0 {
+        return Err(ConversionError::DivByZero);
     }
-}
 
-impl<T: TimeInt> Rate for Generic<T> {}
+    let max_reload = <u128 as From<u32>>::from(max_arr);
+    let min_prescaler = cycles.div_ceil(scale * max_reload).max(1);
+    let max_prescaler = (min_prescaler * 2)
+        .min(min_prescaler + 65_535)
+        .min(<u128 as From<u32>>::from(u32::MAX));
+    if min_prescaler > max_prescaler {
+        return Err(ConversionError::Overflow);
+    }
+
+    let mut best: Option<(u128, u128, u128)> = None;
+    for prescaler in min_prescaler..=max_prescaler {
+        // the reload closest to the target, rounded to the nearest cycle
+        let divisor = prescaler * scale;
+        let reload = ((cycles + divisor / 2) / divisor).min(max_reload);
+        if reload == 0 {
+            continue;
+        }
+
+        let error = (prescaler * reload * scale).abs_diff(cycles);
+        if best.is_none_or(|(_, _, best_error)| error < best_error) {
+            best = Some((prescaler, reload, error));
+            if error == 0 {
+                break;
+            }
+        }
+    }
+
+    // both are at most `u32::MAX`
+    best.map(|(prescaler, reload, _)| (prescaler as u32, reload as u32))
+        .ok_or(ConversionError::ConversionFailure)
+}
 
 /// Rate-type units
 #[doc(hidden)]
@@ -392,24 +1165,150 @@ pub mod units {
     #[doc(hidden)]
     pub use Extensions as _;
 
+    macro_rules! impl_consts {
+        ($name:ident, $($int:ty),+) => {
+            $(
+                impl $name<$int> {
+                    /// The zero rate
+                    pub const ZERO: Self = Self(0);
+                    /// The smallest rate of the _integer_ type (zero)
+                    pub const MIN: Self = Self(<$int>::MIN);
+                    /// The largest rate of the _integer_ type
+                    pub const MAX: Self = Self(<$int>::MAX);
+                }
+            )+
+        };
+    }
+
+    macro_rules! impl_try_from_int {
+        ($name:ident, $($method:ident: $int:ty),+) => {
+            impl<T: TimeInt> $name<T> {
+                $(
+                    #[doc = concat!("Construct from a [`", stringify!($int), "`], checking that it fits in the _integer_ type")]
+                    ///
+                    /// # Errors
+                    ///
+                    /// [`ConversionError::ConversionFailure`] : The value doesn't fit in the _integer_
+                    /// type
+                    pub fn $method(integer: $int) -> Result<Self, ConversionError>
+                    where
+                        T: TryFrom<$int>,
+                    {
+                        <T as TryFrom<$int>>::try_from(integer)
+                            .map(Self)
+                            .map_err(|_| ConversionError::ConversionFailure)
+                    }
+                )+
+            }
+        };
+    }
+
+    macro_rules! impl_try_from_wider_int {
+        ($name:ident, $narrow:ty, $($wide:ty),+) => {
+            $(
+                impl TryFrom<$wide> for $name<$narrow> {
+                    type Error = ConversionError;
+
+                    /// Checks that the value fits in the _integer_ type
+                    fn try_from(integer: $wide) -> Result<Self, Self::Error> {
+                        <$narrow>::try_from(integer)
+                            .map(Self)
+                            .map_err(|_| ConversionError::ConversionFailure)
+                    }
+                }
+            )+
+        };
+    }
+
     macro_rules! impl_rate {
         ( $name:ident, ($numer:expr, $denom:expr), $desc:literal ) => {
             #[doc = $desc]
             #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #[cfg_attr(feature = "defmt", derive(defmt::Format))]
             pub struct $name<T: TimeInt = u32>(pub T);
 
+            impl_consts![$name, u8, u16, u32, u64, u128];
+            impl_try_from_int![
+                $name,
+                try_from_u8: u8,
+                try_from_u16: u16,
+                try_from_u32: u32,
+                try_from_u64: u64,
+                try_from_u128: u128
+            ];
+            impl_try_from_wider_int![$name, u8, u16, u32, u64, u128];
+            impl_try_from_wider_int![$name, u16, u32, u64, u128];
+            impl_try_from_wider_int![$name, u32, u64, u128];
+            impl_try_from_wider_int![$name, u64, u128];
+
             impl<T: TimeInt> $name<T> {
                 /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
-                pub fn new(value: T) -> Self {
+                pub const fn new(value: T) -> Self {
                     Self(value)
                 }
+
+                /// Returns the lesser of `self` and `other`, as the type of `self`
+                ///
+                /// See [Min/Max/Clamp](trait.Rate.html#minmaxclamp)
+                pub fn min<Rhs: Rate>(self, other: Rhs) -> Self
+                where
+                    Self: PartialOrd<Rhs> + TryFrom<Rhs>,
+                {
+                    if self > other {
+                        Self::convert(other)
+                    } else {
+                        self
+                    }
+                }
+
+                /// Returns the greater of `self` and `other`, as the type of `self`
+                ///
+                /// See [Min/Max/Clamp](trait.Rate.html#minmaxclamp)
+                pub fn max<Rhs: Rate>(self, other: Rhs) -> Self
+                where
+                    Self: PartialOrd<Rhs> + TryFrom<Rhs>,
+                {
+                    if self < other {
+                        Self::convert(other)
+                    } else {
+                        self
+                    }
+                }
+
+                /// Returns `self` restricted to `min..=max`, as the type of `self`
+                ///
+                /// See [Min/Max/Clamp](trait.Rate.html#minmaxclamp)
+                pub fn clamp<Min: Rate, Max: Rate>(self, min: Min, max: Max) -> Self
+                where
+                    Self: PartialOrd<Min> + PartialOrd<Max> + TryFrom<Min> + TryFrom<Max>,
+                {
+                    if self < min {
+                        Self::convert(min)
+                    } else if self > max {
+                        Self::convert(max)
+                    } else {
+                        self
+                    }
+                }
+
+                fn convert<Rhs>(rhs: Rhs) -> Self
+                where
+                    Self: TryFrom<Rhs>,
+                {
+                    if let Ok(v) = Self::try_from(rhs) {
+                        v
+                    } else {
+                        panic!("Conversion failed")
+                    }
+                }
             }
 
             impl<T: TimeInt> Rate for $name<T> {}
 
             impl<T: TimeInt> FixedPoint for $name<T> {
                 type T = T;
+                type Dimension = fixed_point::dimension::Frequency;
                 const SCALING_FACTOR: Fraction = Fraction::new($numer, $denom);
 
                 /// See [Constructing a rate](trait.Rate.html#constructing-a-rate)
@@ -430,9 +1329,18 @@ pub mod units {
                 }
             }
 
+            impl<T: TimeInt + TryFrom<u128>> core::str::FromStr for $name<T> {
+                type Err = crate::ParseError;
+
+                /// See [Parsing](trait.Rate.html#parsing)
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    crate::parse::rate(s)?.ticks(Self::SCALING_FACTOR).map(Self)
+                }
+            }
+
             impl<T: TimeInt, Rhs: Rate> ops::Add<Rhs> for $name<T>
             where
-                Rhs: FixedPoint,
+                Rhs: FixedPoint<Dimension = fixed_point::dimension::Frequency>,
                 Self: TryFrom<Rhs>,
             {
                 type Output = Self;
@@ -446,7 +1354,7 @@ pub mod units {
             impl<T: TimeInt, Rhs: Rate> ops::Sub<Rhs> for $name<T>
             where
                 Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
+                Rhs: FixedPoint<Dimension = fixed_point::dimension::Frequency>,
             {
                 type Output = Self;
 
@@ -477,7 +1385,7 @@ pub mod units {
             impl<T: TimeInt, Rhs: Rate> ops::Rem<Rhs> for $name<T>
             where
                 Self: TryFrom<Rhs>,
-                Rhs: FixedPoint,
+                Rhs: FixedPoint<Dimension = fixed_point::dimension::Frequency>,
             {
                 type Output = Self;
 
@@ -522,6 +1430,7 @@ pub mod units {
     impl_rate![Centihertz, (1, 100), "Hertz / 100"];
     impl_rate![Millihertz, (1, 1_000), "Hertz / 1000"];
     impl_rate![Microhertz, (1, 1_000_000), "Hertz / 1,000,000"];
+    impl_rate![Rpm, (1, 60), "Revolutions per minute (Hertz / 60)"];
     impl_rate![
         MebibytesPerSecond,
         (1_048_576 * 8, 1),
@@ -545,9 +1454,40 @@ pub mod units {
     impl_rate![Kibibaud, (1_024, 1), "Baud × 1,024"];
     impl_rate![Kilobaud, (1_000, 1), "Baud × 1,000"];
     impl_rate![Baud, (1, 1), "Baud"];
+    impl_rate![KilosamplesPerSecond, (1_000, 1), "Samples/s × 1,000"];
+    impl_rate![SamplesPerSecond, (1, 1), "Samples/s"];
+    impl_rate![KiloframesPerSecond, (1_000, 1), "Frames/s × 1,000"];
+    impl_rate![FramesPerSecond, (1, 1), "Frames/s"];
+
+    macro_rules! impl_widen_conversion {
+        ($name:ident, $($narrow:ty => $($wide:ty),+);+) => {
+            $($(
+                impl From<$name<$narrow>> for $name<$wide> {
+                    /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+                    fn from(source: $name<$narrow>) -> Self {
+                        Self::new(<$wide>::from(source.integer()))
+                    }
+                }
+
+                impl TryFrom<$name<$wide>> for $name<$narrow> {
+                    type Error = ConversionError;
+
+                    /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+                    fn try_from(source: $name<$wide>) -> Result<Self, Self::Error> {
+                        fixed_point::FixedPoint::from_ticks(
+                            source.integer(),
+                            $name::<$wide>::SCALING_FACTOR,
+                        )
+                    }
+                }
+            )+)+
+        };
+    }
 
     macro_rules! impl_conversion {
         ($name:ident) => {
+            impl_widen_conversion![$name, u8 => u16, u32, u64, u128; u16 => u32, u64, u128; u32 => u128; u64 => u128];
+
             impl From<$name<u32>> for $name<u64> {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
                 fn from(source: $name<u32>) -> Self {
@@ -594,7 +1534,7 @@ pub mod units {
             }
         };
 
-        (once, $big:ident, $small:ident) => {
+        (from, $big:ident, $small:ident) => {
             impl<T: TimeInt> From<$small<T>> for $big<T>
             {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
@@ -606,7 +1546,24 @@ pub mod units {
                     }
                 }
             }
+        };
+
+        (try_from, $big:ident, $small:ident) => {
+            impl<T: TimeInt> TryFrom<$small<T>> for $big<T>
+            {
+                type Error = ConversionError;
+
+                /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+                fn try_from(small: $small<T>) -> Result<Self, Self::Error> {
+                    fixed_point::FixedPoint::from_ticks(
+                        small.integer(),
+                        $small::<T>::SCALING_FACTOR,
+                    )
+                }
+            }
+        };
 
+        (once, $big:ident, $small:ident) => {
             impl From<$small<u32>> for $big<u64>
             {
                 /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
@@ -725,6 +1682,15 @@ pub mod units {
             impl_conversion![$big];
             $(
                 impl_conversion![once, $big, $small];
+                impl_conversion![from, $big, $small];
+            )+
+        };
+        // the first arg is the finer unit, so the conversions from the others can overflow
+        (finer $big:ident; $($small:ident),+) => {
+            impl_conversion![$big];
+            $(
+                impl_conversion![once, $big, $small];
+                impl_conversion![try_from, $big, $small];
             )+
         };
         // ($big:ident, $($small:ident),+) => {
@@ -742,10 +1708,13 @@ pub mod units {
     impl_conversion![Megahertz; Kilohertz, Hertz];
     impl_conversion![Kilohertz; Hertz];
     impl_conversion![Hertz];
-    impl_conversion![Decihertz; Hertz];
-    impl_conversion![Centihertz; Hertz];
-    impl_conversion![Millihertz; Hertz];
-    impl_conversion![Microhertz; Hertz];
+    impl_conversion![finer Decihertz; Hertz];
+    impl_conversion![finer Centihertz; Hertz];
+    impl_conversion![finer Millihertz; Hertz];
+    impl_conversion![finer Microhertz; Hertz];
+    impl_conversion![Rpm];
+    impl_conversion![once, Hertz, Rpm];
+    impl_conversion![from, Hertz, Rpm];
 
     // The first arg implements From/TryFrom all following
     impl_conversion![MebibytesPerSecond; MebibitsPerSecond, KibibytesPerSecond, KibibitsPerSecond, BytesPerSecond, BitsPerSecond];
@@ -767,6 +1736,93 @@ pub mod units {
     impl_conversion![Kilobaud; Baud];
     impl_conversion![Baud];
 
+    impl_conversion![KilosamplesPerSecond; SamplesPerSecond];
+    impl_conversion![SamplesPerSecond];
+
+    impl_conversion![KiloframesPerSecond; FramesPerSecond];
+    impl_conversion![FramesPerSecond];
+
+    /// Returns `value × factor`
+    fn mul_u32<T: TimeInt>(value: T, factor: u32) -> Result<T, ConversionError> {
+        T::try_from(factor)
+            .ok()
+            .and_then(|factor| value.checked_mul(&factor))
+            .ok_or(ConversionError::Overflow)
+    }
+
+    /// Returns `value / divisor` (truncated)
+    fn div_u32<T: TimeInt>(value: T, divisor: u32) -> Result<T, ConversionError> {
+        match T::try_from(divisor) {
+            Ok(divisor) => value
+                .checked_div(&divisor)
+                .ok_or(ConversionError::DivByZero),
+            // the divisor is larger than any value
+            Err(_) => Ok(T::from(0)),
+        }
+    }
+
+    impl<T: TimeInt> Baud<T> {
+        /// Returns the bit rate of a line carrying `bits_per_symbol` bits per symbol (eg. `1` for
+        /// UART/SPI/CAN, `2` for 4-FSK/QPSK)
+        ///
+        /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+        pub fn to_bits_per_second(
+            self,
+            bits_per_symbol: u32,
+        ) -> Result<BitsPerSecond<T>, ConversionError> {
+            mul_u32(self.0, bits_per_symbol).map(BitsPerSecond)
+        }
+    }
+
+    impl<T: TimeInt> BitsPerSecond<T> {
+        /// Returns the symbol rate of a line carrying `bits_per_symbol` bits per symbol (truncated)
+        ///
+        /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::DivByZero`] : `bits_per_symbol` is `0`
+        pub fn to_baud(self, bits_per_symbol: u32) -> Result<Baud<T>, ConversionError> {
+            div_u32(self.0, bits_per_symbol).map(Baud)
+        }
+
+        /// Returns the rate of the words (bytes) framed in `bits_per_word` bits (truncated), eg.
+        /// `10` for 8N1 UART frames (start bit, 8 data bits, stop bit)
+        ///
+        /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::DivByZero`] : `bits_per_word` is `0`
+        pub fn to_bytes_per_second(
+            self,
+            bits_per_word: u32,
+        ) -> Result<BytesPerSecond<T>, ConversionError> {
+            div_u32(self.0, bits_per_word).map(BytesPerSecond)
+        }
+    }
+
+    impl<T: TimeInt> BytesPerSecond<T> {
+        /// Returns the bit rate needed to carry the words (bytes) framed in `bits_per_word` bits,
+        /// eg. `10` for 8N1 UART frames (start bit, 8 data bits, stop bit)
+        ///
+        /// See [Converting between `Rate`s](trait.Rate.html#converting-between-rates)
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The result doesn't fit in the _integer_ type
+        pub fn to_bits_per_second(
+            self,
+            bits_per_word: u32,
+        ) -> Result<BitsPerSecond<T>, ConversionError> {
+            mul_u32(self.0, bits_per_word).map(BitsPerSecond)
+        }
+    }
+
     /// Create rate-based extensions from primitive numeric types.
     ///
     /// ```rust
@@ -776,6 +1832,7 @@ pub mod units {
     /// assert_eq!(5.KiHz(), Kibihertz(5_u32));
     /// assert_eq!(5.kHz(), Kilohertz(5_u32));
     /// assert_eq!(5.Hz(), Hertz(5_u32));
+    /// assert_eq!(5.rpm(), Rpm(5_u32));
     /// assert_eq!(5.MiBps(), MebibytesPerSecond(5_u32));
     /// assert_eq!(5.MBps(), MegabytesPerSecond(5_u32));
     /// assert_eq!(5.KiBps(), KibibytesPerSecond(5_u32));
@@ -791,6 +1848,10 @@ pub mod units {
     /// assert_eq!(5.KiBd(), Kibibaud(5_u32));
     /// assert_eq!(5.kBd(), Kilobaud(5_u32));
     /// assert_eq!(5.Bd(), Baud(5_u32));
+    /// assert_eq!(5.ksps(), KilosamplesPerSecond(5_u32));
+    /// assert_eq!(5.sps(), SamplesPerSecond(5_u32));
+    /// assert_eq!(5.kfps(), KiloframesPerSecond(5_u32));
+    /// assert_eq!(5.fps(), FramesPerSecond(5_u32));
     /// ```
     #[allow(non_snake_case)]
     pub trait Extensions: TimeInt {
@@ -819,6 +1880,11 @@ pub mod units {
             Hertz::new(self)
         }
 
+        /// revolutions per minute
+        fn rpm(self) -> Rpm<Self> {
+            Rpm::new(self)
+        }
+
         /// mebibytes per second
         fn MiBps(self) -> MebibytesPerSecond<Self> {
             MebibytesPerSecond::new(self)
@@ -893,10 +1959,372 @@ pub mod units {
         fn Bd(self) -> Baud<Self> {
             Baud::new(self)
         }
+
+        /// kilosamples per second
+        fn ksps(self) -> KilosamplesPerSecond<Self> {
+            KilosamplesPerSecond::new(self)
+        }
+
+        /// samples per second
+        fn sps(self) -> SamplesPerSecond<Self> {
+            SamplesPerSecond::new(self)
+        }
+
+        /// kiloframes per second
+        fn kfps(self) -> KiloframesPerSecond<Self> {
+            KiloframesPerSecond::new(self)
+        }
+
+        /// frames per second
+        fn fps(self) -> FramesPerSecond<Self> {
+            FramesPerSecond::new(self)
+        }
     }
 
     impl Extensions for u32 {}
 }
 
+/// A rate constant from a literal, parsed at compile time
+///
+/// `rate!("literal")` evaluates to a [`Generic`] `Rate` with a `u32` _integer_ and the exact
+/// _scaling factor_ of the literal. `rate!("literal", Unit<Int>)` evaluates to the _named_ `Rate`
+/// type. The units are those of the [`Extensions`] (eg. `kHz`, `Mbps`, `KiBps` or `kBd`) and `dHz`,
+/// `cHz`, `mHz` and `µHz` (or `uHz`), optionally separated from the number by a space.
+///
+/// Literals that don't fit in the chosen type, or that aren't a whole number of its unit, are
+/// rejected at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_time::{rate, rate::*};
+///
+/// const BAUD: Generic<u32> = rate!("115.2kBd");
+/// assert_eq!(BAUD, Generic::new(1_152_u32, Fraction::new(100, 1)));
+///
+/// const TICK: Hertz<u32> = rate!("32.768 kHz", Hertz<u32>);
+/// assert_eq!(TICK, Hertz(32_768_u32));
+/// assert_eq!(rate!("115.2kBd", Baud<u32>), Baud(115_200_u32));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use embedded_time::{rate, rate::*};
+/// // doesn't fit in a u16
+/// const BAUD: Baud<u16> = rate!("115.2kBd", Baud<u16>);
+/// ```
+#[macro_export]
+macro_rules! rate {
+    ($literal:literal) => {{
+        const RATE: $crate::rate::Generic<u32> = {
+            let literal = $crate::literal::rate($literal);
+            $crate::rate::Generic::new(
+                $crate::literal::to_u32(literal.integer),
+                $crate::fraction::Fraction::new(
+                    $crate::literal::to_u32(literal.numerator),
+                    $crate::literal::to_u32(literal.denominator),
+                ),
+            )
+        };
+        RATE
+    }};
+    ($literal:literal, $unit:ident < $int:ty >) => {{
+        const INTEGER: $int = {
+            let integer = $crate::literal::integer(
+                $crate::literal::rate($literal),
+                <$crate::rate::$unit<$int> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+            );
+            if integer > <$int>::MAX as u128 {
+                panic!("Literal doesn't fit in the integer type");
+            }
+            integer as $int
+        };
+        $crate::rate::$unit::<$int>(INTEGER)
+    }};
+}
+
+/// Define a custom _named_ `Rate` type
+///
+/// `rate_unit!(Name, (numerator, denominator))` defines `Name<T: TimeInt = u32>(pub T)` with the
+/// given _scaling factor_ (in hertz). As with the built-in units (eg. [`Kilohertz`]), it implements
+/// [`Rate`] and [`FixedPoint`](crate::fixed_point::FixedPoint), so it can be converted to a
+/// duration with [`Rate::to_duration()`]. It can be:
+///
+/// - converted from and to all the built-in units (with the same _integer_ type) and [`Generic`]
+/// - compared with itself and all the built-in units (with the same _integer_ type)
+/// - added to, subtracted from and taken the remainder of by any `Rate` it can be converted from,
+///   the result is of the LHS type
+/// - multiplied and divided by integers
+///
+/// Attributes (eg. doc comments) and a visibility may precede the name.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use embedded_time::{duration::*, rate::*, rate_unit};
+///
+/// rate_unit!(
+///     /// Video lines per second
+///     pub LinesPerSecond, (1, 1)
+/// );
+/// rate_unit!(
+///     /// Symbols per second of a 4-FSK modem (2 bits per symbol)
+///     pub Symbols, (2, 1)
+/// );
+///
+/// assert_eq!(LinesPerSecond::try_from(Kilohertz(15_u32)), Ok(LinesPerSecond(15_000_u32)));
+/// assert_eq!(BitsPerSecond::try_from(Symbols(4_800_u32)), Ok(BitsPerSecond(9_600_u32)));
+/// assert_eq!(LinesPerSecond(15_734_u32).to_duration(), Ok(Microseconds(63_u32)));
+///
+/// assert_eq!(Symbols(4_800_u32), Baud(9_600_u32));
+/// assert!(LinesPerSecond(15_734_u32) > Kilohertz(15_u32));
+///
+/// assert_eq!(LinesPerSecond(10_u32) + Kilohertz(1_u32), LinesPerSecond(1_010_u32));
+/// assert_eq!(Symbols(3_u32) * 2, Symbols(6_u32));
+/// ```
+#[macro_export]
+macro_rules! rate_unit {
+    ($(#[$attr:meta])* $vis:vis $name:ident, ($numer:expr, $denom:expr)) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, Ord, Hash, Debug, Default)]
+        $vis struct $name<T: $crate::TimeInt = u32>(pub T);
+
+        impl<T: $crate::TimeInt> $name<T> {
+            /// Construct a rate of the given _integer_
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T: $crate::TimeInt> $crate::rate::Rate for $name<T> {}
+
+        impl<T: $crate::TimeInt> $crate::fixed_point::FixedPoint for $name<T> {
+            type T = T;
+            type Dimension = $crate::fixed_point::dimension::Frequency;
+            const SCALING_FACTOR: $crate::fraction::Fraction =
+                $crate::fraction::Fraction::new($numer, $denom);
+
+            fn new(value: Self::T) -> Self {
+                Self(value)
+            }
+
+            fn integer(&self) -> Self::T {
+                self.0
+            }
+        }
+
+        impl<T: $crate::TimeInt> core::fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> core::cmp::PartialEq<$name<RhsInt>>
+            for $name<T>
+        where
+            T: core::convert::TryFrom<RhsInt>,
+        {
+            fn eq(&self, rhs: &$name<RhsInt>) -> bool {
+                match <T as core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => self.0 == rhs_integer,
+                    Err(_) => false,
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt, RhsInt: $crate::TimeInt> core::cmp::PartialOrd<$name<RhsInt>>
+            for $name<T>
+        where
+            T: core::convert::TryFrom<RhsInt>,
+        {
+            fn partial_cmp(&self, rhs: &$name<RhsInt>) -> Option<core::cmp::Ordering> {
+                match <T as core::convert::TryFrom<RhsInt>>::try_from(rhs.0) {
+                    Ok(rhs_integer) => Some(self.0.cmp(&rhs_integer)),
+                    Err(_) => Some(core::cmp::Ordering::Less),
+                }
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::rate::Rate> core::ops::Add<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint<
+                Dimension = $crate::fixed_point::dimension::Frequency,
+            >,
+            Self: core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            fn add(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::add(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::rate::Rate> core::ops::Sub<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint<
+                Dimension = $crate::fixed_point::dimension::Frequency,
+            >,
+            Self: core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            fn sub(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::sub(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt, Rhs: $crate::rate::Rate> core::ops::Rem<Rhs> for $name<T>
+        where
+            Rhs: $crate::fixed_point::FixedPoint<
+                Dimension = $crate::fixed_point::dimension::Frequency,
+            >,
+            Self: core::convert::TryFrom<Rhs>,
+        {
+            type Output = Self;
+
+            fn rem(self, rhs: Rhs) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::rem(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> core::ops::Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::mul(self, rhs)
+            }
+        }
+
+        impl<T: $crate::TimeInt> core::ops::Div<T> for $name<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self::Output {
+                <Self as $crate::fixed_point::FixedPoint>::div(self, rhs)
+            }
+        }
+
+        impl<SourceInt: $crate::TimeInt, DestInt: $crate::TimeInt>
+            core::convert::TryFrom<$crate::rate::Generic<SourceInt>> for $name<DestInt>
+        where
+            DestInt: core::convert::TryFrom<SourceInt>,
+        {
+            type Error = $crate::ConversionError;
+
+            fn try_from(
+                generic_rate: $crate::rate::Generic<SourceInt>,
+            ) -> Result<Self, Self::Error> {
+                $crate::fixed_point::FixedPoint::from_ticks(
+                    generic_rate.integer(),
+                    *generic_rate.scaling_factor(),
+                )
+            }
+        }
+
+        impl<T: $crate::TimeInt> From<$name<T>> for $crate::rate::Generic<T> {
+            fn from(rate: $name<T>) -> Self {
+                Self::new(
+                    rate.0,
+                    <$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                )
+            }
+        }
+
+        $crate::rate_unit!(
+            @builtin $name,
+            Mebihertz,
+            Megahertz,
+            Kibihertz,
+            Kilohertz,
+            Hertz,
+            Decihertz,
+            Centihertz,
+            Millihertz,
+            Microhertz,
+            Rpm,
+            MebibytesPerSecond,
+            MegabytesPerSecond,
+            KibibytesPerSecond,
+            KilobytesPerSecond,
+            BytesPerSecond,
+            MebibitsPerSecond,
+            MegabitsPerSecond,
+            KibibitsPerSecond,
+            KilobitsPerSecond,
+            BitsPerSecond,
+            Mebibaud,
+            Megabaud,
+            Kibibaud,
+            Kilobaud,
+            Baud,
+            KilosamplesPerSecond,
+            SamplesPerSecond,
+            KiloframesPerSecond,
+            FramesPerSecond
+        );
+    };
+    (@builtin $name:ident, $($unit:ident),+) => {
+        $(
+            impl<T: $crate::TimeInt> core::convert::TryFrom<$crate::rate::$unit<T>>
+                for $name<T>
+            {
+                type Error = $crate::ConversionError;
+
+                fn try_from(rate: $crate::rate::$unit<T>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_ticks(
+                        rate.0,
+                        <$crate::rate::$unit<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                    )
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::convert::TryFrom<$name<T>>
+                for $crate::rate::$unit<T>
+            {
+                type Error = $crate::ConversionError;
+
+                fn try_from(rate: $name<T>) -> Result<Self, Self::Error> {
+                    $crate::fixed_point::FixedPoint::from_ticks(
+                        rate.0,
+                        <$name<T> as $crate::fixed_point::FixedPoint>::SCALING_FACTOR,
+                    )
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialEq<$crate::rate::$unit<T>> for $name<T> {
+                fn eq(&self, rhs: &$crate::rate::$unit<T>) -> bool {
+                    $crate::rate::Generic::from(*self) == $crate::rate::Generic::from(*rhs)
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialEq<$name<T>> for $crate::rate::$unit<T> {
+                fn eq(&self, rhs: &$name<T>) -> bool {
+                    $crate::rate::Generic::from(*self) == $crate::rate::Generic::from(*rhs)
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialOrd<$crate::rate::$unit<T>>
+                for $name<T>
+            {
+                fn partial_cmp(
+                    &self,
+                    rhs: &$crate::rate::$unit<T>,
+                ) -> Option<core::cmp::Ordering> {
+                    $crate::rate::Generic::from(*self)
+                        .partial_cmp(&$crate::rate::Generic::from(*rhs))
+                }
+            }
+
+            impl<T: $crate::TimeInt> core::cmp::PartialOrd<$name<T>>
+                for $crate::rate::$unit<T>
+            {
+                fn partial_cmp(&self, rhs: &$name<T>) -> Option<core::cmp::Ordering> {
+                    $crate::rate::Generic::from(*self)
+                        .partial_cmp(&$crate::rate::Generic::from(*rhs))
+                }
+            }
+        )+
+    };
+}
+
 #[cfg(test)]
 mod tests {}
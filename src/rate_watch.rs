@@ -0,0 +1,166 @@
+//! Event rate measurement with threshold hysteresis
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, rate::Rate, ConversionError, Instant, TimeError,
+};
+use core::convert::TryFrom;
+
+/// A threshold crossing reported by [`RateWatch::update()`]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Crossing {
+    /// The measured rate rose to or above the high threshold
+    High,
+    /// The measured rate fell to or below the low threshold
+    Low,
+}
+
+/// Measures the rate of input events over fixed windows and signals threshold crossings
+///
+/// Events (eg. encoder pulses or flow meter ticks) are counted with [`RateWatch::event()`]. Once
+/// the window has elapsed, [`RateWatch::update()`] measures the rate over the window and starts a
+/// new one.
+///
+/// The watch is either _high_ or _low_ (initially low). It becomes high when a measured rate is
+/// at or above the high threshold and only becomes low again when a measured rate is at or below
+/// the low threshold. The gap between the thresholds (the hysteresis) prevents a rate hovering
+/// around a single threshold from producing a burst of crossings, which makes it suitable for
+/// overspeed/underspeed detection.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, fraction::Fraction, rate::*, rate_watch::{Crossing, RateWatch}, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // overspeed above 1 kHz, cleared below 900 Hz, measured over 100 ms windows
+/// let mut watch =
+///     RateWatch::new(Hertz(900_u32), Hertz(1_000_u32), Milliseconds(100_u32), Instant::<Clock>::new(0))
+///         .unwrap();
+///
+/// watch.events(105);
+/// assert_eq!(watch.update(Instant::new(100)), Ok(Some(Crossing::High)));
+/// assert_eq!(watch.rate(), Some(Hertz(1_050)));
+///
+/// // within the hysteresis band
+/// watch.events(95);
+/// assert_eq!(watch.update(Instant::new(200)), Ok(None));
+///
+/// watch.events(85);
+/// assert_eq!(watch.update(Instant::new(300)), Ok(Some(Crossing::Low)));
+/// ```
+#[derive(Debug)]
+pub struct RateWatch<Clock: crate::Clock, R> {
+    low: R,
+    high: R,
+    window: Clock::T,
+    window_start: Instant<Clock>,
+    count: u32,
+    rate: Option<R>,
+    is_high: bool,
+}
+
+impl<Clock: crate::Clock, R> RateWatch<Clock, R>
+where
+    R: Rate + FixedPoint + Ord + Copy,
+    R::T: TryFrom<u64>,
+    u64: TryFrom<Clock::T>,
+{
+    /// Construct a `RateWatch` with the given low and high thresholds and measurement window, the
+    /// first window starting at `start`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The window doesn't fit in the clock's integer type
+    /// - [`ConversionError::DivByZero`] : The window is shorter than one clock tick
+    ///
+    /// # Panics
+    ///
+    /// If `low` is greater than `high`
+    pub fn new<Dur>(
+        low: R,
+        high: R,
+        window: Dur,
+        start: Instant<Clock>,
+    ) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        assert!(low <= high, "Invalid thresholds");
+
+        let window = window
+            .to_generic::<Clock::T>(Clock::SCALING_FACTOR)?
+            .integer();
+        if window == Clock::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+
+        Ok(Self {
+            low,
+            high,
+            window,
+            window_start: start,
+            count: 0,
+            rate: None,
+            is_high: false,
+        })
+    }
+
+    /// Record one event
+    pub fn event(&mut self) {
+        self.events(1);
+    }
+
+    /// Record `count` events
+    pub fn events(&mut self, count: u32) {
+        self.count = self.count.saturating_add(count);
+    }
+
+    /// If the window has elapsed, measure the rate over it, start a new window at `now` and
+    /// return the threshold crossing (if any)
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::ConversionFailure`] : The measured rate doesn't fit in the rate's _integer_
+    ///   type (the window is not restarted)
+    pub fn update(&mut self, now: Instant<Clock>) -> Result<Option<Crossing>, TimeError> {
+        match now.checked_duration_since(&self.window_start) {
+            Some(elapsed) if elapsed.integer() >= self.window => {}
+            _ => return Ok(None),
+        }
+
+        let rate = R::from_events(self.count, &self.window_start, &now)?;
+        self.window_start = now;
+        self.count = 0;
+        self.rate = Some(rate);
+
+        if !self.is_high && rate >= self.high {
+            self.is_high = true;
+            Ok(Some(Crossing::High))
+        } else if self.is_high && rate <= self.low {
+            self.is_high = false;
+            Ok(Some(Crossing::Low))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the rate measured over the latest complete window
+    pub fn rate(&self) -> Option<R> {
+        self.rate
+    }
+
+    /// Returns `true` if the watch is _high_ (the high threshold has been reached and the rate
+    /// hasn't since fallen to the low threshold)
+    pub fn is_high(&self) -> bool {
+        self.is_high
+    }
+}
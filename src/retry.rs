@@ -0,0 +1,174 @@
+//! Retry bookkeeping bounded by an attempt count and an absolute deadline
+
+use crate::{
+    duration, duration::Duration, fixed_point::FixedPoint, jitter::Jitter, ConversionError, Instant,
+};
+use core::convert::TryFrom;
+use num::{Bounded, CheckedMul};
+
+/// How the delay between attempts grows
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Backoff {
+    /// Every retry waits the initial delay
+    Constant,
+    /// The `n`th retry waits `n` × the initial delay
+    Linear,
+    /// The `n`th retry waits 2<sup>`n - 1`</sup> × the initial delay
+    Exponential,
+}
+
+/// Tracks the attempts of an operation against both a maximum attempt count and an absolute
+/// deadline
+///
+/// Before each attempt, [`RetryBudget::next_attempt()`] returns how long to wait before making it
+/// (zero for the first attempt) or `None` once the budget is exhausted: either all attempts have
+/// been made, or the attempt would start at or after the deadline.
+///
+/// Delays grow according to the [`Backoff`] policy and saturate at half the range of the clock.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, retry::{Backoff, RetryBudget}, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // up to 5 attempts within 1 second, backing off from 100 ms
+/// let mut budget = RetryBudget::new(
+///     5,
+///     Instant::<Clock>::new(1_000),
+///     Milliseconds(100_u32),
+///     Backoff::Exponential,
+/// )
+/// .unwrap();
+///
+/// let delay = |delay: Option<Generic<u32>>| delay.map(|delay| delay.integer());
+/// assert_eq!(delay(budget.next_attempt(Instant::new(0))), Some(0));
+/// assert_eq!(delay(budget.next_attempt(Instant::new(10))), Some(100));
+/// assert_eq!(delay(budget.next_attempt(Instant::new(120))), Some(200));
+/// assert_eq!(delay(budget.next_attempt(Instant::new(330))), Some(400));
+///
+/// // waiting 800 ms would pass the deadline
+/// assert_eq!(delay(budget.next_attempt(Instant::new(740))), None);
+/// ```
+#[derive(Debug)]
+pub struct RetryBudget<Clock: crate::Clock> {
+    max_attempts: u32,
+    attempts: u32,
+    deadline: Instant<Clock>,
+    initial_delay: Clock::T,
+    backoff: Backoff,
+}
+
+impl<Clock: crate::Clock> RetryBudget<Clock> {
+    /// Construct a budget of at most `max_attempts` attempts, all starting before `deadline`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The initial delay doesn't fit in the clock's
+    ///   integer type
+    /// - [`ConversionError::Overflow`], [`ConversionError::Unspecified`] : The initial delay can't
+    ///   be converted to ticks of the clock
+    pub fn new<Dur>(
+        max_attempts: u32,
+        deadline: Instant<Clock>,
+        initial_delay: Dur,
+        backoff: Backoff,
+    ) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self {
+            max_attempts,
+            attempts: 0,
+            deadline,
+            initial_delay: initial_delay
+                .to_generic::<Clock::T>(Clock::SCALING_FACTOR)?
+                .integer(),
+            backoff,
+        })
+    }
+
+    /// Returns how long to wait (from `now`) before making the next attempt, or `None` if the
+    /// budget is exhausted
+    ///
+    /// Each `Some` counts as an attempt.
+    pub fn next_attempt(&mut self, now: Instant<Clock>) -> Option<duration::Generic<Clock::T>> {
+        self.attempt(now, |delay| delay)
+    }
+
+    /// Returns how long to wait (from `now`) before making the next attempt, jittered by the
+    /// `random` value, or `None` if the budget is exhausted
+    ///
+    /// The jittered delay is checked against the deadline. Each `Some` counts as an attempt.
+    pub fn next_attempt_jittered(
+        &mut self,
+        now: Instant<Clock>,
+        jitter: &Jitter,
+        random: u32,
+    ) -> Option<duration::Generic<Clock::T>>
+    where
+        u128: From<Clock::T>,
+        Clock::T: TryFrom<u128>,
+    {
+        self.attempt(now, |delay| jitter.apply_ticks(delay, random))
+    }
+
+    /// Returns the delay (adjusted by `adjust`) before the next attempt, counting it, or `None`
+    /// if the budget is exhausted
+    fn attempt(
+        &mut self,
+        now: Instant<Clock>,
+        adjust: impl FnOnce(Clock::T) -> Clock::T,
+    ) -> Option<duration::Generic<Clock::T>> {
+        if self.attempts >= self.max_attempts {
+            return None;
+        }
+
+        let delay = adjust(self.delay(self.attempts));
+        let remaining = self.deadline.checked_duration_since(&now)?.integer();
+        if delay >= remaining {
+            return None;
+        }
+
+        self.attempts += 1;
+        Some(duration::Generic::new(delay, Clock::SCALING_FACTOR))
+    }
+
+    /// Returns the number of attempts made
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns the deadline
+    pub fn deadline(&self) -> Instant<Clock> {
+        self.deadline
+    }
+
+    /// Returns the delay before the given (0-based) attempt
+    fn delay(&self, attempt: u32) -> Clock::T {
+        let max_delay = Clock::T::max_value() / Clock::T::from(2);
+
+        let delay = match (attempt, self.backoff) {
+            (0, _) => Some(Clock::T::from(0)),
+            (_, Backoff::Constant) => Some(self.initial_delay),
+            (_, Backoff::Linear) => Clock::T::try_from(attempt)
+                .ok()
+                .and_then(|attempt| self.initial_delay.checked_mul(&attempt)),
+            (_, Backoff::Exponential) => 2_u32
+                .checked_pow(attempt - 1)
+                .and_then(|factor| Clock::T::try_from(factor).ok())
+                .and_then(|factor| self.initial_delay.checked_mul(&factor)),
+        };
+
+        delay.map_or(max_delay, |delay| delay.min(max_delay))
+    }
+}
@@ -0,0 +1,156 @@
+//! Alarms of RTCs with limited match fields
+//!
+//! RTC alarms typically fire when some of the fields of the time of day (eg. only the minutes and
+//! seconds) match the alarm registers, on a whole second. A deadline further away than the period
+//! of the matched fields, or between whole seconds, can't be hit by the alarm alone:
+//! [`nearest_alarm()`] returns the soonest achievable wake-up on the way to the deadline and the
+//! residual duration to handle (with another alarm or a finer timer) after waking.
+
+use crate::{duration, ConversionError, Instant};
+use core::convert::TryFrom;
+
+/// The time of day fields an RTC alarm matches, the less significant fields always being matched
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MatchFields {
+    /// Only the seconds, the alarm fires every minute
+    Seconds,
+    /// The minutes and seconds, the alarm fires every hour
+    MinutesSeconds,
+    /// The hours, minutes and seconds, the alarm fires every day
+    HoursMinutesSeconds,
+}
+
+impl MatchFields {
+    /// Returns the period of the alarm in seconds
+    pub fn period(&self) -> u32 {
+        match self {
+            MatchFields::Seconds => 60,
+            MatchFields::MinutesSeconds => 60 * 60,
+            MatchFields::HoursMinutesSeconds => 24 * 60 * 60,
+        }
+    }
+}
+
+/// An RTC alarm setting and what remains of the deadline after it fires
+#[derive(Debug)]
+pub struct Alarm<Clock: crate::Clock> {
+    /// The hour to match (`0..24`)
+    pub hour: u8,
+    /// The minute to match (`0..60`)
+    pub minute: u8,
+    /// The second to match (`0..60`)
+    pub second: u8,
+    /// The instant at which the alarm fires
+    pub wake: Instant<Clock>,
+    /// The duration from the alarm firing to the deadline
+    pub residual: duration::Generic<Clock::T>,
+}
+
+impl<Clock: crate::Clock> Copy for Alarm<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Alarm<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Alarm<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hour == other.hour
+            && self.minute == other.minute
+            && self.second == other.second
+            && self.wake == other.wake
+            && self.residual == other.residual
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Alarm<Clock> {}
+
+/// Returns the soonest alarm, of an RTC matching `fields`, that fires at or before `deadline` on
+/// the way to it
+///
+/// The clock is the RTC's counter, its epoch being a midnight. The alarm fires on the first
+/// whole second after `now` matching the fields of the latest whole second at or before
+/// `deadline`. That is the deadline's second if it is within one period of the matched fields,
+/// otherwise a whole number of periods earlier.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, rtc_alarm::{nearest_alarm, MatchFields}, Instant};
+/// # #[derive(Debug)]
+/// struct Rtc;
+/// impl embedded_time::Clock for Rtc {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 256);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // 00:00:10 until 00:02:30.5 with an alarm matching only the seconds
+/// let alarm = nearest_alarm(
+///     MatchFields::Seconds,
+///     Instant::<Rtc>::new(10 * 256),
+///     Instant::new(150 * 256 + 128),
+/// )
+/// .unwrap();
+///
+/// assert_eq!((alarm.hour, alarm.minute, alarm.second), (0, 0, 30));
+/// assert_eq!(alarm.wake, Instant::new(30 * 256));
+/// assert_eq!(alarm.residual, Generic::new(120 * 256 + 128, Fraction::new(1, 256)));
+/// ```
+///
+/// # Errors
+///
+/// - [`ConversionError::NegDuration`] : There is no whole second after `now` and at or before
+///   `deadline`
+/// - [`ConversionError::DivByZero`] : The clock's _scaling factor_ is zero
+/// - [`ConversionError::Overflow`] : An intermediate value overflowed
+pub fn nearest_alarm<Clock>(
+    fields: MatchFields,
+    now: Instant<Clock>,
+    deadline: Instant<Clock>,
+) -> Result<Alarm<Clock>, ConversionError>
+where
+    Clock: crate::Clock,
+    u128: From<Clock::T>,
+    Clock::T: TryFrom<u128>,
+{
+    let numerator = <u128 as From<u32>>::from(*Clock::SCALING_FACTOR.numerator());
+    let denominator = <u128 as From<u32>>::from(*Clock::SCALING_FACTOR.denominator());
+    if numerator == 0 || denominator == 0 {
+        return Err(ConversionError::DivByZero);
+    }
+
+    let seconds = |instant: Instant<Clock>| {
+        u128::from(instant.duration_since_epoch().integer())
+            .checked_mul(numerator)
+            .map(|ticks| ticks / denominator)
+            .ok_or(ConversionError::Overflow)
+    };
+    let deadline_ticks = u128::from(deadline.duration_since_epoch().integer());
+
+    let first = seconds(now)? + 1;
+    let last = seconds(deadline)?;
+    if last < first {
+        return Err(ConversionError::NegDuration);
+    }
+
+    let wake = first + (last - first) % <u128 as From<u32>>::from(fields.period());
+
+    // the first tick of the wake second, no later than the deadline
+    let wake_ticks = (wake * denominator).div_ceil(numerator);
+
+    Ok(Alarm {
+        hour: (wake / 3_600 % 24) as u8,
+        minute: (wake / 60 % 60) as u8,
+        second: (wake % 60) as u8,
+        wake: Instant::new(Clock::T::try_from(wake_ticks).map_err(|_| ConversionError::Overflow)?),
+        residual: duration::Generic::new(
+            Clock::T::try_from(deadline_ticks - wake_ticks)
+                .map_err(|_| ConversionError::Overflow)?,
+            Clock::SCALING_FACTOR,
+        ),
+    })
+}
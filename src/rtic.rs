@@ -0,0 +1,93 @@
+//! An RTIC [`Monotonic`] timer backed by a [`Clock`](crate::Clock)
+
+use crate::{clock, duration, Instant};
+use rtic_monotonic::Monotonic;
+
+/// A [`Clock`](crate::Clock) with a compare (alarm) interrupt
+pub trait Compare: crate::Clock {
+    /// Set the compare value, the interrupt is raised once the clock reaches `instant`
+    fn set_compare(&mut self, instant: Instant<Self>);
+
+    /// Clear the compare interrupt flag
+    fn clear_compare_flag(&mut self);
+
+    /// Reset the counter to `0`
+    fn reset(&mut self);
+
+    /// Called from the compare interrupt handler (eg. to extend a counter on overflow)
+    fn on_interrupt(&mut self) {}
+}
+
+/// Implements the RTIC (`rtic-monotonic`) [`Monotonic`] trait using any [`Clock`](crate::Clock)
+/// with a [`Compare`] capability
+///
+/// The monotonic's `Instant` is the clock's [`Instant`] and its `Duration` is a
+/// [`duration::Generic`] with the clock's _scaling factor_. Instants can also be offset by any
+/// _named_ duration (eg. `monotonics::now() + Milliseconds(10_u32)`).
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[monotonic(binds = TIM2, default = true)]
+/// type Mono = RticMonotonic<Tim2Clock>;
+/// ```
+#[derive(Debug)]
+pub struct RticMonotonic<Clock: Compare> {
+    clock: Clock,
+}
+
+impl<Clock: Compare> RticMonotonic<Clock> {
+    /// Wrap a clock
+    pub fn new(clock: Clock) -> Self {
+        Self { clock }
+    }
+
+    /// Returns a reference to the underlying clock
+    pub fn inner(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the underlying clock
+    pub fn into_inner(self) -> Clock {
+        self.clock
+    }
+}
+
+impl<Clock: Compare> Monotonic for RticMonotonic<Clock> {
+    type Instant = Instant<Clock>;
+    type Duration = duration::Generic<Clock::T>;
+
+    /// Returns the current time
+    ///
+    /// # Panics
+    ///
+    /// If the clock can't be read
+    fn now(&mut self) -> Self::Instant {
+        match clock::try_now(&self.clock) {
+            Ok(now) => now,
+            Err(_) => panic!("Clock read failed"),
+        }
+    }
+
+    fn set_compare(&mut self, instant: Self::Instant) {
+        self.clock.set_compare(instant);
+    }
+
+    fn clear_compare_flag(&mut self) {
+        self.clock.clear_compare_flag();
+    }
+
+    fn zero() -> Self::Instant {
+        Instant::new(Clock::T::from(0))
+    }
+
+    // the trait requires an `unsafe fn`, the implementation itself is safe
+    #[allow(unsafe_code)]
+    unsafe fn reset(&mut self) {
+        self.clock.reset();
+    }
+
+    fn on_interrupt(&mut self) {
+        self.clock.on_interrupt();
+    }
+}
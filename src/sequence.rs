@@ -0,0 +1,242 @@
+//! Correlation of sequence numbers with the instants they were observed
+
+use crate::{duration, fixed_point::FixedPoint, rate::Rate, Instant};
+use core::convert::TryFrom;
+use num::{
+    traits::{WrappingAdd, WrappingSub},
+    Bounded,
+};
+
+/// How an observed sequence number relates to the previous ones, returned by
+/// [`SequenceCorrelator::observe()`]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Arrival {
+    /// The first observation (since construction or [`SequenceCorrelator::reset()`])
+    First,
+    /// A sequence number after the latest one, `skipped` sequence numbers were not observed in
+    /// between (eg. lost packets)
+    Next {
+        /// The number of sequence numbers missing since the latest one
+        skipped: u32,
+    },
+    /// A duplicate or a sequence number before the latest one (eg. a reordered packet), it is
+    /// ignored
+    Stale,
+}
+
+/// Correlates the sequence numbers of a sender with the instants they were observed
+///
+/// From the first and latest observations, the correlator estimates the sender's period (and
+/// rate) and the instant at which any sequence number is expected. It also tracks the jitter: the
+/// largest deviation of an observation from the instant expected from the previous ones. Together,
+/// they give the window within which the next item is expected, for jitter-buffer and lost-packet
+/// timeout logic.
+///
+/// Sequence numbers are `u32`s compared with serial number arithmetic (RFC 1982), so they may wrap:
+/// a sequence number less than 2<sup>31</sup> after the latest one is the next, any other is
+/// stale. The time between the first and latest observations must be less than half the clock's
+/// wrap-around period, [`SequenceCorrelator::reset()`] starts over.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, rate::*, sequence::{Arrival, SequenceCorrelator}, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // a packet every 20 ms
+/// let mut correlator = SequenceCorrelator::<Clock>::new();
+/// assert_eq!(correlator.observe(100, Instant::new(1_000)), Arrival::First);
+/// assert_eq!(correlator.observe(101, Instant::new(1_020)), Arrival::Next { skipped: 0 });
+/// assert_eq!(correlator.observe(104, Instant::new(1_079)), Arrival::Next { skipped: 2 });
+/// assert_eq!(correlator.observe(103, Instant::new(1_080)), Arrival::Stale);
+/// assert_eq!(correlator.observe(105, Instant::new(1_100)), Arrival::Next { skipped: 0 });
+///
+/// assert_eq!(correlator.period(), Some(Milliseconds(20_u32).into()));
+/// assert_eq!(correlator.rate(), Some(Hertz(50_u32)));
+/// assert_eq!(correlator.expected(106), Some(Instant::new(1_120)));
+///
+/// // 104 arrived 1 ms before it was expected
+/// assert_eq!(correlator.jitter(), Milliseconds(1_u32).into());
+/// assert_eq!(correlator.next_window(), Some((Instant::new(1_119), Instant::new(1_121))));
+/// assert!(!correlator.is_overdue(Instant::new(1_121)));
+/// assert!(correlator.is_overdue(Instant::new(1_122)));
+/// ```
+#[derive(Debug)]
+pub struct SequenceCorrelator<Clock: crate::Clock> {
+    first: Option<Instant<Clock>>,
+    latest: Option<(u32, Instant<Clock>)>,
+    // sequence numbers from the first observation to the latest
+    steps: u64,
+    jitter: Clock::T,
+}
+
+impl<Clock: crate::Clock> SequenceCorrelator<Clock>
+where
+    u64: TryFrom<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    /// Construct a correlator without observations
+    pub fn new() -> Self {
+        Self {
+            first: None,
+            latest: None,
+            steps: 0,
+            jitter: Clock::T::from(0),
+        }
+    }
+
+    /// Record that `sequence` was observed at `instant`
+    pub fn observe(&mut self, sequence: u32, instant: Instant<Clock>) -> Arrival {
+        let (latest, _) = match self.latest {
+            Some(latest) => latest,
+            None => {
+                self.first = Some(instant);
+                self.latest = Some((sequence, instant));
+                return Arrival::First;
+            }
+        };
+
+        let step = sequence.wrapping_sub(latest);
+        if step == 0 || step >= 1 << 31 {
+            return Arrival::Stale;
+        }
+
+        if let Some(expected) = self.expected(sequence) {
+            let deviation = instant
+                .checked_duration_since(&expected)
+                .or_else(|| expected.checked_duration_since(&instant))
+                .map(|deviation| deviation.integer());
+            if let Some(deviation) = deviation {
+                if deviation > self.jitter {
+                    self.jitter = deviation;
+                }
+            }
+        }
+
+        self.latest = Some((sequence, instant));
+        self.steps += u64::from(step);
+
+        Arrival::Next { skipped: step - 1 }
+    }
+
+    /// Returns the estimated period of the sender (truncated to clock ticks)
+    ///
+    /// Returns [`None`] until two sequence numbers have been observed.
+    pub fn period(&self) -> Option<duration::Generic<Clock::T>> {
+        let ticks = self.elapsed()? / self.steps;
+
+        Some(duration::Generic::new(
+            Clock::T::try_from(ticks).ok()?,
+            Clock::SCALING_FACTOR,
+        ))
+    }
+
+    /// Returns the estimated rate of the sender (rounded)
+    ///
+    /// Returns [`None`] until two sequence numbers have been observed at different instants, or
+    /// if the rate doesn't fit in the _integer_ type.
+    pub fn rate<R>(&self) -> Option<R>
+    where
+        R: Rate + FixedPoint,
+        R::T: TryFrom<u64>,
+    {
+        let first = self.first.as_ref()?;
+        let (_, latest) = self.latest.as_ref()?;
+
+        R::from_events(u32::try_from(self.steps).ok()?, first, latest).ok()
+    }
+
+    /// Returns the instant at which `sequence` is expected (rounded to the nearest clock tick)
+    ///
+    /// Returns [`None`] until two sequence numbers have been observed, or if `sequence` is before
+    /// the first observed one.
+    pub fn expected(&self, sequence: u32) -> Option<Instant<Clock>> {
+        let elapsed = self.elapsed()?;
+        let first = self.first?;
+        let (latest, _) = self.latest?;
+
+        // sequence numbers from the first observation to `sequence`
+        let steps =
+            i64::try_from(self.steps).ok()? + i64::from(sequence.wrapping_sub(latest) as i32);
+        let steps = <u64 as TryFrom<i64>>::try_from(steps).ok()?;
+
+        let ticks = (u128::from(steps) * u128::from(elapsed) + u128::from(self.steps) / 2)
+            / u128::from(self.steps);
+        // wrap around with the clock
+        let modulus = u128::from(u64::try_from(Clock::T::max_value()).ok()?) + 1;
+        let ticks =
+            Clock::T::try_from(<u64 as TryFrom<u128>>::try_from(ticks % modulus).ok()?).ok()?;
+
+        Some(Instant::new(
+            first.duration_since_epoch().integer().wrapping_add(&ticks),
+        ))
+    }
+
+    /// Returns the largest deviation of an observation from its expected instant
+    pub fn jitter(&self) -> duration::Generic<Clock::T> {
+        duration::Generic::new(self.jitter, Clock::SCALING_FACTOR)
+    }
+
+    /// Returns the earliest and latest instants at which the sequence number after the latest
+    /// observed one is expected (its expected instant ± the jitter)
+    ///
+    /// Returns [`None`] until two sequence numbers have been observed.
+    pub fn next_window(&self) -> Option<(Instant<Clock>, Instant<Clock>)> {
+        let (latest, _) = self.latest?;
+        let expected = self
+            .expected(latest.wrapping_add(1))?
+            .duration_since_epoch();
+
+        Some((
+            Instant::new(expected.integer().wrapping_sub(&self.jitter)),
+            Instant::new(expected.integer().wrapping_add(&self.jitter)),
+        ))
+    }
+
+    /// Returns whether `now` is past the window of the next sequence number (eg. it was lost)
+    ///
+    /// Returns `false` until two sequence numbers have been observed.
+    pub fn is_overdue(&self, now: Instant<Clock>) -> bool {
+        match self.next_window() {
+            Some((_, latest)) => now
+                .checked_duration_since(&latest)
+                .is_some_and(|overdue| overdue.integer() > Clock::T::from(0)),
+            None => false,
+        }
+    }
+
+    /// Discard all observations
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the clock ticks from the first observation to the latest
+    fn elapsed(&self) -> Option<u64> {
+        if self.steps == 0 {
+            return None;
+        }
+
+        let (_, latest) = self.latest.as_ref()?;
+        let elapsed = latest.checked_duration_since(self.first.as_ref()?)?;
+
+        u64::try_from(elapsed.integer()).ok()
+    }
+}
+
+impl<Clock: crate::Clock> Default for SequenceCorrelator<Clock>
+where
+    u64: TryFrom<Clock::T>,
+    Clock::T: TryFrom<u64>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
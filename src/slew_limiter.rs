@@ -0,0 +1,150 @@
+//! Rate-of-change (slew) limiting of a setpoint
+
+use crate::{
+    fixed_point::FixedPoint,
+    rate::{self, Rate},
+    Instant,
+};
+use core::convert::TryFrom;
+
+/// Limits how fast a numeric setpoint may change over time
+///
+/// The maximum rate of change is expressed as a [`Rate`] where one "event" is one unit of the
+/// setpoint. For example, a maximum rate of `Hertz(500)` allows the output to move by at most 500
+/// units per second, and `Kilohertz(2)` allows it to move by 2,000 units per second.
+///
+/// Each call to [`SlewLimiter::update()`] moves the output toward the requested target by no more
+/// than the amount allowed by the time elapsed since the previous update. Elapsed time that is too
+/// short to allow a whole unit of change is carried over to the next update rather than lost.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, rate::*, slew_limiter::SlewLimiter, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // ramp at no more than 100 units/second, starting from 0
+/// let mut limiter = SlewLimiter::new(Hertz(100_u32), 0, Instant::<Clock>::new(0));
+///
+/// // 250 ms later, the output may only have moved by 25 units
+/// assert_eq!(limiter.update(1_000, Instant::<Clock>::new(250)), 25);
+///
+/// // a target within reach is applied directly
+/// assert_eq!(limiter.update(30, Instant::<Clock>::new(500)), 30);
+/// ```
+#[derive(Debug)]
+pub struct SlewLimiter<Clock: crate::Clock> {
+    max_rate: rate::Generic<u64>,
+    output: i32,
+    last_update: Instant<Clock>,
+    /// The change allowed since the previous update that is less than a whole unit (in units of
+    /// the combined _scaling factor_ denominator)
+    remainder: u128,
+}
+
+impl<Clock: crate::Clock> SlewLimiter<Clock>
+where
+    u64: From<Clock::T>,
+{
+    /// Construct a new `SlewLimiter` with the given maximum rate of change, initial output and
+    /// the [`Instant`] the initial output was set
+    pub fn new<R>(max_rate: R, initial: i32, now: Instant<Clock>) -> Self
+    where
+        R: Rate + FixedPoint,
+        u64: From<R::T>,
+    {
+        Self {
+            max_rate: rate::Generic::new(u64::from(max_rate.integer()), R::SCALING_FACTOR),
+            output: initial,
+            last_update: now,
+            remainder: 0,
+        }
+    }
+
+    /// Returns the current (limited) output
+    pub fn output(&self) -> i32 {
+        self.output
+    }
+
+    /// Change the maximum rate of change
+    ///
+    /// The new rate applies from the next [`SlewLimiter::update()`]. Any carried-over fraction of
+    /// a unit is dropped.
+    pub fn set_max_rate<R>(&mut self, max_rate: R)
+    where
+        R: Rate + FixedPoint,
+        u64: From<R::T>,
+    {
+        self.max_rate = rate::Generic::new(u64::from(max_rate.integer()), R::SCALING_FACTOR);
+        self.remainder = 0;
+    }
+
+    /// Force the output to a value, bypassing the limit
+    pub fn reset(&mut self, value: i32, now: Instant<Clock>) {
+        self.output = value;
+        self.last_update = now;
+        self.remainder = 0;
+    }
+
+    /// Move the output toward `target` by no more than the maximum rate allows for the time
+    /// elapsed since the previous update, returning the new output
+    ///
+    /// If `now` is earlier than the previous update, the output is left unchanged.
+    pub fn update(&mut self, target: i32, now: Instant<Clock>) -> i32 {
+        let elapsed = match now.checked_duration_since(&self.last_update) {
+            Some(elapsed) => elapsed,
+            None => return self.output,
+        };
+
+        let (max_step, remainder) = self.max_step(u64::from(elapsed.integer()));
+        let error = i64::from(target) - i64::from(self.output);
+        self.last_update = now;
+
+        if error.unsigned_abs() <= max_step {
+            self.output = target;
+            self.remainder = 0;
+        } else {
+            // `max_step` is less than the magnitude of the error, so the result lies between the
+            // output and the target and can't overflow
+            let step = max_step as i64;
+            self.output = if error > 0 {
+                (i64::from(self.output) + step) as i32
+            } else {
+                (i64::from(self.output) - step) as i32
+            };
+            self.remainder = remainder;
+        }
+
+        self.output
+    }
+
+    /// Returns the maximum change allowed over the given number of clock ticks (and the carried
+    /// over remainder) and the new remainder, saturating upon overflow
+    fn max_step(&self, ticks: u64) -> (u64, u128) {
+        // ticks × clock scaling factor × rate, in units of the combined denominator
+        let numerator = u128::from(*self.max_rate.scaling_factor().numerator())
+            * u128::from(*Clock::SCALING_FACTOR.numerator());
+        let denominator = u128::from(*self.max_rate.scaling_factor().denominator())
+            * u128::from(*Clock::SCALING_FACTOR.denominator());
+
+        let step = || {
+            let scaled = u128::from(ticks)
+                .checked_mul(u128::from(self.max_rate.integer()))?
+                .checked_mul(numerator)?
+                .checked_add(self.remainder)?;
+            let step = u64::try_from(scaled.checked_div(denominator)?).ok()?;
+
+            Some((step, scaled % denominator))
+        };
+
+        step().unwrap_or((u64::MAX, 0))
+    }
+}
@@ -0,0 +1,213 @@
+//! Software (bit-banged) PWM scheduling
+
+use crate::{
+    duration::Duration, fixed_point::FixedPoint, fraction::Fraction, time_int::TimeInt,
+    ConversionError, Instant,
+};
+use core::convert::TryFrom;
+use num::{traits::WrappingAdd, Bounded};
+
+/// The output of [`SoftPwm::poll()`]
+#[derive(Debug)]
+pub struct Output<Clock: crate::Clock> {
+    /// The channel levels, bit `n` is set if channel `n` is high
+    pub levels: u32,
+    /// When the levels next change, [`SoftPwm::poll()`] should be called again at (or soon after)
+    /// this instant
+    pub next_deadline: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Copy for Output<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Output<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Output<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.levels == other.levels && self.next_deadline == other.next_deadline
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Output<Clock> {}
+
+/// Schedules up to 32 bit-banged PWM channels from a single timer
+///
+/// All channels share the base period. Each period starts with every channel with a non-zero duty
+/// cycle high, each channel then goes low once its duty cycle has elapsed. A duty cycle of `0`
+/// keeps the channel low and a duty cycle of `1` (or more) keeps it high.
+///
+/// [`SoftPwm::poll()`] returns the channel levels at the given instant along with the deadline of
+/// the next level change, which would be used to program a one-shot timer whose interrupt handler
+/// polls again and writes the levels to the GPIOs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, soft_pwm::SoftPwm, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // 2 channels with a 1 ms period
+/// let mut pwm = SoftPwm::<Clock, 2>::new(Milliseconds(1_u32), Instant::new(0)).unwrap();
+/// pwm.set_duty(0, Fraction::new(1, 4));
+/// pwm.set_duty(1, Fraction::new(1, 2));
+///
+/// let output = pwm.poll(Instant::new(0));
+/// assert_eq!((output.levels, output.next_deadline), (0b11, Instant::new(250)));
+///
+/// let output = pwm.poll(Instant::new(250));
+/// assert_eq!((output.levels, output.next_deadline), (0b10, Instant::new(500)));
+///
+/// let output = pwm.poll(Instant::new(500));
+/// assert_eq!((output.levels, output.next_deadline), (0b00, Instant::new(1_000)));
+/// ```
+#[derive(Debug)]
+pub struct SoftPwm<Clock: crate::Clock, const CH: usize> {
+    period: Clock::T,
+    period_start: Instant<Clock>,
+    on_ticks: [Clock::T; CH],
+}
+
+impl<Clock: crate::Clock, const CH: usize> SoftPwm<Clock, CH> {
+    /// Construct a `SoftPwm` with all channels at a duty cycle of `0`, the first period starting at
+    /// `start`
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : The period doesn't fit in the clock's integer type
+    /// - [`ConversionError::Overflow`] : The period is longer than half the range of the clock
+    /// - [`ConversionError::DivByZero`] : The period is shorter than one clock tick
+    ///
+    /// # Panics
+    ///
+    /// If `CH` is greater than `32`
+    pub fn new<Dur>(period: Dur, start: Instant<Clock>) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        assert!(CH <= 32, "Invalid channel count");
+
+        let period = period
+            .to_generic::<Clock::T>(Clock::SCALING_FACTOR)?
+            .integer();
+        if period == Clock::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+        if period > Clock::T::max_value() / Clock::T::from(2) {
+            return Err(ConversionError::Overflow);
+        }
+
+        Ok(Self {
+            period,
+            period_start: start,
+            on_ticks: [Clock::T::from(0); CH],
+        })
+    }
+
+    /// Set the duty cycle (the fraction of the period the output is high) of a channel
+    ///
+    /// The on-time is truncated to whole clock ticks. The change takes effect from the next
+    /// [`SoftPwm::poll()`].
+    ///
+    /// # Panics
+    ///
+    /// If `channel` is not less than `CH`
+    pub fn set_duty(&mut self, channel: usize, duty: Fraction) {
+        self.on_ticks[channel] = if duty >= Fraction::new(1, 1) {
+            self.period
+        } else {
+            self.on_ticks(duty)
+        };
+    }
+
+    /// Returns period × `duty` (< 1), truncated
+    ///
+    /// `period × numerator` can overflow the clock's integer type, so the whole multiples of the
+    /// denominator are scaled first and the remainder (less than the denominator) is scaled in
+    /// [`u128`]. Both results are less than the period.
+    fn on_ticks(&self, duty: Fraction) -> Clock::T {
+        let (numerator, denominator) = (*duty.numerator(), *duty.denominator());
+
+        let (multiples, remainder) = match Clock::T::try_from(denominator) {
+            Ok(denominator) => (self.period / denominator, self.period % denominator),
+            // the period is less than the denominator
+            Err(_) => (Clock::T::from(0), self.period),
+        };
+
+        // multiples × numerator ≤ period × duty, so the numerator fits if there are any multiples
+        let multiples = match Clock::T::try_from(numerator) {
+            Ok(numerator) if multiples > Clock::T::from(0) => multiples * numerator,
+            _ => Clock::T::from(0),
+        };
+
+        // remainder × duty < numerator, so it fits a u32
+        let remainder = remainder
+            .to_u128()
+            .and_then(|remainder| {
+                u32::try_from(remainder * u128::from(numerator) / u128::from(denominator)).ok()
+            })
+            .and_then(|remainder| Clock::T::try_from(remainder).ok())
+            .unwrap_or_else(|| Clock::T::from(0));
+
+        multiples + remainder
+    }
+
+    /// Returns the channel levels at `now` and the deadline of the next level change
+    ///
+    /// Periods that have elapsed entirely since the previous poll are skipped.
+    pub fn poll(&mut self, now: Instant<Clock>) -> Output<Clock> {
+        let mut elapsed = match now.checked_duration_since(&self.period_start) {
+            Some(elapsed) => elapsed.integer(),
+            None => {
+                return Output {
+                    levels: 0,
+                    next_deadline: self.period_start,
+                }
+            }
+        };
+
+        if elapsed >= self.period {
+            let skipped = elapsed - elapsed % self.period;
+            self.period_start = self.after_start(skipped);
+            elapsed = elapsed - skipped;
+        }
+
+        let mut levels = 0;
+        let mut next_change = self.period;
+        for (channel, on_ticks) in self.on_ticks.iter().enumerate() {
+            if elapsed < *on_ticks {
+                levels |= 1 << channel;
+
+                if *on_ticks < next_change {
+                    next_change = *on_ticks;
+                }
+            }
+        }
+
+        Output {
+            levels,
+            next_deadline: self.after_start(next_change),
+        }
+    }
+
+    /// Returns the instant `ticks` after the start of the current period
+    fn after_start(&self, ticks: Clock::T) -> Instant<Clock> {
+        Instant::new(
+            self.period_start
+                .duration_since_epoch()
+                .integer()
+                .wrapping_add(&ticks),
+        )
+    }
+}
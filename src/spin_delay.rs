@@ -0,0 +1,183 @@
+//! Calibrated busy-wait delays
+
+use crate::{
+    clock,
+    duration::{Duration, Microseconds},
+    fixed_point::FixedPoint,
+    fraction::Fraction,
+    time_int::TimeInt,
+    ConversionError, TimeError,
+};
+use core::convert::TryFrom;
+
+/// A busy-wait delay calibrated against a [`Clock`](crate::Clock)
+///
+/// For chips without a spare timer, delays can be generated by repeatedly calling a short spin
+/// function (eg. one containing a `nop` instruction). [`SpinDelay::calibrate()`] measures how many
+/// calls of the spin function fit in a given duration of a reference clock. The resulting
+/// `SpinDelay` no longer needs the clock.
+///
+/// The accuracy of the delay depends on the precision of the reference clock, the length of the
+/// calibration window and whether the spin function is interrupted.
+///
+/// With the `embedded-hal` feature enabled, `SpinDelay` implements the `embedded-hal` (0.2)
+/// `DelayMs` and `DelayUs` traits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, duration::*, spin_delay::SpinDelay, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// # let clock = Clock(Cell::new(0));
+/// # let spin = || clock.0.set(clock.0.get() + 2);
+/// // (`spin` takes 2 μs per call)
+/// let mut delay = SpinDelay::calibrate(&clock, spin, Milliseconds(10_u32)).unwrap();
+/// assert_eq!(delay.iterations_for(Milliseconds(1_u32)), Ok(500));
+///
+/// delay.delay(Milliseconds(1_u32)).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct SpinDelay<Spin: FnMut()> {
+    spin: Spin,
+    iterations_per_micro: Fraction,
+}
+
+impl<Spin: FnMut()> SpinDelay<Spin> {
+    /// Construct a `SpinDelay` by measuring how many calls of `spin` fit in (at least) the given
+    /// calibration `window` of the reference `clock`
+    ///
+    /// The spin function is called in successively doubling batches, reading the clock only
+    /// between batches, so the overhead of reading the clock doesn't skew the result.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    /// - [`TimeError::Overflow`] : The measurement overflowed before the calibration window
+    ///   elapsed
+    /// - [`TimeError::DivByZero`] : The calibration window is zero
+    pub fn calibrate<Clock, Dur>(
+        clock: &Clock,
+        mut spin: Spin,
+        window: Dur,
+    ) -> Result<Self, TimeError>
+    where
+        Clock: crate::Clock,
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T> + TryFrom<Clock::T>,
+    {
+        let window: u64 = window.into_ticks(Microseconds::<u64>::SCALING_FACTOR)?;
+        if window == 0 {
+            return Err(TimeError::DivByZero);
+        }
+
+        let mut batch = 1_u32;
+        loop {
+            let start = clock::try_now(clock)?;
+            for _ in 0..batch {
+                spin();
+            }
+            let elapsed = clock::try_now(clock)?
+                .checked_duration_since(&start)
+                .ok_or(TimeError::NegDuration)?;
+            let elapsed: Microseconds<u64> = Microseconds::try_from(elapsed)?;
+
+            if elapsed.integer() >= window {
+                let elapsed = u32::try_from(elapsed.integer()).map_err(|_| TimeError::Overflow)?;
+                return Ok(Self {
+                    spin,
+                    iterations_per_micro: Fraction::new_reduce(batch, elapsed)?,
+                });
+            }
+
+            batch = batch.checked_mul(2).ok_or(TimeError::Overflow)?;
+        }
+    }
+
+    /// Returns the number of calls of the spin function equal to the given duration
+    ///
+    /// # Errors
+    ///
+    /// Failure will only occur if the number of calls doesn't fit in a [`u64`].
+    ///
+    /// - [`ConversionError::ConversionFailure`]
+    /// - [`ConversionError::Unspecified`]
+    pub fn iterations_for<Dur>(&self, duration: Dur) -> Result<u64, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+    {
+        let micros: u64 = duration.into_ticks(Microseconds::<u64>::SCALING_FACTOR)?;
+        micros
+            .checked_mul_fraction(&self.iterations_per_micro)
+            .ok_or(ConversionError::Unspecified)
+    }
+
+    /// Block for (approximately) the given duration
+    ///
+    /// # Errors
+    ///
+    /// See [`SpinDelay::iterations_for()`]
+    pub fn delay<Dur>(&mut self, duration: Dur) -> Result<(), ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        u64: TryFrom<Dur::T>,
+    {
+        for _ in 0..self.iterations_for(duration)? {
+            (self.spin)();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+mod hal {
+    use super::SpinDelay;
+    use crate::duration::{Microseconds, Milliseconds};
+    use embedded_hal::blocking::delay::{DelayMs, DelayUs};
+
+    macro_rules! impl_delay {
+        ($($int:ty),+) => {
+            $(
+                impl<Spin: FnMut()> DelayMs<$int> for SpinDelay<Spin> {
+                    /// Delays for the given number of milliseconds
+                    ///
+                    /// # Panics
+                    ///
+                    /// If the number of calls of the spin function doesn't fit in a [`u64`]
+                    fn delay_ms(&mut self, ms: $int) {
+                        if self.delay(Milliseconds(u32::from(ms))).is_err() {
+                            panic!("Delay failed")
+                        }
+                    }
+                }
+
+                impl<Spin: FnMut()> DelayUs<$int> for SpinDelay<Spin> {
+                    /// Delays for the given number of microseconds
+                    ///
+                    /// # Panics
+                    ///
+                    /// If the number of calls of the spin function doesn't fit in a [`u64`]
+                    fn delay_us(&mut self, us: $int) {
+                        if self.delay(Microseconds(u32::from(us))).is_err() {
+                            panic!("Delay failed")
+                        }
+                    }
+                }
+            )+
+        };
+    }
+    impl_delay![u8, u16, u32];
+}
@@ -0,0 +1,232 @@
+//! Overflow-robust statistics of many durations
+//!
+//! Summing durations and dividing by their number overflows the accumulator quickly (a `u32`
+//! sum of nanoseconds overflows after ~4.3 s in total). Instead, the running mean is kept as a
+//! quotient and a remainder of the (never materialized) sum divided by the number of durations,
+//! each update only involves values no larger than the durations themselves. The mean is exact
+//! (truncated) for any number of durations of any _integer_ type.
+
+use crate::{clock, duration::Duration, fixed_point::FixedPoint, TimeError};
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+
+/// Running statistics of a stream of durations
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, stats::Stats};
+/// let mut stats = Stats::new();
+///
+/// for _ in 0..1_000 {
+///     stats.add(Nanoseconds(u32::MAX));
+///     stats.add(Nanoseconds(u32::MAX - 2));
+/// }
+///
+/// assert_eq!(stats.count(), 2_000);
+/// assert_eq!(stats.mean(), Some(Nanoseconds(u32::MAX - 1)));
+/// ```
+pub struct Stats<Dur> {
+    count: u64,
+    quotient: u128,
+    remainder: u128,
+    _duration: PhantomData<Dur>,
+}
+
+impl<Dur> Stats<Dur>
+where
+    Dur: Duration + FixedPoint,
+    u128: From<Dur::T>,
+    Dur::T: TryFrom<u128>,
+{
+    /// Construct empty statistics
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            quotient: 0,
+            remainder: 0,
+            _duration: PhantomData,
+        }
+    }
+
+    /// Add a duration
+    ///
+    /// # Panics
+    ///
+    /// If more than [`u64::MAX`] durations are added
+    pub fn add(&mut self, duration: Dur) {
+        let value = u128::from(duration.integer());
+        self.count = match self.count.checked_add(1) {
+            Some(count) => count,
+            None => panic!("Stats count overflow"),
+        };
+        let count = <u128 as From<u64>>::from(self.count);
+
+        // the sum was `quotient × (count - 1) + remainder`, it is now
+        // `quotient × count + remainder + value - quotient`
+        if value >= self.quotient {
+            // remainder < count, so only the first term can be large
+            let excess = value - self.quotient;
+            let remainder = excess % count + self.remainder;
+            self.quotient += excess / count + remainder / count;
+            self.remainder = remainder % count;
+        } else if self.remainder >= self.quotient - value {
+            self.remainder -= self.quotient - value;
+        } else {
+            let deficit = self.quotient - value - self.remainder;
+            self.quotient -= deficit.div_ceil(count);
+            self.remainder = match deficit % count {
+                0 => 0,
+                shortfall => count - shortfall,
+            };
+        }
+    }
+
+    /// Returns the number of durations added
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean (truncated) of the durations added or [`None`] if there are none
+    pub fn mean(&self) -> Option<Dur> {
+        if self.count == 0 {
+            return None;
+        }
+
+        // the mean is never larger than the largest duration
+        Dur::T::try_from(self.quotient).ok().map(Dur::new)
+    }
+}
+
+impl<Dur> Default for Stats<Dur>
+where
+    Dur: Duration + FixedPoint,
+    u128: From<Dur::T>,
+    Dur::T: TryFrom<u128>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Dur> Copy for Stats<Dur> {}
+
+impl<Dur> Clone for Stats<Dur> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Dur> fmt::Debug for Stats<Dur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stats")
+            .field("count", &self.count)
+            .field("quotient", &self.quotient)
+            .field("remainder", &self.remainder)
+            .finish()
+    }
+}
+
+/// Returns the mean (truncated) of the durations or [`None`] if there are none
+///
+/// See [`Stats`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, stats::average};
+/// let durations = [Microseconds(4_000_000_000_u32), Microseconds(4_000_000_001), Microseconds(3)];
+///
+/// assert_eq!(average(durations.iter().copied()), Some(Microseconds(2_666_666_668_u32)));
+/// assert_eq!(average(core::iter::empty::<Microseconds<u32>>()), None);
+/// ```
+pub fn average<Dur, Durations>(durations: Durations) -> Option<Dur>
+where
+    Dur: Duration + FixedPoint,
+    u128: From<Dur::T>,
+    Dur::T: TryFrom<u128>,
+    Durations: IntoIterator<Item = Dur>,
+{
+    durations
+        .into_iter()
+        .fold(Stats::new(), |mut stats, duration| {
+            stats.add(duration);
+            stats
+        })
+        .mean()
+}
+
+/// Returns the statistics of the durations of `iterations` calls of `f`, each timed separately
+///
+/// See [`time_it!`](crate::time_it) for the shorthand.
+///
+/// # Errors
+///
+/// - [`TimeError::Clock`] : The clock could not be read
+/// - [`TimeError::NegDuration`] : The clock went backwards during an iteration
+/// - Any conversion error of an iteration's duration into `Dur`
+pub fn measure<Clock, Dur>(
+    clock: &Clock,
+    iterations: u32,
+    mut f: impl FnMut(),
+) -> Result<Stats<Dur>, TimeError>
+where
+    Clock: crate::Clock,
+    Dur: Duration + FixedPoint,
+    Dur::T: TryFrom<Clock::T> + TryFrom<u128>,
+    u128: From<Dur::T>,
+{
+    let mut stats = Stats::new();
+
+    for _ in 0..iterations {
+        let start = clock::try_now(clock)?;
+        f();
+        let elapsed = clock::try_now(clock)?
+            .checked_duration_since(&start)
+            .ok_or(TimeError::NegDuration)?;
+
+        stats.add(Dur::from_ticks(elapsed.integer(), Clock::SCALING_FACTOR)?);
+    }
+
+    Ok(stats)
+}
+
+/// Micro-benchmark a block, returning the per-iteration [`Stats`]
+///
+/// `time_it!(clock, iterations, { body })` runs the body `iterations` times, timing each run with
+/// the clock, and evaluates to a `Result<Stats<Dur>, TimeError>` (see [`measure()`]). The
+/// duration type is usually inferred from the use of the result.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, stats::Stats, time_it, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 16_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let clock = Clock(Cell::new(0));
+///
+/// let stats: Stats<Nanoseconds<u32>> = time_it!(clock, 100, {
+///     // 24 cycles at 16 MHz
+///     clock.0.set(clock.0.get() + 24);
+/// })
+/// .unwrap();
+///
+/// assert_eq!(stats.count(), 100);
+/// assert_eq!(stats.mean(), Some(Nanoseconds(1_500)));
+/// ```
+#[macro_export]
+macro_rules! time_it {
+    ($clock:expr, $iterations:expr, $body:block) => {
+        $crate::stats::measure(&$clock, $iterations, || $body)
+    };
+}
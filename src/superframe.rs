@@ -0,0 +1,235 @@
+//! Slot schedules of beacon-based radio protocols
+
+use crate::{duration::Duration, fixed_point::FixedPoint, ConversionError, Instant};
+use core::convert::TryFrom;
+use num::{traits::WrappingAdd, Bounded};
+
+/// A slot of a [`Superframe`]
+#[derive(Debug)]
+pub struct Slot<Clock: crate::Clock> {
+    /// The start of the slot
+    pub open: Instant<Clock>,
+    /// The end of the slot (the start of the next one)
+    pub close: Instant<Clock>,
+    /// The earliest instant a transmission may start, the guard time after the slot opens
+    pub transmit_start: Instant<Clock>,
+    /// The instant a transmission must be complete by, the guard time before the slot closes
+    pub transmit_end: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Copy for Slot<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Slot<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Slot<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.open == other.open
+            && self.close == other.close
+            && self.transmit_start == other.transmit_start
+            && self.transmit_end == other.transmit_end
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Slot<Clock> {}
+
+/// The slot schedule of a beacon-based radio protocol
+///
+/// Each beacon starts a superframe of equal slots, the first slot opening with the beacon. Slots
+/// that don't fit entirely before the next beacon are not used. A guard time at each end of a slot
+/// absorbs the clock drift between nodes.
+///
+/// All instants are computed with wrap-aware math from the latest beacon, which should be updated
+/// with [`Superframe::resync()`] whenever a beacon is received.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, superframe::Superframe, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // a beacon every 100 ms, 10 ms slots with 500 µs guard times
+/// let superframe = Superframe::<Clock>::new(
+///     Instant::new(1_000),
+///     Milliseconds(100_u32),
+///     Milliseconds(10_u32),
+///     Microseconds(500_u32),
+/// )
+/// .unwrap();
+/// assert_eq!(superframe.slots(), 10);
+///
+/// let slot = superframe.slot(3).unwrap();
+/// assert_eq!(slot.open, Instant::new(31_000));
+/// assert_eq!(slot.transmit_start, Instant::new(31_500));
+/// assert_eq!(slot.transmit_end, Instant::new(40_500));
+///
+/// // slot 3 of the current superframe has opened, the next one is in the following superframe
+/// let slot = superframe.next_slot(Instant::new(250_000), 3).unwrap();
+/// assert_eq!(slot.open, Instant::new(331_000));
+/// ```
+#[derive(Debug)]
+pub struct Superframe<Clock: crate::Clock> {
+    beacon: Instant<Clock>,
+    beacon_interval: Clock::T,
+    slot_duration: Clock::T,
+    guard_time: Clock::T,
+}
+
+impl<Clock: crate::Clock> Superframe<Clock> {
+    /// Construct a `Superframe` starting with the given beacon
+    ///
+    /// # Errors
+    ///
+    /// - [`ConversionError::ConversionFailure`] : A duration doesn't fit in the clock's integer
+    ///   type
+    /// - [`ConversionError::Overflow`] : The beacon interval is longer than half the range of the
+    ///   clock
+    /// - [`ConversionError::DivByZero`] : The slot duration is shorter than one clock tick
+    ///
+    /// # Panics
+    ///
+    /// If the slot duration is longer than the beacon interval or if the guard times fill the slot
+    pub fn new<Interval, SlotDuration, GuardTime>(
+        beacon: Instant<Clock>,
+        beacon_interval: Interval,
+        slot_duration: SlotDuration,
+        guard_time: GuardTime,
+    ) -> Result<Self, ConversionError>
+    where
+        Interval: Duration + FixedPoint,
+        SlotDuration: Duration + FixedPoint,
+        GuardTime: Duration + FixedPoint,
+        Clock::T: TryFrom<Interval::T> + TryFrom<SlotDuration::T> + TryFrom<GuardTime::T>,
+    {
+        let beacon_interval = beacon_interval
+            .to_generic::<Clock::T>(Clock::SCALING_FACTOR)?
+            .integer();
+        let slot_duration = slot_duration
+            .to_generic::<Clock::T>(Clock::SCALING_FACTOR)?
+            .integer();
+        let guard_time = guard_time
+            .to_generic::<Clock::T>(Clock::SCALING_FACTOR)?
+            .integer();
+
+        if slot_duration == Clock::T::from(0) {
+            return Err(ConversionError::DivByZero);
+        }
+        if beacon_interval > Clock::T::max_value() / Clock::T::from(2) {
+            return Err(ConversionError::Overflow);
+        }
+        assert!(
+            slot_duration <= beacon_interval
+                && guard_time < slot_duration
+                && guard_time < slot_duration - guard_time,
+            "Invalid superframe"
+        );
+
+        Ok(Self {
+            beacon,
+            beacon_interval,
+            slot_duration,
+            guard_time,
+        })
+    }
+
+    /// Set the instant of the latest beacon
+    pub fn resync(&mut self, beacon: Instant<Clock>) {
+        self.beacon = beacon;
+    }
+
+    /// Returns the instant of the latest beacon
+    pub fn beacon(&self) -> Instant<Clock> {
+        self.beacon
+    }
+
+    /// Returns the number of slots in a superframe
+    pub fn slots(&self) -> Clock::T {
+        self.beacon_interval / self.slot_duration
+    }
+
+    /// Returns the slot with the given index in the superframe of the latest beacon
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : `index` is not less than [`Superframe::slots()`]
+    pub fn slot(&self, index: Clock::T) -> Result<Slot<Clock>, ConversionError> {
+        self.slot_after(self.beacon, index)
+    }
+
+    /// Returns the first slot with the given index that opens at or after `now`
+    ///
+    /// If `now` is before the latest beacon, this is the slot of the latest beacon's superframe.
+    /// Expected beacons are extrapolated from the latest beacon.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : `index` is not less than [`Superframe::slots()`]
+    pub fn next_slot(
+        &self,
+        now: Instant<Clock>,
+        index: Clock::T,
+    ) -> Result<Slot<Clock>, ConversionError> {
+        let beacon = self.beacon_at_or_before(now);
+        let slot = self.slot_after(beacon, index)?;
+
+        match now.checked_duration_since(&slot.open) {
+            Some(late) if late.integer() > Clock::T::from(0) => {
+                self.slot_after(self.offset(beacon, self.beacon_interval), index)
+            }
+            _ => Ok(slot),
+        }
+    }
+
+    /// Returns the latest (expected) beacon at or before `now`, or the latest beacon if `now` is
+    /// before it
+    fn beacon_at_or_before(&self, now: Instant<Clock>) -> Instant<Clock> {
+        match now.checked_duration_since(&self.beacon) {
+            Some(elapsed) => {
+                let elapsed = elapsed.integer();
+                self.offset(self.beacon, elapsed - elapsed % self.beacon_interval)
+            }
+            None => self.beacon,
+        }
+    }
+
+    fn slot_after(
+        &self,
+        beacon: Instant<Clock>,
+        index: Clock::T,
+    ) -> Result<Slot<Clock>, ConversionError> {
+        if index >= self.slots() {
+            return Err(ConversionError::Overflow);
+        }
+
+        // index < slots, so the offsets are within the beacon interval
+        let open = self.offset(beacon, index * self.slot_duration);
+        let close = self.offset(open, self.slot_duration);
+
+        Ok(Slot {
+            open,
+            close,
+            transmit_start: self.offset(open, self.guard_time),
+            transmit_end: self.offset(open, self.slot_duration - self.guard_time),
+        })
+    }
+
+    fn offset(&self, instant: Instant<Clock>, ticks: Clock::T) -> Instant<Clock> {
+        Instant::new(
+            instant
+                .duration_since_epoch()
+                .integer()
+                .wrapping_add(&ticks),
+        )
+    }
+}
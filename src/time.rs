@@ -0,0 +1,186 @@
+//! Conversions to and from [`time::Duration`]
+//!
+//! A `time::Duration` is signed while the [`Duration`](crate::duration::Duration)s are not:
+//!
+//! - The durations convert to a (non-negative) `time::Duration`, truncated to whole nanoseconds.
+//! - A negative `time::Duration` never converts to a duration
+//!   ([`ConversionError::NegDuration`]), a non-negative one is truncated to whole units.
+//! - A [`SignedGeneric`] converts to a `time::Duration` of the same sign (truncated toward `0`, so
+//!   `x` and `-x` have the same magnitude). A `time::Duration` converts to a nanoseconds
+//!   [`SignedGeneric`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use embedded_time::{duration::*, ConversionError};
+//! # use core::convert::TryFrom;
+//! assert_eq!(
+//!     time::Duration::try_from(Milliseconds(2_500_u32)),
+//!     Ok(time::Duration::milliseconds(2_500))
+//! );
+//! assert_eq!(Seconds::<u32>::try_from(time::Duration::milliseconds(2_500)), Ok(Seconds(2_u32)));
+//! assert_eq!(
+//!     Seconds::<u32>::try_from(time::Duration::milliseconds(-2_500)),
+//!     Err(ConversionError::NegDuration)
+//! );
+//!
+//! let offset = SignedGeneric::new(-3_i32, Fraction::new(1, 1_000));
+//! assert_eq!(time::Duration::try_from(offset), Ok(time::Duration::milliseconds(-3)));
+//! assert_eq!(
+//!     SignedGeneric::<i64>::try_from(time::Duration::milliseconds(-3)),
+//!     Ok(SignedGeneric::new(-3_000_000_i64, Fraction::new(1, 1_000_000_000)))
+//! );
+//! ```
+
+use crate::{
+    duration::{self, SignedGeneric},
+    fixed_point::{self, FixedPoint, RoundingMode},
+    fraction::Fraction,
+    ConversionError, TimeInt,
+};
+use core::convert::TryFrom;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+const NANOSECOND: Fraction = Fraction::new(1, 1_000_000_000);
+
+/// Converts `ticks` of the `scaling_factor` to a [`time::Duration`] (truncated
+/// toward `0` to nanoseconds), negated if `negative`
+fn to_time_duration(
+    ticks: u128,
+    scaling_factor: Fraction,
+    negative: bool,
+) -> Result<::time::Duration, ConversionError> {
+    let nanos = fixed_point::rescale(ticks, scaling_factor, NANOSECOND, RoundingMode::Floor)?;
+    let (secs, nanos) = (
+        i128::try_from(nanos / NANOS_PER_SEC).map_err(|_| ConversionError::Overflow)?,
+        (nanos % NANOS_PER_SEC) as i32,
+    );
+    let (secs, nanos) = if negative {
+        (-secs, -nanos)
+    } else {
+        (secs, nanos)
+    };
+
+    Ok(::time::Duration::new(
+        i64::try_from(secs).map_err(|_| ConversionError::Overflow)?,
+        nanos,
+    ))
+}
+
+/// Converts a [`time::Duration`] to ticks of the `scaling_factor` (truncated)
+fn from_time_duration(
+    duration: ::time::Duration,
+    scaling_factor: Fraction,
+) -> Result<u128, ConversionError> {
+    if duration.is_negative() {
+        return Err(ConversionError::NegDuration);
+    }
+
+    fixed_point::rescale(
+        duration.whole_nanoseconds().unsigned_abs(),
+        NANOSECOND,
+        scaling_factor,
+        RoundingMode::Floor,
+    )
+}
+
+macro_rules! impl_time_duration {
+    ( $($name:ident),* ) => {$(
+        /// Truncated to nanoseconds
+        ///
+        /// # Errors
+        ///
+        /// [`ConversionError::Overflow`] : The duration doesn't fit a `time::Duration`
+        impl<T: TimeInt> TryFrom<duration::$name<T>> for ::time::Duration {
+            type Error = ConversionError;
+
+            fn try_from(duration: duration::$name<T>) -> Result<Self, Self::Error> {
+                let ticks = duration.integer().to_u128().ok_or(ConversionError::Overflow)?;
+                to_time_duration(ticks, duration::$name::<T>::SCALING_FACTOR, false)
+            }
+        }
+
+        /// Truncated to whole units
+        ///
+        /// # Errors
+        ///
+        /// - [`ConversionError::NegDuration`] : The `time::Duration` is negative
+        /// - [`ConversionError::ConversionFailure`] : The duration doesn't fit the integer type
+        impl<T: TimeInt + TryFrom<u128>> TryFrom<::time::Duration> for duration::$name<T> {
+            type Error = ConversionError;
+
+            fn try_from(duration: ::time::Duration) -> Result<Self, Self::Error> {
+                let ticks = from_time_duration(duration, Self::SCALING_FACTOR)?;
+                T::try_from(ticks)
+                    .map(Self::new)
+                    .map_err(|_| ConversionError::ConversionFailure)
+            }
+        }
+    )*};
+}
+
+impl_time_duration![
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds
+];
+
+/// Truncated to nanoseconds
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The duration doesn't fit a `time::Duration`
+/// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+impl<T: TimeInt> TryFrom<duration::Generic<T>> for ::time::Duration {
+    type Error = ConversionError;
+
+    fn try_from(duration: duration::Generic<T>) -> Result<Self, Self::Error> {
+        let ticks = duration
+            .integer()
+            .to_u128()
+            .ok_or(ConversionError::Overflow)?;
+        to_time_duration(ticks, *duration.scaling_factor(), false)
+    }
+}
+
+/// Truncated toward `0` to nanoseconds
+///
+/// # Errors
+///
+/// - [`ConversionError::Overflow`] : The duration doesn't fit a `time::Duration`
+/// - [`ConversionError::DivByZero`] : The _scaling factor_ has a `0` denominator
+impl<T: Copy> TryFrom<SignedGeneric<T>> for ::time::Duration
+where
+    i128: From<T>,
+{
+    type Error = ConversionError;
+
+    fn try_from(duration: SignedGeneric<T>) -> Result<Self, Self::Error> {
+        let integer = i128::from(duration.integer());
+        to_time_duration(
+            integer.unsigned_abs(),
+            *duration.scaling_factor(),
+            integer < 0,
+        )
+    }
+}
+
+/// Converted to nanoseconds (a `1/1_000_000_000` _scaling factor_)
+///
+/// # Errors
+///
+/// [`ConversionError::ConversionFailure`] : The nanoseconds don't fit the integer type
+impl<T: Copy + TryFrom<i128>> TryFrom<::time::Duration> for SignedGeneric<T> {
+    type Error = ConversionError;
+
+    fn try_from(duration: ::time::Duration) -> Result<Self, Self::Error> {
+        T::try_from(duration.whole_nanoseconds())
+            .map(|nanos| Self::new(nanos, NANOSECOND))
+            .map_err(|_| ConversionError::ConversionFailure)
+    }
+}
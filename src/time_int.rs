@@ -1,5 +1,5 @@
 use crate::fraction::Fraction;
-use core::{fmt, ops};
+use core::{convert::TryFrom, fmt, ops};
 
 /// The core inner-type trait for time-related types
 pub trait TimeInt:
@@ -12,7 +12,8 @@ pub trait TimeInt:
     + num::CheckedSub
     + num::CheckedMul
     + num::CheckedDiv
-    + From<u32>
+    + From<u8>
+    + TryFrom<u32>
     + ops::Mul<Fraction, Output = Self>
     + ops::Div<Fraction, Output = Self>
     + fmt::Display
@@ -22,24 +23,216 @@ pub trait TimeInt:
     ///
     /// Returns truncated (rounded toward `0`) integer or [`None`] upon failure
     fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
-        self.checked_mul(&(*fraction.numerator()).into())?
-            .checked_div(&(*fraction.denominator()).into())
+        self.checked_mul(&Self::try_from(*fraction.numerator()).ok()?)?
+            .checked_div(&Self::try_from(*fraction.denominator()).ok()?)
+    }
+
+    /// Returns the value as a [`u128`]
+    ///
+    /// Not generally useful to call directly, it allows exact comparisons of values of any
+    /// _scaling factors_. [`None`] for integer types that aren't primitive integers.
+    #[doc(hidden)]
+    fn to_u128(&self) -> Option<u128> {
+        None
     }
 
     /// Checked integer / [`Fraction`] = integer
     ///
     /// Returns truncated (rounded toward `0`) integer or [`None`] upon failure
     fn checked_div_fraction(&self, fraction: &Fraction) -> Option<Self> {
-        self.checked_mul_fraction(&fraction.recip())
+        self.checked_mul_fraction(&fraction.checked_recip()?)
+    }
+}
+
+// The primitive integers share the non-generic `mul_fraction_*()` functions rather than each
+// having its own copy of the math
+macro_rules! impl_narrow_time_int {
+    ($($int:ty),*) => {$(
+        /// The arithmetic is done in [`u64`] so the _scaling factor_ can be larger than the type
+        impl TimeInt for $int {
+            fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
+                Self::try_from(mul_fraction_u64(u64::from(*self), fraction, u64::MAX)?).ok()
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                Some((*self).into())
+            }
+        }
+    )*};
+}
+
+impl_narrow_time_int!(u8, u16);
+
+impl TimeInt for u32 {
+    fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
+        // the product is limited to `u32::MAX`, as if done in `u32`
+        Self::try_from(mul_fraction_u64(
+            u64::from(*self),
+            fraction,
+            u32::MAX.into(),
+        )?)
+        .ok()
     }
+
+    fn to_u128(&self) -> Option<u128> {
+        Some((*self).into())
+    }
+}
+
+impl TimeInt for u64 {
+    fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
+        mul_fraction_u64(*self, fraction, u64::MAX)
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        Some((*self).into())
+    }
+}
+
+impl TimeInt for u128 {
+    fn checked_mul_fraction(&self, fraction: &Fraction) -> Option<Self> {
+        mul_fraction_u128(*self, fraction)
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        Some(*self)
+    }
+}
+
+/// Returns `value` × `fraction` (truncated), or [`None`] if `value` × numerator is more than `max`
+/// or the denominator is `0`
+fn mul_fraction_u64(value: u64, fraction: &Fraction, max: u64) -> Option<u64> {
+    let product = value
+        .checked_mul(u64::from(*fraction.numerator()))
+        .filter(|product| *product <= max)?;
+
+    fast_div::u64(product, *fraction.denominator())
+}
+
+/// Returns `value` × `fraction` (truncated), or [`None`] upon overflow or a `0` denominator
+fn mul_fraction_u128(value: u128, fraction: &Fraction) -> Option<u128> {
+    value
+        .checked_mul(u128::from(*fraction.numerator()))?
+        .checked_div(u128::from(*fraction.denominator()))
 }
 
-impl TimeInt for u32 {}
-impl TimeInt for u64 {}
+/// Division by the common _scaling factor_ denominators without a division instruction
+///
+/// Targets without a hardware divider (eg. Cortex-M0) call into a library routine for every
+/// division, and even those with one (eg. Cortex-M3/4) call `__aeabi_uldivmod` for 64-bit
+/// divisions. Dividing by a power of two is a shift, and dividing by a power of ten (eg.
+/// milliseconds to seconds) is a multiplication by a precomputed reciprocal and a shift. Other
+/// divisors are divided as usual.
+pub(crate) mod fast_div {
+    use core::convert::TryFrom;
+
+    /// The multipliers and shifts dividing by 10 to 1,000,000,000
+    ///
+    /// `10^k = 2^k × 5^k`, so the value is first shifted right by `k`, leaving fewer bits to
+    /// divide by `5^k`. This keeps all the multipliers within the width of the value.
+    const POWERS_OF_TEN: [Reciprocal; 9] = {
+        let mut reciprocals = [Reciprocal {
+            divisor: 0,
+            pre_shift: 0,
+            multiplier_32: 0,
+            shift_32: 0,
+            multiplier_64: 0,
+            shift_64: 0,
+        }; 9];
+        let mut i = 0;
+        while i < reciprocals.len() {
+            let pre_shift = i as u32 + 1;
+            let (divisor, odd) = (10_u32.pow(pre_shift), 5_u32.pow(pre_shift));
+            let (multiplier_32, shift_32) = reciprocal(odd, 32 - pre_shift, 32);
+            let (multiplier_64, shift_64) = reciprocal(odd, 64 - pre_shift, 64);
+            reciprocals[i] = Reciprocal {
+                divisor,
+                pre_shift,
+                multiplier_32: multiplier_32 as u32,
+                shift_32,
+                multiplier_64: multiplier_64 as u64,
+                shift_64,
+            };
+            i += 1;
+        }
+        reciprocals
+    };
+
+    #[derive(Copy, Clone)]
+    struct Reciprocal {
+        divisor: u32,
+        pre_shift: u32,
+        multiplier_32: u32,
+        shift_32: u32,
+        multiplier_64: u64,
+        shift_64: u32,
+    }
+
+    /// Returns the smallest `(multiplier, shift)` such that `x * multiplier >> shift == x / divisor`
+    /// for every `bits`-bit `x`, with `multiplier` fitting in `width` bits
+    ///
+    /// `x * ceil(2^shift / divisor) / 2^shift` is exact if the rounding error of the multiplier,
+    /// `multiplier * divisor - 2^shift`, is at most `2^(shift - bits)`.
+    const fn reciprocal(divisor: u32, bits: u32, width: u32) -> (u128, u32) {
+        let divisor = divisor as u128;
+        let mut shift = bits;
+        while shift < bits + width {
+            let power = 1_u128 << shift;
+            let multiplier = power.div_ceil(divisor);
+            if multiplier >> width == 0 && multiplier * divisor - power <= 1_u128 << (shift - bits)
+            {
+                return (multiplier, shift);
+            }
+            shift += 1;
+        }
+
+        panic!("No reciprocal");
+    }
+
+    /// Returns `value / divisor` or [`None`] if `divisor` is `0`
+    pub(crate) fn u32(value: u32, divisor: u32) -> Option<u32> {
+        if divisor.is_power_of_two() {
+            return Some(value >> divisor.trailing_zeros());
+        }
+
+        for reciprocal in &POWERS_OF_TEN {
+            if reciprocal.divisor == divisor {
+                let value = u64::from(value >> reciprocal.pre_shift);
+                return Some(
+                    ((value * u64::from(reciprocal.multiplier_32)) >> reciprocal.shift_32) as u32,
+                );
+            }
+        }
+
+        value.checked_div(divisor)
+    }
+
+    /// Returns `value / divisor` or [`None`] if `divisor` is `0`
+    pub(crate) fn u64(value: u64, divisor: u32) -> Option<u64> {
+        if let Ok(value) = u32::try_from(value) {
+            return self::u32(value, divisor).map(u64::from);
+        }
+        if divisor.is_power_of_two() {
+            return Some(value >> divisor.trailing_zeros());
+        }
+
+        for reciprocal in &POWERS_OF_TEN {
+            if reciprocal.divisor == divisor {
+                let value = u128::from(value >> reciprocal.pre_shift);
+                return Some(
+                    ((value * u128::from(reciprocal.multiplier_64)) >> reciprocal.shift_64) as u64,
+                );
+            }
+        }
+
+        value.checked_div(u64::from(divisor))
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use crate::{fraction::Fraction, time_int::TimeInt};
+    use core::convert::TryFrom;
 
     #[test]
     fn checked_integer_mul_fraction() {
@@ -55,6 +248,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fast_div() {
+        use super::fast_div;
+
+        let divisors = (0..=9).map(|exponent| 10_u32.pow(exponent)).chain([
+            0,
+            2,
+            3,
+            48,
+            1_024,
+            32_768,
+            1 << 31,
+            1_000_001,
+            u32::MAX,
+        ]);
+        let mut random = 0x2545_f491_4f6c_dd1d_u64;
+
+        for divisor in divisors {
+            let mut values = vec![0, 1, u64::from(u32::MAX), u64::from(u32::MAX) + 1, u64::MAX];
+            if let (Some(max_32), Some(max_64)) = (
+                u32::MAX.checked_div(divisor),
+                u64::MAX.checked_div(u64::from(divisor)),
+            ) {
+                for multiple in [1, 7, u64::from(max_32), max_64] {
+                    let value = multiple * u64::from(divisor);
+                    values.extend([value.saturating_sub(1), value, value.saturating_add(1)]);
+                }
+            }
+            for _ in 0..10_000 {
+                // xorshift
+                random ^= random << 13;
+                random ^= random >> 7;
+                random ^= random << 17;
+                values.extend([random, random >> 32, random >> (random % 64)]);
+            }
+
+            for value in values {
+                assert_eq!(
+                    fast_div::u64(value, divisor),
+                    value.checked_div(u64::from(divisor)),
+                    "{} / {}",
+                    value,
+                    divisor
+                );
+                if let Ok(value) = u32::try_from(value) {
+                    assert_eq!(
+                        fast_div::u32(value, divisor),
+                        value.checked_div(divisor),
+                        "{} / {}",
+                        value,
+                        divisor
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn checked_integer_div_fraction() {
         assert_eq!(
@@ -67,5 +317,7 @@ mod tests {
             8_u32.checked_div_fraction(&Fraction::new(3, 1)),
             Some(2_u32)
         );
+
+        assert_eq!(8_u32.checked_div_fraction(&Fraction::new(0, 1)), None);
     }
 }
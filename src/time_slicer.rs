@@ -0,0 +1,80 @@
+//! Time-slicing for cooperative tasks
+
+use crate::{duration::Duration, fixed_point::FixedPoint, ConversionError, Instant};
+use core::convert::TryFrom;
+
+/// Grants cooperative tasks a bounded slice of time
+///
+/// A superloop starts a slice before running each task with [`TimeSlicer::start_slice()`]. The
+/// task then periodically calls [`TimeSlicer::should_yield()`] and returns control to the loop
+/// once its slice is exhausted, bounding the latency seen by every other task.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{fraction::Fraction, duration::*, time_slicer::TimeSlicer, Instant};
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// let mut slicer = TimeSlicer::<Clock>::new(Milliseconds(5_u32)).unwrap();
+///
+/// slicer.start_slice(Instant::new(100));
+/// assert!(!slicer.should_yield(Instant::new(104)));
+/// assert!(slicer.should_yield(Instant::new(105)));
+/// ```
+#[derive(Debug)]
+pub struct TimeSlicer<Clock: crate::Clock> {
+    slice: Clock::T,
+    slice_start: Option<Instant<Clock>>,
+}
+
+impl<Clock: crate::Clock> TimeSlicer<Clock> {
+    /// Construct a new `TimeSlicer` granting slices of the given duration
+    ///
+    /// # Errors
+    ///
+    /// Failure will only occur if the slice duration can't be represented in ticks of the clock.
+    ///
+    /// - [`ConversionError::Unspecified`]
+    /// - [`ConversionError::ConversionFailure`]
+    pub fn new<Dur>(slice: Dur) -> Result<Self, ConversionError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        Ok(Self {
+            slice: slice.into_ticks(Clock::SCALING_FACTOR)?,
+            slice_start: None,
+        })
+    }
+
+    /// Start a new slice at `now`
+    pub fn start_slice(&mut self, now: Instant<Clock>) {
+        self.slice_start = Some(now);
+    }
+
+    /// End the current slice
+    pub fn end_slice(&mut self) {
+        self.slice_start = None;
+    }
+
+    /// Returns whether the current task should yield as of `now`
+    ///
+    /// This is `true` once the full slice duration has elapsed or if no slice has been started.
+    pub fn should_yield(&self, now: Instant<Clock>) -> bool {
+        match self.slice_start {
+            Some(slice_start) => match now.checked_duration_since(&slice_start) {
+                Some(elapsed) => elapsed.integer() >= self.slice,
+                None => false,
+            },
+            None => true,
+        }
+    }
+}
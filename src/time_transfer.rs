@@ -0,0 +1,127 @@
+//! Two-way time transfer (the request/response exchange of SNTP and PTP)
+
+use crate::{duration, ConversionError, Instant};
+use core::convert::TryFrom;
+
+/// The four timestamps of a request/response exchange with a remote clock
+///
+/// The remote timestamps are expressed as ticks of the same [`Clock`](crate::Clock) type as the
+/// local ones (eg. both in microseconds). Assuming the request and response take the same time in
+/// transit, the remote clock is ahead of the local clock by
+/// `((request_received - request_sent) + (response_sent - response_received)) / 2`.
+///
+/// All differences are computed with wrap-aware math, so each pair of timestamps must be within
+/// half the range of the clock of each other.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, time_transfer::Exchange, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// // the remote clock is 1 ms behind, 300 µs each way in transit, 50 µs to respond
+/// let exchange = Exchange::<Clock> {
+///     request_sent: Instant::new(10_000),
+///     request_received: Instant::new(9_300),
+///     response_sent: Instant::new(9_350),
+///     response_received: Instant::new(10_650),
+/// };
+///
+/// assert_eq!(exchange.offset(), Ok(SignedGeneric::new(-1_000_i64, Fraction::new(1, 1_000_000))));
+/// assert_eq!(
+///     Microseconds::<u32>::try_from(exchange.round_trip_delay().unwrap()),
+///     Ok(Microseconds(600_u32))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Exchange<Clock: crate::Clock> {
+    /// When the request was sent (local clock)
+    pub request_sent: Instant<Clock>,
+    /// When the request was received (remote clock)
+    pub request_received: Instant<Clock>,
+    /// When the response was sent (remote clock)
+    pub response_sent: Instant<Clock>,
+    /// When the response was received (local clock)
+    pub response_received: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Copy for Exchange<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Exchange<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Exchange<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.request_sent == other.request_sent
+            && self.request_received == other.request_received
+            && self.response_sent == other.response_sent
+            && self.response_received == other.response_received
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Exchange<Clock> {}
+
+impl<Clock: crate::Clock> Exchange<Clock> {
+    /// Returns the offset of the remote clock from the local clock (positive if it's ahead)
+    ///
+    /// The offset is truncated (rounded toward `0`) to whole ticks.
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::Overflow`] : The offset doesn't fit in an [`i64`]
+    pub fn offset(&self) -> Result<duration::SignedGeneric<i64>, ConversionError>
+    where
+        i128: TryFrom<Clock::T>,
+    {
+        let outbound = self
+            .request_received
+            .signed_duration_since::<i128>(&self.request_sent);
+        let inbound = self
+            .response_sent
+            .signed_duration_since::<i128>(&self.response_received);
+
+        let offset = outbound
+            .zip(inbound)
+            .and_then(|(outbound, inbound)| outbound.integer().checked_add(inbound.integer()))
+            .map(|sum| sum / 2)
+            .ok_or(ConversionError::Overflow)?;
+
+        Ok(duration::SignedGeneric::new(
+            i64::try_from(offset).map_err(|_| ConversionError::Overflow)?,
+            Clock::SCALING_FACTOR,
+        ))
+    }
+
+    /// Returns the time spent in transit, excluding the remote's response time
+    ///
+    /// # Errors
+    ///
+    /// [`ConversionError::NegDuration`] : A timestamp is earlier than the one it should follow,
+    /// or the remote took longer to respond than the whole exchange
+    pub fn round_trip_delay(&self) -> Result<duration::Generic<Clock::T>, ConversionError> {
+        let exchange = self
+            .response_received
+            .checked_duration_since(&self.request_sent)
+            .ok_or(ConversionError::NegDuration)?;
+        let response = self
+            .response_sent
+            .checked_duration_since(&self.request_received)
+            .ok_or(ConversionError::NegDuration)?;
+
+        exchange
+            .checked_sub(&response)
+            .map_err(|_| ConversionError::NegDuration)
+    }
+}
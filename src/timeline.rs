@@ -0,0 +1,155 @@
+//! Recording of timestamped events for post-mortem analysis
+
+use crate::{clock, duration, Instant, TimeError};
+
+/// An event recorded in a [`Timeline`]
+#[derive(Debug)]
+pub struct Event<Clock: crate::Clock> {
+    /// When the event happened
+    pub instant: Instant<Clock>,
+    /// The application-defined event code
+    pub code: u8,
+}
+
+impl<Clock: crate::Clock> Copy for Event<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Event<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Event<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant == other.instant && self.code == other.code
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Event<Clock> {}
+
+/// A black box of the latest `N` events
+///
+/// Events are kept in the order they were recorded, the oldest being overwritten once the
+/// timeline is full. As the order doesn't depend on the instants, it's preserved across
+/// wrap-arounds of the clock. The durations between events are also wrap-aware (see
+/// [`Instant::checked_duration_since()`]).
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, timeline::{Event, Timeline}, Instant};
+/// # use core::convert::TryFrom;
+/// # #[derive(Debug)]
+/// struct Clock;
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {unimplemented!()}
+/// }
+///
+/// const BOOT: u8 = 0;
+/// const SENSOR_TIMEOUT: u8 = 1;
+/// const FAULT: u8 = 2;
+///
+/// let mut timeline = Timeline::<Clock, 2>::new();
+/// timeline.record(Instant::new(u32::MAX - 9), BOOT);
+/// timeline.record(Instant::new(u32::MAX), SENSOR_TIMEOUT);
+/// // the counter wraps, and the oldest event is overwritten
+/// timeline.record(Instant::new(40), FAULT);
+///
+/// assert!(timeline.iter().map(|event| event.code).eq([SENSOR_TIMEOUT, FAULT].iter().copied()));
+///
+/// let mut deltas = timeline.deltas();
+/// assert_eq!(
+///     Milliseconds::<u32>::try_from(deltas.next().unwrap().unwrap()),
+///     Ok(Milliseconds(41_u32))
+/// );
+/// assert_eq!(deltas.next(), None);
+/// ```
+#[derive(Debug)]
+pub struct Timeline<Clock: crate::Clock, const N: usize> {
+    events: [Option<Event<Clock>>; N],
+    next: usize,
+}
+
+impl<Clock: crate::Clock, const N: usize> Timeline<Clock, N> {
+    /// Construct an empty `Timeline`
+    ///
+    /// # Panics
+    ///
+    /// If `N` is `0`
+    pub fn new() -> Self {
+        assert!(N > 0, "Invalid capacity");
+
+        Self {
+            events: [None; N],
+            next: 0,
+        }
+    }
+
+    /// Record an event, overwriting the oldest one if the timeline is full
+    pub fn record(&mut self, instant: Instant<Clock>, code: u8) {
+        self.events[self.next] = Some(Event { instant, code });
+        self.next = (self.next + 1) % N;
+    }
+
+    /// Record an event at the current instant of the clock
+    ///
+    /// # Errors
+    ///
+    /// [`crate::clock::Error`] : The clock could not be read, nothing is recorded
+    pub fn record_now(&mut self, clock: &Clock, code: u8) -> Result<(), crate::clock::Error> {
+        self.record(clock::try_now(clock)?, code);
+
+        Ok(())
+    }
+
+    /// Returns the number of recorded events
+    pub fn len(&self) -> usize {
+        self.events.iter().filter(|event| event.is_some()).count()
+    }
+
+    /// Returns whether no events are recorded
+    pub fn is_empty(&self) -> bool {
+        self.events[0].is_none()
+    }
+
+    /// Returns the most recent event
+    pub fn latest(&self) -> Option<Event<Clock>> {
+        self.events[(self.next + N - 1) % N]
+    }
+
+    /// Discard all events
+    pub fn clear(&mut self) {
+        self.events = [None; N];
+        self.next = 0;
+    }
+
+    /// Returns an iterator over the events, from the oldest to the most recent
+    pub fn iter(&self) -> impl Iterator<Item = Event<Clock>> + '_ {
+        // slots after `next` are either empty or older than those before it
+        self.events[self.next..]
+            .iter()
+            .chain(self.events[..self.next].iter())
+            .flatten()
+            .copied()
+    }
+
+    /// Returns an iterator over the durations between consecutive events
+    ///
+    /// `n` events yield `n - 1` durations, each being a [`TimeError::NegDuration`] if the event
+    /// was recorded with an earlier instant than the one before it.
+    pub fn deltas(
+        &self,
+    ) -> impl Iterator<Item = Result<duration::Generic<Clock::T>, TimeError>> + '_ {
+        crate::deltas::deltas(self.iter().map(|event| event.instant))
+    }
+}
+
+impl<Clock: crate::Clock, const N: usize> Default for Timeline<Clock, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,12 +2,13 @@
 
 use crate::fraction::Fraction;
 use crate::{
+    clock,
     duration::{self, *},
     fixed_point::FixedPoint,
     timer::param::*,
     ConversionError, Instant, TimeError,
 };
-use core::{convert::TryFrom, marker::PhantomData, ops::Add, prelude::v1::*};
+use core::{convert::TryFrom, marker::PhantomData, ops::Add};
 
 /// Timer type/state parameters
 pub mod param {
@@ -43,10 +44,10 @@ pub struct Timer<'a, Type, State, Clock: crate::Clock, Dur: Duration> {
     _state: PhantomData<State>,
 }
 
-impl<'a, Clock: crate::Clock, Dur: Duration> Timer<'_, param::None, param::None, Clock, Dur> {
+impl<Clock: crate::Clock, Dur: Duration> Timer<'_, param::None, param::None, Clock, Dur> {
     /// Construct a new, `OneShot` `Timer`
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(clock: &Clock, duration: Dur) -> Timer<OneShot, Armed, Clock, Dur> {
+    pub fn new(clock: &Clock, duration: Dur) -> Timer<'_, OneShot, Armed, Clock, Dur> {
         Timer::<OneShot, Armed, Clock, Dur> {
             clock,
             duration,
@@ -91,9 +92,7 @@ impl<'a, Type, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, Armed, Clock,
         Ok(Timer::<Type, Running, Clock, Dur> {
             clock: self.clock,
             duration: self.duration,
-            expiration: self
-                .clock
-                .try_now()?
+            expiration: clock::try_now(self.clock)?
                 .checked_add(self.duration)
                 .ok_or(ConversionError::Overflow)?,
             _type: PhantomData,
@@ -104,7 +103,7 @@ impl<'a, Type, Clock: crate::Clock, Dur: Duration> Timer<'a, Type, Armed, Clock,
 
 impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, Dur> {
     fn _is_expired(&self) -> Result<bool, TimeError> {
-        Ok(self.clock.try_now()? >= self.expiration)
+        Ok(clock::try_now(self.clock)? >= self.expiration)
     }
 
     /// Returns the [`Duration`] of time elapsed since it was started
@@ -118,9 +117,7 @@ impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, D
         Dur::T: TryFrom<Clock::T>,
         Clock::T: TryFrom<Dur::T>,
     {
-        let generic_duration = self
-            .clock
-            .try_now()?
+        let generic_duration = clock::try_now(self.clock)?
             .checked_duration_since(
                 &(self
                     .expiration
@@ -145,7 +142,7 @@ impl<Type, Clock: crate::Clock, Dur: Duration> Timer<'_, Type, Running, Clock, D
     {
         let result = self
             .expiration
-            .checked_duration_since(&self.clock.try_now()?)
+            .checked_duration_since(&clock::try_now(self.clock)?)
             .or_else(|| {
                 Some(duration::Generic::<Clock::T>::new(
                     0.into(),
@@ -170,6 +167,18 @@ impl<'a, Clock: crate::Clock, Dur: Duration> Timer<'a, OneShot, Running, Clock,
         ))
     }
 
+    /// Check whether the timer has expired, without blocking
+    ///
+    /// Returns [`nb::Error::WouldBlock`] until the timer has expired. The timer is not restarted.
+    #[cfg(feature = "embedded-hal")]
+    pub fn try_wait(&self) -> nb::Result<(), TimeError> {
+        if self._is_expired()? {
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
     /// Check whether the timer has expired
     ///
     /// The timer is not restarted
@@ -200,6 +209,22 @@ impl<Clock: crate::Clock, Dur: Duration> Timer<'_, Periodic, Running, Clock, Dur
         })
     }
 
+    /// Check whether a _periodic_ timer has elapsed, without blocking
+    ///
+    /// Returns [`nb::Error::WouldBlock`] until the timer has elapsed. The timer is restarted once
+    /// it has elapsed.
+    #[cfg(feature = "embedded-hal")]
+    pub fn try_wait(&mut self) -> nb::Result<(), TimeError>
+    where
+        Instant<Clock>: Add<Dur, Output = Instant<Clock>>,
+    {
+        if self.period_complete()? {
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
     /// Check whether a _periodic_ timer has elapsed
     ///
     /// The timer is restarted if it has elapsed.
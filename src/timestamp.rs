@@ -0,0 +1,112 @@
+//! Timestamps for age checks
+
+use crate::{clock, duration, duration::Duration, fixed_point::FixedPoint, Instant, TimeError};
+use core::convert::TryFrom;
+
+/// The [`Instant`] at which something happened, for checking how long ago that was
+///
+/// A lighter-weight alternative to a [`Timer`](crate::Timer) for cache-expiry style checks: the
+/// timestamp holds neither a reference to the clock nor a duration, so it can be stored alongside
+/// the data it stamps and checked against any maximum age later.
+///
+/// # Examples
+///
+/// ```rust
+/// # use embedded_time::{duration::*, timestamp::Timestamp, Instant};
+/// # use core::cell::Cell;
+/// # #[derive(Debug)]
+/// struct Clock(Cell<u32>);
+/// impl embedded_time::Clock for Clock {
+///     type T = u32;
+/// # type Error = embedded_time::clock::Error;
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///     // ...
+/// # fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+/// #     Ok(Instant::new(self.0.get()))
+/// # }
+/// }
+///
+/// let clock = Clock(Cell::new(0));
+/// let stamp = Timestamp::new(&clock).unwrap();
+///
+/// clock.0.set(300);
+/// assert_eq!(stamp.elapsed(&clock), Ok(Generic::new(300_u32, Fraction::new(1, 1_000))));
+/// assert_eq!(stamp.age_exceeds(&clock, Milliseconds(250_u32)), Ok(true));
+/// assert_eq!(stamp.age_exceeds(&clock, Seconds(1_u32)), Ok(false));
+/// ```
+#[derive(Debug)]
+pub struct Timestamp<Clock: crate::Clock> {
+    instant: Instant<Clock>,
+}
+
+impl<Clock: crate::Clock> Copy for Timestamp<Clock> {}
+
+impl<Clock: crate::Clock> Clone for Timestamp<Clock> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Clock: crate::Clock> PartialEq for Timestamp<Clock> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant == other.instant
+    }
+}
+
+impl<Clock: crate::Clock> Eq for Timestamp<Clock> {}
+
+impl<Clock: crate::Clock> Timestamp<Clock> {
+    /// Capture the current instant of the clock
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the clock
+    pub fn new(clock: &Clock) -> Result<Self, clock::Error> {
+        Ok(Self {
+            instant: clock::try_now(clock)?,
+        })
+    }
+
+    /// Returns the captured instant
+    pub fn instant(&self) -> Instant<Clock> {
+        self.instant
+    }
+
+    /// Returns the time elapsed since the timestamp was captured
+    ///
+    /// As with all [`Instant`] differences, the result is only valid for less than half the range
+    /// of the clock's integer type.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    /// - [`TimeError::NegDuration`] : The clock reads earlier than the timestamp
+    pub fn elapsed(&self, clock: &Clock) -> Result<duration::Generic<Clock::T>, TimeError> {
+        clock::try_now(clock)?
+            .checked_duration_since(&self.instant)
+            .ok_or(TimeError::NegDuration)
+    }
+
+    /// Returns whether more than `max_age` has elapsed since the timestamp was captured
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeError::Clock`] : The clock could not be read
+    /// - [`TimeError::NegDuration`] : The clock reads earlier than the timestamp
+    /// - Any conversion error of `max_age` into clock ticks
+    pub fn age_exceeds<Dur>(&self, clock: &Clock, max_age: Dur) -> Result<bool, TimeError>
+    where
+        Dur: Duration + FixedPoint,
+        Clock::T: TryFrom<Dur::T>,
+    {
+        let max_age = max_age.into_ticks::<Clock::T>(Clock::SCALING_FACTOR)?;
+
+        Ok(self.elapsed(clock)?.integer() > max_age)
+    }
+}
+
+impl<Clock: crate::Clock> From<Instant<Clock>> for Timestamp<Clock> {
+    fn from(instant: Instant<Clock>) -> Self {
+        Self { instant }
+    }
+}
@@ -0,0 +1,63 @@
+use embedded_time::{accumulator::Accumulator, duration::*, ConversionError};
+
+#[test]
+fn total() {
+    let mut energy = Accumulator::<Microseconds<u32>>::new();
+    assert_eq!(energy.total::<Seconds<u32>>(), Ok(0));
+
+    // 1.5 mW for 500 ms, then 2 mW for 250 ms
+    energy.add(1_500, Microseconds(500_000)).unwrap();
+    energy.add(2_000, Microseconds(250_000)).unwrap();
+
+    assert_eq!(energy.total::<Microseconds<u32>>(), Ok(1_250_000_000));
+    assert_eq!(energy.total::<Milliseconds<u32>>(), Ok(1_250_000));
+    assert_eq!(energy.total::<Seconds<u32>>(), Ok(1_250));
+    assert_eq!(energy.total::<Hours<u32>>(), Ok(0));
+
+    energy.reset();
+    assert_eq!(energy.total::<Seconds<u32>>(), Ok(0));
+}
+
+#[test]
+fn negative_readings() {
+    let mut charge = Accumulator::<Seconds<u32>>::default();
+
+    charge.add(100, Seconds(3_600)).unwrap();
+    charge.add(-300, Seconds(7_200)).unwrap();
+    assert_eq!(charge.total::<Hours<u32>>(), Ok(-500));
+
+    // truncated toward zero
+    charge.add(-1, Seconds(1_800)).unwrap();
+    assert_eq!(charge.total::<Hours<u32>>(), Ok(-500));
+}
+
+#[test]
+fn overflow() {
+    let mut accumulator = Accumulator::<Nanoseconds<u64>>::new();
+    accumulator.add(i32::MAX, Nanoseconds(u64::MAX)).unwrap();
+    assert_eq!(
+        accumulator.total::<Nanoseconds<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        accumulator.total::<Hours<u32>>(),
+        Ok((u128::from(u64::MAX) * i32::MAX as u128 / 3_600_000_000_000) as i64)
+    );
+
+    let mut accumulator = Accumulator::<Nanoseconds<u128>>::new();
+    assert_eq!(
+        accumulator.add(1, Nanoseconds(u128::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    accumulator
+        .add(i32::MAX, Nanoseconds(u128::MAX >> 32))
+        .unwrap();
+    assert_eq!(
+        accumulator.add(i32::MAX, Nanoseconds(u128::MAX >> 32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        accumulator.total::<Hours<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+}
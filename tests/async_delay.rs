@@ -0,0 +1,184 @@
+#![cfg(feature = "async")]
+
+use core::{
+    cell::Cell,
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+use embedded_hal_async::delay::DelayNs;
+use embedded_time::{
+    self as time,
+    async_delay::{AsyncDelay, DelayFuture, WakeHook, WakeImmediately},
+    duration::*,
+    Clock as _, Instant, TimeError,
+};
+
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+/// Records the expiration of the pending delay
+#[derive(Debug, Default)]
+struct AlarmHook(Cell<Option<Instant<Clock>>>);
+
+impl WakeHook<Clock> for AlarmHook {
+    fn register(&self, expiration: &Instant<Clock>, _waker: &Waker) {
+        self.0.set(Some(*expiration));
+    }
+}
+
+fn poll<F: Future>(future: core::pin::Pin<&mut F>) -> Poll<F::Output> {
+    future.poll(&mut Context::from_waker(Waker::noop()))
+}
+
+#[test]
+fn delay_async() {
+    let clock = Clock(Cell::new(u32::MAX - 1));
+    let mut delay = pin!(clock.delay_async(Milliseconds(3_u32)));
+
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+
+    clock.0.set(0);
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+
+    // more than 3 ticks after the start
+    clock.0.set(1);
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+
+    clock.0.set(2);
+    assert_eq!(poll(delay.as_mut()), Poll::Ready(Ok(())));
+}
+
+#[test]
+fn delay_async_rounds_up() {
+    let clock = Clock(Cell::new(0));
+    let mut delay = pin!(clock.delay_async(Microseconds(900_u32)));
+
+    // less than a tick doesn't complete immediately
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+
+    clock.0.set(1);
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+
+    clock.0.set(2);
+    assert_eq!(poll(delay.as_mut()), Poll::Ready(Ok(())));
+
+    // 1.5 ticks are rounded up to 2
+    let mut delay = pin!(clock.delay_async(Microseconds(1_500_u32)));
+
+    clock.0.set(4);
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+
+    clock.0.set(5);
+    assert_eq!(poll(delay.as_mut()), Poll::Ready(Ok(())));
+}
+
+#[test]
+fn wake_hook() {
+    let clock = Clock(Cell::new(100));
+    let hook = AlarmHook::default();
+    let mut delay = pin!(DelayFuture::new(&clock, Seconds(1_u32), &hook));
+
+    assert_eq!(poll(delay.as_mut()), Poll::Pending);
+    assert_eq!(hook.0.get(), Some(Instant::new(1_101)));
+
+    clock.0.set(1_101);
+    assert_eq!(poll(delay.as_mut()), Poll::Ready(Ok(())));
+}
+
+#[test]
+fn overflow() {
+    let clock = Clock(Cell::new(0));
+    let mut delay = pin!(DelayFuture::new(&clock, Seconds(u32::MAX), WakeImmediately));
+
+    assert_eq!(poll(delay.as_mut()), Poll::Ready(Err(TimeError::Overflow)));
+}
+
+#[test]
+fn delay_ns() {
+    /// Advances one tick per read
+    #[derive(Debug)]
+    struct SteppingClock(Cell<u32>);
+    impl time::Clock for SteppingClock {
+        type T = u32;
+        type Error = time::clock::Error;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            self.0.set(self.0.get() + 1);
+            Ok(Instant::new(self.0.get()))
+        }
+    }
+
+    let mut delay = AsyncDelay::new(SteppingClock(Cell::new(0)), WakeImmediately);
+
+    {
+        let mut future = pin!(delay.delay_ms(2));
+        while poll(future.as_mut()).is_pending() {}
+    }
+    // more than 2 ticks after the start (1)
+    assert_eq!(delay.inner().0.get(), 4);
+
+    {
+        let mut future = pin!(delay.delay_us(3_000));
+        while poll(future.as_mut()).is_pending() {}
+    }
+    assert_eq!(delay.inner().0.get(), 9);
+
+    // less than a tick is rounded up
+    {
+        let mut future = pin!(delay.delay_ns(500));
+        while poll(future.as_mut()).is_pending() {}
+    }
+    assert_eq!(delay.inner().0.get(), 12);
+}
+
+/// A `u32` clock of the given frequency advancing 1 ms per read
+#[derive(Debug)]
+struct FastClock<const HZ: u32>(Cell<u64>);
+
+impl<const HZ: u32> time::Clock for FastClock<HZ> {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, HZ);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.0.set(self.0.get() + u64::from(HZ / 1_000));
+        Ok(Instant::new(self.0.get() as u32))
+    }
+}
+
+#[test]
+fn delay_longer_than_the_conversion_range() {
+    // 5s × 1_000_000 overflows a u32 before dividing by 1_000
+    let mut delay = AsyncDelay::new(FastClock::<1_000_000>(Cell::new(0)), WakeImmediately);
+    {
+        let mut future = pin!(delay.delay_ms(5_000));
+        while poll(future.as_mut()).is_pending() {}
+    }
+
+    let elapsed = delay.inner().0.get();
+    assert!((5_000_000..5_010_000).contains(&elapsed));
+}
+
+#[test]
+fn delay_longer_than_half_the_clock_range() {
+    let mut delay = AsyncDelay::new(FastClock::<1_000_000_000>(Cell::new(0)), WakeImmediately);
+    {
+        let mut future = pin!(delay.delay_ms(5_000));
+        while poll(future.as_mut()).is_pending() {}
+    }
+
+    let elapsed = delay.inner().0.get();
+    assert!((5_000_000_000..5_010_000_000).contains(&elapsed));
+}
@@ -0,0 +1,106 @@
+use embedded_time::{self as time, batch, duration::*, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u64;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn convert_slice() {
+    let ticks = [0_u32, 1, 32_768, u32::MAX];
+    let mut durations = [Nanoseconds(0_u64); 4];
+
+    assert_eq!(
+        batch::convert_slice(&ticks, Fraction::new(1, 32_768), &mut durations),
+        Ok(4)
+    );
+    assert_eq!(
+        durations,
+        [
+            Nanoseconds(0_u64),
+            Nanoseconds(30_517),
+            Nanoseconds(1_000_000_000),
+            Nanoseconds(131_071_999_969_482),
+        ]
+    );
+}
+
+#[test]
+fn convert_slice_lengths() {
+    let ticks = [1_u32, 2, 3];
+    let mut short = [Seconds(0_u32); 2];
+    let mut long = [Seconds(0_u32); 4];
+
+    assert_eq!(
+        batch::convert_slice(&ticks, Fraction::new(1, 1), &mut short),
+        Ok(2)
+    );
+    assert_eq!(short, [Seconds(1_u32), Seconds(2)]);
+
+    assert_eq!(
+        batch::convert_slice(&ticks, Fraction::new(1, 1), &mut long),
+        Ok(3)
+    );
+    assert_eq!(long, [Seconds(1_u32), Seconds(2), Seconds(3), Seconds(0)]);
+}
+
+#[test]
+fn convert_slice_errors() {
+    let mut durations = [Nanoseconds(0_u32); 2];
+
+    assert_eq!(
+        batch::convert_slice(&[1, 5], Fraction::new(1, 1), &mut durations),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(durations[0], Nanoseconds(1_000_000_000_u32));
+
+    assert_eq!(
+        batch::convert_slice(&[1], Fraction::new(1, 0), &mut durations),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        batch::convert_slice::<Nanoseconds<u64>>(&[1], Fraction::new(u32::MAX, 1), &mut []),
+        Err(ConversionError::Unspecified)
+    );
+}
+
+#[test]
+fn durations() {
+    let ticks = vec![1_u32, 2, u32::MAX];
+    let millis: Vec<_> = batch::durations::<Microseconds<u32>, _>(ticks, Fraction::new(1, 1_000))
+        .unwrap()
+        .collect();
+
+    assert_eq!(
+        millis,
+        [
+            Ok(Microseconds(1_000_u32)),
+            Ok(Microseconds(2_000)),
+            Err(ConversionError::ConversionFailure)
+        ]
+    );
+}
+
+#[test]
+fn instants() {
+    let instants: Vec<_> = batch::instants::<Clock, _>(vec![0_u32, 1, 2], Fraction::new(1, 1_000))
+        .unwrap()
+        .collect();
+
+    assert_eq!(
+        instants,
+        [
+            Ok(Instant::new(0)),
+            Ok(Instant::new(1_000)),
+            Ok(Instant::new(2_000))
+        ]
+    );
+}
@@ -0,0 +1,78 @@
+use embedded_time::calendar::{days_in_month, is_leap_year, DateTime};
+use test_case::test_case;
+
+fn date_time(year: u32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    }
+}
+
+#[test_case(0, date_time(1970, 1, 1, 0, 0, 0) ; "epoch")]
+#[test_case(951_782_400, date_time(2000, 2, 29, 0, 0, 0) ; "leap day 2000")]
+#[test_case(1_600_000_000, date_time(2020, 9, 13, 12, 26, 40) ; "2020")]
+#[test_case(2_147_483_647, date_time(2038, 1, 19, 3, 14, 7) ; "i32 max")]
+#[test_case(4_107_542_399, date_time(2100, 2, 28, 23, 59, 59) ; "not leap 2100")]
+#[test_case(4_107_542_400, date_time(2100, 3, 1, 0, 0, 0) ; "after not leap 2100")]
+#[test_case(253_402_300_799, date_time(9999, 12, 31, 23, 59, 59) ; "9999")]
+fn conversions(secs: u64, expected: DateTime) {
+    assert_eq!(DateTime::from_unix_secs(secs), Some(expected));
+    assert_eq!(expected.to_unix_secs(), Some(secs));
+}
+
+#[test]
+fn round_trips() {
+    // every day over a few 400-year cycles, at varying times of day
+    let mut previous = None;
+    for day in 0..(3 * 146_097) {
+        let secs = day * 86_400 + (day * 7_919) % 86_400;
+        let date_time = DateTime::from_unix_secs(secs).unwrap();
+
+        assert_eq!(date_time.to_unix_secs(), Some(secs));
+        assert!(
+            date_time.day >= 1 && date_time.day <= days_in_month(date_time.year, date_time.month)
+        );
+        assert!(Some(date_time) > previous);
+        previous = Some(date_time);
+    }
+}
+
+#[test]
+fn extremes() {
+    let last = date_time(u32::MAX, 12, 31, 23, 59, 59);
+    let secs = last.to_unix_secs().unwrap();
+
+    assert_eq!(DateTime::from_unix_secs(secs), Some(last));
+    assert_eq!(DateTime::from_unix_secs(secs + 1), None);
+    assert_eq!(DateTime::from_unix_secs(u64::MAX), None);
+}
+
+#[test_case(date_time(1969, 12, 31, 23, 59, 59) ; "before epoch")]
+#[test_case(date_time(2021, 0, 1, 0, 0, 0) ; "month 0")]
+#[test_case(date_time(2021, 13, 1, 0, 0, 0) ; "month 13")]
+#[test_case(date_time(2021, 1, 0, 0, 0, 0) ; "day 0")]
+#[test_case(date_time(2021, 2, 29, 0, 0, 0) ; "not leap")]
+#[test_case(date_time(2021, 4, 31, 0, 0, 0) ; "april 31")]
+#[test_case(date_time(2021, 1, 1, 24, 0, 0) ; "hour 24")]
+#[test_case(date_time(2021, 1, 1, 0, 60, 0) ; "minute 60")]
+#[test_case(date_time(2021, 1, 1, 0, 0, 60) ; "leap second")]
+fn invalid(date_time: DateTime) {
+    assert_eq!(date_time.to_unix_secs(), None);
+}
+
+#[test]
+fn leap_years() {
+    assert!(is_leap_year(2024));
+    assert!(is_leap_year(2000));
+    assert!(!is_leap_year(2100));
+    assert!(!is_leap_year(2023));
+
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2023, 2), 28);
+    assert_eq!(days_in_month(2023, 12), 31);
+    assert_eq!(days_in_month(2023, 13), 0);
+}
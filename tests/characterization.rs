@@ -0,0 +1,125 @@
+use embedded_time::{
+    self as time, characterization::characterize, duration::*, Instant, TimeError,
+};
+use std::cell::Cell;
+
+/// The true time in nanoseconds, advanced by every clock read
+#[derive(Debug)]
+struct Time {
+    now: Cell<u64>,
+    read_latency: u64,
+}
+
+impl Time {
+    fn new(read_latency: u64) -> Self {
+        Self {
+            now: Cell::new(0),
+            read_latency,
+        }
+    }
+
+    fn read(&self) -> u64 {
+        self.now.set(self.now.get() + self.read_latency);
+        self.now.get()
+    }
+}
+
+/// A 32.768 kHz clock running 200 ppm slow
+#[derive(Debug)]
+struct Clock<'a>(&'a Time);
+
+impl time::Clock for Clock<'_> {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        let ticks = u128::from(self.0.read()) * 32_768 * 999_800 / 1_000_000 / 1_000_000_000;
+        Ok(Instant::new(ticks as u32))
+    }
+}
+
+/// A 1 MHz reference clock
+#[derive(Debug)]
+struct ReferenceClock<'a>(&'a Time);
+
+impl time::Clock for ReferenceClock<'_> {
+    type T = u64;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.read() / 1_000))
+    }
+}
+
+#[derive(Debug)]
+struct BadClock;
+
+impl time::Clock for BadClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::NotRunning)
+    }
+}
+
+/// A clock that returns `Ok` but has stopped counting
+#[derive(Debug)]
+struct FrozenClock;
+
+impl time::Clock for FrozenClock {
+    type T = u64;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(42))
+    }
+}
+
+#[test]
+fn report() {
+    let time = Time::new(2_000);
+    let report = characterize(&Clock(&time), &ReferenceClock(&time), Seconds(10_u32)).unwrap();
+
+    // one tick of a 32.768 kHz clock is 30_517.578125 ns
+    assert_eq!(report.resolution, Nanoseconds(30_517_u64));
+    // (the measurement includes one of the reference clock reads)
+    assert_eq!(report.read_latency, Nanoseconds(2_000_u64 + 2_000 / 256));
+    assert!((report.drift_ppm + 200).abs() <= 4, "{}", report.drift_ppm);
+}
+
+#[test]
+fn errors() {
+    let time = Time::new(2_000);
+
+    assert_eq!(
+        characterize(&Clock(&time), &ReferenceClock(&time), Seconds(0_u32)),
+        Err(TimeError::DivByZero)
+    );
+    assert_eq!(
+        characterize(&BadClock, &ReferenceClock(&time), Seconds(1_u32)),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+    assert_eq!(
+        characterize(&Clock(&time), &BadClock, Seconds(1_u32)),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+}
+
+#[test]
+fn frozen_clocks() {
+    let time = Time::new(2_000);
+
+    assert_eq!(
+        characterize(&FrozenClock, &ReferenceClock(&time), Seconds(1_u32)),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+    assert_eq!(
+        characterize(&Clock(&time), &FrozenClock, Seconds(1_u32)),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+}
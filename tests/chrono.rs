@@ -0,0 +1,132 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, TimeDelta, Utc};
+use core::convert::TryFrom;
+use embedded_time::{chrono::UnixClock, duration::*, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+impl UnixClock for Clock {}
+
+#[derive(Debug)]
+struct FastClock;
+
+impl embedded_time::Clock for FastClock {
+    type T = u64;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+impl UnixClock for FastClock {}
+
+#[test]
+fn to_time_delta() {
+    assert_eq!(TimeDelta::try_from(Weeks(2_u32)), Ok(TimeDelta::weeks(2)));
+    assert_eq!(
+        TimeDelta::try_from(Seconds(5_u8)),
+        Ok(TimeDelta::seconds(5))
+    );
+    assert_eq!(
+        TimeDelta::try_from(Nanoseconds(1_500_000_001_u64)),
+        Ok(TimeDelta::new(1, 500_000_001).unwrap())
+    );
+    assert_eq!(
+        TimeDelta::try_from(Generic::new(3_u32, Fraction::new(1, 3))),
+        Ok(TimeDelta::seconds(1))
+    );
+    // truncated to nanoseconds
+    assert_eq!(
+        TimeDelta::try_from(Generic::new(1_u32, Fraction::new(1, 3))),
+        Ok(TimeDelta::nanoseconds(333_333_333))
+    );
+
+    assert_eq!(
+        TimeDelta::try_from(Weeks(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn from_time_delta() {
+    assert_eq!(
+        Milliseconds::<u32>::try_from(TimeDelta::new(2, 345_678_901).unwrap()),
+        Ok(Milliseconds(2_345_u32))
+    );
+    assert_eq!(
+        Minutes::<u32>::try_from(TimeDelta::seconds(119)),
+        Ok(Minutes(1_u32))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(TimeDelta::days(1)),
+        Ok(Nanoseconds(86_400_000_000_000_u64))
+    );
+
+    assert_eq!(
+        Seconds::<u32>::try_from(TimeDelta::seconds(-1)),
+        Err(ConversionError::NegDuration)
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(TimeDelta::days(50)),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn instants() {
+    let instant = Instant::<Clock>::new(1_600_000_000);
+    let date_time = DateTime::<Utc>::try_from(instant).unwrap();
+
+    assert_eq!(
+        date_time,
+        DateTime::from_timestamp(1_600_000_000, 0).unwrap()
+    );
+    assert_eq!(Instant::<Clock>::try_from(date_time), Ok(instant));
+    assert_eq!(
+        DateTime::<Utc>::try_from(Instant::<Clock>::new(0)),
+        Ok(DateTime::UNIX_EPOCH)
+    );
+
+    // truncated to whole ticks
+    assert_eq!(
+        Instant::<Clock>::try_from(DateTime::from_timestamp(1_600_000_000, 999_999_999).unwrap()),
+        Ok(instant)
+    );
+    assert_eq!(
+        Instant::<FastClock>::try_from(DateTime::from_timestamp(2, 500_000_000).unwrap()),
+        Ok(Instant::<FastClock>::new(81_920))
+    );
+    assert_eq!(
+        DateTime::<Utc>::try_from(Instant::<FastClock>::new(1)),
+        Ok(DateTime::from_timestamp(0, 30_517).unwrap())
+    );
+
+    assert_eq!(
+        Instant::<Clock>::try_from(DateTime::from_timestamp(-1, 0).unwrap()),
+        Err(ConversionError::NegDuration)
+    );
+    // beyond 2106
+    assert_eq!(
+        Instant::<Clock>::try_from(DateTime::from_timestamp(1 << 32, 0).unwrap()),
+        Err(ConversionError::ConversionFailure)
+    );
+    // beyond the range of DateTime
+    assert_eq!(
+        DateTime::<Utc>::try_from(Instant::<FastClock>::new(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
@@ -0,0 +1,86 @@
+use embedded_time::{
+    self as time, clock, clock_handle, duration::*, fraction::Fraction, Clock as _, Instant,
+    TimeError,
+};
+
+#[derive(Debug)]
+struct StoppedClock;
+impl time::Clock for StoppedClock {
+    type T = u32;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Err(clock::Error::NotRunning)
+    }
+}
+
+#[test]
+fn try_now_error() {
+    assert_eq!(StoppedClock.try_now(), Err(clock::Error::NotRunning));
+}
+
+#[test]
+fn error_propagates_through_timer() {
+    let clock = StoppedClock;
+
+    assert_eq!(
+        clock.new_timer(1_u32.seconds()).start().err(),
+        Some(TimeError::Clock(clock::Error::NotRunning))
+    );
+}
+
+#[test]
+fn error_converts_to_time_error() {
+    assert_eq!(
+        TimeError::from(clock::Error::NotRunning),
+        TimeError::Clock(clock::Error::NotRunning)
+    );
+}
+
+/// A driver's own error type
+#[derive(Debug, PartialEq)]
+enum BusError {
+    Nack,
+    Timeout,
+}
+
+impl From<BusError> for clock::Error {
+    fn from(error: BusError) -> Self {
+        match error {
+            BusError::Nack => clock::Error::Unspecified,
+            BusError::Timeout => clock::Error::NotRunning,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ExternalRtc;
+impl time::Clock for ExternalRtc {
+    type T = u32;
+    type Error = BusError;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1);
+
+    fn try_now(&self) -> Result<Instant<Self>, BusError> {
+        Err(BusError::Timeout)
+    }
+}
+
+#[test]
+fn associated_error_type() {
+    let clock = ExternalRtc;
+
+    assert_eq!(clock.try_now(), Err(BusError::Timeout));
+    assert_eq!(
+        clock.new_timer(1_u32.seconds()).start().err(),
+        Some(TimeError::Clock(clock::Error::NotRunning))
+    );
+    assert_eq!(
+        clock_handle::ClockHandle::new(&clock).try_now(),
+        Err(BusError::Timeout)
+    );
+    assert_eq!(
+        clock::Error::from(BusError::Nack),
+        clock::Error::Unspecified
+    );
+}
@@ -0,0 +1,50 @@
+use core::cell::Cell;
+use embedded_time::{
+    self as time,
+    clock_handle::ClockHandle,
+    duration::*,
+    monotonic_guard::{MonotonicGuard, Policy},
+    Clock as _, Instant,
+};
+
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[test]
+fn multiple_owners() {
+    let clock = Clock(Cell::new(10));
+    let handle = ClockHandle::new(&clock);
+
+    // two wrappers taking a clock by value share the same hardware clock
+    let guard = MonotonicGuard::new(handle, Policy::Clamp);
+    let timer = handle.new_timer(5_u32.milliseconds()).start().unwrap();
+
+    clock.0.set(15);
+
+    assert_eq!(guard.try_now(), Ok(Instant::new(15)));
+    assert!(timer.is_expired().unwrap());
+    assert_eq!(
+        ClockHandle::into_clock_instant(handle.try_now().unwrap()),
+        clock.try_now().unwrap()
+    );
+}
+
+#[test]
+fn instant_conversion() {
+    let instant = Instant::<Clock>::new(23);
+
+    assert_eq!(
+        ClockHandle::into_clock_instant(ClockHandle::from_clock_instant(instant)),
+        instant
+    );
+}
@@ -0,0 +1,40 @@
+use core::convert::TryFrom;
+use embedded_time::{
+    consts,
+    duration::{self, Microseconds, Milliseconds, Nanoseconds},
+    fixed_point::FixedPoint as _,
+    fraction::Fraction,
+    rate::Rate as _,
+};
+
+#[test]
+fn scaling_factors() {
+    assert_eq!(
+        Nanoseconds::<u32>::try_from(duration::Generic::new(
+            1_u32,
+            consts::scaling_factor::TICK_32_768_KHZ
+        )),
+        Ok(Nanoseconds(30_517_u32))
+    );
+    assert_eq!(
+        consts::scaling_factor::TICK_1_KHZ,
+        Milliseconds::<u32>::SCALING_FACTOR
+    );
+    assert_eq!(
+        consts::scaling_factor::TICK_1_MHZ,
+        Fraction::new(1, 1_000_000)
+    );
+}
+
+#[test]
+fn periods_and_rates() {
+    assert_eq!(
+        consts::crystal::F_32_768_KHZ.to_duration(),
+        Ok(Microseconds(30_u32))
+    );
+    assert_eq!(consts::period::SYSTICK, Microseconds(1_000_u32));
+    assert_eq!(
+        consts::baud::B_9600.to_duration(),
+        Ok(Microseconds(104_u32))
+    );
+}
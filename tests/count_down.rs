@@ -0,0 +1,77 @@
+#![cfg(feature = "embedded-hal")]
+
+use core::cell::Cell;
+use embedded_hal::timer::CountDown;
+use embedded_time::{self as time, count_down::CountDownTimer, duration::*, Instant};
+
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+fn timer(ticks: u32) -> CountDownTimer<Clock> {
+    CountDownTimer::new(Clock(Cell::new(ticks)))
+}
+
+#[test]
+fn not_started() {
+    let mut timer = timer(0);
+    timer.inner().0.set(1_000);
+
+    assert!(timer.wait().is_err());
+}
+
+#[test]
+fn periodic() {
+    let mut timer = timer(100);
+    timer.start(Seconds(1_u32));
+
+    timer.inner().0.set(1_099);
+    assert!(timer.wait().is_err());
+
+    // expires late, the next period is not extended
+    timer.inner().0.set(1_150);
+    assert!(timer.wait().is_ok());
+    assert!(timer.wait().is_err());
+
+    timer.inner().0.set(2_100);
+    assert!(timer.wait().is_ok());
+}
+
+#[test]
+fn generic_count() {
+    let mut timer = timer(0);
+    timer.start(Generic::new(3_u32, Fraction::new(1, 100)));
+
+    timer.inner().0.set(29);
+    assert!(timer.wait().is_err());
+
+    timer.inner().0.set(30);
+    assert!(timer.wait().is_ok());
+}
+
+#[test]
+fn wrap_around() {
+    let mut timer = timer(u32::MAX - 4);
+    timer.start(Milliseconds(10_u32));
+
+    timer.inner().0.set(4);
+    assert!(timer.wait().is_err());
+
+    timer.inner().0.set(5);
+    assert!(timer.wait().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "Timer start failed")]
+fn count_overflow() {
+    timer(0).start(Seconds(u32::MAX));
+}
@@ -0,0 +1,70 @@
+use embedded_time::{
+    self as time, counting_clock::CountingClock, duration::*, Clock as _, Instant,
+};
+use std::cell::Cell;
+
+#[derive(Debug)]
+struct Clock {
+    now: Cell<u32>,
+    running: Cell<bool>,
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        if self.running.get() {
+            Ok(Instant::new(self.now.get()))
+        } else {
+            Err(time::clock::Error::NotRunning)
+        }
+    }
+}
+
+fn ms(ticks: u32) -> Option<Generic<u32>> {
+    Some(Generic::new(ticks, Fraction::new(1, 1_000)))
+}
+
+#[test]
+fn intervals() {
+    let clock = CountingClock::new(Clock {
+        now: Cell::new(u32::MAX - 1),
+        running: Cell::new(true),
+    });
+    assert_eq!(clock.min_interval(), None);
+
+    for step in [0, 5, 2, 9] {
+        let now = clock.inner().now.get().wrapping_add(step);
+        clock.inner().now.set(now);
+        clock.try_now().unwrap();
+    }
+
+    assert_eq!(clock.reads(), 4);
+    assert_eq!(clock.min_interval(), ms(2));
+    assert_eq!(clock.max_interval(), ms(9));
+
+    clock.reset();
+    assert_eq!(clock.reads(), 0);
+    assert_eq!(clock.max_interval(), None);
+}
+
+#[test]
+fn failed_and_backwards_reads() {
+    let clock = CountingClock::new(Clock {
+        now: Cell::new(100),
+        running: Cell::new(true),
+    });
+    clock.try_now().unwrap();
+
+    clock.inner().running.set(false);
+    assert_eq!(clock.try_now(), Err(time::clock::Error::NotRunning));
+
+    clock.inner().running.set(true);
+    clock.inner().now.set(90);
+    clock.try_now().unwrap();
+
+    assert_eq!(clock.reads(), 3);
+    assert_eq!(clock.min_interval(), None);
+}
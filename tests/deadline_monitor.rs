@@ -0,0 +1,110 @@
+use embedded_time::{
+    self as time, deadline_monitor::DeadlineMonitor, duration::*, Instant, TimeError,
+};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Debug)]
+struct Clock(AtomicU32);
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.load(Ordering::SeqCst)))
+    }
+}
+
+#[derive(Debug)]
+struct BadClock;
+
+impl time::Clock for BadClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::NotRunning)
+    }
+}
+
+#[test]
+fn missed_deadlines() {
+    let clock = Clock(AtomicU32::new(1_000));
+    let mut monitor = DeadlineMonitor::<_, 3>::new(&clock);
+
+    monitor.register(0, Milliseconds(100_u32)).unwrap();
+    monitor.register(2, Seconds(1_u32)).unwrap();
+
+    assert_eq!(monitor.poll(Instant::new(1_100)).count(), 0);
+    assert!(monitor.poll(Instant::new(1_101)).eq([0].iter().copied()));
+    assert!(monitor.poll(Instant::new(2_001)).eq([0, 2].iter().copied()));
+
+    assert!(monitor.is_missed(0, Instant::new(1_101)));
+    assert!(!monitor.is_missed(1, Instant::new(1_101)));
+}
+
+#[test]
+fn check_in_restarts_interval() {
+    let clock = Clock(AtomicU32::new(0));
+    let mut monitor = DeadlineMonitor::<_, 1>::new(&clock);
+
+    monitor.register(0, Milliseconds(100_u32)).unwrap();
+
+    clock.0.store(90, Ordering::SeqCst);
+    monitor.check_in(0).unwrap();
+
+    assert!(!monitor.is_missed(0, Instant::new(150)));
+    assert!(monitor.is_missed(0, Instant::new(191)));
+}
+
+#[test]
+fn deregister() {
+    let clock = Clock(AtomicU32::new(0));
+    let mut monitor = DeadlineMonitor::<_, 1>::new(&clock);
+
+    monitor.register(0, Milliseconds(100_u32)).unwrap();
+    monitor.deregister(0);
+
+    assert!(!monitor.is_missed(0, Instant::new(1_000)));
+    assert_eq!(monitor.check_in(0), Ok(()));
+}
+
+#[test]
+fn clock_wrap() {
+    let clock = Clock(AtomicU32::new(u32::MAX - 49));
+    let mut monitor = DeadlineMonitor::<_, 1>::new(&clock);
+
+    monitor.register(0, Milliseconds(100_u32)).unwrap();
+
+    assert!(!monitor.is_missed(0, Instant::new(50)));
+    assert!(monitor.is_missed(0, Instant::new(51)));
+}
+
+#[test]
+fn errors() {
+    let clock = Clock(AtomicU32::new(0));
+    let mut monitor = DeadlineMonitor::<_, 1>::new(&clock);
+
+    assert_eq!(
+        monitor.register(0, Hours(u32::MAX)),
+        Err(TimeError::Unspecified)
+    );
+
+    let mut monitor = DeadlineMonitor::<_, 1>::new(&BadClock);
+
+    assert_eq!(
+        monitor.register(0, Milliseconds(100_u32)),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+}
+
+#[test]
+#[should_panic]
+fn invalid_id() {
+    let clock = Clock(AtomicU32::new(0));
+    let mut monitor = DeadlineMonitor::<_, 1>::new(&clock);
+
+    let _ = monitor.register(1, Milliseconds(100_u32));
+}
@@ -0,0 +1,87 @@
+use embedded_time::{
+    defer_queue::{DeferQueue, Error},
+    duration::*,
+    Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn deadline_order() {
+    let mut queue = DeferQueue::<Clock, u8, 4>::new();
+    let now = Instant::new(1_000);
+
+    queue.push(now, Milliseconds(30_u32), 1).unwrap();
+    queue.push(now, Milliseconds(10_u32), 2).unwrap();
+    queue.push(now, Milliseconds(30_u32), 3).unwrap();
+    queue.push(now, Seconds(0_u32), 4).unwrap();
+    assert_eq!(queue.len(), 4);
+    assert_eq!(queue.push(now, Milliseconds(1_u32), 5), Err(Error::Full(5)));
+
+    assert!(queue.pop_ready(now).eq([4]));
+    assert_eq!(queue.next_deadline(), Some(Instant::new(1_010)));
+    assert!(queue.pop_ready(Instant::new(1_029)).eq([2]));
+    // same deadline, in push order
+    assert!(queue.pop_ready(Instant::new(1_030)).eq([1, 3]));
+    assert!(queue.is_empty());
+    assert_eq!(queue.next_deadline(), None);
+}
+
+#[test]
+fn partially_consumed() {
+    let mut queue = DeferQueue::<Clock, u8, 3>::default();
+    queue.push_at(Instant::new(5), 1).unwrap();
+    queue.push_at(Instant::new(6), 2).unwrap();
+    queue.push_at(Instant::new(7), 3).unwrap();
+
+    assert_eq!(queue.pop_ready(Instant::new(10)).next(), Some(1));
+    assert_eq!(queue.len(), 2);
+
+    queue.push_at(Instant::new(1), 4).unwrap();
+    assert!(queue.pop_ready(Instant::new(10)).eq([4, 2, 3]));
+
+    queue.push_at(Instant::new(1), 5).unwrap();
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop_ready(Instant::new(10)).next(), None);
+}
+
+#[test]
+fn wrapping() {
+    let mut queue = DeferQueue::<Clock, u8, 2>::new();
+    let now = Instant::new(u32::MAX - 5);
+
+    queue.push(now, Milliseconds(10_u32), 1).unwrap();
+    queue.push(now, Milliseconds(2_u32), 2).unwrap();
+    assert_eq!(queue.next_deadline(), Some(Instant::new(u32::MAX - 3)));
+
+    assert!(queue.pop_ready(Instant::new(u32::MAX)).eq([2]));
+    assert_eq!(queue.pop_ready(Instant::new(3)).next(), None);
+    assert!(queue.pop_ready(Instant::new(4)).eq([1]));
+}
+
+#[test]
+fn overflow() {
+    let mut queue = DeferQueue::<Clock, u8, 1>::new();
+
+    assert_eq!(
+        queue.push(Instant::new(0), Milliseconds(u32::MAX), 1),
+        Err(Error::Overflow(1))
+    );
+    assert!(queue.is_empty());
+
+    let mut queue = DeferQueue::<Clock, u8, 0>::new();
+    assert_eq!(queue.push_at(Instant::new(0), 1), Err(Error::Full(1)));
+    assert_eq!(queue.next_deadline(), None);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "defmt")]
+
+use embedded_time::{
+    clock, duration, fraction::Fraction, rate, ConversionError, Instant, TimeError,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn implemented() {
+    assert_format::<duration::Milliseconds<u32>>();
+    assert_format::<duration::Nanoseconds<u128>>();
+    assert_format::<duration::Generic<u64>>();
+    assert_format::<duration::SignedGeneric<i64>>();
+    assert_format::<rate::Kilohertz<u32>>();
+    assert_format::<rate::Baud<u8>>();
+    assert_format::<rate::Generic<u32>>();
+    assert_format::<Fraction>();
+    assert_format::<Instant<Clock>>();
+    assert_format::<ConversionError>();
+    assert_format::<TimeError>();
+    assert_format::<clock::Error>();
+}
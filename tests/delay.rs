@@ -0,0 +1,72 @@
+#![cfg(feature = "embedded-hal-1")]
+
+use core::cell::Cell;
+use embedded_hal_1::delay::DelayNs;
+use embedded_time::{self as time, delay::Delay, fraction::Fraction, Instant};
+
+/// A 1 kHz clock that advances one tick per read
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.0.set(self.0.get().wrapping_add(1));
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+fn new_delay(ticks: u32) -> Delay<Clock> {
+    Delay::new(Clock(Cell::new(ticks)))
+}
+
+#[test]
+fn delay_ms() {
+    let mut delay = new_delay(0);
+    delay.delay_ms(10);
+
+    // 1 start read + 11 reads until more than 10 ticks have elapsed
+    assert_eq!(delay.inner().0.get(), 12);
+}
+
+#[test]
+fn rounds_up() {
+    let mut delay = new_delay(0);
+    delay.delay_us(1);
+
+    assert_eq!(delay.inner().0.get(), 3);
+
+    let mut delay = new_delay(0);
+    delay.delay_ns(0);
+
+    assert_eq!(delay.inner().0.get(), 0);
+}
+
+#[test]
+fn wrap_around() {
+    let mut delay = new_delay(u32::MAX - 5);
+    delay.delay_ms(10);
+
+    assert_eq!(delay.inner().0.get(), 6);
+}
+
+#[test]
+#[should_panic(expected = "Clock read failed")]
+fn clock_error() {
+    #[derive(Debug)]
+    struct StoppedClock;
+    impl time::Clock for StoppedClock {
+        type T = u32;
+        type Error = time::clock::Error;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            Err(time::clock::Error::NotRunning)
+        }
+    }
+
+    Delay::new(StoppedClock).delay_ms(1);
+}
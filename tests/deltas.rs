@@ -0,0 +1,67 @@
+use embedded_time::{self as time, deltas::deltas, duration::*, Instant, TimeError};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn instants(ticks: &[u32]) -> impl Iterator<Item = Instant<Clock>> + '_ {
+    ticks.iter().copied().map(Instant::new)
+}
+
+#[test]
+fn consecutive_differences() {
+    let mut deltas = deltas(instants(&[10, 15, 15, 40]));
+
+    assert_eq!(deltas.size_hint(), (3, Some(3)));
+    assert_eq!(
+        deltas.next(),
+        Some(Ok(Generic::new(5, Fraction::new(1, 1_000))))
+    );
+    assert_eq!(
+        deltas.next(),
+        Some(Ok(Generic::new(0, Fraction::new(1, 1_000))))
+    );
+    assert_eq!(
+        deltas.next(),
+        Some(Ok(Generic::new(25, Fraction::new(1, 1_000))))
+    );
+    assert_eq!(deltas.size_hint(), (0, Some(0)));
+    assert_eq!(deltas.next(), None);
+}
+
+#[test]
+fn wrap_around() {
+    let mut deltas = deltas(instants(&[u32::MAX - 1, 3]));
+
+    assert_eq!(
+        deltas.next(),
+        Some(Ok(Generic::new(5, Fraction::new(1, 1_000))))
+    );
+    assert_eq!(deltas.next(), None);
+}
+
+#[test]
+fn backwards() {
+    let mut deltas = deltas(instants(&[10, 5, 7]));
+
+    assert_eq!(deltas.next(), Some(Err(TimeError::NegDuration)));
+    assert_eq!(
+        deltas.next(),
+        Some(Ok(Generic::new(2, Fraction::new(1, 1_000))))
+    );
+}
+
+#[test]
+fn too_few_instants() {
+    assert_eq!(deltas(instants(&[])).next(), None);
+    assert_eq!(deltas(instants(&[1])).next(), None);
+}
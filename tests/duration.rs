@@ -1,6 +1,12 @@
 use core::convert::{TryFrom, TryInto};
 use embedded_time::duration::Generic;
-use embedded_time::{duration, duration::*, fraction::Fraction, rate::*, ConversionError};
+use embedded_time::{
+    duration,
+    duration::*,
+    fraction::Fraction,
+    rate::{Hertz, Kilohertz, Megahertz, Rate},
+    ConversionError, ParseError,
+};
 
 #[test]
 fn construction() {
@@ -15,6 +21,62 @@ fn construction() {
     assert_eq!(5_u32.hours(), Hours(5_u32));
 }
 
+#[test]
+fn construction_from_another_unit() {
+    assert_eq!(
+        Milliseconds::<u32>::try_from_secs(5_u32),
+        Ok(Milliseconds(5_000_u32))
+    );
+    assert_eq!(Minutes::<u32>::try_from_secs(179_u16), Ok(Minutes(2_u32)));
+    assert_eq!(
+        Minutes::<u32>::try_from_secs_rounded(179_u16),
+        Ok(Minutes(3_u32))
+    );
+    assert_eq!(
+        Hours::<u32>::try_from_secs_rounded(1_799_u32),
+        Ok(Hours(0_u32))
+    );
+    assert_eq!(
+        Hours::<u32>::try_from_secs_rounded(1_800_u32),
+        Ok(Hours(1_u32))
+    );
+
+    assert_eq!(
+        Seconds::<u32>::try_from_millis(1_499_u64),
+        Ok(Seconds(1_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from_millis_rounded(1_499_u64),
+        Ok(Seconds(1_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from_millis_rounded(1_500_u64),
+        Ok(Seconds(2_u32))
+    );
+
+    assert_eq!(
+        Milliseconds::<u64>::try_from_micros(2_500_u32),
+        Ok(Milliseconds(2_u64))
+    );
+    assert_eq!(
+        Microseconds::<u32>::try_from_nanos_rounded(2_500_u32),
+        Ok(Microseconds(3_u32))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from_secs(u64::MAX / 1_000_000_000),
+        Ok(Nanoseconds(u64::MAX / 1_000_000_000 * 1_000_000_000))
+    );
+
+    assert_eq!(
+        Milliseconds::<u32>::try_from_secs(u32::MAX),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from_secs(u64::MAX),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
 #[test]
 fn comparisons() {
     assert_ne!(Seconds(5_u32), Nanoseconds(u32::MAX));
@@ -89,10 +151,13 @@ fn mul_overflow() {
 fn checked_mul() {
     assert_eq!(
         Milliseconds(2_001_u32).checked_mul(&2),
-        Some(Milliseconds(4_002_u32))
+        Ok(Milliseconds(4_002_u32))
     );
 
-    assert_eq!(Milliseconds(u32::MAX).checked_mul(&2), None);
+    assert_eq!(
+        Milliseconds(u32::MAX).checked_mul(&2),
+        Err(ConversionError::Overflow)
+    );
 }
 
 #[test]
@@ -109,10 +174,13 @@ fn div_div_by_zero() {
 fn checked_div() {
     assert_eq!(
         Milliseconds(2_002_u32).checked_div(&2),
-        Some(Milliseconds(1_001_u32))
+        Ok(Milliseconds(1_001_u32))
     );
 
-    assert_eq!(Milliseconds(u32::MAX).checked_div(&0), None);
+    assert_eq!(
+        Milliseconds(u32::MAX).checked_div(&0),
+        Err(ConversionError::DivByZero)
+    );
 }
 
 #[test]
@@ -504,3 +572,1147 @@ fn error_try_from() {
         Err(ConversionError::Unspecified)
     );
 }
+
+#[test]
+fn parse() {
+    let parsed = Generic::<u32>::parse("2.5ms").unwrap();
+    assert_eq!(parsed.integer(), 1);
+    assert_eq!(parsed.scaling_factor(), &Fraction::new(1, 400));
+
+    let parsed = Generic::<u64>::try_from(" 1.50 h ").unwrap();
+    assert_eq!(parsed.integer(), 5_400);
+    assert_eq!(parsed.scaling_factor(), &Fraction::new(1, 1));
+
+    let parsed = Generic::<u32>::parse("250µs").unwrap();
+    assert_eq!(parsed.integer(), 1);
+    assert_eq!(parsed.scaling_factor(), &Fraction::new(1, 4_000));
+
+    assert_eq!(Generic::<u32>::parse("10 s"), Ok(Seconds(10_u32).into()));
+    assert_eq!(
+        Generic::<u32>::parse(".5s"),
+        Ok(Milliseconds(500_u32).into())
+    );
+    assert_eq!(Generic::<u32>::parse("0ns").unwrap().integer(), 0);
+
+    // the same grammar as `str::parse()`
+    assert_eq!(Generic::<u32>::parse("1h30m"), Ok(Minutes(90_u32).into()));
+    assert_eq!(
+        Generic::<u32>::parse("1 min 2.5 s"),
+        "1 min 2.5 s".parse::<Generic<u32>>()
+    );
+
+    assert_eq!(Generic::<u32>::parse("ms"), Err(ParseError::InvalidNumber));
+    assert_eq!(Generic::<u32>::parse("."), Err(ParseError::InvalidNumber));
+    assert_eq!(
+        Generic::<u32>::parse("1.2.3s"),
+        Err(ParseError::InvalidNumber)
+    );
+    assert_eq!(Generic::<u32>::parse("5"), Err(ParseError::UnknownUnit));
+    assert_eq!(
+        Generic::<u32>::parse("5 days"),
+        Err(ParseError::UnknownUnit)
+    );
+    assert_eq!(Generic::<u32>::parse("-5s"), Err(ParseError::InvalidNumber));
+    assert_eq!(
+        Generic::<u32>::parse("5000000000s"),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+fn parse_reduces_the_decimal_digits() {
+    // 25 × 1/10_000_000_000 doesn't fit a `Fraction`, 1 × 1/400_000_000 does
+    let parsed = Generic::<u32>::parse("2.5ns").unwrap();
+    assert_eq!(parsed.integer(), 1);
+    assert_eq!(parsed.scaling_factor(), &Fraction::new(1, 400_000_000));
+    assert_eq!(parsed, Generic::new(5_u32, Fraction::new(1, 2_000_000_000)));
+
+    assert_eq!(
+        Generic::<u32>::parse("1.5ns"),
+        Ok(Generic::new(3_u32, Fraction::new(1, 2_000_000_000)))
+    );
+    assert_eq!(
+        Generic::<u8>::parse("2.50000000000000000000ms"),
+        Ok(Generic::new(1_u8, Fraction::new(1, 400)))
+    );
+    assert_eq!(
+        Generic::<u32>::parse("4.294967295s"),
+        Ok(Generic::new(858_993_459_u32, Fraction::new(1, 200_000_000)))
+    );
+
+    // nothing left to reduce
+    assert_eq!(Generic::<u32>::parse("0.1ns"), Err(ParseError::Overflow));
+}
+
+#[test]
+fn signed_generic() {
+    let negative = SignedGeneric::new(-3_i64, Fraction::new(1, 1_000));
+    let positive = -negative;
+
+    assert!(negative.is_negative());
+    assert!(!positive.is_negative());
+    assert_eq!(positive.integer(), 3);
+
+    assert_eq!(
+        Generic::<u32>::try_from(negative),
+        Err(ConversionError::NegDuration)
+    );
+    assert_eq!(
+        Generic::<u64>::try_from(positive),
+        Ok(Generic::new(3_u64, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        Generic::<u32>::try_from(SignedGeneric::new(i64::MAX, Fraction::new(1, 1))),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        SignedGeneric::<i32>::try_from(Generic::new(u32::MAX, Fraction::new(1, 1))),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        SignedGeneric::<i64>::try_from(Generic::new(u32::MAX, Fraction::new(1, 1))),
+        Ok(SignedGeneric::new(i64::from(u32::MAX), Fraction::new(1, 1)))
+    );
+}
+
+#[test]
+fn narrow_integer() {
+    assert_eq!(Seconds::<u32>::from(Seconds(200_u8)), Seconds(200_u32));
+    assert_eq!(
+        Milliseconds::<u64>::from(Milliseconds(60_000_u16)),
+        Milliseconds(60_000_u64)
+    );
+    assert_eq!(
+        Seconds::<u8>::try_from(Seconds(200_u32)),
+        Ok(Seconds(200_u8))
+    );
+    assert_eq!(
+        Seconds::<u8>::try_from(Seconds(256_u64)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(Seconds(5_u8), Seconds(5_u32));
+    assert!(Milliseconds(200_u8) < Milliseconds(1_000_u32));
+    assert!(Milliseconds(1_000_u32) > Milliseconds(200_u8));
+
+    assert_eq!(
+        Milliseconds::<u16>::try_from(Seconds(60_u16)),
+        Ok(Milliseconds(60_000_u16))
+    );
+    assert_eq!(
+        Milliseconds::<u16>::try_from(Seconds(66_u16)),
+        Err(ConversionError::Unspecified)
+    );
+
+    // the conversion is done in a wider type
+    assert_eq!(
+        Seconds(200_u8).to_generic::<u32>(Fraction::new(1, 1_000)),
+        Ok(Generic::new(200_000_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        Microseconds::<u8>::try_from(Generic::new(3_u8, Fraction::new(1, 32_768))),
+        Ok(Microseconds(91_u8))
+    );
+    assert_eq!(
+        Milliseconds(250_u16).to_rate::<Hertz<u16>>(),
+        Ok(Hertz(4_u16))
+    );
+}
+
+#[test]
+fn u128_integer() {
+    assert_eq!(
+        Nanoseconds::<u128>::from(Nanoseconds(u64::MAX)),
+        Nanoseconds(u64::MAX as u128)
+    );
+    assert_eq!(Seconds::<u128>::from(Seconds(5_u32)), Seconds(5_u128));
+
+    // beyond the range of u64 nanoseconds
+    let nanos = Nanoseconds::<u128>::try_from(Seconds(100_000_000_000_u128)).unwrap();
+    assert_eq!(nanos, Nanoseconds(100_000_000_000_000_000_000_u128));
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(nanos),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds::<u64>::try_from(Seconds::<u128>::from(nanos)),
+        Ok(Seconds(100_000_000_000_u64))
+    );
+
+    assert_eq!(
+        Nanoseconds::<u128>::try_from(core::time::Duration::from_secs(u64::MAX)),
+        Ok(Nanoseconds(u64::MAX as u128 * 1_000_000_000))
+    );
+    assert_eq!(
+        Nanoseconds(2_500_u128).to_generic::<u64>(Fraction::new(1, 1_000_000)),
+        Ok(Generic::new(2_u64, Fraction::new(1, 1_000_000)))
+    );
+    assert!(Nanoseconds(u64::MAX as u128 + 1) > Nanoseconds(u64::MAX));
+}
+
+#[test]
+fn checked_add_sub() {
+    assert_eq!(
+        Milliseconds(1_u32).checked_add(&Seconds(1_u32)),
+        Ok(Milliseconds(1_001_u32))
+    );
+    assert_eq!(
+        Seconds(1_u32).checked_add(&Milliseconds(1_999_u64)),
+        Ok(Seconds(2_u32))
+    );
+    assert_eq!(
+        Seconds(u32::MAX).checked_add(&Seconds(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Milliseconds(1_u32).checked_add(&Seconds(u64::MAX)),
+        Err(ConversionError::Unspecified)
+    );
+
+    assert_eq!(
+        Milliseconds(2_001_u32).checked_sub(&Seconds(1_u32)),
+        Ok(Milliseconds(1_001_u32))
+    );
+    assert_eq!(
+        Seconds(1_u32).checked_sub(&Milliseconds(1_999_u32)),
+        Ok(Seconds(0_u32))
+    );
+    assert_eq!(
+        Seconds(1_u32).checked_sub(&Milliseconds(2_000_u32)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn generic_checked_arithmetic() {
+    let millis = Generic::new(1_500_u32, Fraction::new(1, 1_000));
+    let ticks = Generic::new(16_384_u32, Fraction::new(1, 32_768));
+
+    assert_eq!(
+        millis.checked_add(&ticks),
+        Ok(Generic::new(2_000_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        millis.checked_sub(&ticks),
+        Ok(Generic::new(1_000_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(ticks.checked_sub(&millis), Err(ConversionError::Overflow));
+    assert_eq!(
+        Generic::new(u32::MAX, Fraction::new(1, 1_000)).checked_add(&ticks),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        millis.checked_mul(&3),
+        Ok(Generic::new(4_500_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        millis.checked_mul(&u32::MAX),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        millis.checked_div(&4),
+        Ok(Generic::new(375_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(millis.checked_div(&0), Err(ConversionError::DivByZero));
+}
+
+#[test]
+fn saturating_add_sub() {
+    assert_eq!(
+        Milliseconds(1_u32).saturating_add(&Seconds(1_u32)),
+        Milliseconds(1_001_u32)
+    );
+    assert_eq!(
+        Milliseconds(u32::MAX - 1_000).saturating_add(&Seconds(2_u32)),
+        Milliseconds(u32::MAX)
+    );
+    assert_eq!(
+        Milliseconds(0_u32).saturating_add(&Hours(u64::MAX)),
+        Milliseconds(u32::MAX)
+    );
+
+    assert_eq!(
+        Seconds(5_u32).saturating_sub(&Milliseconds(2_500_u64)),
+        Seconds(3_u32)
+    );
+    assert_eq!(
+        Seconds(5_u32).saturating_sub(&Seconds(6_u32)),
+        Seconds(0_u32)
+    );
+    assert_eq!(
+        Microseconds(5_u16).saturating_sub(&Minutes(u32::MAX)),
+        Microseconds(0_u16)
+    );
+}
+
+#[test]
+fn wrapping_add_sub() {
+    assert_eq!(
+        Microseconds(u32::MAX - 1).wrapping_add(&Microseconds(5_u32)),
+        Microseconds(3_u32)
+    );
+    assert_eq!(
+        Microseconds(1_u16).wrapping_sub(&Microseconds(2_u16)),
+        Microseconds(u16::MAX)
+    );
+    assert_eq!(Seconds(7_u64).wrapping_sub(&Seconds(2_u64)), Seconds(5_u64));
+}
+
+#[test]
+fn generic_mul_div() {
+    let ticks = Generic::new(3_u32, Fraction::new(1, 32_768));
+    assert_eq!(ticks * 4, Generic::new(12_u32, Fraction::new(1, 32_768)));
+    assert_eq!(ticks / 2, Generic::new(1_u32, Fraction::new(1, 32_768)));
+    assert_eq!(10_u32.milliseconds() * 3, Milliseconds(30_u32));
+}
+
+#[test]
+#[should_panic]
+fn generic_div_by_zero() {
+    let _ = Generic::new(3_u32, Fraction::new(1, 1_000)) / 0;
+}
+
+#[test]
+fn div_duration() {
+    assert_eq!(
+        Seconds(1_u32).div_duration(Milliseconds(20_u32)),
+        Ok(Fraction::new(50, 1))
+    );
+    assert_eq!(
+        Milliseconds(20_u8).div_duration(Minutes(1_u64)),
+        Ok(Fraction::new(1, 3_000))
+    );
+    assert_eq!(
+        Nanoseconds(0_u64).div_duration(Hours(1_u32)),
+        Ok(Fraction::new(0, 1))
+    );
+    assert_eq!(
+        Hours(u32::MAX).div_duration(Nanoseconds(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Nanoseconds(1_u128).div_duration(Seconds(u128::MAX)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Seconds(1_u32).div_duration(Nanoseconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn cycles_at() {
+    assert_eq!(Seconds(2_u8).cycles_at(Kilohertz(32_u16)), Ok(64_000));
+    assert_eq!(Nanoseconds(999_u32).cycles_at(Megahertz(1_u32)), Ok(0));
+    assert_eq!(Seconds(0_u32).cycles_at(Megahertz(1_u32)), Ok(0));
+    assert_eq!(
+        Seconds(u64::MAX).cycles_at(Hertz(2_u32)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn sum() {
+    let latencies = [Milliseconds(3_u32), Milliseconds(5), Milliseconds(7)];
+    assert_eq!(
+        latencies.iter().sum::<Milliseconds<u32>>(),
+        Milliseconds(15_u32)
+    );
+    assert_eq!(
+        latencies
+            .iter()
+            .copied()
+            .filter(|&latency| latency > Milliseconds(4_u32))
+            .sum::<Milliseconds<u32>>(),
+        Milliseconds(12_u32)
+    );
+    assert_eq!(
+        core::iter::empty::<Seconds<u64>>().sum::<Seconds<u64>>(),
+        Seconds(0_u64)
+    );
+
+    assert_eq!(Milliseconds::try_sum(latencies), Ok(Milliseconds(15_u32)));
+    assert_eq!(
+        Seconds::try_sum([Seconds(u8::MAX), Seconds(1)]),
+        Err(ConversionError::Overflow)
+    );
+
+    let generic = [
+        Generic::new(2_u32, Fraction::new(1, 1)),
+        Generic::new(250_u32, Fraction::new(1, 1_000)),
+    ];
+    assert_eq!(
+        generic.iter().sum::<Generic<u32>>(),
+        Generic::new(2_u32, Fraction::new(1, 1))
+    );
+    assert_eq!(
+        Generic::try_sum(generic.iter().rev().copied()),
+        Ok(Generic::new(2_250_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        Generic::try_sum(core::iter::empty::<Generic<u32>>()),
+        Ok(Generic::new(0_u32, Fraction::new(1, 1)))
+    );
+    assert_eq!(
+        Generic::try_sum([
+            Generic::new(u32::MAX, Fraction::new(1, 1)),
+            Generic::new(1_u32, Fraction::new(1, 1))
+        ]),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+#[should_panic]
+fn sum_overflow() {
+    let _: Seconds<u32> = [Seconds(u32::MAX), Seconds(1_u32)].iter().sum();
+}
+
+#[test]
+fn min_max_clamp() {
+    assert_eq!(Milliseconds(5_u32).min(Seconds(1_u32)), Milliseconds(5_u32));
+    assert_eq!(
+        Milliseconds(5_u32).max(Seconds(1_u32)),
+        Milliseconds(1_000_u32)
+    );
+    assert_eq!(
+        Microseconds(1_500_u32).min(Milliseconds(1_u64)),
+        Microseconds(1_000_u32)
+    );
+    assert_eq!(Seconds(3_u32).max(Seconds(2_u32)), Seconds(3_u32));
+    assert_eq!(Seconds(3_u64).min(Seconds(2_u32)), Seconds(2_u64));
+
+    let (min, max) = (Milliseconds(10_u32), Seconds(1_u32));
+    assert_eq!(
+        Microseconds(1_u32).clamp(min, max),
+        Microseconds(10_000_u32)
+    );
+    assert_eq!(
+        Microseconds(20_000_u32).clamp(min, max),
+        Microseconds(20_000_u32)
+    );
+    assert_eq!(Minutes(1_u32).clamp(min, max), Minutes(0_u32));
+
+    assert!(Seconds(0_u32).is_zero());
+    assert!(!Nanoseconds(1_u8).is_zero());
+    assert!(Generic::new(0_u32, Fraction::new(1, 1_000)).is_zero());
+}
+
+#[test]
+#[should_panic(expected = "Conversion failed")]
+fn max_overflow() {
+    let _ = Milliseconds(1_u32).max(Hours(u32::MAX));
+}
+
+#[test]
+fn literal() {
+    const TABLE: [Microseconds<u32>; 3] = [
+        duration!("1.5ms", Microseconds<u32>),
+        duration!("250 us", Microseconds<u32>),
+        duration!("0.000001 s", Microseconds<u32>),
+    ];
+    assert_eq!(
+        TABLE,
+        [Microseconds(1_500_u32), Microseconds(250), Microseconds(1)]
+    );
+
+    assert_eq!(duration!("1.50h", Minutes<u8>), Minutes(90_u8));
+    assert_eq!(duration!("3µs", Nanoseconds<u64>), Nanoseconds(3_000_u64));
+    assert_eq!(
+        duration!("4294967295ns", Nanoseconds<u32>),
+        Nanoseconds(u32::MAX)
+    );
+
+    assert_eq!(
+        duration!("1.5ms"),
+        Generic::new(15_u32, Fraction::new(1, 10_000))
+    );
+    assert_eq!(duration!("1.5ms"), Generic::<u32>::parse("1.5ms").unwrap());
+    assert_eq!(duration!("2 min"), Seconds(120_u32).into());
+
+    assert_eq!(duration!("2d", Days<u32>), Days(2_u32));
+    assert_eq!(duration!("1w", Weeks<u32>), Weeks(1_u32));
+    assert_eq!(duration!("1.5d", Hours<u32>), Hours(36_u32));
+    assert_eq!(duration!("1w"), Days(7_u32).into());
+}
+
+#[test]
+fn consts() {
+    const LIMITS: [Milliseconds<u32>; 3] = [
+        Milliseconds::<u32>::ZERO,
+        Milliseconds::<u32>::MIN,
+        Milliseconds::<u32>::MAX,
+    ];
+    assert_eq!(
+        LIMITS,
+        [Milliseconds(0_u32), Milliseconds(0), Milliseconds(u32::MAX)]
+    );
+
+    assert_eq!(Hours::<u8>::MAX, Hours(u8::MAX));
+    assert_eq!(Nanoseconds::<u128>::MAX, Nanoseconds(u128::MAX));
+    assert!(Seconds::<u64>::ZERO.is_zero());
+}
+
+#[test]
+fn rounding() {
+    use embedded_time::fixed_point::RoundingMode::*;
+
+    assert_eq!(
+        Seconds::<u32>::try_convert_from_with(Milliseconds(1_999_u32), Floor),
+        Ok(Seconds(1_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_convert_from_with(Milliseconds(1_999_u32), Ceil),
+        Ok(Seconds(2_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_convert_from_with(Milliseconds(1_999_u32), Nearest),
+        Ok(Seconds(2_u32))
+    );
+    assert_eq!(
+        Milliseconds(1_499_u32).to_rounded(Nearest),
+        Ok(Seconds(1_u32))
+    );
+    assert_eq!(
+        Milliseconds(1_500_u32).to_rounded(Nearest),
+        Ok(Seconds(2_u32))
+    );
+    assert_eq!(Milliseconds(2_000_u32).to_rounded(Ceil), Ok(Seconds(2_u32)));
+    assert_eq!(
+        Seconds(3_u32).to_rounded(Floor),
+        Ok(Milliseconds(3_000_u64))
+    );
+
+    // rounding can't make the result fit
+    assert_eq!(
+        Nanoseconds(u64::MAX).to_rounded::<Nanoseconds<u32>>(Nearest),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds(u128::MAX).to_rounded::<Milliseconds<u128>>(Floor),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Seconds(u128::MAX).to_rounded::<Hours<u128>>(Ceil),
+        Ok(Hours(u128::MAX / 3_600 + 1))
+    );
+
+    let duration = Generic::new(1_999_u32, Fraction::new(1, 1_000));
+    assert_eq!(duration.to_rounded(Floor), Ok(Seconds(1_u32)));
+    assert_eq!(duration.to_rounded(Ceil), Ok(Seconds(2_u32)));
+    assert_eq!(duration.to_rounded(Nearest), Ok(Seconds(2_u32)));
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn float() {
+    assert_eq!(Seconds(3_u32).to_secs_f64(), 3.0);
+    assert_eq!(Microseconds(1_500_u64).to_secs_f64(), 0.0015);
+    assert_eq!(Minutes(2_u8).to_secs_f32(), 120.0);
+    assert_eq!(Nanoseconds(u128::MAX).to_secs_f64(), u128::MAX as f64 / 1e9);
+    assert_eq!(Generic::new(5_u32, Fraction::new(1, 4)).to_secs_f32(), 1.25);
+
+    assert_eq!(
+        Milliseconds::<u32>::try_from_secs_f64(1.5),
+        Ok(Milliseconds(1_500_u32))
+    );
+    assert_eq!(Seconds::<u32>::try_from_secs_f64(1.999), Ok(Seconds(1_u32)));
+    assert_eq!(Hours::<u32>::try_from_secs_f32(7_200.0), Ok(Hours(2_u32)));
+    assert_eq!(
+        Nanoseconds::<u64>::try_from_secs_f32(0.0),
+        Ok(Nanoseconds(0_u64))
+    );
+
+    for secs in [-1.0, f64::NAN, f64::INFINITY, 5.0].iter() {
+        assert_eq!(
+            Nanoseconds::<u32>::try_from_secs_f64(*secs),
+            Err(ConversionError::ConversionFailure)
+        );
+    }
+    assert_eq!(
+        Nanoseconds::<u128>::try_from_secs_f64(f64::MAX),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn display_hms() {
+    assert_eq!(
+        Milliseconds(5_025_678_u32).display_hms().to_string(),
+        "01:23:45.678"
+    );
+    assert_eq!(Seconds(0_u32).display_hms().to_string(), "00:00:00");
+    assert_eq!(Minutes(61_u8).display_hms().to_string(), "01:01:00");
+    assert_eq!(
+        Nanoseconds(1_u32).display_hms().to_string(),
+        "00:00:00.000000001"
+    );
+    assert_eq!(
+        Hours(u128::MAX).display_hms().to_string(),
+        Seconds(u128::MAX).display_hms().to_string()
+    );
+
+    // precision
+    assert_eq!(
+        format!("{:.3}", Nanoseconds(1_999_999_999_u32).display_hms()),
+        "00:00:01.999"
+    );
+    assert_eq!(
+        format!("{:.2}", Seconds(1_u32).display_hms()),
+        "00:00:01.00"
+    );
+    assert_eq!(
+        format!("{:.12}", Microseconds(1_u32).display_hms()),
+        "00:00:00.000001000"
+    );
+    assert_eq!(
+        format!("{:.0}", Milliseconds(1_500_u32).display_hms()),
+        "00:00:01"
+    );
+
+    // scaling factors
+    assert_eq!(
+        Generic::new(5_u32, Fraction::new(1, 10))
+            .display_hms()
+            .to_string(),
+        "00:00:00.5"
+    );
+    assert_eq!(
+        Generic::new(3_u32, Fraction::new(5, 2))
+            .display_hms()
+            .to_string(),
+        "00:00:07.5"
+    );
+    assert_eq!(
+        Generic::new(1_u32, Fraction::new(2, 4))
+            .display_hms()
+            .to_string(),
+        "00:00:00.5"
+    );
+}
+
+#[test]
+fn try_from_int() {
+    assert_eq!(
+        Milliseconds::<u16>::try_from_u32(65_535),
+        Ok(Milliseconds(u16::MAX))
+    );
+    assert_eq!(
+        Milliseconds::<u16>::try_from_u32(65_536),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(Seconds::<u64>::try_from_u8(5), Ok(Seconds(5_u64)));
+    assert_eq!(Hours::<u32>::try_from_u128(7), Ok(Hours(7_u32)));
+    assert_eq!(
+        Nanoseconds::<u64>::try_from_u128(u128::MAX),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        Microseconds::<u8>::try_from(255_u16),
+        Ok(Microseconds(255_u8))
+    );
+    assert_eq!(
+        Microseconds::<u8>::try_from(256_u16),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Minutes::<u64>::try_from(u128::from(u64::MAX)),
+        Ok(Minutes(u64::MAX))
+    );
+    assert_eq!(
+        Minutes::<u32>::try_from(u64::from(u32::MAX) + 1),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn from_str() {
+    use embedded_time::ParseError;
+
+    assert_eq!("250ms".parse(), Ok(Milliseconds(250_u32)));
+    assert_eq!("1h30m".parse(), Ok(Seconds(5_400_u32)));
+    assert_eq!("1h 30min 15s".parse(), Ok(Seconds(5_415_u32)));
+    assert_eq!(" 2.5 s ".parse(), Ok(Milliseconds(2_500_u32)));
+    assert_eq!("1.5us".parse(), Ok(Nanoseconds(1_500_u64)));
+    assert_eq!("3µs".parse(), Ok(Microseconds(3_u32)));
+    assert_eq!("2d".parse(), Ok(Days(2_u32)));
+    assert_eq!("1w".parse(), Ok(Weeks(1_u32)));
+    assert_eq!("1w 2d 12h".parse(), Ok(Hours(228_u32)));
+    assert_eq!("0.5w".parse(), Ok(Hours(84_u32)));
+    assert_eq!("1d".parse::<Weeks<u32>>(), Err(ParseError::Inexact));
+
+    assert_eq!(
+        "1.5s".parse::<Generic<u32>>(),
+        Ok(Generic::new(3_u32, Fraction::new(1, 2)))
+    );
+    assert_eq!(
+        "250ms".parse::<Generic<u32>>(),
+        Ok(Generic::new(1_u32, Fraction::new(1, 4)))
+    );
+
+    assert_eq!("2.5s".parse::<Seconds<u32>>(), Err(ParseError::Inexact));
+    assert_eq!(
+        "5 days".parse::<Seconds<u32>>(),
+        Err(ParseError::UnknownUnit)
+    );
+    assert_eq!("5".parse::<Seconds<u32>>(), Err(ParseError::UnknownUnit));
+    assert_eq!("s".parse::<Seconds<u32>>(), Err(ParseError::InvalidNumber));
+    assert_eq!("".parse::<Seconds<u32>>(), Err(ParseError::InvalidNumber));
+    assert_eq!(
+        "-1s".parse::<Seconds<u32>>(),
+        Err(ParseError::InvalidNumber)
+    );
+    assert_eq!("300s".parse::<Seconds<u8>>(), Err(ParseError::Overflow));
+}
+
+#[test]
+fn generic_arithmetic() {
+    let half_seconds = Generic::new(3_u32, Fraction::new(1, 2));
+    let millis = Generic::new(1_500_u32, Fraction::new(1, 1_000));
+
+    assert_eq!(half_seconds, millis);
+    assert_eq!(millis, Milliseconds(1_500_u32).into());
+    assert_ne!(
+        half_seconds,
+        Generic::new(1_501_u32, Fraction::new(1, 1_000))
+    );
+    assert!(half_seconds < Generic::new(1_501_u32, Fraction::new(1, 1_000)));
+    assert!(half_seconds > Generic::new(1_499_u32, Fraction::new(1, 1_000)));
+    assert!(
+        Generic::new(1_u32, Fraction::new(1, 3)) > Generic::new(333_u32, Fraction::new(1, 1_000))
+    );
+    assert!(
+        Generic::new(0_u32, Fraction::new(1, 3)) < Generic::new(1_u32, Fraction::new(1, 1_000))
+    );
+
+    // a value that doesn't fit the common scaling factor is the larger one
+    assert!(
+        Generic::new(u32::MAX, Fraction::new(1, 1)) > Generic::new(1_u32, Fraction::new(1, 1_000))
+    );
+
+    assert_eq!(
+        half_seconds + Seconds(1_u32),
+        Generic::new(5_u32, Fraction::new(1, 2))
+    );
+    assert_eq!(half_seconds + millis, Seconds(3_u32).into());
+    assert_eq!(
+        (half_seconds + millis).scaling_factor(),
+        &Fraction::new(1, 2)
+    );
+    assert_eq!(
+        millis - half_seconds,
+        Generic::new(0_u32, Fraction::new(1, 1))
+    );
+    // the rhs is truncated to the scaling factor of the lhs
+    assert_eq!(half_seconds - Milliseconds(999_u32), Seconds(1_u32).into());
+
+    assert_eq!(millis % Milliseconds(400_u32), Milliseconds(300_u32).into());
+    assert_eq!(half_seconds % Seconds(1_u32), Milliseconds(500_u32).into());
+    assert_eq!(half_seconds % Milliseconds(1_u32), Seconds(0_u32).into());
+
+    assert_eq!(format!("{}", half_seconds), "3 × 1/2 s");
+    assert_eq!(format!("{:04}", millis), "1500 × 1/1000 s");
+}
+
+#[test]
+#[should_panic(expected = "Sub failed")]
+fn generic_sub_overflow() {
+    let _ = Generic::new(1_u32, Fraction::new(1, 1)) - Milliseconds(2_000_u32);
+}
+
+embedded_time::duration_unit!(
+    /// Frames of a 25 fps video
+    Frames, (1, 25)
+);
+
+#[derive(Debug)]
+struct FrameClock;
+
+impl embedded_time::Clock for FrameClock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 25);
+
+    fn try_now(&self) -> Result<embedded_time::Instant<Self>, embedded_time::clock::Error> {
+        Ok(embedded_time::Instant::new(100))
+    }
+}
+
+#[test]
+fn custom_unit() {
+    use embedded_time::fixed_point::FixedPoint;
+
+    assert_eq!(Frames::new(5_u32).integer(), 5);
+    assert_eq!(Frames::<u32>::SCALING_FACTOR, Fraction::new(1, 25));
+    assert_eq!(format!("{}", Frames(5_u32)), "5");
+
+    assert_eq!(Frames::try_from(Seconds(2_u32)), Ok(Frames(50_u32)));
+    assert_eq!(Frames::try_from(Milliseconds(100_u32)), Ok(Frames(2_u32)));
+    assert_eq!(
+        Milliseconds::try_from(Frames(3_u32)),
+        Ok(Milliseconds(120_u32))
+    );
+    assert_eq!(
+        Frames::<u8>::try_from(Hours(1_u8)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(
+        Frames::<u32>::try_from(Generic::new(1_u32, Fraction::new(1, 5))),
+        Ok(Frames(5_u32))
+    );
+    assert_eq!(
+        Generic::from(Frames(3_u32)),
+        Generic::new(3_u32, Fraction::new(1, 25))
+    );
+    assert_eq!(
+        Frames(3_u32).to_generic::<u32>(Fraction::new(1, 1_000)),
+        Ok(Generic::new(120_u32, Fraction::new(1, 1_000)))
+    );
+
+    assert_eq!(Frames(5_u32), Frames(5_u64));
+    assert!(Frames(5_u32) < Frames(6_u64));
+    assert_eq!(Frames(25_u32), Seconds(1_u32));
+    assert_eq!(Seconds(1_u32), Frames(25_u32));
+    assert_ne!(Frames(26_u32), Seconds(1_u32));
+    assert!(Frames(1_u32) > Milliseconds(39_u32));
+    assert!(Milliseconds(39_u32) < Frames(1_u32));
+    assert_eq!(Frames(5_u32).max(Frames(7_u32)), Frames(7_u32));
+
+    assert_eq!(Frames(1_u32) + Seconds(1_u32), Frames(26_u32));
+    assert_eq!(Frames(1_u32) + Frames(1_u32), Frames(2_u32));
+    assert_eq!(Frames(26_u32) - Milliseconds(40_u32), Frames(25_u32));
+    assert_eq!(Frames(26_u32) % Seconds(1_u32), Frames(1_u32));
+    assert_eq!(Frames(3_u32) * 2, Frames(6_u32));
+    assert_eq!(Frames(7_u32) / 2, Frames(3_u32));
+
+    let now = embedded_time::Clock::try_now(&FrameClock).unwrap();
+    assert_eq!(
+        (now + Frames(5_u32)).duration_since_epoch(),
+        Generic::new(105_u32, Fraction::new(1, 25))
+    );
+}
+
+#[test]
+fn display_uptime() {
+    assert_eq!(
+        Seconds(274_376_u32).display_uptime().to_string(),
+        "3d 04:12:56"
+    );
+    assert_eq!(Seconds(0_u32).display_uptime().to_string(), "00:00:00");
+    assert_eq!(
+        Milliseconds(86_399_999_u32).display_uptime().to_string(),
+        "23:59:59"
+    );
+    assert_eq!(Hours(24_u8).display_uptime().to_string(), "1d 00:00:00");
+    assert_eq!(
+        Hours(u32::MAX).display_uptime().to_string(),
+        "178956970d 15:00:00"
+    );
+    assert_eq!(
+        Milliseconds(u64::MAX).display_uptime().to_string(),
+        "213503982334d 14:25:51"
+    );
+
+    // saturates at u128::MAX seconds
+    assert_eq!(
+        Hours(u128::MAX).display_uptime(),
+        Seconds(u128::MAX).display_uptime()
+    );
+    assert_eq!(
+        Seconds(u128::MAX).display_uptime().to_string(),
+        "3938453320844195178974243141571391d 08:04:15"
+    );
+
+    assert_eq!(
+        Generic::new(3_u32, Fraction::new(86_400, 1))
+            .display_uptime()
+            .to_string(),
+        "3d 00:00:00"
+    );
+}
+
+#[test]
+fn widening_from_bigger_units() {
+    // every u32 fits
+    assert_eq!(
+        Milliseconds::<u64>::from(Weeks(u32::MAX)),
+        Milliseconds(u64::from(u32::MAX) * 604_800_000)
+    );
+    assert_eq!(
+        Microseconds::<u64>::from(Hours(u32::MAX)),
+        Microseconds(u64::from(u32::MAX) * 3_600_000_000)
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::from(Seconds(u32::MAX)),
+        Nanoseconds(u64::from(u32::MAX) * 1_000_000_000)
+    );
+
+    // the largest values that fit
+    assert_eq!(
+        Microseconds::<u64>::try_from(Weeks(30_500_568_u32)),
+        Ok(Microseconds(30_500_568 * 604_800_000_000_u64))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Weeks(30_500_u32)),
+        Ok(Nanoseconds(30_500 * 604_800_000_000_000_u64))
+    );
+    assert_eq!(
+        Microseconds::<u64>::try_from(Days(213_503_982_u32)),
+        Ok(Microseconds(213_503_982 * 86_400_000_000_u64))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Days(213_503_u32)),
+        Ok(Nanoseconds(213_503 * 86_400_000_000_000_u64))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Hours(5_124_095_u32)),
+        Ok(Nanoseconds(5_124_095 * 3_600_000_000_000_u64))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from(Minutes(307_445_734_u32)),
+        Ok(Nanoseconds(307_445_734 * 60_000_000_000_u64))
+    );
+
+    // and one more
+    assert!(Microseconds::<u64>::try_from(Weeks(30_500_569_u32)).is_err());
+    assert!(Nanoseconds::<u64>::try_from(Weeks(30_501_u32)).is_err());
+    assert!(Microseconds::<u64>::try_from(Days(213_503_983_u32)).is_err());
+    assert!(Nanoseconds::<u64>::try_from(Days(213_504_u32)).is_err());
+    assert!(Nanoseconds::<u64>::try_from(Hours(5_124_096_u32)).is_err());
+    assert!(Nanoseconds::<u64>::try_from(Minutes(307_445_735_u32)).is_err());
+    assert!(Nanoseconds::<u64>::try_from(Weeks(u32::MAX)).is_err());
+}
+
+#[test]
+fn days_and_weeks() {
+    assert_eq!(1_u32.weeks(), Weeks(1_u32));
+    assert_eq!(1_u32.days(), Days(1_u32));
+    assert_eq!(format!("{}", Days(3_u32)), "3");
+
+    assert_eq!(Days::<u32>::try_from(Weeks(2_u32)), Ok(Days(14_u32)));
+    assert_eq!(Hours::<u32>::try_from(Days(2_u32)), Ok(Hours(48_u32)));
+    assert_eq!(Seconds::<u64>::from(Weeks(1_u32)), Seconds(604_800_u64));
+    assert_eq!(Weeks::<u32>::from(Days(20_u32)), Weeks(2_u32));
+    assert_eq!(Days::<u32>::from(Milliseconds(u32::MAX)), Days(49_u32));
+    assert_eq!(Days::<u32>::try_from(Seconds(86_400_u64)), Ok(Days(1_u32)));
+    assert_eq!(
+        Nanoseconds::<u32>::try_from(Days(1_u32)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(
+        Days(1_u32).to_generic::<u32>(Fraction::new(3_600, 1)),
+        Ok(Generic::new(24_u32, Fraction::new(3_600, 1)))
+    );
+    assert_eq!(
+        Days::<u32>::try_from(Generic::new(48_u32, Fraction::new(3_600, 1))),
+        Ok(Days(2_u32))
+    );
+
+    assert_eq!(Weeks(1_u32), Days(7_u32));
+    assert_eq!(Days(1_u32), Minutes(1_440_u32));
+    assert_ne!(Days(1_u32), Hours(25_u32));
+    assert!(Weeks(1_u32) > Days(6_u32));
+    assert!(Hours(167_u32) < Weeks(1_u32));
+    assert_eq!(Days(1_u32).max(Weeks(1_u32)), Days(7_u32));
+
+    assert_eq!(Days(1_u32) + Hours(48_u32), Days(3_u32));
+    assert_eq!(Weeks(2_u32) - Days(7_u32), Weeks(1_u32));
+    assert_eq!(Days(9_u32) % Weeks(1_u32), Days(2_u32));
+    assert_eq!(Days(3_u32).display_uptime().to_string(), "3d 00:00:00");
+
+    assert_eq!(
+        core::time::Duration::try_from(Days(2_u32)),
+        Ok(core::time::Duration::from_secs(172_800))
+    );
+    assert_eq!(
+        Weeks::<u32>::try_from(core::time::Duration::from_secs(1_300_000)),
+        Ok(Weeks(2_u32))
+    );
+    assert_eq!(
+        Days::<u64>::from(core::time::Duration::from_secs(172_800)),
+        Days(2_u64)
+    );
+}
+
+#[test]
+fn picoseconds() {
+    assert_eq!(
+        Nanoseconds::from(Picoseconds(1_999_u64)),
+        Nanoseconds(1_u64)
+    );
+    assert_eq!(
+        Nanoseconds::from(Picoseconds(u64::MAX)),
+        Nanoseconds(u64::MAX / 1_000)
+    );
+    assert_eq!(Nanoseconds::from(Picoseconds(255_u8)), Nanoseconds(0_u8));
+
+    assert_eq!(
+        Picoseconds(1_499_u64).to_nanoseconds_rounded(),
+        Nanoseconds(1_u64)
+    );
+    assert_eq!(
+        Picoseconds(1_500_u64).to_nanoseconds_rounded(),
+        Nanoseconds(2_u64)
+    );
+    assert_eq!(
+        Picoseconds(u64::MAX).to_nanoseconds_rounded(),
+        Nanoseconds(u64::MAX / 1_000 + 1)
+    );
+    assert_eq!(
+        Picoseconds(255_u8).to_nanoseconds_rounded(),
+        Nanoseconds(0_u8)
+    );
+
+    assert_eq!(
+        Picoseconds::<u64>::try_from(Nanoseconds(u32::MAX)),
+        Ok(Picoseconds(u64::from(u32::MAX) * 1_000))
+    );
+    assert_eq!(
+        Picoseconds::<u8>::try_from(Nanoseconds(0_u32)),
+        Ok(Picoseconds(0_u8))
+    );
+    assert_eq!(
+        Picoseconds::<u128>::try_from(Nanoseconds(u128::MAX)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        core::time::Duration::try_from(Picoseconds(2_500_000_000_999_u64)),
+        Ok(core::time::Duration::new(2, 500_000_000))
+    );
+    assert_eq!(
+        core::time::Duration::try_from(Picoseconds(u128::MAX)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Picoseconds::<u64>::try_from(core::time::Duration::new(2, 5)),
+        Ok(Picoseconds(2_000_000_005_000_u64))
+    );
+    assert_eq!(
+        Picoseconds::<u32>::try_from(core::time::Duration::from_millis(5)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        Picoseconds(5_u64).checked_add(Picoseconds(3)),
+        Ok(Picoseconds(8))
+    );
+    assert_eq!(
+        Picoseconds(u64::MAX).checked_add(Picoseconds(1)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Picoseconds(5_u64).checked_sub(Picoseconds(8)),
+        Err(ConversionError::Overflow)
+    );
+    assert!(Picoseconds(5_u64) < Picoseconds(8));
+    assert_eq!(Picoseconds(1_250_u64).to_string(), "1250");
+}
+
+#[test]
+fn const_construction() {
+    struct Config {
+        timeout: Milliseconds,
+        poll: Microseconds<u64>,
+        clock: Hertz,
+        tick: Generic<u32>,
+    }
+
+    static CONFIGS: [Config; 2] = [
+        Config {
+            timeout: Milliseconds::new(100),
+            poll: Microseconds::new(250),
+            clock: Hertz::new(32_768),
+            tick: Generic::new(1, Fraction::new(1, 32_768).reduce()),
+        },
+        Config {
+            timeout: Milliseconds::new(1_000),
+            poll: Microseconds::new(10_000),
+            clock: Hertz::new(1_000_000),
+            tick: Generic::new(1, Fraction::new(10, 10_000_000).reduce()),
+        },
+    ];
+    const PICOS: Picoseconds = Picoseconds::new(1_500);
+
+    assert_eq!(CONFIGS[0].timeout, Milliseconds(100_u32));
+    assert_eq!(CONFIGS[1].poll, Milliseconds(10_u32));
+    assert_eq!(CONFIGS[0].clock.to_duration(), Ok(Microseconds(30_u32)));
+    assert_eq!(
+        *CONFIGS[1].tick.scaling_factor(),
+        Fraction::new(1, 1_000_000)
+    );
+    assert_eq!(PICOS.integer(), 1_500);
+}
+
+#[test]
+fn const_convert() {
+    use embedded_time::{const_convert, duration_unit};
+
+    duration_unit!(RtcTicks, (1, 32_768));
+
+    static TIMINGS: [Microseconds<u32>; 3] = [
+        const_convert!(Milliseconds(5_u32) => Microseconds<u32>),
+        const_convert!(Seconds(1_u8) => Microseconds<u32>),
+        const_convert!(Nanoseconds(1_999_u64) => Microseconds<u32>),
+    ];
+
+    assert_eq!(
+        TIMINGS,
+        [
+            Microseconds(5_000_u32),
+            Microseconds(1_000_000_u32),
+            Microseconds(1_u32)
+        ]
+    );
+    assert_eq!(
+        const_convert!(Seconds(2_u32) => Nanoseconds<u32>),
+        Nanoseconds(2_000_000_000_u32)
+    );
+    assert_eq!(
+        const_convert!(Hours(u32::MAX) => Nanoseconds<u128>),
+        Nanoseconds(u128::from(u32::MAX) * 3_600_000_000_000)
+    );
+    assert_eq!(
+        const_convert!(Milliseconds(500_u32) => RtcTicks<u16>),
+        RtcTicks(16_384_u16)
+    );
+    assert_eq!(
+        const_convert!(Kilohertz(32_u32) => Hertz<u16>),
+        Hertz(32_000_u16)
+    );
+}
+
+#[test]
+fn generic_comparisons_never_panic() {
+    use core::cmp::Ordering;
+
+    // 1/32_768 and 1/1_000_000_000 have no common scaling factor that fits a `Fraction`
+    let rtc = Generic::new(32_768_u32, Fraction::new(1, 32_768));
+    let nanos = Generic::new(1_000_000_000_u32, Fraction::new(1, 1_000_000_000));
+    assert_eq!(rtc.cmp(&nanos), Ordering::Equal);
+    assert_eq!(rtc, nanos);
+    assert!(Generic::new(32_769_u32, Fraction::new(1, 32_768)) > nanos);
+    assert!(Generic::new(999_999_999_u32, Fraction::new(1, 1_000_000_000)) < rtc);
+
+    // neither fits the integer type once converted
+    let lhs = Generic::new(u128::MAX, Fraction::new(1_000, 7));
+    let rhs = Generic::new(u128::MAX - 1, Fraction::new(1_000, 7));
+    assert_eq!(lhs.cmp(&rhs), Ordering::Greater);
+    assert_eq!(
+        Generic::new(u128::MAX, Fraction::new(u32::MAX, 1))
+            .cmp(&Generic::new(u128::MAX, Fraction::new(u32::MAX - 1, 1))),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Generic::new(u128::MAX, Fraction::new(1, 3))
+            .cmp(&Generic::new(u128::MAX / 3, Fraction::new(1, 1))),
+        Ordering::Equal
+    );
+    assert!(
+        Generic::new(u64::MAX, Fraction::new(7, 1_000_000_007))
+            < Generic::new(u64::MAX, Fraction::new(7, 1_000_000_006))
+    );
+}
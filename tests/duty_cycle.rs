@@ -0,0 +1,139 @@
+use embedded_time::{
+    duration, duration::*, duty_cycle::DutyCycle, fraction::Fraction, rate::*, ConversionError,
+};
+
+#[test]
+fn construction() {
+    assert_eq!(DutyCycle::from_ppm(1_000_000), Ok(DutyCycle::FULL));
+    assert_eq!(
+        DutyCycle::from_ppm(1_000_001),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        DutyCycle::from_permille(125).map(|duty| duty.ppm()),
+        Ok(125_000)
+    );
+    assert_eq!(
+        DutyCycle::from_permille(1_001),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(DutyCycle::from_percent(0), Ok(DutyCycle::ZERO));
+    assert_eq!(
+        DutyCycle::from_percent(101),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(DutyCycle::default(), DutyCycle::ZERO);
+    assert_eq!(
+        DutyCycle::from_percent(30).unwrap().complement(),
+        DutyCycle::from_percent(70).unwrap()
+    );
+}
+
+#[test]
+fn from_durations() {
+    assert_eq!(
+        DutyCycle::from_durations(Microseconds(1_500_u32), Milliseconds(2_u32)),
+        DutyCycle::from_percent(75)
+    );
+    // truncated
+    assert_eq!(
+        DutyCycle::from_durations(Seconds(1_u32), Seconds(3_u32)).map(|duty| duty.ppm()),
+        Ok(333_333)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Hours(1_u32), Hours(1_u32)),
+        Ok(DutyCycle::FULL)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Nanoseconds(0_u32), Hours(1_u32)),
+        Ok(DutyCycle::ZERO)
+    );
+    // just under half
+    assert_eq!(
+        DutyCycle::from_durations(Nanoseconds(u128::MAX / 2), Nanoseconds(u128::MAX))
+            .map(|duty| duty.ppm()),
+        Ok(499_999)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Nanoseconds(u128::MAX / 2 + 1), Nanoseconds(u128::MAX - 1)),
+        DutyCycle::from_percent(50)
+    );
+
+    assert_eq!(
+        DutyCycle::from_durations(Milliseconds(3_u32), Milliseconds(2_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Milliseconds(0_u32), Milliseconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        DutyCycle::from_durations(Hours(u128::MAX), Nanoseconds(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn on_and_off_times() {
+    let duty = DutyCycle::from_ppm(333_333).unwrap();
+
+    assert_eq!(Seconds(3_u32) * duty, Seconds(0_u32));
+    assert_eq!(Milliseconds(3_000_u32) * duty, Milliseconds(999_u32));
+    assert_eq!(duty.on_time(Milliseconds(3_000_u32)), Milliseconds(999_u32));
+    assert_eq!(
+        duty.off_time(Milliseconds(3_000_u32)),
+        Milliseconds(2_001_u32)
+    );
+
+    assert_eq!(
+        Milliseconds(u32::MAX) * DutyCycle::FULL,
+        Milliseconds(u32::MAX)
+    );
+    assert_eq!(
+        Nanoseconds(u128::MAX) * DutyCycle::FULL,
+        Nanoseconds(u128::MAX)
+    );
+    assert_eq!(
+        Nanoseconds(u128::MAX) * DutyCycle::ZERO,
+        Nanoseconds(0_u128)
+    );
+    assert_eq!(
+        Milliseconds(200_u8) * DutyCycle::from_percent(50).unwrap(),
+        Milliseconds(100_u8)
+    );
+
+    assert_eq!(
+        duration::Generic::new(1_000_u32, Fraction::new(1, 32_768))
+            * DutyCycle::from_percent(25).unwrap(),
+        duration::Generic::new(250_u32, Fraction::new(1, 32_768))
+    );
+
+    // a 25 kHz fan PWM at 40 %
+    let duty = DutyCycle::from_percent(40).unwrap();
+    assert_eq!(
+        duty.on_time_at(Kilohertz(25_u32)),
+        Ok(Nanoseconds(16_000_u32))
+    );
+    assert_eq!(
+        duty.on_time_at::<Seconds<u32>, _>(Hertz(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn conversions_and_formatting() {
+    assert_eq!(
+        Fraction::from(DutyCycle::from_percent(25).unwrap()),
+        Fraction::new(1, 4)
+    );
+
+    assert_eq!(DutyCycle::ZERO.to_string(), "0%");
+    assert_eq!(DutyCycle::FULL.to_string(), "100%");
+    assert_eq!(DutyCycle::from_permille(125).unwrap().to_string(), "12.5%");
+    assert_eq!(
+        DutyCycle::from_ppm(333_333).unwrap().to_string(),
+        "33.3333%"
+    );
+    assert_eq!(DutyCycle::from_ppm(1).unwrap().to_string(), "0.0001%");
+    assert_eq!(DutyCycle::from_ppm(1_050).unwrap().to_string(), "0.105%");
+}
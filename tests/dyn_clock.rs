@@ -0,0 +1,56 @@
+use core::{cell::Cell, convert::TryFrom};
+use embedded_time::{self as time, duration::*, dyn_clock::DynClock, Instant};
+
+#[derive(Debug)]
+struct MilliClock(Cell<u32>);
+
+impl time::Clock for MilliClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[derive(Debug)]
+struct StoppedClock;
+
+impl time::Clock for StoppedClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::NotRunning)
+    }
+}
+
+/// A driver holding whichever clock it is given at runtime
+struct Driver<'a> {
+    clock: &'a dyn DynClock<u32>,
+}
+
+impl Driver<'_> {
+    fn uptime(&self) -> Result<Milliseconds<u32>, time::clock::Error> {
+        self.clock
+            .try_now_generic()
+            .map(|now| Milliseconds::try_from(now).unwrap())
+    }
+}
+
+#[test]
+fn swap_clocks() {
+    let millis = MilliClock(Cell::new(1_500));
+    let mut driver = Driver { clock: &millis };
+
+    assert_eq!(driver.clock.scaling_factor(), Fraction::new(1, 1_000));
+    assert_eq!(driver.uptime(), Ok(Milliseconds(1_500)));
+    millis.0.set(2_000);
+    assert_eq!(driver.uptime(), Ok(Milliseconds(2_000)));
+
+    driver.clock = &StoppedClock;
+    assert_eq!(driver.clock.scaling_factor(), Fraction::new(1, 32_768));
+    assert_eq!(driver.uptime(), Err(time::clock::Error::NotRunning));
+}
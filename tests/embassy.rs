@@ -0,0 +1,82 @@
+#![cfg(feature = "embassy")]
+
+use core::task::Waker;
+use embassy_time_driver::{Driver, TICK_HZ};
+use embedded_time::{
+    self as time, async_delay::WakeHook, embassy::EmbassyDriver, fraction::Fraction, Instant,
+};
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::Wake,
+};
+
+/// A 32.768 kHz clock
+#[derive(Debug, Default)]
+struct Clock(AtomicU64);
+
+impl time::Clock for Clock {
+    type T = u64;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.load(Ordering::SeqCst)))
+    }
+}
+
+#[derive(Debug, Default)]
+struct AlarmHook(Mutex<Option<Instant<Clock>>>);
+
+impl WakeHook<Clock> for AlarmHook {
+    fn register(&self, expiration: &Instant<Clock>, _waker: &Waker) {
+        *self.0.lock().unwrap() = Some(*expiration);
+    }
+}
+
+fn driver(ticks: u64) -> EmbassyDriver<Clock, AlarmHook> {
+    EmbassyDriver::new(Clock(AtomicU64::new(ticks)), AlarmHook::default())
+}
+
+#[test]
+fn now() {
+    assert_eq!(driver(0).now(), 0);
+    assert_eq!(driver(32_768).now(), TICK_HZ);
+    assert_eq!(driver(3 * 32_768 + 1).now(), 3 * TICK_HZ + TICK_HZ / 32_768);
+}
+
+#[test]
+fn schedule_wake() {
+    let driver = driver(32_768);
+
+    driver.schedule_wake(2 * TICK_HZ, Waker::noop());
+    assert_eq!(*driver.hook().0.lock().unwrap(), Some(Instant::new(65_536)));
+
+    // rounded up to the next clock tick
+    driver.schedule_wake(2 * TICK_HZ + 1, Waker::noop());
+    assert_eq!(*driver.hook().0.lock().unwrap(), Some(Instant::new(65_537)));
+}
+
+#[test]
+fn schedule_wake_in_the_past() {
+    struct CountingWaker(AtomicUsize);
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref()
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let driver = driver(32_768);
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+
+    driver.schedule_wake(TICK_HZ, &Waker::from(counter.clone()));
+
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    assert_eq!(*driver.hook().0.lock().unwrap(), None);
+}
@@ -0,0 +1,133 @@
+use embedded_time::{duration::*, epoch::UnixTime, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 32_768);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn construction() {
+    assert_eq!(
+        UnixTime::new(5, 999_999_999).unwrap().subsec_nanos(),
+        999_999_999
+    );
+    assert_eq!(UnixTime::new(5, 1_000_000_000), None);
+    assert_eq!(UnixTime::from_secs(5).secs(), 5);
+    assert_eq!(UnixTime::default(), UnixTime::EPOCH);
+
+    assert!(UnixTime::new(5, 1).unwrap() > UnixTime::from_secs(5));
+    assert!(UnixTime::new(4, 999_999_999).unwrap() < UnixTime::from_secs(5));
+}
+
+#[test]
+fn arithmetic() {
+    let time = UnixTime::new(1_600_000_000, 900_000_000).unwrap();
+
+    assert_eq!(
+        time + Milliseconds(100_u32),
+        UnixTime::from_secs(1_600_000_001)
+    );
+    assert_eq!(
+        time - Hours(1_u32),
+        UnixTime::new(1_599_996_400, 900_000_000).unwrap()
+    );
+    assert_eq!(
+        time.checked_add(Generic::new(1_u32, Fraction::new(1, 3))),
+        UnixTime::new(1_600_000_001, 233_333_333)
+    );
+    assert_eq!(
+        UnixTime::new(u64::MAX, 999_999_999)
+            .unwrap()
+            .checked_add(Nanoseconds(1_u32)),
+        None
+    );
+    assert_eq!(UnixTime::from_secs(1).checked_sub(Seconds(2_u32)), None);
+
+    assert_eq!(
+        time.checked_duration_since(&UnixTime::from_secs(1_600_000_000)),
+        Some(Generic::new(
+            900_000_000_u64,
+            Fraction::new(1, 1_000_000_000)
+        ))
+    );
+    assert_eq!(
+        UnixTime::from_secs(u64::MAX).checked_duration_since(&UnixTime::EPOCH),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_before_epoch() {
+    let _ = UnixTime::EPOCH - Nanoseconds(1_u32);
+}
+
+#[test]
+fn instants() {
+    let epoch = UnixTime::new(1_600_000_000, 500_000_000).unwrap();
+
+    assert_eq!(
+        UnixTime::from_instant(&Instant::<Clock>::new(49_152), epoch),
+        Some(UnixTime::from_secs(1_600_000_002))
+    );
+    // truncated to nanoseconds
+    assert_eq!(
+        UnixTime::from_instant(&Instant::<Clock>::new(1), epoch),
+        UnixTime::new(1_600_000_000, 500_030_517)
+    );
+    assert_eq!(
+        UnixTime::from_instant(&Instant::<Clock>::new(1), UnixTime::from_secs(u64::MAX)),
+        UnixTime::new(u64::MAX, 30_517)
+    );
+    assert_eq!(
+        UnixTime::from_instant(
+            &Instant::<Clock>::new(32_768),
+            UnixTime::from_secs(u64::MAX)
+        ),
+        None
+    );
+
+    assert_eq!(
+        UnixTime::from_secs(1_600_000_002).to_instant(epoch),
+        Some(Instant::<Clock>::new(49_152))
+    );
+    // truncated to whole ticks
+    assert_eq!(
+        UnixTime::new(1_600_000_000, 500_030_517)
+            .unwrap()
+            .to_instant(epoch),
+        Some(Instant::<Clock>::new(0))
+    );
+    assert_eq!(UnixTime::from_secs(1).to_instant::<Clock>(epoch), None);
+    assert_eq!(
+        UnixTime::from_secs(1_800_000_000).to_instant::<Clock>(epoch),
+        None
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn system_time() {
+    use core::convert::TryFrom;
+    use embedded_time::ConversionError;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let system_time = UNIX_EPOCH + Duration::new(1_600_000_000, 250);
+    let time = UnixTime::try_from(system_time).unwrap();
+
+    assert_eq!(time, UnixTime::new(1_600_000_000, 250).unwrap());
+    assert_eq!(SystemTime::try_from(time), Ok(system_time));
+
+    assert_eq!(
+        UnixTime::try_from(UNIX_EPOCH - Duration::from_secs(1)),
+        Err(ConversionError::NegDuration)
+    );
+}
@@ -0,0 +1,49 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+use embedded_time::{
+    self as time, extended_clock::ExtendedClock, fraction::Fraction, Clock as _, Instant,
+};
+
+#[derive(Debug)]
+struct Counter(AtomicU32);
+
+impl time::Clock for Counter {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.load(Ordering::SeqCst)))
+    }
+}
+
+#[test]
+fn sixteen_bit() {
+    let clock = ExtendedClock::<_, 16>::new(Counter(AtomicU32::new(65_535)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(65_535)));
+
+    clock.inner().0.store(3, Ordering::SeqCst);
+    clock.on_overflow();
+    assert_eq!(clock.try_now(), Ok(Instant::new(65_539)));
+
+    // bits above the counter width are ignored
+    clock.inner().0.store(0x1_0004, Ordering::SeqCst);
+    assert_eq!(clock.try_now(), Ok(Instant::new(65_540)));
+}
+
+#[test]
+fn thirty_two_bit() {
+    let clock = ExtendedClock::<_, 32>::new(Counter(AtomicU32::new(u32::MAX)));
+
+    clock.on_overflow();
+    clock.on_overflow();
+    assert_eq!(
+        clock.try_now(),
+        Ok(Instant::new(2 * (1 << 32) + u64::from(u32::MAX)))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid counter width")]
+fn invalid_width() {
+    ExtendedClock::<_, 33>::new(Counter(AtomicU32::new(0)));
+}
@@ -0,0 +1,136 @@
+use core::cell::Cell;
+use embedded_time::{
+    self as time, clock, duration::*, fallback_clock::FallbackClock, Clock as _, Instant,
+};
+
+#[derive(Debug)]
+struct Primary(Cell<Option<u32>>);
+
+impl time::Clock for Primary {
+    type T = u32;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        self.0
+            .get()
+            .map(Instant::new)
+            .ok_or(clock::Error::NotRunning)
+    }
+}
+
+#[derive(Debug)]
+struct Secondary(Cell<u8>);
+
+impl time::Clock for Secondary {
+    type T = u8;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 100);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[derive(Debug)]
+struct Slow(Cell<u8>);
+
+impl time::Clock for Slow {
+    type T = u8;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 3);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[test]
+fn primary_healthy() {
+    let clock = FallbackClock::new(Primary(Cell::new(Some(5))), Secondary(Cell::new(200)));
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(5)));
+    clock.primary().0.set(Some(10));
+    assert_eq!(clock.try_now(), Ok(Instant::new(10)));
+
+    assert!(!clock.is_fallback());
+    assert_eq!(clock.switchover(), None);
+}
+
+#[test]
+fn switchover() {
+    let clock = FallbackClock::new(Primary(Cell::new(Some(1_000))), Secondary(Cell::new(250)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_000)));
+
+    clock.primary().0.set(None);
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_000)));
+    assert!(clock.is_fallback());
+    assert_eq!(clock.switchover(), Some(Instant::new(1_000)));
+
+    // the secondary clock wraps
+    clock.secondary().0.set(10);
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_160)));
+
+    // the switch is permanent
+    clock.primary().0.set(Some(0));
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_160)));
+    assert_eq!(clock.switchover(), Some(Instant::new(1_000)));
+}
+
+#[test]
+fn primary_never_read() {
+    let clock = FallbackClock::new(Primary(Cell::new(None)), Secondary(Cell::new(42)));
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+    clock.secondary().0.set(43);
+    assert_eq!(clock.try_now(), Ok(Instant::new(10)));
+    assert_eq!(clock.switchover(), Some(Instant::new(0)));
+}
+
+#[test]
+fn secondary_wraps_repeatedly() {
+    let clock = FallbackClock::new(Primary(Cell::new(None)), Secondary(Cell::new(0)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    // 10 times the secondary clock's range, read every 1s
+    for second in 1..=256_u32 * 10 / 100 {
+        clock.secondary().0.set((second * 100 % 256) as u8);
+        assert_eq!(clock.try_now(), Ok(Instant::new(second * 1_000)));
+    }
+}
+
+#[test]
+fn conversion_remainder_is_kept() {
+    let clock = FallbackClock::new(Primary(Cell::new(None)), Slow(Cell::new(0)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    for (ticks, expected) in [(1, 333), (2, 666), (3, 1_000), (4, 1_333), (6, 2_000)] {
+        clock.secondary().0.set(ticks);
+        assert_eq!(clock.try_now(), Ok(Instant::new(expected)));
+    }
+}
+
+#[test]
+fn secondary_jumps_backwards() {
+    let clock = FallbackClock::new(Primary(Cell::new(None)), Secondary(Cell::new(100)));
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+    clock.secondary().0.set(90);
+    assert_eq!(clock.try_now(), Err(clock::Error::NonMonotonic));
+}
+
+#[test]
+fn timers_survive_the_switchover() {
+    let clock = FallbackClock::new(Primary(Cell::new(Some(0))), Secondary(Cell::new(0)));
+    let timer = clock.new_timer(Milliseconds(50_u32)).start().unwrap();
+
+    clock.primary().0.set(Some(20));
+    assert!(!timer.is_expired().unwrap());
+
+    clock.primary().0.set(None);
+    assert!(!timer.is_expired().unwrap());
+    clock.secondary().0.set(2);
+    assert!(!timer.is_expired().unwrap());
+    clock.secondary().0.set(4);
+    assert!(timer.is_expired().unwrap());
+}
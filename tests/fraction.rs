@@ -60,3 +60,125 @@ fn fraction_div_fraction() {
     assert_eq!(*product.numerator(), 100_000_u32);
     assert_eq!(*product.denominator(), 1_u32);
 }
+
+#[test]
+fn fraction64() {
+    use core::convert::TryFrom;
+    use embedded_time::{
+        duration::*,
+        fixed_point::{FixedPoint, RoundingMode},
+        fraction::Fraction64,
+    };
+
+    assert_eq!(
+        Fraction64::new_reduce(6_000_000_000, 3_000),
+        Ok(Fraction64::new(2_000_000, 1))
+    );
+    assert_eq!(
+        Fraction64::new_reduce(6, 0),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(Fraction64::default(), Fraction64::new(1, 1));
+    assert_eq!(Fraction64::new(7, 2).to_integer(), 3);
+    assert_eq!(
+        Fraction64::from(Fraction::new(u32::MAX, 2)),
+        Fraction64::new(u64::from(u32::MAX), 2)
+    );
+    assert_eq!(
+        Fraction::try_from(Fraction64::new(10_000_000_000, 10_000_000_000)),
+        Ok(Fraction::new(1, 1))
+    );
+    assert_eq!(
+        Fraction::try_from(Fraction64::new(u64::MAX, 1)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Fraction::try_from(Fraction64::new(1, 0)),
+        Err(ConversionError::DivByZero)
+    );
+
+    // 1 ps ticks can't be a `Fraction`
+    let picosecond = Fraction64::new(1, 1_000_000_000_000);
+    assert_eq!(
+        Nanoseconds::<u64>::try_from_ticks_with(1_999_u32, picosecond, RoundingMode::Floor),
+        Ok(Nanoseconds(1_u64))
+    );
+    assert_eq!(
+        Nanoseconds::<u64>::try_from_ticks_with(1_500_u32, picosecond, RoundingMode::Nearest),
+        Ok(Nanoseconds(2_u64))
+    );
+    assert_eq!(
+        Seconds(1_u32).to_ticks_with(picosecond, RoundingMode::Floor),
+        Ok(1_000_000_000_000_u64)
+    );
+    assert_eq!(
+        Seconds(1_u32).to_ticks_with::<u32>(picosecond, RoundingMode::Floor),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Seconds(1_u32).to_ticks_with::<u64>(Fraction64::new(0, 1), RoundingMode::Floor),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn const_arithmetic() {
+    use core::cmp::Ordering;
+
+    const TIMER_CLOCK: Fraction = Fraction::new(1, 72_000_000);
+    const PRESCALED: Option<Fraction> = TIMER_CLOCK.checked_mul(&Fraction::new(7_200, 1));
+    const REDUCED: Fraction = Fraction::new(1_000, 8_000).reduce();
+
+    assert_eq!(PRESCALED, Some(Fraction::new(1, 10_000)));
+    assert_eq!(*REDUCED.numerator(), 1);
+    assert_eq!(*REDUCED.denominator(), 8);
+
+    assert_eq!(
+        Fraction::new(3, 4).checked_mul(&Fraction::new(0, 1)),
+        Some(Fraction::new(0, 1))
+    );
+    assert_eq!(Fraction::new(3, 4).checked_mul(&Fraction::new(1, 0)), None);
+    // cross-reduced before multiplying
+    assert_eq!(
+        Fraction::new(u32::MAX, 3).checked_mul(&Fraction::new(3, u32::MAX)),
+        Some(Fraction::new(1, 1))
+    );
+
+    assert_eq!(
+        Fraction::new(3, 4).checked_div(&Fraction::new(3, 2)),
+        Some(Fraction::new(1, 2))
+    );
+    assert_eq!(Fraction::new(3, 4).checked_div(&Fraction::new(0, 2)), None);
+
+    assert_eq!(
+        Fraction::new(1, 4).checked_add(&Fraction::new(1, 6)),
+        Some(Fraction::new(5, 12))
+    );
+    assert_eq!(
+        Fraction::new(1, u32::MAX).checked_add(&Fraction::new(1, u32::MAX - 1)),
+        None
+    );
+    assert_eq!(Fraction::new(1, 0).checked_add(&Fraction::new(1, 2)), None);
+
+    assert_eq!(Fraction::new(0, 1).checked_recip(), None);
+    assert_eq!(Fraction::new(2, 3).recip(), Fraction::new(3, 2));
+
+    assert_eq!(
+        Fraction::new(1, 3).compare(&Fraction::new(1, 2)),
+        Ordering::Less
+    );
+    assert_eq!(
+        Fraction::new(u32::MAX, 1).compare(&Fraction::new(u32::MAX - 1, 1)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Fraction::new(3, 9).compare(&Fraction::new(1, 3)),
+        Ordering::Equal
+    );
+}
+
+#[test]
+#[should_panic]
+fn recip_of_zero() {
+    let _ = Fraction::new(0, 1).recip();
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "fugit")]
+
+use core::convert::TryFrom;
+use embedded_time::{duration, duration::*, rate, rate::*, ConversionError};
+
+#[test]
+fn durations() {
+    let ticks = fugit::TimerDurationU32::<32_768>::from_ticks(49_152);
+
+    assert_eq!(
+        duration::Generic::from(ticks),
+        duration::Generic::new(49_152_u32, Fraction::new(1, 32_768))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(ticks),
+        Ok(Milliseconds(1_500_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from(ticks),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        fugit::TimerDurationU32::<32_768>::try_from(Milliseconds(1_500_u32)),
+        Ok(ticks)
+    );
+    assert_eq!(
+        fugit::TimerDurationU32::<32_768>::try_from(Microseconds(1_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        fugit::TimerDurationU32::<32_768>::try_from(Weeks(1_000_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        fugit::NanosDurationU64::try_from(duration::Generic::new(3_u64, Fraction::new(1, 1_000))),
+        Ok(fugit::NanosDurationU64::millis(3))
+    );
+    assert_eq!(
+        fugit::MillisDurationU64::try_from(duration::Generic::new(1_u64, Fraction::new(1, 3))),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Hours::<u64>::try_from(fugit::SecsDurationU64::minutes(180)),
+        Ok(Hours(3_u64))
+    );
+}
+
+#[test]
+fn rates() {
+    let rate = fugit::HertzU32::kHz(48);
+
+    assert_eq!(
+        rate::Generic::from(rate),
+        rate::Generic::new(48_000_u32, Fraction::new(1, 1))
+    );
+    assert_eq!(Kilohertz::<u32>::try_from(rate), Ok(Kilohertz(48_u32)));
+    assert_eq!(
+        Megahertz::<u32>::try_from(rate),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(fugit::HertzU32::try_from(Kilohertz(48_u32)), Ok(rate));
+    assert_eq!(
+        fugit::KilohertzU32::try_from(Rpm(60_000_u32)),
+        Ok(fugit::KilohertzU32::kHz(1))
+    );
+    assert_eq!(
+        fugit::KilohertzU32::try_from(Hertz(1_500_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        fugit::HertzU32::try_from(Megahertz(5_000_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    assert_eq!(
+        fugit::MegahertzU64::try_from(rate::Generic::new(8_u64, Fraction::new(1_000_000, 1))),
+        Ok(fugit::MegahertzU64::MHz(8))
+    );
+    assert_eq!(
+        Mebihertz::<u64>::try_from(fugit::HertzU64::Hz(2_097_152)),
+        Ok(Mebihertz(2_u64))
+    );
+}
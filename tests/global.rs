@@ -0,0 +1,88 @@
+#![cfg(feature = "global")]
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use embedded_time::{self as time, duration::*, global, Instant, TimeError};
+
+/// A millisecond clock advancing by 1 ms every time it is read
+#[derive(Debug)]
+struct MilliClock(AtomicU32);
+
+impl time::Clock for MilliClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.fetch_add(1, Ordering::SeqCst)))
+    }
+}
+
+/// A 1/4 s clock wrapping every 64 s
+#[derive(Debug)]
+struct WrappingClock(AtomicU32);
+
+impl time::Clock for WrappingClock {
+    type T = u8;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 4);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.fetch_add(1, Ordering::SeqCst) as u8))
+    }
+}
+
+static MILLI_CLOCK: MilliClock = MilliClock(AtomicU32::new(1_000));
+static WRAPPING_CLOCK: WrappingClock = WrappingClock(AtomicU32::new(250));
+
+// The global clock is shared by the whole test binary, so this is a single sequential test
+#[test]
+fn global_clock() {
+    assert_eq!(
+        global::now(),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+    assert_eq!(
+        global::timeout(Seconds(1_u32)).err(),
+        Some(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+
+    global::set_global_clock(&MILLI_CLOCK);
+
+    assert_eq!(global::now(), Ok(Milliseconds(1_000_u64).into()));
+    assert_eq!(
+        global::now().unwrap().scaling_factor(),
+        &Fraction::new(1, 1_000)
+    );
+
+    // started at 1_002 ms
+    let timeout = global::timeout(Milliseconds(5_u32)).unwrap();
+    assert_eq!(timeout.remaining(), Ok(Milliseconds(4_u64).into()));
+    assert_eq!(timeout.is_expired(), Ok(false));
+    assert_eq!(timeout.remaining(), Ok(Milliseconds(2_u64).into()));
+    assert_eq!(timeout.is_expired(), Ok(false));
+    assert_eq!(timeout.is_expired(), Ok(true));
+    assert_eq!(timeout.remaining(), Ok(Milliseconds(0_u64).into()));
+
+    // rounded up to whole ticks
+    let timeout = global::timeout(Microseconds(1_500_u32)).unwrap();
+    assert_eq!(timeout.remaining(), Ok(Milliseconds(1_u64).into()));
+
+    let before = global::now().unwrap();
+    global::delay(Milliseconds(10_u32)).unwrap();
+    assert!(global::now().unwrap() >= before + Milliseconds(10_u64));
+
+    global::set_global_clock(&WRAPPING_CLOCK);
+
+    assert_eq!(global::now(), Ok(Milliseconds(62_500_u64).into()));
+    assert_eq!(
+        global::timeout(Seconds(64_u32)).err(),
+        Some(TimeError::Overflow)
+    );
+
+    // expires after the clock wraps (started at 251 ticks)
+    let timeout = global::timeout(Seconds(2_u32)).unwrap();
+    for _ in 0..7 {
+        assert_eq!(timeout.is_expired(), Ok(false));
+    }
+    assert_eq!(timeout.is_expired(), Ok(true));
+}
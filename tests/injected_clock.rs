@@ -0,0 +1,76 @@
+use core::convert::TryFrom;
+use embedded_time::{clocks::InjectedClock, duration::*, Clock as _, Instant};
+
+#[test]
+fn advances_only_when_injected() {
+    let clock = InjectedClock::<1, 1_000>::new();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    clock.advance(0);
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    clock.advance(250);
+    let elapsed = clock
+        .try_now()
+        .unwrap()
+        .checked_duration_since(&Instant::new(0))
+        .unwrap();
+    assert_eq!(
+        Milliseconds::<u64>::try_from(elapsed),
+        Ok(Milliseconds(250_u64))
+    );
+}
+
+#[test]
+fn wraps() {
+    let clock = InjectedClock::<1, 1_000>::new();
+    clock.advance(u64::MAX);
+    let before = clock.try_now().unwrap();
+
+    clock.advance(3);
+    assert_eq!(clock.try_now(), Ok(Instant::new(2)));
+    assert_eq!(
+        clock.try_now().unwrap().checked_duration_since(&before),
+        Some(Generic::new(3, Fraction::new(1, 1_000)))
+    );
+}
+
+#[test]
+fn lockstep_periodic_timer() {
+    static CLOCK: InjectedClock<1, 1_000_000> = InjectedClock::new();
+
+    let mut timer = CLOCK
+        .new_timer(Milliseconds(10_u32))
+        .into_periodic()
+        .start()
+        .unwrap();
+
+    // the simulator steps 3 ms at a time, the timer fires on the first step at or past each period
+    let mut fired = Vec::new();
+    for step in 1..=10_u64 {
+        CLOCK.advance(3_000);
+        if timer.period_complete().unwrap() {
+            fired.push(step * 3);
+        }
+    }
+
+    assert_eq!(fired, [12, 21, 30]);
+}
+
+#[test]
+fn advanced_from_another_thread() {
+    static CLOCK: InjectedClock<1, 1_000> = InjectedClock::new();
+
+    let timer = CLOCK.new_timer(Seconds(1_u32)).start().unwrap();
+
+    let simulator = std::thread::spawn(|| {
+        for _ in 0..1_000 {
+            CLOCK.advance(1);
+        }
+    });
+    timer.wait().unwrap();
+    simulator.join().unwrap();
+
+    assert_eq!(CLOCK.try_now(), Ok(Instant::new(1_000)));
+}
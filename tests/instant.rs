@@ -1,4 +1,4 @@
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
 use embedded_time::{
     self as time,
     duration::{self, *},
@@ -11,6 +11,7 @@ struct Clock;
 
 impl time::Clock for Clock {
     type T = u32;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
 
     fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
@@ -40,6 +41,30 @@ fn duration_since() {
     assert_eq!(diff, None);
 }
 
+#[test]
+fn signed_duration_since() {
+    let earlier = Instant::<Clock>::new(u32::MAX - 1);
+    let later = Instant::<Clock>::new(3);
+
+    assert_eq!(
+        later.signed_duration_since::<i64>(&earlier),
+        Some(duration::SignedGeneric::new(5_i64, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(
+        earlier.signed_duration_since::<i32>(&later),
+        Some(duration::SignedGeneric::new(
+            -5_i32,
+            Fraction::new(1, 1_000)
+        ))
+    );
+
+    // half the range of the clock doesn't fit in an i16
+    assert_eq!(
+        Instant::<Clock>::new(u32::MAX / 2).signed_duration_since::<i16>(&Instant::new(0)),
+        None
+    );
+}
+
 #[test]
 fn duration_until() {
     let clock = Clock;
@@ -93,3 +118,131 @@ fn checked_add(base: u32, addition: u32) -> Option<Instant<Clock>> {
 fn checked_sub(base: u32, subtrahend: u32) -> Option<Instant<Clock>> {
     Instant::<Clock>::new(base).checked_sub(Milliseconds(subtrahend))
 }
+
+#[test_case(u32::MAX, 0 => true ; "Wrapped instant is later")]
+#[test_case(0, u32::MAX / 2 - 1 => true ; "Less than half the counter range")]
+#[test_case(0, u32::MAX / 2 + 1 => false ; "More than half the counter range")]
+fn ordering_across_wrap(earlier: u32, later: u32) -> bool {
+    Instant::<Clock>::new(later) > Instant::<Clock>::new(earlier)
+}
+
+#[test]
+fn arithmetic_across_wrap() {
+    let before_wrap = Instant::<Clock>::new(u32::MAX - 1);
+    let after_wrap = before_wrap + Milliseconds(3_u32);
+
+    assert_eq!(after_wrap, Instant::<Clock>::new(1));
+    assert_eq!(after_wrap - Milliseconds(3_u32), before_wrap);
+    assert_eq!(
+        after_wrap.checked_duration_since(&before_wrap),
+        Some(duration::Generic::new(3_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(after_wrap - before_wrap, Milliseconds(3_u32).into());
+}
+
+#[test]
+fn generic_duration_arithmetic() {
+    let start = Instant::<Clock>::new(u32::MAX - 4);
+    let end = start + Milliseconds(10_u32);
+
+    let elapsed: duration::Generic<u32> = end - start;
+    assert_eq!(elapsed, duration::Generic::new(10, Fraction::new(1, 1_000)));
+
+    assert_eq!(start + elapsed, end);
+    assert_eq!(end - elapsed, start);
+    assert_eq!(
+        start + duration::Generic::new(1_u32, Fraction::new(1, 1)),
+        Instant::new(995)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Add failed")]
+fn generic_duration_add_panic() {
+    let _ = Instant::<Clock>::new(0)
+        + duration::Generic::new(u32::MAX / 2 + 1, Fraction::new(1, 1_000));
+}
+
+#[test]
+fn narrow_clock() {
+    #[derive(Debug)]
+    struct Clock;
+
+    impl time::Clock for Clock {
+        type T = u8;
+        type Error = time::clock::Error;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            Ok(Instant::new(250))
+        }
+    }
+
+    let start = Clock.try_now().unwrap();
+    let end = start + Milliseconds(10_u32);
+    assert_eq!(end, Instant::new(4));
+
+    let elapsed = end.checked_duration_since(&start).unwrap();
+    assert_eq!(
+        elapsed,
+        duration::Generic::new(10_u8, Fraction::new(1, 1_000))
+    );
+    assert_eq!(
+        Microseconds::<u32>::try_from(elapsed),
+        Ok(Microseconds(10_000_u32))
+    );
+}
+
+#[test]
+fn u128_clock() {
+    #[derive(Debug)]
+    struct Clock;
+
+    impl time::Clock for Clock {
+        type T = u128;
+        type Error = time::clock::Error;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            Ok(Instant::new(u64::MAX as u128 * 3))
+        }
+    }
+
+    let start = Instant::<Clock>::new(u64::MAX as u128);
+    let now = Clock.try_now().unwrap();
+
+    let elapsed = now.checked_duration_since(&start).unwrap();
+    assert_eq!(
+        Nanoseconds::<u128>::try_from(elapsed),
+        Ok(Nanoseconds(u64::MAX as u128 * 2))
+    );
+    assert_eq!(
+        Seconds::<u64>::try_from(elapsed),
+        Ok(Seconds(u64::MAX / 500_000_000))
+    );
+    assert_eq!(
+        start + Seconds(1_u32),
+        Instant::new(u64::MAX as u128 + 1_000_000_000)
+    );
+}
+
+#[test]
+fn wrapping_add_sub() {
+    assert_eq!(
+        Instant::<Clock>::new(u32::MAX - 1).wrapping_add(Milliseconds(3_u32)),
+        Ok(Instant::new(1))
+    );
+    // longer than half the range
+    assert_eq!(
+        Instant::<Clock>::new(5).wrapping_add(Milliseconds(u32::MAX / 2 + 10)),
+        Ok(Instant::new(u32::MAX / 2 + 15))
+    );
+    assert_eq!(
+        Instant::<Clock>::new(1).wrapping_sub(Seconds(1_u32)),
+        Ok(Instant::new(u32::MAX - 998))
+    );
+    assert_eq!(
+        Instant::<Clock>::new(1).wrapping_sub(Seconds(u32::MAX)),
+        Err(time::ConversionError::Unspecified)
+    );
+}
@@ -0,0 +1,120 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    jitter::Jitter,
+    periodic::Periodic,
+    retry::{Backoff, RetryBudget},
+    Instant,
+};
+use test_case::test_case;
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test_case(0 => 900 ; "lowest")]
+#[test_case(u32::MAX / 4 => 949 ; "quarter")]
+#[test_case(1 << 31 => 1_000 ; "middle")]
+#[test_case(u32::MAX => 1_099 ; "highest")]
+fn tenth(random: u32) -> u32 {
+    Jitter::new(Fraction::new(1, 10))
+        .apply(Milliseconds(1_000_u32), random)
+        .integer()
+}
+
+#[test]
+fn bounds() {
+    let jitter = Jitter::new(Fraction::new(1, 1));
+    assert_eq!(jitter.apply(Seconds(0_u32), u32::MAX), Seconds(0_u32));
+    assert_eq!(jitter.apply(Seconds(10_u32), 0), Seconds(0_u32));
+    assert_eq!(jitter.apply(Seconds(10_u32), u32::MAX), Seconds(19_u32));
+    assert_eq!(jitter.apply(Seconds(u32::MAX), u32::MAX), Seconds(u32::MAX));
+    assert_eq!(jitter.apply(Seconds(u128::MAX), 0), Seconds(0_u128));
+    assert_eq!(jitter.apply(Seconds(u64::MAX), u32::MAX), Seconds(u64::MAX));
+    assert_eq!(
+        jitter.apply(Milliseconds(200_u8), u32::MAX),
+        Milliseconds(u8::MAX)
+    );
+
+    let jitter = Jitter::new(Fraction::new(0, 1));
+    assert_eq!(jitter.apply(Seconds(10_u32), u32::MAX), Seconds(10_u32));
+}
+
+#[test]
+fn generic() {
+    let jitter = Jitter::new(Fraction::new(1, 2));
+    assert_eq!(
+        jitter.apply_generic(Generic::new(100_u64, Fraction::new(1, 1_000)), 0),
+        Generic::new(50_u64, Fraction::new(1, 1_000))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid spread")]
+fn spread_too_large() {
+    Jitter::new(Fraction::new(3, 2));
+}
+
+#[test]
+fn retry() {
+    let jitter = Jitter::new(Fraction::new(1, 2));
+    let mut budget = RetryBudget::new(
+        5,
+        Instant::<Clock>::new(1_000),
+        Milliseconds(100_u32),
+        Backoff::Exponential,
+    )
+    .unwrap();
+
+    let delay = |delay: Option<Generic<u32>>| delay.map(|delay| delay.integer());
+    assert_eq!(
+        delay(budget.next_attempt_jittered(Instant::new(0), &jitter, u32::MAX)),
+        Some(0)
+    );
+    assert_eq!(
+        delay(budget.next_attempt_jittered(Instant::new(0), &jitter, 0)),
+        Some(50)
+    );
+    assert_eq!(
+        delay(budget.next_attempt_jittered(Instant::new(50), &jitter, u32::MAX)),
+        Some(299)
+    );
+
+    // the jittered delay of 599 ms would pass the deadline, the unjittered 400 ms wouldn't
+    assert_eq!(
+        delay(budget.next_attempt_jittered(Instant::new(500), &jitter, u32::MAX)),
+        None
+    );
+    assert_eq!(budget.attempts(), 3);
+    assert_eq!(
+        delay(budget.next_attempt_jittered(Instant::new(500), &jitter, 0)),
+        Some(200)
+    );
+}
+
+#[test]
+fn periodic_does_not_drift() {
+    let jitter = Jitter::new(Fraction::new(1, 10));
+    let mut periodic =
+        Periodic::<Clock>::new(Milliseconds(100_u32), Instant::new(u32::MAX - 49)).unwrap();
+
+    assert_eq!(periodic.jittered_deadline(&jitter, 0), Instant::new(40));
+    assert_eq!(
+        periodic.jittered_deadline(&jitter, u32::MAX),
+        Instant::new(59)
+    );
+
+    for _ in 0..10 {
+        periodic.advance();
+    }
+    assert_eq!(periodic.jittered_deadline(&jitter, 0), Instant::new(1_040));
+}
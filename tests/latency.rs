@@ -0,0 +1,91 @@
+use embedded_time::{self as time, duration::*, latency::LatencyMeter, Instant, TimeError};
+use std::cell::Cell;
+
+/// A 1 MHz clock advancing by `step` ticks on every read
+#[derive(Debug)]
+struct Clock {
+    now: Cell<u32>,
+    step: Cell<u32>,
+}
+
+impl Clock {
+    fn new(step: u32) -> Self {
+        Self {
+            now: Cell::new(0),
+            step: Cell::new(step),
+        }
+    }
+
+    fn advance(&self, ticks: u32) {
+        self.now.set(self.now.get() + ticks);
+    }
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        self.advance(self.step.get());
+        Ok(Instant::new(self.now.get()))
+    }
+}
+
+#[test]
+fn stats_and_histogram() {
+    let clock = Clock::new(0);
+    let mut meter = LatencyMeter::<_, 3>::new(&clock, Microseconds(10_u32)).unwrap();
+
+    assert_eq!(meter.stats(), None);
+
+    for latency in [4, 15, 19, 250] {
+        meter.trigger().unwrap();
+        clock.advance(latency);
+        meter.handled().unwrap();
+    }
+
+    assert_eq!(meter.histogram(), &[1, 2, 1]);
+
+    let stats = meter.stats().unwrap();
+    assert_eq!(stats.count, 4);
+    assert_eq!(stats.min, Nanoseconds(4_000_u64));
+    assert_eq!(stats.max, Nanoseconds(250_000_u64));
+    assert_eq!(stats.mean, Nanoseconds(72_000_u64));
+
+    meter.reset();
+    assert_eq!(meter.stats(), None);
+    assert_eq!(meter.histogram(), &[0, 0, 0]);
+}
+
+#[test]
+fn calibration_overhead_is_subtracted() {
+    // every clock read takes 2 µs
+    let clock = Clock::new(2);
+    let mut meter = LatencyMeter::<_, 4>::new(&clock, Microseconds(10_u32)).unwrap();
+
+    assert_eq!(meter.calibrate(), Ok(Nanoseconds(2_000_u64)));
+
+    meter.trigger().unwrap();
+    clock.advance(5);
+    assert_eq!(meter.handled(), Ok(Some(Nanoseconds(5_000_u64))));
+}
+
+#[test]
+fn handled_without_trigger() {
+    let clock = Clock::new(0);
+    let mut meter = LatencyMeter::<_, 4>::new(&clock, Microseconds(10_u32)).unwrap();
+
+    assert_eq!(meter.handled(), Ok(None));
+    assert_eq!(meter.stats(), None);
+}
+
+#[test]
+fn zero_bucket_width() {
+    let clock = Clock::new(0);
+
+    assert_eq!(
+        LatencyMeter::<_, 4>::new(&clock, Microseconds(0_u32)).unwrap_err(),
+        TimeError::DivByZero
+    );
+}
@@ -0,0 +1,47 @@
+use embedded_time::{duration::*, lerp::lerp, ConversionError};
+use test_case::test_case;
+
+#[test_case(0, 100, 0 => Ok(0) ; "start")]
+#[test_case(0, 100, 250 => Ok(25) ; "quarter")]
+#[test_case(0, 100, 999 => Ok(99) ; "truncated")]
+#[test_case(0, 100, 1_000 => Ok(100) ; "end")]
+#[test_case(0, 100, 5_000 => Ok(100) ; "clamped")]
+#[test_case(100, 0, 999 => Ok(1) ; "descending truncated toward start")]
+#[test_case(-50, 50, 500 => Ok(0) ; "signed")]
+#[test_case(i64::MIN, i64::MAX, 500 => Ok(-1) ; "full range")]
+fn millis_over_a_second(start: i64, end: i64, elapsed: u32) -> Result<i64, ConversionError> {
+    lerp(start, end, Milliseconds(elapsed), Seconds(1_u32))
+}
+
+#[test]
+fn mixed_units() {
+    assert_eq!(
+        lerp(0_u16, 1_000, Nanoseconds(1_u64), Microseconds(3_u32)),
+        Ok(0)
+    );
+    assert_eq!(
+        lerp(0_u16, 3_000, Nanoseconds(1_001_u64), Microseconds(3_u32)),
+        Ok(1_001)
+    );
+    assert_eq!(
+        lerp(0_u64, u64::MAX, Minutes(30_u32), Hours(1_u32)),
+        Ok(u64::MAX / 2)
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        lerp(0_u8, 255, Seconds(0_u32), Seconds(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        lerp(
+            0_u64,
+            u64::MAX,
+            Nanoseconds(u128::MAX / 2),
+            Nanoseconds(u128::MAX)
+        ),
+        Err(ConversionError::Overflow)
+    );
+}
@@ -7,6 +7,7 @@ use embedded_time::{self as time, duration::*, Clock as _, ConversionError, Time
 struct MockClock64;
 impl time::Clock for MockClock64 {
     type T = u64;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: time::fraction::Fraction = <time::fraction::Fraction>::new(1, 64_000_000);
 
     fn try_now(&self) -> Result<time::Instant<Self>, time::clock::Error> {
@@ -19,6 +20,7 @@ struct MockClock32;
 
 impl time::Clock for MockClock32 {
     type T = u32;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: time::fraction::Fraction = <time::fraction::Fraction>::new(1, 16_000_000);
 
     fn try_now(&self) -> Result<time::Instant<Self>, time::clock::Error> {
@@ -31,6 +33,7 @@ struct BadClock;
 
 impl time::Clock for BadClock {
     type T = u32;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: time::fraction::Fraction = <time::fraction::Fraction>::new(1, 16_000_000);
 
     fn try_now(&self) -> Result<time::Instant<Self>, time::clock::Error> {
@@ -0,0 +1,57 @@
+use embedded_time::{self as time, duration::*, long_delay, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn ticks(chunks: long_delay::Chunks<Clock>) -> Vec<u32> {
+    chunks.map(|chunk| chunk.integer()).collect()
+}
+
+#[test]
+fn splits_into_chunks() {
+    let chunks = long_delay::chunks::<Clock, _>(Seconds(25_u32), 10_000).unwrap();
+    assert_eq!(chunks.size_hint(), (3, Some(3)));
+    assert_eq!(ticks(chunks), [10_000, 10_000, 5_000]);
+}
+
+#[test]
+fn rounds_up_to_whole_ticks() {
+    let chunks = long_delay::chunks::<Clock, _>(Microseconds(2_500_u32), 2).unwrap();
+    assert_eq!(chunks.remaining_ticks(), 3);
+    assert_eq!(ticks(chunks), [2, 1]);
+}
+
+#[test]
+fn longer_than_the_clock_range() {
+    let chunks = long_delay::chunks::<Clock, _>(Hours(2_000_u32), u32::MAX).unwrap();
+
+    assert_eq!(chunks.remaining_ticks(), 7_200_000_000);
+    assert_eq!(
+        ticks(chunks),
+        [u32::MAX / 2, u32::MAX / 2, u32::MAX / 2, 757_549_059]
+    );
+}
+
+#[test]
+fn empty() {
+    let chunks = long_delay::chunks::<Clock, _>(Seconds(0_u32), 100).unwrap();
+    assert_eq!(ticks(chunks), []);
+}
+
+#[test]
+fn zero_max_ticks() {
+    assert_eq!(
+        long_delay::chunks::<Clock, _>(Seconds(1_u32), 0).unwrap_err(),
+        ConversionError::DivByZero
+    );
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "mock")]
+
+use embedded_time::{clock, clocks::MockClock, duration::*, Clock as _, Instant};
+
+#[test]
+fn advance_and_set() {
+    let clock = MockClock::new();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    clock.advance(Microseconds(3_u32));
+    clock.advance(Nanoseconds(5_u64));
+    assert_eq!(clock.try_now(), Ok(Instant::new(3_005)));
+
+    // reads don't move the clock
+    assert_eq!(clock.try_now(), Ok(Instant::new(3_005)));
+
+    clock.set(Instant::new(1_000));
+    assert_eq!(clock.try_now(), Ok(Instant::new(1_000)));
+}
+
+#[test]
+fn timer_expiration() {
+    let clock = MockClock::new();
+    let timer = clock.new_timer(Seconds(2_u32)).start().unwrap();
+
+    clock.advance(Milliseconds(1_999_u32));
+    assert!(!timer.is_expired().unwrap());
+
+    clock.advance(Milliseconds(1_u32));
+    assert!(timer.is_expired().unwrap());
+}
+
+#[test]
+fn auto_advance_lets_blocking_waits_return() {
+    let clock = MockClock::new();
+    clock.set_auto_advance(Milliseconds(1_u32));
+
+    clock
+        .new_timer(Milliseconds(10_u32))
+        .start()
+        .unwrap()
+        .wait()
+        .unwrap();
+
+    assert!(clock.try_now().unwrap() > Instant::new(10_000_000));
+}
+
+#[test]
+fn backwards_jump() {
+    use embedded_time::monotonic_guard::{MonotonicGuard, Policy};
+
+    let clock = MonotonicGuard::new(MockClock::new(), Policy::Error);
+    clock.inner().set(Instant::new(100));
+    clock.try_now().unwrap();
+
+    clock.inner().set(Instant::new(50));
+    assert_eq!(clock.try_now(), Err(clock::Error::NonMonotonic));
+}
+
+#[cfg(feature = "async")]
+mod wake_hook {
+    use super::*;
+    use embedded_time::async_delay::DelayFuture;
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    #[derive(Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn wakes_delay_once_expired() {
+        let clock = MockClock::new();
+        let counter = Arc::new(CountingWaker::default());
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut delay = pin!(DelayFuture::new(&clock, Milliseconds(5_u32), &clock));
+        assert_eq!(delay.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+        clock.advance(Milliseconds(4_u32));
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+        // more than 5 ms
+        clock.advance(Milliseconds(1_u32));
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+        clock.advance(Nanoseconds(1_u32));
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert_eq!(delay.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+
+        // the alarm was consumed
+        clock.advance(Milliseconds(1_u32));
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,92 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    monotonic_guard::{MonotonicGuard, Policy},
+    Clock as _, Instant,
+};
+use std::cell::Cell;
+
+#[derive(Debug)]
+struct Counter(Cell<u32>);
+
+impl time::Clock for Counter {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[derive(Debug)]
+struct BadClock;
+
+impl time::Clock for BadClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::NotRunning)
+    }
+}
+
+#[test]
+fn clamp() {
+    let clock = MonotonicGuard::new(Counter(Cell::new(100)), Policy::Clamp);
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+
+    clock.inner().0.set(50);
+    assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+
+    clock.inner().0.set(150);
+    assert_eq!(clock.try_now(), Ok(Instant::new(150)));
+}
+
+#[test]
+fn error() {
+    let clock = MonotonicGuard::new(Counter(Cell::new(100)), Policy::Error);
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+
+    clock.inner().0.set(50);
+    assert_eq!(clock.try_now(), Err(time::clock::Error::NonMonotonic));
+
+    // the latest good reading is still the reference
+    clock.inner().0.set(99);
+    assert_eq!(clock.try_now(), Err(time::clock::Error::NonMonotonic));
+
+    clock.inner().0.set(100);
+    assert_eq!(clock.try_now(), Ok(Instant::new(100)));
+}
+
+#[test]
+fn counter_wrap() {
+    let clock = MonotonicGuard::new(Counter(Cell::new(u32::MAX)), Policy::Error);
+
+    assert_eq!(clock.try_now(), Ok(Instant::new(u32::MAX)));
+
+    clock.inner().0.set(5);
+    assert_eq!(clock.try_now(), Ok(Instant::new(5)));
+}
+
+#[test]
+fn underlying_error() {
+    let clock = MonotonicGuard::new(BadClock, Policy::Clamp);
+
+    assert_eq!(clock.try_now(), Err(time::clock::Error::NotRunning));
+}
+
+#[test]
+fn timer() {
+    let clock = MonotonicGuard::new(Counter(Cell::new(0)), Policy::Clamp);
+    let timer = clock.new_timer(Milliseconds(10_u32)).start().unwrap();
+
+    clock.inner().0.set(10);
+    assert!(timer.is_expired().unwrap());
+
+    let counter = clock.into_inner();
+    assert_eq!(counter.0.get(), 10);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "riscv")]
+
+use core::cell::Cell;
+use embedded_time::{clocks::MtimeClock, Clock as _, Instant};
+
+/// Simulated `mtime` (2 words) followed by `mtimecmp` (2 words)
+struct Registers([Cell<u32>; 4]);
+
+impl Registers {
+    fn new() -> Self {
+        Self(Default::default())
+    }
+
+    fn clock(&self) -> MtimeClock<1_000_000> {
+        let address = self.0[0].as_ptr() as usize;
+        unsafe { MtimeClock::new(address, address + 8) }
+    }
+
+    fn words(&self) -> [u32; 4] {
+        [0, 1, 2, 3].map(|i| self.0[i].get())
+    }
+}
+
+#[test]
+fn read() {
+    let registers = Registers::new();
+    registers.0[0].set(0x8765_4321);
+    registers.0[1].set(0x1);
+
+    assert_eq!(registers.clock().try_now(), Ok(Instant::new(0x1_8765_4321)));
+}
+
+#[test]
+fn compare() {
+    let registers = Registers::new();
+    let mut clock = registers.clock();
+
+    clock.set_compare(Instant::new(0x2_0000_0010));
+    assert_eq!(registers.words(), [0, 0, 0x10, 0x2]);
+
+    clock.clear_compare();
+    assert_eq!(registers.words(), [0, 0, u32::MAX, u32::MAX]);
+
+    registers.0[0].set(5);
+    clock.reset();
+    assert_eq!(registers.words(), [0, 0, u32::MAX, u32::MAX]);
+}
@@ -0,0 +1,157 @@
+use core::convert::TryFrom;
+use embedded_time::{
+    duration::*,
+    epoch::UnixTime,
+    ntp::{NtpShort, NtpTimestamp},
+    ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn timestamp_parts() {
+    let timestamp = NtpTimestamp::new(0xE2D0_4E00, 0x8000_0000);
+
+    assert_eq!(timestamp.to_bits(), 0xE2D0_4E00_8000_0000);
+    assert_eq!(NtpTimestamp::from_bits(timestamp.to_bits()), timestamp);
+    assert_eq!(timestamp.secs(), 0xE2D0_4E00);
+    assert_eq!(timestamp.fraction(), 0x8000_0000);
+}
+
+#[test]
+fn unix_time() {
+    assert_eq!(
+        NtpTimestamp::from_unix_time(UnixTime::EPOCH),
+        NtpTimestamp::new(2_208_988_800, 0)
+    );
+    assert_eq!(
+        NtpTimestamp::from_unix_time(UnixTime::new(1, 500_000_000).unwrap()),
+        NtpTimestamp::new(2_208_988_801, 1 << 31)
+    );
+    assert_eq!(
+        NtpTimestamp::new(2_208_988_800, 0).to_unix_time(),
+        Some(UnixTime::EPOCH)
+    );
+    // 1968 (era 0) is before the Unix epoch
+    assert_eq!(NtpTimestamp::new(1 << 31, 0).to_unix_time(), None);
+
+    // era 1 starts on 2036-02-07 06:28:16
+    let era_1 = UnixTime::from_secs(2_085_978_496);
+    assert_eq!(NtpTimestamp::from_unix_time(era_1), NtpTimestamp::new(0, 0));
+    assert_eq!(NtpTimestamp::new(0, 0).to_unix_time(), Some(era_1));
+    assert_eq!(NtpTimestamp::new(0, 0).to_unix_time_in_era(0), None);
+    assert_eq!(
+        NtpTimestamp::new(0, 0).to_unix_time_in_era(2),
+        UnixTime::new(2_085_978_496 + (1 << 32), 0)
+    );
+
+    // the nanoseconds survive a round trip
+    for nanos in [0, 1, 2, 232, 233, 499_999_999, 999_999_999] {
+        let time = UnixTime::new(1_600_000_000, nanos).unwrap();
+        assert_eq!(
+            NtpTimestamp::from_unix_time(time).to_unix_time(),
+            Some(time)
+        );
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let timestamp = NtpTimestamp::new(100, 0);
+
+    assert_eq!(
+        timestamp.wrapping_add(Milliseconds(250_u32)),
+        Ok(NtpTimestamp::new(100, 1 << 30))
+    );
+    assert_eq!(
+        timestamp.wrapping_sub(Seconds(101_u32)),
+        Ok(NtpTimestamp::new(u32::MAX, 0))
+    );
+    assert_eq!(
+        timestamp.wrapping_add(Seconds(u64::from(u32::MAX) + 1)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        NtpTimestamp::new(100, 1 << 31).signed_duration_since(&timestamp),
+        SignedGeneric::new(500_000_000_i64, Fraction::new(1, 1_000_000_000))
+    );
+    assert_eq!(
+        NtpTimestamp::new(99, 1).signed_duration_since(&timestamp),
+        SignedGeneric::new(-999_999_999_i64, Fraction::new(1, 1_000_000_000))
+    );
+}
+
+#[test]
+fn instants() {
+    let epoch = NtpTimestamp::new(u32::MAX, 0);
+
+    assert_eq!(
+        NtpTimestamp::from_instant(&Instant::<Clock>::new(1_500), epoch),
+        Ok(NtpTimestamp::new(0, 1 << 31))
+    );
+    assert_eq!(
+        NtpTimestamp::new(0, 1 << 31).to_instant(epoch),
+        Some(Instant::<Clock>::new(1_500))
+    );
+    assert_eq!(
+        NtpTimestamp::new(u32::MAX - 1, 0).to_instant::<Clock>(epoch),
+        None
+    );
+    assert_eq!(
+        NtpTimestamp::new(u32::MAX / 2, 0).to_instant::<Clock>(epoch),
+        None
+    );
+}
+
+#[test]
+fn short_format() {
+    let short = NtpShort::new(2, 0x8000);
+
+    assert_eq!(short.to_bits(), 0x0002_8000);
+    assert_eq!(NtpShort::from_bits(0x0002_8000), short);
+    assert_eq!((short.secs(), short.fraction()), (2, 0x8000));
+
+    assert_eq!(
+        Generic::from(short),
+        Generic::new(0x0002_8000_u32, Fraction::new(1, 65_536))
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(Generic::from(short)),
+        Ok(Milliseconds(2_500_u32))
+    );
+
+    assert_eq!(NtpShort::try_from(Milliseconds(2_500_u32)), Ok(short));
+    assert_eq!(
+        NtpShort::try_from(Generic::new(5_u32, Fraction::new(1, 2))),
+        Ok(short)
+    );
+    // truncated to a fraction
+    assert_eq!(
+        NtpShort::try_from(Microseconds(15_u32)),
+        Ok(NtpShort::from_bits(0))
+    );
+    assert_eq!(
+        NtpShort::try_from(Microseconds(16_u32)),
+        Ok(NtpShort::from_bits(1))
+    );
+    assert_eq!(
+        NtpShort::try_from(Seconds(65_535_u32)),
+        Ok(NtpShort::new(u16::MAX, 0))
+    );
+    assert_eq!(
+        NtpShort::try_from(Seconds(65_536_u32)),
+        Err(ConversionError::Overflow)
+    );
+}
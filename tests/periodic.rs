@@ -0,0 +1,72 @@
+use embedded_time::{
+    self as time, duration::*, periodic::Periodic, rate::Hertz, ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn whole_ticks() {
+    let mut periodic = Periodic::<Clock>::new(Milliseconds(10_u32), Instant::new(5)).unwrap();
+
+    assert_eq!(periodic.next_deadline(), Instant::new(15));
+    assert_eq!(periodic.advance(), Instant::new(25));
+    assert_eq!(periodic.advance(), Instant::new(35));
+}
+
+#[test]
+fn exact_average_rate() {
+    // 1.5 ms periods alternate between 1 and 2 ticks
+    let mut periodic = Periodic::<Clock>::new(Microseconds(1_500_u32), Instant::new(0)).unwrap();
+
+    assert_eq!(periodic.next_deadline(), Instant::new(1));
+    assert_eq!(periodic.advance(), Instant::new(3));
+    assert_eq!(periodic.advance(), Instant::new(4));
+    assert_eq!(periodic.advance(), Instant::new(6));
+
+    // after 44_100 periods of a 44.1 kHz rate exactly 1 s has elapsed
+    let mut periodic = Periodic::<Clock>::from_rate(Hertz(44_100_u32), Instant::new(0)).unwrap();
+    for _ in 1..44_100 {
+        periodic.advance();
+    }
+    assert_eq!(periodic.next_deadline(), Instant::new(1_000));
+}
+
+#[test]
+fn period_complete() {
+    let mut periodic = Periodic::<Clock>::from_rate(Hertz(3_u32), Instant::new(u32::MAX)).unwrap();
+
+    assert!(!periodic.period_complete(Instant::new(331)));
+    assert!(periodic.period_complete(Instant::new(332)));
+    assert_eq!(periodic.next_deadline(), Instant::new(665));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Periodic::<Clock>::new(Milliseconds(0_u32), Instant::new(0)).err(),
+        Some(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Periodic::<Clock>::from_rate(Hertz(0_u32), Instant::new(0)).err(),
+        Some(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        Periodic::<Clock>::new(Seconds(u32::MAX), Instant::new(0)).err(),
+        Some(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Periodic::<Clock>::new(Milliseconds(u32::MAX / 2 + 1), Instant::new(0)).err(),
+        Some(ConversionError::Overflow)
+    );
+}
@@ -0,0 +1,132 @@
+use core::convert::TryFrom;
+use embedded_time::{
+    duration::*,
+    ptp::{Correction, PtpTimestamp},
+    ConversionError,
+};
+
+const MAX_SECS: u64 = (1 << 48) - 1;
+
+#[test]
+fn construction() {
+    assert_eq!(
+        PtpTimestamp::new(MAX_SECS, 999_999_999).map(|t| (t.secs(), t.nanos())),
+        Some((MAX_SECS, 999_999_999))
+    );
+    assert_eq!(PtpTimestamp::new(MAX_SECS + 1, 0), None);
+    assert_eq!(PtpTimestamp::new(0, 1_000_000_000), None);
+
+    assert!(PtpTimestamp::new(1, 0) > PtpTimestamp::new(0, 999_999_999));
+}
+
+#[test]
+fn bytes() {
+    let timestamp = PtpTimestamp::new(0x0102_0304_0506, 0x0708_090A).unwrap();
+    let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A];
+
+    assert_eq!(timestamp.to_bytes(), bytes);
+    assert_eq!(PtpTimestamp::from_bytes(bytes), Some(timestamp));
+    assert_eq!(
+        PtpTimestamp::from_bytes([0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3B, 0x9A, 0xCA, 0x00]),
+        None
+    );
+}
+
+#[test]
+fn durations() {
+    let max = PtpTimestamp::new(MAX_SECS, 999_999_999).unwrap();
+    let max_nanos = u128::from(MAX_SECS) * 1_000_000_000 + 999_999_999;
+
+    assert_eq!(Nanoseconds::<u128>::from(max), Nanoseconds(max_nanos));
+    assert_eq!(
+        Generic::<u128>::from(max),
+        Generic::new(max_nanos, Fraction::new(1, 1_000_000_000))
+    );
+    assert_eq!(PtpTimestamp::try_from(Nanoseconds(max_nanos)), Ok(max));
+    assert_eq!(
+        PtpTimestamp::try_from(Nanoseconds(max_nanos + 1)),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        PtpTimestamp::try_from(Milliseconds(1_500_u32)),
+        PtpTimestamp::new(1, 500_000_000).ok_or(ConversionError::Unspecified)
+    );
+    // truncated to nanoseconds
+    assert_eq!(
+        PtpTimestamp::try_from(Generic::new(2_u32, Fraction::new(1, 3))),
+        PtpTimestamp::new(0, 666_666_666).ok_or(ConversionError::Unspecified)
+    );
+}
+
+#[test]
+fn differences() {
+    let max = PtpTimestamp::new(MAX_SECS, 999_999_999).unwrap();
+    let zero = PtpTimestamp::default();
+    let max_nanos = i128::from(MAX_SECS) * 1_000_000_000 + 999_999_999;
+
+    assert_eq!(
+        max - zero,
+        SignedGeneric::new(max_nanos, Fraction::new(1, 1_000_000_000))
+    );
+    assert_eq!(
+        zero.signed_duration_since(&max),
+        SignedGeneric::new(-max_nanos, Fraction::new(1, 1_000_000_000))
+    );
+}
+
+#[test]
+fn corrections() {
+    let correction = Correction::from_bits(-0x0002_8000); // -2.5ns
+
+    assert_eq!(correction.to_bits(), -0x0002_8000);
+    assert_eq!(correction.nanos(), -2);
+    assert_eq!(
+        correction.to_duration(),
+        SignedGeneric::new(-2_i64, Fraction::new(1, 1_000_000_000))
+    );
+    assert_eq!(
+        Correction::from_nanos(3),
+        Some(Correction::from_bits(3 << 16))
+    );
+    assert_eq!(Correction::from_nanos(i64::MAX / 65_536 + 1), None);
+
+    assert_eq!(
+        Correction::try_from(SignedGeneric::new(3_i32, Fraction::new(1, 1_000_000))),
+        Ok(Correction::from_bits(3_000 << 16))
+    );
+    assert_eq!(
+        Correction::try_from(SignedGeneric::new(
+            -(1_i64 << 47),
+            Fraction::new(1, 1_000_000_000)
+        )),
+        Ok(Correction::from_bits(i64::MIN))
+    );
+    assert_eq!(
+        Correction::try_from(SignedGeneric::new(
+            1_i64 << 48,
+            Fraction::new(1, 1_000_000_000)
+        )),
+        Err(ConversionError::Overflow)
+    );
+
+    let timestamp = PtpTimestamp::new(1, 0).unwrap();
+    assert_eq!(
+        timestamp.checked_add_correction(correction),
+        PtpTimestamp::new(0, 999_999_998)
+    );
+    assert_eq!(
+        timestamp.checked_sub_correction(correction),
+        PtpTimestamp::new(1, 2)
+    );
+    assert_eq!(
+        PtpTimestamp::default().checked_add_correction(correction),
+        None
+    );
+    assert_eq!(
+        PtpTimestamp::new(MAX_SECS, 999_999_999)
+            .unwrap()
+            .checked_sub_correction(correction),
+        None
+    );
+}
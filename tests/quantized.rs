@@ -0,0 +1,87 @@
+use embedded_time::{
+    duration::*, fixed_point::RoundingMode::*, quantized::Quantized, ConversionError,
+};
+
+#[test]
+fn rounding() {
+    let duration = Microseconds(2_500_u32);
+
+    assert_eq!(
+        Quantized::new(duration, Milliseconds(1_u32), Floor).map(|q| q.duration()),
+        Ok(Microseconds(2_000_u32))
+    );
+    assert_eq!(
+        Quantized::new(duration, Milliseconds(1_u32), Ceil).map(|q| q.duration()),
+        Ok(Microseconds(3_000_u32))
+    );
+    assert_eq!(
+        Quantized::new(duration, Milliseconds(1_u32), Nearest).map(|q| q.duration()),
+        Ok(Microseconds(3_000_u32))
+    );
+    assert_eq!(
+        Quantized::new(duration, Microseconds(500_u32), Ceil).map(|q| q.into_inner()),
+        Ok(duration)
+    );
+}
+
+#[test]
+fn resolution() {
+    let quantized = Quantized::new(Milliseconds(90_000_u32), Minutes(1_u32), Floor).unwrap();
+    assert_eq!(quantized.resolution(), Fraction::new(60, 1));
+    assert_eq!(quantized.duration(), Milliseconds(60_000_u32));
+
+    // finer than a tick of the duration type
+    assert_eq!(
+        Quantized::new(Milliseconds(1_u32), Microseconds(1_u32), Floor),
+        Err(ConversionError::ConversionFailure)
+    );
+    // not a whole number of ticks
+    assert_eq!(
+        Quantized::new(Milliseconds(1_u32), Microseconds(1_500_u32), Floor),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Quantized::new(Milliseconds(1_u32), Seconds(0_u32), Floor),
+        Err(ConversionError::DivByZero)
+    );
+    // rounded up out of range
+    assert_eq!(
+        Quantized::new(Milliseconds(u32::MAX), Seconds(1_u32), Ceil),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn conversions() {
+    let quantized =
+        Quantized::new(Nanoseconds(7_300_000_u64), Microseconds(100_u32), Nearest).unwrap();
+
+    assert_eq!(
+        quantized
+            .try_convert::<Microseconds<u32>>()
+            .map(|q| q.duration()),
+        Ok(Microseconds(7_300_u32))
+    );
+    assert_eq!(
+        quantized
+            .try_convert::<Nanoseconds<u32>>()
+            .map(|q| q.resolution()),
+        Ok(Fraction::new(1, 10_000))
+    );
+    assert_eq!(
+        quantized.try_convert::<Milliseconds<u32>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        quantized.try_convert::<Nanoseconds<u8>>(),
+        Err(ConversionError::ConversionFailure)
+    );
+
+    let requantized = quantized.requantize(Milliseconds(1_u32), Floor).unwrap();
+    assert_eq!(
+        requantized
+            .try_convert::<Milliseconds<u32>>()
+            .map(|q| q.duration()),
+        Ok(Milliseconds(7_u32))
+    );
+}
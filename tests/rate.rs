@@ -2,12 +2,22 @@
 
 use core::convert::{TryFrom, TryInto};
 use embedded_time::{
-    duration::*,
-    fraction::Fraction,
-    rate::{self, *},
-    ConversionError,
+    duration::*, fraction::Fraction, rate, rate::*, ConversionError, Instant, TimeError,
 };
 
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
 #[test]
 fn construction() {
     assert_eq!(<Hertz>::new(5), Hertz(5_u32));
@@ -38,6 +48,42 @@ fn construction() {
     assert_eq!(5_u32.Bd(), Baud(5_u32));
 }
 
+#[test]
+fn from_events() {
+    let start = Instant::<Clock>::new(u32::MAX - 499_998);
+    let end = Instant::<Clock>::new(500_000);
+
+    assert_eq!(
+        Hertz::<u32>::from_events(1_000, &start, &end),
+        Ok(Hertz(1_000_u32))
+    );
+    assert_eq!(
+        Millihertz::<u32>::from_events(1, &start, &end),
+        Ok(Millihertz(1_000_u32))
+    );
+    assert_eq!(
+        Kilohertz::<u32>::from_events(2_499, &start, &end),
+        Ok(Kilohertz(2_u32))
+    );
+    assert_eq!(
+        Kilohertz::<u32>::from_events(2_500, &start, &end),
+        Ok(Kilohertz(3_u32))
+    );
+
+    assert_eq!(
+        Hertz::<u32>::from_events(1, &end, &start),
+        Err(TimeError::NegDuration)
+    );
+    assert_eq!(
+        Hertz::<u32>::from_events(1, &end, &end),
+        Err(TimeError::DivByZero)
+    );
+    assert_eq!(
+        Microhertz::<u32>::from_events(u32::MAX, &start, &end),
+        Err(TimeError::ConversionFailure)
+    );
+}
+
 #[test]
 fn comparisons() {
     assert_ne!(2_001.Hz(), 2_u32.kHz());
@@ -88,10 +134,13 @@ fn mul_overflow() {
 fn checked_mul() {
     assert_eq!(
         Kilohertz(2_001_u32).checked_mul(&2),
-        Some(Kilohertz(4_002_u32))
+        Ok(Kilohertz(4_002_u32))
     );
 
-    assert_eq!(Kilohertz(u32::MAX).checked_mul(&2), None);
+    assert_eq!(
+        Kilohertz(u32::MAX).checked_mul(&2),
+        Err(ConversionError::Overflow)
+    );
 }
 
 #[test]
@@ -108,10 +157,13 @@ fn div_div_by_zero() {
 fn checked_div() {
     assert_eq!(
         Milliseconds(2_002_u32).checked_div(&2),
-        Some(Milliseconds(1_001_u32))
+        Ok(Milliseconds(1_001_u32))
     );
 
-    assert_eq!(Milliseconds(u32::MAX).checked_div(&0), None);
+    assert_eq!(
+        Milliseconds(u32::MAX).checked_div(&0),
+        Err(ConversionError::DivByZero)
+    );
 }
 
 #[test]
@@ -179,6 +231,48 @@ fn convert_to_duration() {
     );
 }
 
+#[test]
+fn sub_hertz_to_duration() {
+    // every 5 minutes
+    assert_eq!(Millihertz(3_u32).to_duration(), Ok(Seconds(333_u32)));
+    assert_eq!(Microhertz(3_333_u32).to_duration(), Ok(Seconds(300_u32)));
+    assert_eq!(Microhertz(3_333_u32).to_duration(), Ok(Minutes(5_u32)));
+
+    // the combined _scaling factor_ (1/10^12, 1/10^15) doesn't fit a `Fraction`
+    assert_eq!(
+        Microhertz(1_u32).to_duration(),
+        Ok(Microseconds(1_000_000_000_000_u64))
+    );
+    assert_eq!(
+        Millihertz(3_u32).to_duration(),
+        Ok(Nanoseconds(333_333_333_333_u64))
+    );
+    assert_eq!(
+        Microhertz(1_u64).to_duration(),
+        Ok(Nanoseconds(1_000_000_000_000_000_u64))
+    );
+    assert_eq!(
+        Microhertz(1_u32).to_duration::<Microseconds<u32>>(),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Microhertz(0_u32).to_duration::<Nanoseconds<u64>>(),
+        Err(ConversionError::DivByZero)
+    );
+
+    // and back
+    assert_eq!(Seconds(300_u32).to_rate(), Ok(Microhertz(3_333_u32)));
+    assert_eq!(Hours(1_u32).to_rate(), Ok(Millihertz(0_u32)));
+    assert_eq!(
+        Microseconds(1_000_000_000_000_u64).to_rate(),
+        Ok(Microhertz(1_u64))
+    );
+    assert_eq!(
+        Nanoseconds(333_333_333_333_u64).to_rate(),
+        Ok(Millihertz(3_u64))
+    );
+}
+
 #[test]
 fn frequency_scaling() {
     assert_eq!(1_u32.Hz(), 1_u32.Hz());
@@ -382,3 +476,569 @@ fn into_smaller() {
     test_into_smaller![Baud, Kilobaud, Megabaud];
     test_into_smaller![Baud, Kibibaud, Mebibaud];
 }
+
+#[test]
+fn sub_hertz_from_hertz() {
+    assert_eq!(
+        Decihertz::<u32>::try_from(Hertz(5_u32)),
+        Ok(Decihertz(50_u32))
+    );
+    assert_eq!(
+        Centihertz::<u8>::try_from(Hertz(2_u8)),
+        Ok(Centihertz(200_u8))
+    );
+    assert_eq!(Millihertz::<u64>::from(Hertz(5_u32)), Millihertz(5_000_u64));
+    assert_eq!(
+        Microhertz::<u64>::try_from(Hertz(u64::MAX / 1_000_000)),
+        Ok(Microhertz(u64::MAX / 1_000_000 * 1_000_000))
+    );
+
+    // the finer units can't hold every rate in hertz
+    assert!(Decihertz::<u32>::try_from(Hertz(u32::MAX)).is_err());
+    assert!(Centihertz::<u8>::try_from(Hertz(3_u8)).is_err());
+    assert!(Microhertz::<u64>::try_from(Hertz(u64::MAX / 1_000_000 + 1)).is_err());
+
+    // the other direction can't overflow
+    assert_eq!(
+        Hertz::<u64>::from(Millihertz(u32::MAX)),
+        Hertz(u64::from(u32::MAX) / 1_000)
+    );
+}
+
+#[test]
+fn narrow_integer() {
+    assert_eq!(Hertz::<u32>::from(Hertz(50_u8)), Hertz(50_u32));
+    assert_eq!(
+        Kilohertz::<u16>::try_from(Kilohertz(300_u32)),
+        Ok(Kilohertz(300_u16))
+    );
+    assert_eq!(
+        Hertz::<u8>::try_from(Hertz(300_u32)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert!(Hertz(50_u8) < Hertz(1_000_u32));
+
+    assert_eq!(
+        Hertz(50_u8).to_duration::<Milliseconds<u16>>(),
+        Ok(Milliseconds(20_u16))
+    );
+}
+
+#[test]
+fn u128_integer() {
+    assert_eq!(Hertz::<u128>::from(Hertz(50_u32)), Hertz(50_u128));
+    assert_eq!(
+        Hertz::<u64>::try_from(Hertz(u64::MAX as u128 + 1)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Kilohertz(50_u128).to_duration::<Nanoseconds<u128>>(),
+        Ok(Nanoseconds(20_000_u128))
+    );
+}
+
+#[test]
+fn checked_add_sub() {
+    assert_eq!(
+        Hertz(1_u32).checked_add(&Kilohertz(1_u32)),
+        Ok(Hertz(1_001_u32))
+    );
+    assert_eq!(
+        Hertz(u32::MAX).checked_add(&Hertz(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Hertz(2_001_u32).checked_sub(&Kilohertz(1_u32)),
+        Ok(Hertz(1_001_u32))
+    );
+    assert_eq!(
+        Kilohertz(1_u32).checked_sub(&Hertz(2_000_u32)),
+        Err(ConversionError::Overflow)
+    );
+
+    let rate = rate::Generic::new(3_u32, Fraction::new(1_000, 1));
+    assert_eq!(
+        rate.checked_add(&rate::Generic::new(2_000_u32, Fraction::new(1, 1))),
+        Ok(rate::Generic::new(5_u32, Fraction::new(1_000, 1)))
+    );
+    assert_eq!(
+        rate.checked_sub(&rate::Generic::new(4_u32, Fraction::new(1_000, 1))),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        rate.checked_mul(&2),
+        Ok(rate::Generic::new(6_u32, Fraction::new(1_000, 1)))
+    );
+    assert_eq!(rate.checked_div(&0), Err(ConversionError::DivByZero));
+}
+
+#[test]
+fn saturating_add_sub() {
+    assert_eq!(
+        Hertz(1_u32).saturating_add(&Kilohertz(1_u32)),
+        Hertz(1_001_u32)
+    );
+    assert_eq!(
+        Kilohertz(u32::MAX).saturating_add(&Kilohertz(1_u32)),
+        Kilohertz(u32::MAX)
+    );
+    assert_eq!(Hertz(10_u32).saturating_sub(&Hertz(11_u32)), Hertz(0_u32));
+    assert_eq!(Hertz(10_u8).saturating_sub(&Megahertz(1_u32)), Hertz(0_u8));
+}
+
+#[test]
+fn div_rate() {
+    assert_eq!(
+        Kilohertz(48_u32).div_rate(Hertz(44_100_u32)),
+        Ok(Fraction::new(160, 147))
+    );
+    assert_eq!(
+        Hertz(0_u16).div_rate(Megahertz(1_u64)),
+        Ok(Fraction::new(0, 1))
+    );
+    assert_eq!(
+        Megahertz(u32::MAX).div_rate(Hertz(1_u32)),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Hertz(1_u32).div_rate(Kilohertz(0_u32)),
+        Err(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+fn count_in() {
+    assert_eq!(Kilohertz(48_u32).count_in(Milliseconds(10_u16)), Ok(480));
+    assert_eq!(Hertz(1_u32).count_in(Milliseconds(999_u32)), Ok(0));
+    assert_eq!(
+        Megahertz(u32::MAX).count_in(Seconds(1_000_u32)),
+        Ok(u64::from(u32::MAX) * 1_000_000_000)
+    );
+    assert_eq!(
+        Hertz(u64::MAX).count_in(Seconds(2_u32)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn min_max_clamp() {
+    assert_eq!(Hertz(500_u32).min(Kilohertz(1_u32)), Hertz(500_u32));
+    assert_eq!(Hertz(500_u32).max(Kilohertz(1_u32)), Hertz(1_000_u32));
+    assert_eq!(
+        Kilohertz(3_u32).clamp(Hertz(100_u32), Hertz(2_500_u32)),
+        Kilohertz(2_u32)
+    );
+    assert_eq!(
+        Baud(300_u32).clamp(Baud(9_600_u32), Kilobaud(115_u32)),
+        Baud(9_600_u32)
+    );
+
+    assert!(Kilohertz(0_u32).is_zero());
+    assert!(!Hertz(1_u64).is_zero());
+    assert!(!rate::Generic::new(1_u32, Fraction::new(1, 1)).is_zero());
+}
+
+#[test]
+fn literal() {
+    assert_eq!(rate!("115.2kBd", Baud<u32>), Baud(115_200_u32));
+    assert_eq!(rate!("32.768 kHz", Hertz<u16>), Hertz(32_768_u16));
+    assert_eq!(rate!("1MiHz", Hertz<u64>), Hertz(1_048_576_u64));
+    assert_eq!(
+        rate!("2 kBps", BitsPerSecond<u32>),
+        BitsPerSecond(16_000_u32)
+    );
+    assert_eq!(rate!("0.5Hz", Millihertz<u32>), Millihertz(500_u32));
+    assert_eq!(rate!("7uHz", Microhertz<u32>), Microhertz(7_u32));
+
+    assert_eq!(
+        rate!("115.2kBd"),
+        rate::Generic::new(1_152_u32, Fraction::new(100, 1))
+    );
+    assert_eq!(
+        rate!("2.5 MHz"),
+        rate::Generic::new(25_u32, Fraction::new(100_000, 1))
+    );
+}
+
+#[test]
+fn consts() {
+    assert_eq!(Hertz::<u16>::ZERO, Hertz(0_u16));
+    assert_eq!(Megabaud::<u64>::MIN, Megabaud(0_u64));
+    assert_eq!(Kilohertz::<u32>::MAX, Kilohertz(u32::MAX));
+    assert_eq!(BytesPerSecond::<u128>::MAX, BytesPerSecond(u128::MAX));
+}
+
+#[test]
+fn rounding() {
+    use embedded_time::fixed_point::RoundingMode::*;
+
+    assert_eq!(
+        Kilohertz::<u32>::try_convert_from_with(Hertz(32_768_u32), Floor),
+        Ok(Kilohertz(32_u32))
+    );
+    assert_eq!(
+        Kilohertz::<u32>::try_convert_from_with(Hertz(32_768_u32), Ceil),
+        Ok(Kilohertz(33_u32))
+    );
+    assert_eq!(Hertz(32_768_u32).to_rounded(Nearest), Ok(Kilohertz(33_u32)));
+    assert_eq!(Hertz(32_499_u32).to_rounded(Nearest), Ok(Kilohertz(32_u32)));
+    assert_eq!(Kibihertz(1_u32).to_rounded(Nearest), Ok(Kilohertz(1_u32)));
+    assert_eq!(
+        BitsPerSecond(1_001_u32).to_rounded(Ceil),
+        Ok(KilobitsPerSecond(2_u32))
+    );
+
+    let rate = rate::Generic::new(2_500_u32, Fraction::new(1, 1));
+    assert_eq!(rate.to_rounded(Floor), Ok(Kilohertz(2_u32)));
+    assert_eq!(rate.to_rounded(Nearest), Ok(Kilohertz(3_u32)));
+    assert_eq!(
+        rate.to_rounded::<Megahertz<u32>>(Ceil),
+        Ok(Megahertz(1_u32))
+    );
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn float() {
+    assert_eq!(Hertz(50_u32).to_hz_f64(), 50.0);
+    assert_eq!(Millihertz(1_500_u32).to_hz_f32(), 1.5);
+    assert_eq!(Kibihertz(1_u32).to_hz_f64(), 1_024.0);
+    assert_eq!(
+        rate::Generic::new(3_u32, Fraction::new(1, 2)).to_hz_f64(),
+        1.5
+    );
+
+    assert_eq!(
+        Kilohertz::<u32>::try_from_hz_f64(32_768.0),
+        Ok(Kilohertz(32_u32))
+    );
+    assert_eq!(
+        Millihertz::<u64>::try_from_hz_f32(0.5),
+        Ok(Millihertz(500_u64))
+    );
+    assert_eq!(
+        Hertz::<u8>::try_from_hz_f64(256.0),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        Hertz::<u32>::try_from_hz_f32(-0.5),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn try_from_int() {
+    assert_eq!(Hertz::<u8>::try_from_u32(200), Ok(Hertz(200_u8)));
+    assert_eq!(
+        Hertz::<u8>::try_from_u32(300),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(Baud::<u128>::try_from_u16(9_600), Ok(Baud(9_600_u128)));
+
+    assert_eq!(Kilohertz::<u16>::try_from(32_u64), Ok(Kilohertz(32_u16)));
+    assert_eq!(
+        Kilohertz::<u16>::try_from(70_000_u32),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn parse() {
+    use embedded_time::ParseError;
+
+    assert_eq!("8MHz".parse(), Ok(Kilohertz(8_000_u32)));
+    assert_eq!("8 MHz".parse(), Ok(Hertz(8_000_000_u32)));
+    assert_eq!("115.2kbps".parse(), Ok(BitsPerSecond(115_200_u32)));
+    assert_eq!("9600Bd".parse(), Ok(Baud(9_600_u32)));
+    assert_eq!("1KiBps".parse(), Ok(BitsPerSecond(8_192_u32)));
+
+    assert_eq!(
+        "2.5Hz".parse::<rate::Generic<u32>>(),
+        Ok(rate::Generic::new(5_u32, Fraction::new(1, 2)))
+    );
+
+    assert_eq!("1.5Hz".parse::<Hertz<u32>>(), Err(ParseError::Inexact));
+    assert_eq!(
+        "8 parsecs".parse::<Hertz<u32>>(),
+        Err(ParseError::UnknownUnit)
+    );
+    assert_eq!("1Hz1Hz".parse::<Hertz<u32>>(), Err(ParseError::UnknownUnit));
+    assert_eq!("MHz".parse::<Hertz<u32>>(), Err(ParseError::InvalidNumber));
+    assert_eq!("1MHz".parse::<Hertz<u16>>(), Err(ParseError::Overflow));
+}
+
+#[test]
+fn generic_arithmetic() {
+    let half_hertz = rate::Generic::new(3_u32, Fraction::new(1, 2));
+    let millihertz = rate::Generic::new(1_500_u32, Fraction::new(1, 1_000));
+
+    assert_eq!(half_hertz, millihertz);
+    assert_eq!(millihertz, Millihertz(1_500_u32).into());
+    assert!(half_hertz < rate::Generic::new(1_501_u32, Fraction::new(1, 1_000)));
+    assert!(
+        rate::Generic::new(1_u32, Fraction::new(1, 1))
+            > rate::Generic::new(999_u32, Fraction::new(1, 1_000))
+    );
+    assert!(
+        rate::Generic::new(2_u32, Fraction::new(1_000, 1))
+            < rate::Generic::new(1_u32, Fraction::new(1_000_000, 1))
+    );
+
+    assert_eq!(
+        half_hertz + Hertz(1_u32),
+        rate::Generic::new(5_u32, Fraction::new(1, 2))
+    );
+    assert_eq!(half_hertz - millihertz, Hertz(0_u32).into());
+    assert_eq!(
+        Kilohertz(2_u32)
+            .to_generic::<u32>(Fraction::new(1, 1))
+            .unwrap()
+            % Kilohertz(1_u32),
+        Hertz(0_u32).into()
+    );
+    assert_eq!(millihertz % Hertz(1_u32), Millihertz(500_u32).into());
+
+    assert_eq!(format!("{}", half_hertz), "3 × 1/2 Hz");
+}
+
+#[test]
+#[should_panic(expected = "Add failed")]
+fn generic_add_overflow() {
+    let _ = rate::Generic::new(u32::MAX, Fraction::new(1, 1)) + Hertz(1_u32);
+}
+
+embedded_time::rate_unit!(
+    /// Pixels per second of a 25.175 MHz VGA pixel clock
+    PixelClock, (25_175_000, 1)
+);
+
+#[test]
+fn custom_unit() {
+    use embedded_time::fixed_point::FixedPoint;
+
+    assert_eq!(PixelClock::new(2_u32).integer(), 2);
+    assert_eq!(
+        PixelClock::<u32>::SCALING_FACTOR,
+        Fraction::new(25_175_000, 1)
+    );
+    assert_eq!(format!("{}", PixelClock(2_u32)), "2");
+
+    assert_eq!(
+        Hertz::try_from(PixelClock(2_u32)),
+        Ok(Hertz(50_350_000_u32))
+    );
+    assert_eq!(
+        Kilohertz::try_from(PixelClock(1_u32)),
+        Ok(Kilohertz(25_175_u32))
+    );
+    assert_eq!(
+        PixelClock::try_from(Megahertz(100_u32)),
+        Ok(PixelClock(3_u32))
+    );
+    assert_eq!(
+        rate::Generic::from(PixelClock(3_u32)),
+        rate::Generic::new(3_u32, Fraction::new(25_175_000, 1))
+    );
+    assert_eq!(
+        PixelClock::<u32>::try_from(rate::Generic::new(50_350_u32, Fraction::new(1_000, 1))),
+        Ok(PixelClock(2_u32))
+    );
+    assert_eq!(
+        PixelClock(1_u32).to_duration::<Nanoseconds<u32>>(),
+        Ok(Nanoseconds(39_u32))
+    );
+
+    assert_eq!(PixelClock(1_u32), PixelClock(1_u64));
+    assert!(PixelClock(1_u32) < PixelClock(2_u8));
+    assert_eq!(PixelClock(1_u32), Kilohertz(25_175_u32));
+    assert_eq!(Kilohertz(25_175_u32), PixelClock(1_u32));
+    assert!(PixelClock(1_u32) > Megahertz(25_u32));
+    assert!(Megahertz(26_u32) > PixelClock(1_u32));
+
+    assert_eq!(PixelClock(1_u32) + PixelClock(1_u32), PixelClock(2_u32));
+    assert_eq!(PixelClock(3_u32) - Megahertz(26_u32), PixelClock(2_u32));
+    assert_eq!(PixelClock(3_u32) % PixelClock(2_u32), PixelClock(1_u32));
+    assert_eq!(PixelClock(3_u32) * 2, PixelClock(6_u32));
+    assert_eq!(PixelClock(7_u32) / 2, PixelClock(3_u32));
+}
+
+#[test]
+fn line_rates() {
+    assert_eq!(
+        Baud(9_600_u32).to_bits_per_second(1),
+        Ok(BitsPerSecond(9_600_u32))
+    );
+    assert_eq!(
+        Baud(4_800_u32).to_bits_per_second(2),
+        Ok(BitsPerSecond(9_600_u32))
+    );
+    assert_eq!(
+        Baud(u32::MAX).to_bits_per_second(2),
+        Err(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Baud(200_u8).to_bits_per_second(256),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(BitsPerSecond(9_601_u32).to_baud(2), Ok(Baud(4_800_u32)));
+    assert_eq!(
+        BitsPerSecond(9_600_u32).to_baud(0),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(BitsPerSecond(200_u8).to_baud(256), Ok(Baud(0_u8)));
+
+    // 8N1 and 8E1 UART frames
+    assert_eq!(
+        BitsPerSecond(115_200_u32).to_bytes_per_second(10),
+        Ok(BytesPerSecond(11_520_u32))
+    );
+    assert_eq!(
+        BitsPerSecond(115_200_u32).to_bytes_per_second(11),
+        Ok(BytesPerSecond(10_472_u32))
+    );
+    assert_eq!(
+        BitsPerSecond(115_200_u32).to_bytes_per_second(0),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        BytesPerSecond(11_520_u32).to_bits_per_second(10),
+        Ok(BitsPerSecond(115_200_u32))
+    );
+    assert_eq!(
+        BytesPerSecond(u32::MAX).to_bits_per_second(10),
+        Err(ConversionError::Overflow)
+    );
+
+    // the same as `From` with 8 bits per byte
+    assert_eq!(
+        BytesPerSecond(1_000_u32).to_bits_per_second(8),
+        BitsPerSecond::<u32>::try_from(BytesPerSecond(1_000_u32))
+    );
+}
+
+#[test]
+fn sample_and_frame_rates() {
+    assert_eq!(
+        SamplesPerSecond::<u32>::try_from(KilosamplesPerSecond(48_u32)),
+        Ok(SamplesPerSecond(48_000_u32))
+    );
+    assert_eq!(
+        KilosamplesPerSecond::<u32>::from(SamplesPerSecond(44_100_u32)),
+        KilosamplesPerSecond(44_u32)
+    );
+    assert_eq!(
+        FramesPerSecond::<u32>::try_from(KiloframesPerSecond(1_u32)),
+        Ok(FramesPerSecond(1_000_u32))
+    );
+    assert_eq!(48_u32.ksps(), 48_000_u32.sps());
+    assert_eq!("48ksps".parse(), Ok(SamplesPerSecond(48_000_u32)));
+    assert_eq!(rate!("30fps", FramesPerSecond<u32>), 30_u32.fps());
+
+    // an I2S master clock of 256 × fs, and a buffer of 480 samples
+    let sample_rate = SamplesPerSecond(48_000_u32);
+    let master_clock =
+        Hertz::<u32>::try_from(sample_rate.to_generic::<u32>(Fraction::new(1, 1)).unwrap())
+            .unwrap()
+            * 256;
+    assert_eq!(master_clock, Hertz(12_288_000_u32));
+    assert_eq!(
+        (sample_rate / 480).to_duration(),
+        Ok(Microseconds(10_000_u32))
+    );
+    // (the sample period is truncated)
+    assert_eq!(sample_rate.to_duration(), Ok(Nanoseconds(20_833_u32)));
+
+    // a 30 fps camera frame period
+    assert_eq!(
+        FramesPerSecond(30_u32).to_duration(),
+        Ok(Microseconds(33_333_u32))
+    );
+}
+
+#[test]
+fn rpm() {
+    assert_eq!(Rpm::<u32>::try_from(Hertz(50_u32)), Ok(Rpm(3_000_u32)));
+    assert_eq!(
+        Rpm::<u32>::try_from(Hertz(u32::MAX)),
+        Err(ConversionError::Unspecified)
+    );
+    assert_eq!(
+        Rpm::<u64>::from(Hertz(u32::MAX)),
+        Rpm(u64::from(u32::MAX) * 60)
+    );
+    assert_eq!(Hertz::<u32>::from(Rpm(90_u32)), Hertz(1_u32));
+    assert_eq!(Rpm(60_u32), Hertz(1_u32));
+    assert!(Rpm(61_u32) > Hertz(1_u32));
+    assert_eq!(
+        rate::Generic::from(Rpm(1_500_u32)),
+        rate::Generic::new(25_u32, Fraction::new(1, 1))
+    );
+    assert_eq!("1500rpm".parse(), Ok(Rpm(1_500_u32)));
+    assert_eq!(rate!("7.5kHz", Rpm<u32>), 450_000_u32.rpm());
+
+    // period of a revolution
+    assert_eq!(Rpm(3_000_u32).to_duration(), Ok(Milliseconds(20_u32)));
+    assert_eq!(Rpm(7_u32).to_duration(), Ok(Milliseconds(8_571_u32)));
+    assert_eq!(Milliseconds(20_u32).to_rate(), Ok(Rpm(3_000_u32)));
+    assert_eq!(
+        Rpm(0_u32).to_duration::<Seconds<u32>>(),
+        Err(ConversionError::DivByZero)
+    );
+
+    // an encoder with 1_024 pulses per revolution
+    let pulses = Hertz::<u32>::from(Rpm(3_000_u32 * 1_024));
+    assert_eq!(pulses, Hertz(51_200_u32));
+}
+
+#[test]
+fn prescaler_for() {
+    // exact with the smallest prescaler
+    assert_eq!(
+        rate::prescaler_for(Hertz(72_000_000), Milliseconds(1_u32), 65_536),
+        Ok((2, 36_000))
+    );
+    // 1 Hz with 48 MHz = 750 × 64_000
+    assert_eq!(
+        rate::prescaler_for(Hertz(48_000_000), Seconds(1_u64), 65_536),
+        Ok((750, 64_000))
+    );
+    // a 32-bit timer needs no prescaler
+    assert_eq!(
+        rate::prescaler_for(Hertz(72_000_000), Seconds(10_u32), u32::MAX),
+        Ok((1, 720_000_000))
+    );
+    // no exact solution, 32_768 × 7 / 1_000 = 229.376 cycles
+    assert_eq!(
+        rate::prescaler_for(Hertz(32_768), Milliseconds(7_u32), 256),
+        Ok((1, 229))
+    );
+    // 32_768 × 70 / 1_000 = 2_293.76 cycles, 9 × 255 = 2_295 is the closest
+    assert_eq!(
+        rate::prescaler_for(Hertz(32_768), Milliseconds(70_u32), 256),
+        Ok((9, 255))
+    );
+    // half a cycle rounds up
+    assert_eq!(
+        rate::prescaler_for(Hertz(1_000), Microseconds(500_u32), 256),
+        Ok((1, 1))
+    );
+
+    // errors
+    assert_eq!(
+        rate::prescaler_for(Hertz(1_000), Microseconds(499_u32), 256),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        rate::prescaler_for(Hertz(0), Seconds(1_u32), 256),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        rate::prescaler_for(Hertz(72_000_000), Seconds(1_u32), 0),
+        Err(ConversionError::DivByZero)
+    );
+    assert_eq!(
+        rate::prescaler_for(Hertz(u32::MAX), Hours(u64::MAX), 65_536),
+        Err(ConversionError::Overflow)
+    );
+}
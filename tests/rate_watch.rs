@@ -0,0 +1,100 @@
+use embedded_time::{
+    self as time,
+    duration::Milliseconds,
+    fraction::Fraction,
+    rate::Hertz,
+    rate_watch::{Crossing, RateWatch},
+    ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn new_watch(start: u32) -> RateWatch<Clock, Hertz> {
+    RateWatch::new(
+        Hertz(900_u32),
+        Hertz(1_000_u32),
+        Milliseconds(100_u32),
+        Instant::new(start),
+    )
+    .unwrap()
+}
+
+#[test]
+fn hysteresis() {
+    let mut watch = new_watch(0);
+    let mut now = 0;
+
+    let mut measure = |events| {
+        now += 100;
+        watch.events(events);
+        let crossing = watch.update(Instant::new(now)).unwrap();
+        (crossing, watch.is_high())
+    };
+
+    assert_eq!(measure(95), (None, false));
+    assert_eq!(measure(100), (Some(Crossing::High), true));
+    assert_eq!(measure(120), (None, true));
+    assert_eq!(measure(95), (None, true));
+    assert_eq!(measure(90), (Some(Crossing::Low), false));
+    assert_eq!(measure(95), (None, false));
+}
+
+#[test]
+fn window_not_elapsed() {
+    let mut watch = new_watch(0);
+
+    watch.events(1_000);
+    assert_eq!(watch.update(Instant::new(99)), Ok(None));
+    assert_eq!(watch.rate(), None);
+
+    // the window is measured to the actual update instant
+    watch.event();
+    assert_eq!(watch.update(Instant::new(143)), Ok(Some(Crossing::High)));
+    assert_eq!(watch.rate(), Some(Hertz(7_000)));
+}
+
+#[test]
+fn clock_wrap() {
+    let mut watch = new_watch(u32::MAX - 49);
+
+    watch.events(100);
+    assert_eq!(watch.update(Instant::new(49)), Ok(None));
+    assert_eq!(watch.update(Instant::new(50)), Ok(Some(Crossing::High)));
+    assert_eq!(watch.rate(), Some(Hertz(1_000)));
+}
+
+#[test]
+fn zero_window() {
+    assert_eq!(
+        RateWatch::<Clock, Hertz>::new(
+            Hertz(900_u32),
+            Hertz(1_000_u32),
+            Milliseconds(0_u32),
+            Instant::new(0)
+        )
+        .unwrap_err(),
+        ConversionError::DivByZero
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid thresholds")]
+fn inverted_thresholds() {
+    let _ = RateWatch::<Clock, Hertz>::new(
+        Hertz(1_000_u32),
+        Hertz(900_u32),
+        Milliseconds(100_u32),
+        Instant::new(0),
+    );
+}
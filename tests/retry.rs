@@ -0,0 +1,103 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    retry::{Backoff, RetryBudget},
+    Instant,
+};
+use test_case::test_case;
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+/// Returns the delays until the budget is exhausted, attempting as soon as allowed
+fn delays(mut budget: RetryBudget<Clock>, start: u32) -> Vec<u32> {
+    let mut now = start;
+    let mut delays = Vec::new();
+
+    while let Some(delay) = budget.next_attempt(Instant::new(now)) {
+        delays.push(delay.integer());
+        now = now.wrapping_add(delay.integer());
+    }
+
+    delays
+}
+
+#[test_case(Backoff::Constant => vec![0, 10, 10, 10, 10] ; "Constant")]
+#[test_case(Backoff::Linear => vec![0, 10, 20, 30, 40] ; "Linear")]
+#[test_case(Backoff::Exponential => vec![0, 10, 20, 40, 80] ; "Exponential")]
+fn backoff(backoff: Backoff) -> Vec<u32> {
+    let budget = RetryBudget::new(5, Instant::new(10_000), Milliseconds(10_u32), backoff).unwrap();
+    delays(budget, 0)
+}
+
+#[test]
+fn deadline_limits_attempts() {
+    let budget = RetryBudget::new(
+        100,
+        Instant::new(100),
+        Milliseconds(10_u32),
+        Backoff::Exponential,
+    )
+    .unwrap();
+
+    // the next attempt (after 80 ms) would start at 150 ms
+    assert_eq!(delays(budget, 0), [0, 10, 20, 40]);
+}
+
+#[test]
+fn past_deadline() {
+    let mut budget = RetryBudget::<Clock>::new(
+        3,
+        Instant::new(100),
+        Milliseconds(10_u32),
+        Backoff::Constant,
+    )
+    .unwrap();
+
+    assert!(budget.next_attempt(Instant::new(100)).is_none());
+    assert!(budget.next_attempt(Instant::new(150)).is_none());
+    assert_eq!(budget.attempts(), 0);
+}
+
+#[test]
+fn clock_wrap() {
+    let budget = RetryBudget::new(
+        10,
+        Instant::new(35),
+        Milliseconds(10_u32),
+        Backoff::Constant,
+    )
+    .unwrap();
+
+    assert_eq!(delays(budget, u32::MAX - 9), [0, 10, 10, 10, 10]);
+}
+
+#[test]
+fn exponential_delay_saturates() {
+    let mut budget = RetryBudget::<Clock>::new(
+        40,
+        Instant::new(u32::MAX / 2),
+        Seconds(1_u32),
+        Backoff::Exponential,
+    )
+    .unwrap();
+
+    for _ in 0..23 {
+        budget.next_attempt(Instant::new(0)).unwrap();
+    }
+
+    // 2^22 s is longer than half the range of the clock, so the delay saturates (and can't be
+    // waited before the deadline)
+    assert!(budget.next_attempt(Instant::new(0)).is_none());
+    assert_eq!(budget.attempts(), 23);
+}
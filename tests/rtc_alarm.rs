@@ -0,0 +1,90 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    rtc_alarm::{nearest_alarm, MatchFields},
+    ConversionError, Instant,
+};
+use test_case::test_case;
+
+#[derive(Debug)]
+struct Rtc;
+
+impl time::Clock for Rtc {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+/// Returns the alarm fields, wake and residual in ms
+fn alarm(
+    fields: MatchFields,
+    now: u32,
+    deadline: u32,
+) -> Result<(u8, u8, u8, u32, u32), ConversionError> {
+    nearest_alarm(fields, Instant::<Rtc>::new(now), Instant::new(deadline)).map(|alarm| {
+        (
+            alarm.hour,
+            alarm.minute,
+            alarm.second,
+            alarm.wake.duration_since_epoch().integer(),
+            alarm.residual.integer(),
+        )
+    })
+}
+
+#[test_case(MatchFields::Seconds, 0, 45_000 => Ok((0, 0, 45, 45_000, 0)) ; "exact")]
+#[test_case(MatchFields::Seconds, 0, 45_250 => Ok((0, 0, 45, 45_000, 250)) ; "sub-second residual")]
+#[test_case(MatchFields::Seconds, 500, 60_000 => Ok((0, 1, 0, 60_000, 0)) ; "one period")]
+#[test_case(MatchFields::Seconds, 0, 200_000 => Ok((0, 0, 20, 20_000, 180_000)) ; "beyond the period")]
+#[test_case(MatchFields::MinutesSeconds, 0, 200_000 => Ok((0, 3, 20, 200_000, 0)) ; "minutes")]
+#[test_case(MatchFields::MinutesSeconds, 3_599_000, 7_300_000 => Ok((1, 1, 40, 3_700_000, 3_600_000)) ; "beyond an hour")]
+#[test_case(MatchFields::HoursMinutesSeconds, 86_000_000, 90_000_999 => Ok((1, 0, 0, 90_000_000, 999)) ; "past midnight")]
+#[test_case(MatchFields::Seconds, 1_100, 1_900 => Err(ConversionError::NegDuration) ; "within a second")]
+#[test_case(MatchFields::Seconds, 1_100, 2_000 => Ok((0, 0, 2, 2_000, 0)) ; "next second")]
+#[test_case(MatchFields::Seconds, 5_000, 1_000 => Err(ConversionError::NegDuration) ; "past deadline")]
+fn milliseconds(
+    fields: MatchFields,
+    now: u32,
+    deadline: u32,
+) -> Result<(u8, u8, u8, u32, u32), ConversionError> {
+    alarm(fields, now, deadline)
+}
+
+#[derive(Debug)]
+struct SlowRtc;
+
+impl time::Clock for SlowRtc {
+    type T = u64;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(2, 3);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn coarse_ticks() {
+    let alarm = nearest_alarm(
+        MatchFields::Seconds,
+        Instant::<SlowRtc>::new(0),
+        Instant::new(100),
+    )
+    .unwrap();
+
+    // the deadline is at 66⅔ s, a minute after the alarm at 6 s (9 ticks)
+    assert_eq!((alarm.minute, alarm.second), (0, 6));
+    assert_eq!(alarm.wake, Instant::new(9));
+    assert_eq!(alarm.residual, Generic::new(91_u64, Fraction::new(2, 3)));
+}
+
+#[test]
+fn periods() {
+    assert_eq!(MatchFields::Seconds.period(), 60);
+    assert_eq!(MatchFields::MinutesSeconds.period(), 3_600);
+    assert_eq!(MatchFields::HoursMinutesSeconds.period(), 86_400);
+}
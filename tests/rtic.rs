@@ -0,0 +1,57 @@
+#![cfg(feature = "rtic")]
+
+use embedded_time::{
+    self as time,
+    duration::Fraction,
+    rtic::{Compare, RticMonotonic},
+    Instant,
+};
+use rtic_monotonic::Monotonic;
+
+#[derive(Debug, Default)]
+struct Clock {
+    ticks: u32,
+    compare: Option<Instant<Clock>>,
+    compare_flag: bool,
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.ticks))
+    }
+}
+
+impl Compare for Clock {
+    fn set_compare(&mut self, instant: Instant<Self>) {
+        self.compare = Some(instant);
+    }
+
+    fn clear_compare_flag(&mut self) {
+        self.compare_flag = false;
+    }
+
+    fn reset(&mut self) {
+        self.ticks = 0;
+    }
+}
+
+#[test]
+fn monotonic() {
+    let mut mono = RticMonotonic::new(Clock {
+        ticks: 50,
+        compare_flag: true,
+        ..Clock::default()
+    });
+
+    unsafe { mono.reset() };
+    assert_eq!(mono.now(), RticMonotonic::<Clock>::zero());
+
+    mono.set_compare(Instant::new(10));
+    mono.clear_compare_flag();
+    assert_eq!(mono.inner().compare, Some(Instant::new(10)));
+    assert!(!mono.inner().compare_flag);
+}
@@ -0,0 +1,143 @@
+use embedded_time::{
+    self as time,
+    duration::{Generic, Milliseconds},
+    fraction::Fraction,
+    rate::{Hertz, Millihertz},
+    sequence::{Arrival, SequenceCorrelator},
+    Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[derive(Debug)]
+struct ByteClock;
+
+impl time::Clock for ByteClock {
+    type T = u8;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn estimates() {
+    let mut correlator = SequenceCorrelator::<Clock>::default();
+    assert_eq!(correlator.period(), None);
+    assert_eq!(correlator.rate::<Hertz>(), None);
+    assert_eq!(correlator.expected(0), None);
+    assert_eq!(correlator.next_window(), None);
+    assert!(!correlator.is_overdue(Instant::new(u32::MAX / 2)));
+
+    assert_eq!(correlator.observe(10, Instant::new(500)), Arrival::First);
+    assert_eq!(correlator.period(), None);
+    assert_eq!(correlator.expected(11), None);
+
+    assert_eq!(
+        correlator.observe(13, Instant::new(800)),
+        Arrival::Next { skipped: 2 }
+    );
+    assert_eq!(correlator.period(), Some(Milliseconds(100_u32).into()));
+    assert_eq!(correlator.rate(), Some(Hertz(10_u32)));
+    assert_eq!(correlator.rate(), Some(Millihertz(10_000_u32)));
+    assert_eq!(correlator.expected(10), Some(Instant::new(500)));
+    assert_eq!(correlator.expected(12), Some(Instant::new(700)));
+    assert_eq!(correlator.expected(20), Some(Instant::new(1_500)));
+    assert_eq!(correlator.expected(9), None);
+    assert_eq!(
+        correlator.jitter(),
+        Generic::new(0_u32, Fraction::new(1, 1_000))
+    );
+    assert_eq!(
+        correlator.next_window(),
+        Some((Instant::new(900), Instant::new(900)))
+    );
+
+    // 30 ms late
+    assert_eq!(
+        correlator.observe(14, Instant::new(930)),
+        Arrival::Next { skipped: 0 }
+    );
+    assert_eq!(correlator.jitter(), Milliseconds(30_u32).into());
+    // (930 - 500) / 4 = 107.5
+    assert_eq!(correlator.period(), Some(Milliseconds(107_u32).into()));
+    assert_eq!(correlator.expected(15), Some(Instant::new(1_038)));
+    assert_eq!(
+        correlator.next_window(),
+        Some((Instant::new(1_008), Instant::new(1_068)))
+    );
+    assert!(!correlator.is_overdue(Instant::new(1_000)));
+    assert!(!correlator.is_overdue(Instant::new(1_068)));
+    assert!(correlator.is_overdue(Instant::new(1_069)));
+
+    // duplicates and reordered sequence numbers are ignored
+    assert_eq!(correlator.observe(14, Instant::new(940)), Arrival::Stale);
+    assert_eq!(correlator.observe(11, Instant::new(940)), Arrival::Stale);
+    assert_eq!(correlator.period(), Some(Milliseconds(107_u32).into()));
+
+    correlator.reset();
+    assert_eq!(correlator.period(), None);
+    assert_eq!(correlator.jitter(), Milliseconds(0_u32).into());
+    assert_eq!(correlator.observe(1, Instant::new(0)), Arrival::First);
+}
+
+#[test]
+fn same_instant() {
+    let mut correlator = SequenceCorrelator::<Clock>::new();
+
+    correlator.observe(0, Instant::new(100));
+    correlator.observe(1, Instant::new(100));
+
+    assert_eq!(correlator.period(), Some(Milliseconds(0_u32).into()));
+    assert_eq!(correlator.rate::<Hertz>(), None);
+    assert_eq!(correlator.expected(5), Some(Instant::new(100)));
+}
+
+#[test]
+fn wrapping() {
+    let mut correlator = SequenceCorrelator::<ByteClock>::new();
+
+    // both the sequence numbers and the clock wrap
+    assert_eq!(
+        correlator.observe(u32::MAX - 1, Instant::new(200)),
+        Arrival::First
+    );
+    assert_eq!(
+        correlator.observe(u32::MAX, Instant::new(220)),
+        Arrival::Next { skipped: 0 }
+    );
+    assert_eq!(
+        correlator.observe(1, Instant::new(4)),
+        Arrival::Next { skipped: 1 }
+    );
+
+    assert_eq!(correlator.period(), Some(Milliseconds(20_u8).into()));
+    assert_eq!(correlator.expected(2), Some(Instant::new(24)));
+    assert_eq!(correlator.expected(u32::MAX), Some(Instant::new(220)));
+    assert_eq!(
+        correlator.observe(u32::MAX / 2, Instant::new(30)),
+        Arrival::Next {
+            skipped: u32::MAX / 2 - 2
+        }
+    );
+
+    let mut correlator = SequenceCorrelator::<ByteClock>::new();
+    correlator.observe(0, Instant::new(0));
+    assert_eq!(
+        correlator.observe(1 << 31, Instant::new(10)),
+        Arrival::Stale
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "serde")]
+
+use embedded_time::{duration, duration::*, rate, rate::*, Instant};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn units() {
+    assert_tokens(
+        &Milliseconds(500_u32),
+        &[
+            Token::NewtypeStruct {
+                name: "Milliseconds",
+            },
+            Token::U32(500),
+        ],
+    );
+    assert_tokens(
+        &Kilohertz(32_u64),
+        &[Token::NewtypeStruct { name: "Kilohertz" }, Token::U64(32)],
+    );
+}
+
+#[test]
+fn fraction() {
+    // not reduced
+    assert_tokens(
+        &Fraction::new(2, 4),
+        &[
+            Token::Tuple { len: 2 },
+            Token::U32(2),
+            Token::U32(4),
+            Token::TupleEnd,
+        ],
+    );
+    assert_de_tokens_error::<Fraction>(
+        &[
+            Token::Tuple { len: 2 },
+            Token::U32(1),
+            Token::U32(0),
+            Token::TupleEnd,
+        ],
+        "Invalid fraction",
+    );
+}
+
+#[test]
+fn generic() {
+    assert_tokens(
+        &duration::Generic::new(1_500_u32, Fraction::new(1, 1_000)),
+        &[
+            Token::Tuple { len: 3 },
+            Token::U32(1_500),
+            Token::U32(1),
+            Token::U32(1_000),
+            Token::TupleEnd,
+        ],
+    );
+    assert_tokens(
+        &duration::SignedGeneric::new(-5_i64, Fraction::new(1, 1)),
+        &[
+            Token::Tuple { len: 3 },
+            Token::I64(-5),
+            Token::U32(1),
+            Token::U32(1),
+            Token::TupleEnd,
+        ],
+    );
+    assert_tokens(
+        &rate::Generic::new(3_u16, Fraction::new(1_000, 1)),
+        &[
+            Token::Tuple { len: 3 },
+            Token::U16(3),
+            Token::U32(1_000),
+            Token::U32(1),
+            Token::TupleEnd,
+        ],
+    );
+    assert_de_tokens_error::<rate::Generic<u32>>(
+        &[
+            Token::Tuple { len: 3 },
+            Token::U32(3),
+            Token::U32(1),
+            Token::U32(0),
+            Token::TupleEnd,
+        ],
+        "Invalid scaling factor",
+    );
+}
+
+#[test]
+fn instant() {
+    assert_tokens(
+        &Instant::<Clock>::new(23),
+        &[
+            Token::Struct {
+                name: "Instant",
+                len: 1,
+            },
+            Token::Str("ticks"),
+            Token::U32(23),
+            Token::StructEnd,
+        ],
+    );
+}
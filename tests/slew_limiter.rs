@@ -0,0 +1,84 @@
+use embedded_time::{
+    self as time, fraction::Fraction, rate::*, slew_limiter::SlewLimiter, Instant,
+};
+use test_case::test_case;
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test_case(0, 1_000, 250 => 25 ; "Rising output is limited")]
+#[test_case(0, -1_000, 250 => -25 ; "Falling output is limited")]
+#[test_case(0, 20, 250 => 20 ; "Target within reach is applied")]
+#[test_case(0, 1_000, 0 => 0 ; "No time elapsed")]
+fn update(initial: i32, target: i32, elapsed_ms: u32) -> i32 {
+    let mut limiter = SlewLimiter::new(Hertz(100_u32), initial, Instant::<Clock>::new(0));
+    limiter.update(target, Instant::<Clock>::new(elapsed_ms))
+}
+
+#[test]
+fn sub_unit_steps_accumulate() {
+    let mut limiter = SlewLimiter::new(Hertz(100_u32), 0, Instant::<Clock>::new(0));
+
+    // 100 units/s is 1 unit per 10 ms
+    assert_eq!(limiter.update(1_000, Instant::<Clock>::new(4)), 0);
+    assert_eq!(limiter.update(1_000, Instant::<Clock>::new(8)), 0);
+    assert_eq!(limiter.update(1_000, Instant::<Clock>::new(12)), 1);
+}
+
+#[test]
+fn partial_steps_carry_the_remainder() {
+    let mut limiter = SlewLimiter::new(Hertz(100_u32), 0, Instant::<Clock>::new(0));
+
+    // 1.5 units per update
+    for update in 1..=20 {
+        assert_eq!(
+            limiter.update(1_000, Instant::<Clock>::new(update * 15)),
+            (update * 3 / 2) as i32
+        );
+    }
+}
+
+#[test]
+fn clock_wrap() {
+    let mut limiter = SlewLimiter::new(Kilohertz(1_u32), 0, Instant::<Clock>::new(u32::MAX - 4));
+
+    assert_eq!(limiter.update(1_000, Instant::<Clock>::new(5)), 10);
+}
+
+#[test]
+fn earlier_instant_is_ignored() {
+    let mut limiter = SlewLimiter::new(Hertz(100_u32), 0, Instant::<Clock>::new(1_000));
+
+    assert_eq!(limiter.update(1_000, Instant::<Clock>::new(500)), 0);
+    assert_eq!(limiter.output(), 0);
+}
+
+#[test]
+fn reset_and_set_max_rate() {
+    let mut limiter = SlewLimiter::new(Hertz(100_u32), 0, Instant::<Clock>::new(0));
+
+    limiter.reset(500, Instant::<Clock>::new(100));
+    limiter.set_max_rate(Kilohertz(1_u32));
+
+    assert_eq!(limiter.update(0, Instant::<Clock>::new(200)), 400);
+}
+
+#[test]
+fn saturation() {
+    let mut limiter = SlewLimiter::new(Megahertz(u32::MAX), i32::MIN, Instant::<Clock>::new(0));
+
+    assert_eq!(
+        limiter.update(i32::MAX, Instant::<Clock>::new(u32::MAX / 2)),
+        i32::MAX
+    );
+}
@@ -0,0 +1,86 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    soft_pwm::{Output, SoftPwm},
+    ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn output(levels: u32, next_deadline: u32) -> Output<Clock> {
+    Output {
+        levels,
+        next_deadline: Instant::new(next_deadline),
+    }
+}
+
+#[test]
+fn duty_cycles() {
+    let mut pwm = SoftPwm::<Clock, 4>::new(Milliseconds(100_u32), Instant::new(1_000)).unwrap();
+    pwm.set_duty(0, Fraction::new(0, 1));
+    pwm.set_duty(1, Fraction::new(3, 10));
+    pwm.set_duty(2, Fraction::new(1, 1));
+    pwm.set_duty(3, Fraction::new(7, 10));
+
+    // before the first period
+    assert_eq!(pwm.poll(Instant::new(900)), output(0b0000, 1_000));
+
+    assert_eq!(pwm.poll(Instant::new(1_000)), output(0b1110, 1_030));
+    assert_eq!(pwm.poll(Instant::new(1_031)), output(0b1100, 1_070));
+    assert_eq!(pwm.poll(Instant::new(1_070)), output(0b0100, 1_100));
+    assert_eq!(pwm.poll(Instant::new(1_100)), output(0b1110, 1_130));
+}
+
+#[test]
+fn missed_periods_are_skipped() {
+    let mut pwm = SoftPwm::<Clock, 1>::new(Milliseconds(100_u32), Instant::new(0)).unwrap();
+    pwm.set_duty(0, Fraction::new(1, 2));
+
+    assert_eq!(pwm.poll(Instant::new(1_020)), output(0b1, 1_050));
+    assert_eq!(pwm.poll(Instant::new(1_060)), output(0b0, 1_100));
+}
+
+#[test]
+fn clock_wrap() {
+    let mut pwm =
+        SoftPwm::<Clock, 1>::new(Milliseconds(100_u32), Instant::new(u32::MAX - 19)).unwrap();
+    pwm.set_duty(0, Fraction::new(1, 2));
+
+    assert_eq!(pwm.poll(Instant::new(u32::MAX)), output(0b1, 30));
+    assert_eq!(pwm.poll(Instant::new(30)), output(0b0, 80));
+}
+
+#[test]
+fn large_period() {
+    // period × numerator overflows the u32
+    let mut pwm = SoftPwm::<Clock, 2>::new(Seconds(10_000_u32), Instant::new(0)).unwrap();
+    pwm.set_duty(0, Fraction::new(999, 1_000));
+    pwm.set_duty(1, Fraction::new(u32::MAX - 1, u32::MAX));
+
+    assert_eq!(pwm.poll(Instant::new(0)), output(0b11, 9_990_000));
+    assert_eq!(pwm.poll(Instant::new(9_990_000)), output(0b10, 9_999_999));
+    assert_eq!(pwm.poll(Instant::new(9_999_999)), output(0b00, 10_000_000));
+}
+
+#[test]
+fn invalid_period() {
+    assert_eq!(
+        SoftPwm::<Clock, 1>::new(Microseconds(10_u32), Instant::new(0)).unwrap_err(),
+        ConversionError::DivByZero
+    );
+    assert_eq!(
+        SoftPwm::<Clock, 1>::new(Milliseconds(u32::MAX), Instant::new(0)).unwrap_err(),
+        ConversionError::Overflow
+    );
+}
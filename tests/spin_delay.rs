@@ -0,0 +1,79 @@
+use embedded_time::{self as time, duration::*, spin_delay::SpinDelay, Instant, TimeError};
+use std::cell::Cell;
+
+#[derive(Debug)]
+struct Clock(Cell<u32>);
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
+#[derive(Debug)]
+struct BadClock;
+
+impl time::Clock for BadClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Err(time::clock::Error::NotRunning)
+    }
+}
+
+#[test]
+fn calibrate() {
+    let clock = Clock(Cell::new(0));
+    let calls = Cell::new(0_u64);
+
+    // each call takes 3 μs
+    let spin = || {
+        clock.0.set(clock.0.get() + 3);
+        calls.set(calls.get() + 1);
+    };
+    let mut delay = SpinDelay::calibrate(&clock, spin, Milliseconds(1_u32)).unwrap();
+
+    assert_eq!(delay.iterations_for(Milliseconds(3_u32)), Ok(1_000));
+    assert_eq!(delay.iterations_for(Seconds(3_u64)), Ok(1_000_000));
+    assert_eq!(delay.iterations_for(Microseconds(2_u32)), Ok(0));
+
+    calls.set(0);
+    delay.delay(Milliseconds(3_u32)).unwrap();
+    assert_eq!(calls.get(), 1_000);
+}
+
+#[test]
+fn clock_wrap() {
+    let clock = Clock(Cell::new(u32::MAX - 100));
+    let spin = || clock.0.set(clock.0.get().wrapping_add(1));
+    let delay = SpinDelay::calibrate(&clock, spin, Milliseconds(1_u32)).unwrap();
+
+    assert_eq!(delay.iterations_for(Milliseconds(1_u32)), Ok(1_000));
+}
+
+#[test]
+fn errors() {
+    let clock = Clock(Cell::new(0));
+
+    assert_eq!(
+        SpinDelay::calibrate(&clock, || {}, Milliseconds(0_u32)).err(),
+        Some(TimeError::DivByZero)
+    );
+
+    // the clock never advances
+    assert_eq!(
+        SpinDelay::calibrate(&clock, || {}, Milliseconds(1_u32)).err(),
+        Some(TimeError::Overflow)
+    );
+
+    assert_eq!(
+        SpinDelay::calibrate(&BadClock, || {}, Milliseconds(1_u32)).err(),
+        Some(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+}
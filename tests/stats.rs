@@ -0,0 +1,136 @@
+use embedded_time::{
+    duration::*,
+    stats::{average, Stats},
+};
+use test_case::test_case;
+
+#[test_case(&[] => None ; "empty")]
+#[test_case(&[7] => Some(7) ; "single")]
+#[test_case(&[1, 2] => Some(1) ; "truncated")]
+#[test_case(&[10, 1, 1, 1] => Some(3) ; "decreasing")]
+#[test_case(&[1, 1, 1, 10, 0, 0, 30] => Some(6) ; "mixed")]
+#[test_case(&[u32::MAX, u32::MAX, u32::MAX] => Some(u32::MAX) ; "max")]
+#[test_case(&[u32::MAX, 0, u32::MAX, 0, 1] => Some(1_717_986_918) ; "alternating")]
+fn mean(durations: &[u32]) -> Option<u32> {
+    average(durations.iter().map(|&duration| Nanoseconds(duration))).map(|mean| mean.integer())
+}
+
+#[test]
+fn matches_wide_sum() {
+    let values = (0..10_000_u64).map(|n| (n * 2_654_435_761 % 4_294_967_291) as u32);
+    let sum: u64 = values.clone().map(u64::from).sum();
+
+    let mut stats = Stats::new();
+    for (count, value) in values.enumerate() {
+        stats.add(Microseconds(value));
+        assert_eq!(stats.count(), count as u64 + 1);
+    }
+
+    assert_eq!(stats.mean(), Some(Microseconds((sum / 10_000) as u32)));
+}
+
+#[test]
+fn u128_durations() {
+    let durations = [
+        Nanoseconds(u128::MAX),
+        Nanoseconds(u128::MAX - 1),
+        Nanoseconds(1),
+    ];
+    assert_eq!(
+        average(durations.iter().copied()),
+        Some(Nanoseconds(u128::MAX / 3 * 2))
+    );
+
+    let durations = [
+        Nanoseconds(u128::MAX),
+        Nanoseconds(0),
+        Nanoseconds(u128::MAX),
+    ];
+    assert_eq!(
+        average(durations.iter().copied()),
+        Some(Nanoseconds(u128::MAX / 3 * 2))
+    );
+}
+
+#[test]
+fn narrow_durations() {
+    let stats = (0..1_000).fold(Stats::new(), |mut stats, n| {
+        stats.add(Milliseconds(200_u8 + (n % 2) as u8));
+        stats
+    });
+
+    assert_eq!(stats.mean(), Some(Milliseconds(200_u8)));
+    assert_eq!(Stats::<Milliseconds<u8>>::default().mean(), None);
+}
+
+mod time_it {
+    use core::cell::Cell;
+    use embedded_time::{self as time, duration::*, stats::Stats, time_it, Instant, TimeError};
+
+    #[derive(Debug)]
+    struct Clock(Cell<u32>);
+
+    impl time::Clock for Clock {
+        type T = u32;
+        type Error = time::clock::Error;
+        const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
+
+        fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+            let now = self.0.get();
+            if now == u32::MAX {
+                Err(time::clock::Error::NotRunning)
+            } else {
+                Ok(Instant::new(now))
+            }
+        }
+    }
+
+    #[test]
+    fn per_iteration() {
+        let clock = Clock(Cell::new(0));
+        let mut iteration = 0;
+
+        let stats: Stats<Nanoseconds<u64>> = time_it!(clock, 4, {
+            iteration += 1;
+            clock.0.set(clock.0.get() + iteration);
+        })
+        .unwrap();
+
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.mean(), Some(Nanoseconds(2_500)));
+        assert_eq!(clock.0.get(), 10);
+    }
+
+    #[test]
+    fn no_iterations() {
+        let clock = Clock(Cell::new(0));
+
+        let stats: Stats<Microseconds<u32>> = time_it!(clock, 0, {}).unwrap();
+
+        assert_eq!(stats.mean(), None);
+    }
+
+    #[test]
+    fn errors() {
+        let clock = Clock(Cell::new(0));
+        let stopped = time_it!(clock, 3, { clock.0.set(u32::MAX) });
+        assert_eq!(
+            stopped.map(|stats: Stats<Microseconds<u32>>| stats.count()),
+            Err(TimeError::Clock(time::clock::Error::NotRunning))
+        );
+
+        let clock = Clock(Cell::new(1_000));
+        let backwards = time_it!(clock, 3, { clock.0.set(0) });
+        assert_eq!(
+            backwards.map(|stats: Stats<Microseconds<u32>>| stats.count()),
+            Err(TimeError::NegDuration)
+        );
+
+        let clock = Clock(Cell::new(0));
+        let overflow = time_it!(clock, 3, { clock.0.set(1_000) });
+        assert_eq!(
+            overflow.map(|stats: Stats<Nanoseconds<u8>>| stats.count()),
+            Err(TimeError::ConversionFailure)
+        );
+    }
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "std")]
+
+use core::convert::TryFrom;
+use embedded_time::{clocks::StdClock, duration::*, Clock as _};
+
+#[test]
+fn measures_wall_clock_time() {
+    let clock = StdClock::new();
+
+    let start = clock.try_now().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    let end = clock.try_now().unwrap();
+
+    let elapsed =
+        Microseconds::<u64>::try_from(end.checked_duration_since(&start).unwrap()).unwrap();
+    assert!(elapsed >= Microseconds(10_000_u64));
+    assert!(end > start);
+}
+
+#[test]
+fn copies_share_the_epoch() {
+    let clock = StdClock::default();
+    let copy = clock;
+
+    assert_eq!(copy.epoch(), clock.epoch());
+}
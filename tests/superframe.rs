@@ -0,0 +1,122 @@
+use embedded_time::{
+    self as time,
+    duration::*,
+    superframe::{Slot, Superframe},
+    ConversionError, Instant,
+};
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn superframe(beacon: u32) -> Superframe<Clock> {
+    // 7 whole slots of 14 ms per 100 ms
+    Superframe::new(
+        Instant::new(beacon),
+        Milliseconds(100_u32),
+        Milliseconds(14_u32),
+        Milliseconds(2_u32),
+    )
+    .unwrap()
+}
+
+fn slot(open: u32) -> Slot<Clock> {
+    Slot {
+        open: Instant::new(open),
+        close: Instant::new(open.wrapping_add(14)),
+        transmit_start: Instant::new(open.wrapping_add(2)),
+        transmit_end: Instant::new(open.wrapping_add(12)),
+    }
+}
+
+#[test]
+fn slots() {
+    let superframe = superframe(1_000);
+    assert_eq!(superframe.slots(), 7);
+    assert_eq!(superframe.slot(0), Ok(slot(1_000)));
+    assert_eq!(superframe.slot(6), Ok(slot(1_084)));
+    assert_eq!(superframe.slot(7), Err(ConversionError::Overflow));
+}
+
+#[test]
+fn next_slot() {
+    let mut superframe = superframe(u32::MAX - 149);
+
+    // before the beacon
+    assert_eq!(
+        superframe.next_slot(Instant::new(u32::MAX - 200), 1),
+        Ok(slot(u32::MAX - 135))
+    );
+    // exactly at the opening
+    assert_eq!(
+        superframe.next_slot(Instant::new(u32::MAX - 135), 1),
+        Ok(slot(u32::MAX - 135))
+    );
+    // just after the opening
+    assert_eq!(
+        superframe.next_slot(Instant::new(u32::MAX - 134), 1),
+        Ok(slot(u32::MAX - 35))
+    );
+    // across the counter wrap
+    assert_eq!(superframe.next_slot(Instant::new(0), 1), Ok(slot(64)));
+    // several superframes later
+    assert_eq!(
+        superframe.next_slot(Instant::new(1_000), 0),
+        Ok(slot(1_050))
+    );
+    assert_eq!(
+        superframe.next_slot(Instant::new(1_000), 6),
+        Ok(slot(1_034))
+    );
+
+    superframe.resync(Instant::new(1_020));
+    assert_eq!(superframe.beacon(), Instant::new(1_020));
+    assert_eq!(
+        superframe.next_slot(Instant::new(1_000), 6),
+        Ok(slot(1_104))
+    );
+}
+
+#[test]
+fn construction_errors() {
+    assert_eq!(
+        Superframe::<Clock>::new(
+            Instant::new(0),
+            Seconds(u32::MAX / 1_000),
+            Milliseconds(10_u32),
+            Milliseconds(1_u32),
+        )
+        .err(),
+        Some(ConversionError::Overflow)
+    );
+    assert_eq!(
+        Superframe::<Clock>::new(
+            Instant::new(0),
+            Seconds(1_u32),
+            Microseconds(10_u32),
+            Microseconds(0_u32),
+        )
+        .err(),
+        Some(ConversionError::DivByZero)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid superframe")]
+fn guard_fills_slot() {
+    let _ = Superframe::<Clock>::new(
+        Instant::new(0),
+        Seconds(1_u32),
+        Milliseconds(10_u32),
+        Milliseconds(5_u32),
+    );
+}
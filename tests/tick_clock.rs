@@ -0,0 +1,41 @@
+use core::convert::TryFrom;
+use embedded_time::{
+    clocks::{TickClock, TickClock64},
+    duration::*,
+    Clock as _, Instant,
+};
+
+#[test]
+fn counts_ticks() {
+    let clock = TickClock::<1, 100>::new();
+    assert_eq!(clock.try_now(), Ok(Instant::new(0)));
+
+    for _ in 0..25 {
+        clock.tick();
+    }
+
+    let elapsed = clock
+        .try_now()
+        .unwrap()
+        .checked_duration_since(&Instant::new(0))
+        .unwrap();
+    assert_eq!(
+        Milliseconds::<u32>::try_from(elapsed),
+        Ok(Milliseconds(250_u32))
+    );
+}
+
+#[test]
+fn shared_with_interrupt() {
+    static CLOCK: TickClock64<1, 1_000> = TickClock64::new();
+
+    std::thread::spawn(|| {
+        for _ in 0..1_000 {
+            CLOCK.tick();
+        }
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(CLOCK.try_now(), Ok(Instant::new(1_000)));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "time")]
+
+use core::convert::TryFrom;
+use embedded_time::{duration::*, ConversionError};
+
+#[test]
+fn to_time_duration() {
+    assert_eq!(
+        time::Duration::try_from(Weeks(2_u32)),
+        Ok(time::Duration::weeks(2))
+    );
+    assert_eq!(
+        time::Duration::try_from(Nanoseconds(1_500_000_001_u64)),
+        Ok(time::Duration::new(1, 500_000_001))
+    );
+    // truncated to nanoseconds
+    assert_eq!(
+        time::Duration::try_from(Generic::new(1_u32, Fraction::new(1, 3))),
+        Ok(time::Duration::nanoseconds(333_333_333))
+    );
+
+    assert_eq!(
+        time::Duration::try_from(Weeks(u64::MAX)),
+        Err(ConversionError::Overflow)
+    );
+}
+
+#[test]
+fn from_time_duration() {
+    assert_eq!(
+        Milliseconds::<u32>::try_from(time::Duration::new(2, 345_678_901)),
+        Ok(Milliseconds(2_345_u32))
+    );
+    assert_eq!(
+        Minutes::<u32>::try_from(time::Duration::seconds(119)),
+        Ok(Minutes(1_u32))
+    );
+    assert_eq!(
+        Seconds::<u32>::try_from(time::Duration::ZERO),
+        Ok(Seconds(0_u32))
+    );
+
+    assert_eq!(
+        Seconds::<u32>::try_from(time::Duration::nanoseconds(-1)),
+        Err(ConversionError::NegDuration)
+    );
+    assert_eq!(
+        Milliseconds::<u32>::try_from(time::Duration::days(50)),
+        Err(ConversionError::ConversionFailure)
+    );
+}
+
+#[test]
+fn signed() {
+    let third = Fraction::new(1, 3);
+
+    assert_eq!(
+        time::Duration::try_from(SignedGeneric::new(-1_i32, third)),
+        Ok(time::Duration::nanoseconds(-333_333_333))
+    );
+    assert_eq!(
+        time::Duration::try_from(SignedGeneric::new(1_i32, third)),
+        Ok(time::Duration::nanoseconds(333_333_333))
+    );
+    assert_eq!(
+        time::Duration::try_from(SignedGeneric::new(i64::MIN, Fraction::new(1, 1))),
+        Ok(time::Duration::seconds(i64::MIN))
+    );
+    assert_eq!(
+        time::Duration::try_from(SignedGeneric::new(
+            i128::from(i64::MIN) - 1,
+            Fraction::new(1, 1)
+        )),
+        Err(ConversionError::Overflow)
+    );
+
+    assert_eq!(
+        SignedGeneric::<i64>::try_from(time::Duration::seconds(-2)),
+        Ok(SignedGeneric::new(
+            -2_000_000_000_i64,
+            Fraction::new(1, 1_000_000_000)
+        ))
+    );
+    assert_eq!(
+        SignedGeneric::<i32>::try_from(time::Duration::seconds(-3)),
+        Err(ConversionError::ConversionFailure)
+    );
+    assert_eq!(
+        SignedGeneric::<i128>::try_from(time::Duration::MIN),
+        Ok(SignedGeneric::new(
+            time::Duration::MIN.whole_nanoseconds(),
+            Fraction::new(1, 1_000_000_000)
+        ))
+    );
+}
@@ -0,0 +1,46 @@
+use embedded_time::{self as time, duration::*, time_slicer::TimeSlicer, ConversionError, Instant};
+use test_case::test_case;
+
+#[derive(Debug)]
+struct Clock;
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+#[test_case(100, 104 => false ; "Slice not exhausted")]
+#[test_case(100, 105 => true ; "Slice exhausted")]
+#[test_case(100, 99 => false ; "Earlier instant")]
+#[test_case(u32::MAX - 1, 2 => false ; "Clock wrap, slice not exhausted")]
+#[test_case(u32::MAX - 1, 3 => true ; "Clock wrap, slice exhausted")]
+fn should_yield(start: u32, now: u32) -> bool {
+    let mut slicer = TimeSlicer::<Clock>::new(Milliseconds(5_u32)).unwrap();
+    slicer.start_slice(Instant::new(start));
+    slicer.should_yield(Instant::new(now))
+}
+
+#[test]
+fn no_slice() {
+    let mut slicer = TimeSlicer::<Clock>::new(Seconds(1_u32)).unwrap();
+    assert!(slicer.should_yield(Instant::new(0)));
+
+    slicer.start_slice(Instant::new(0));
+    assert!(!slicer.should_yield(Instant::new(0)));
+
+    slicer.end_slice();
+    assert!(slicer.should_yield(Instant::new(0)));
+}
+
+#[test]
+fn conversion_error() {
+    assert_eq!(
+        TimeSlicer::<Clock>::new(Hours(u32::MAX)).err(),
+        Some(ConversionError::Unspecified)
+    );
+}
@@ -0,0 +1,82 @@
+use embedded_time::{duration::*, time_transfer::Exchange, ConversionError, Instant};
+
+#[derive(Debug)]
+struct Clock;
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = embedded_time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, embedded_time::clock::Error> {
+        unimplemented!()
+    }
+}
+
+fn exchange(t1: u32, t2: u32, t3: u32, t4: u32) -> Exchange<Clock> {
+    Exchange {
+        request_sent: Instant::new(t1),
+        request_received: Instant::new(t2),
+        response_sent: Instant::new(t3),
+        response_received: Instant::new(t4),
+    }
+}
+
+fn offset(millis: i64) -> Result<SignedGeneric<i64>, ConversionError> {
+    Ok(SignedGeneric::new(millis, Fraction::new(1, 1_000)))
+}
+
+fn delay(millis: u32) -> Result<Generic<u32>, ConversionError> {
+    Ok(Generic::new(millis, Fraction::new(1, 1_000)))
+}
+
+#[test]
+fn synchronized() {
+    let exchange = exchange(100, 110, 115, 125);
+
+    assert_eq!(exchange.offset(), offset(0));
+    assert_eq!(exchange.round_trip_delay(), delay(20));
+}
+
+#[test]
+fn ahead_and_behind() {
+    // remote 1 s ahead
+    assert_eq!(exchange(100, 1_110, 1_115, 125).offset(), offset(1_000));
+    // remote 1 s behind, asymmetric transit (10 ms out, 2 ms back)
+    let exchange = exchange(2_000, 1_010, 1_020, 2_022);
+    assert_eq!(exchange.offset(), offset(-996));
+    assert_eq!(exchange.round_trip_delay(), delay(12));
+}
+
+#[test]
+fn truncated() {
+    assert_eq!(exchange(0, 2, 2, 1).offset(), offset(1));
+    assert_eq!(exchange(0, 0, 0, 3).offset(), offset(-1));
+}
+
+#[test]
+fn wrapping() {
+    let exchange = exchange(u32::MAX - 10, 5_000, 5_010, 20);
+
+    assert_eq!(exchange.offset(), offset(5_000));
+    assert_eq!(exchange.round_trip_delay(), delay(21));
+}
+
+#[test]
+fn invalid() {
+    // received before it was sent
+    assert_eq!(
+        exchange(100, 110, 115, 90).round_trip_delay(),
+        Err(ConversionError::NegDuration)
+    );
+    // the remote responded before it was asked
+    assert_eq!(
+        exchange(100, 110, 105, 125).round_trip_delay(),
+        Err(ConversionError::NegDuration)
+    );
+    // the remote took longer than the whole exchange
+    assert_eq!(
+        exchange(100, 110, 150, 125).round_trip_delay(),
+        Err(ConversionError::NegDuration)
+    );
+}
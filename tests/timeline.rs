@@ -0,0 +1,109 @@
+use core::cell::Cell;
+use embedded_time::{
+    clock,
+    duration::*,
+    timeline::{Event, Timeline},
+    Instant, TimeError,
+};
+
+#[derive(Debug)]
+struct Clock(Cell<Option<u32>>);
+
+impl embedded_time::Clock for Clock {
+    type T = u32;
+    type Error = clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+        self.0
+            .get()
+            .map(Instant::new)
+            .ok_or(clock::Error::NotRunning)
+    }
+}
+
+fn codes<const N: usize>(timeline: &Timeline<Clock, N>) -> Vec<u8> {
+    timeline.iter().map(|event| event.code).collect()
+}
+
+#[test]
+fn record() {
+    let mut timeline = Timeline::<Clock, 3>::new();
+    assert!(timeline.is_empty());
+    assert_eq!(timeline.latest(), None);
+    assert_eq!(timeline.deltas().next(), None);
+
+    timeline.record(Instant::new(10), 1);
+    assert_eq!(codes(&timeline), [1]);
+    assert_eq!(timeline.deltas().next(), None);
+
+    timeline.record(Instant::new(15), 2);
+    timeline.record(Instant::new(35), 3);
+    assert_eq!(timeline.len(), 3);
+    assert_eq!(codes(&timeline), [1, 2, 3]);
+
+    // overwrites the oldest
+    timeline.record(Instant::new(100), 4);
+    timeline.record(Instant::new(101), 5);
+    assert_eq!(timeline.len(), 3);
+    assert_eq!(codes(&timeline), [3, 4, 5]);
+    assert_eq!(
+        timeline.latest(),
+        Some(Event {
+            instant: Instant::new(101),
+            code: 5
+        })
+    );
+    assert_eq!(
+        timeline.deltas().collect::<Vec<_>>(),
+        [
+            Ok(Milliseconds(65_u32).into()),
+            Ok(Milliseconds(1_u32).into())
+        ]
+    );
+
+    timeline.clear();
+    assert!(timeline.is_empty());
+    assert_eq!(timeline.len(), 0);
+}
+
+#[test]
+fn wrapping() {
+    let mut timeline = Timeline::<Clock, 4>::default();
+    timeline.record(Instant::new(u32::MAX - 1), 1);
+    timeline.record(Instant::new(3), 2);
+    timeline.record(Instant::new(1), 3);
+
+    assert_eq!(
+        timeline.deltas().collect::<Vec<_>>(),
+        [Ok(Milliseconds(5_u32).into()), Err(TimeError::NegDuration)]
+    );
+}
+
+#[test]
+fn record_now() {
+    let clock = Clock(Cell::new(Some(7)));
+    let mut timeline = Timeline::<Clock, 1>::new();
+
+    timeline.record_now(&clock, 1).unwrap();
+    assert_eq!(
+        timeline.iter().next(),
+        Some(Event {
+            instant: Instant::new(7),
+            code: 1
+        })
+    );
+
+    clock.0.set(None);
+    assert_eq!(
+        timeline.record_now(&clock, 2),
+        Err(clock::Error::NotRunning)
+    );
+    assert_eq!(codes(&timeline), [1]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid capacity")]
+fn zero_capacity() {
+    let _ = Timeline::<Clock, 0>::new();
+}
@@ -10,6 +10,7 @@ static TICKS: AtomicU64 = AtomicU64::new(0);
 struct Clock;
 impl time::Clock for Clock {
     type T = u64;
+    type Error = time::clock::Error;
     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
 
     fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
@@ -17,6 +18,21 @@ impl time::Clock for Clock {
     }
 }
 
+/// A clock of its own for the tests that mustn't see the other tests advancing `TICKS`
+#[cfg(feature = "embedded-hal")]
+#[derive(Debug)]
+struct CellClock(core::cell::Cell<u32>);
+#[cfg(feature = "embedded-hal")]
+impl time::Clock for CellClock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        Ok(Instant::new(self.0.get()))
+    }
+}
+
 #[test]
 fn oneshot_wait() {
     init_ticks();
@@ -95,6 +111,67 @@ fn periodic_expiration() {
     assert!(timer.period_complete().unwrap());
 }
 
+#[test]
+fn mode_conversion() {
+    init_ticks();
+    let clock = Clock;
+
+    let timer = clock
+        .new_timer(1_u32.seconds())
+        .into_periodic()
+        .into_oneshot()
+        .start()
+        .unwrap();
+
+    add_to_ticks(1_u32.seconds());
+
+    assert!(timer.is_expired().unwrap());
+
+    // a one-shot timer is re-armed once it has expired
+    let mut timer = timer.wait().unwrap().into_periodic().start().unwrap();
+
+    add_to_ticks(1_u32.seconds());
+
+    assert!(timer.period_complete().unwrap());
+}
+
+#[cfg(feature = "embedded-hal")]
+#[test]
+fn oneshot_try_wait() {
+    let clock = CellClock(core::cell::Cell::new(0));
+    let timer = clock.new_timer(10_u32.milliseconds()).start().unwrap();
+
+    clock.0.set(9);
+    assert_eq!(timer.try_wait(), Err(nb::Error::WouldBlock));
+
+    clock.0.set(10);
+    assert_eq!(timer.try_wait(), Ok(()));
+    // the timer isn't restarted
+    assert_eq!(timer.try_wait(), Ok(()));
+}
+
+#[cfg(feature = "embedded-hal")]
+#[test]
+fn periodic_try_wait() {
+    let clock = CellClock(core::cell::Cell::new(0));
+    let mut timer = clock
+        .new_timer(10_u32.milliseconds())
+        .into_periodic()
+        .start()
+        .unwrap();
+
+    clock.0.set(9);
+    assert_eq!(timer.try_wait(), Err(nb::Error::WouldBlock));
+
+    clock.0.set(10);
+    assert_eq!(timer.try_wait(), Ok(()));
+    // the timer is restarted
+    assert_eq!(timer.try_wait(), Err(nb::Error::WouldBlock));
+
+    clock.0.set(20);
+    assert_eq!(timer.try_wait(), Ok(()));
+}
+
 #[test]
 fn read_timer() {
     init_ticks();
@@ -125,9 +202,9 @@ fn read_timer() {
 
 fn init_ticks() {}
 
-fn add_to_ticks<Dur: Duration>(duration: Dur)
+fn add_to_ticks<Dur>(duration: Dur)
 where
-    Dur: fixed_point::FixedPoint,
+    Dur: Duration + fixed_point::FixedPoint,
     u64: From<Dur::T>,
 {
     let ticks = TICKS.load(Ordering::SeqCst);
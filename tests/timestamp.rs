@@ -0,0 +1,74 @@
+use embedded_time::{self as time, duration::*, timestamp::Timestamp, Instant, TimeError};
+use std::cell::Cell;
+
+#[derive(Debug)]
+struct Clock {
+    now: Cell<u32>,
+    running: Cell<bool>,
+}
+
+impl time::Clock for Clock {
+    type T = u32;
+    type Error = time::clock::Error;
+    const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+
+    fn try_now(&self) -> Result<Instant<Self>, time::clock::Error> {
+        if self.running.get() {
+            Ok(Instant::new(self.now.get()))
+        } else {
+            Err(time::clock::Error::NotRunning)
+        }
+    }
+}
+
+fn clock(now: u32) -> Clock {
+    Clock {
+        now: Cell::new(now),
+        running: Cell::new(true),
+    }
+}
+
+#[test]
+fn age() {
+    let clock = clock(u32::MAX - 99);
+    let stamp = Timestamp::new(&clock).unwrap();
+    assert_eq!(stamp.instant(), Instant::new(u32::MAX - 99));
+
+    assert_eq!(
+        stamp.elapsed(&clock),
+        Ok(Generic::new(0_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(stamp.age_exceeds(&clock, Milliseconds(0_u32)), Ok(false));
+
+    // across the wrap
+    clock.now.set(400);
+    assert_eq!(
+        stamp.elapsed(&clock),
+        Ok(Generic::new(500_u32, Fraction::new(1, 1_000)))
+    );
+    assert_eq!(stamp.age_exceeds(&clock, Milliseconds(500_u32)), Ok(false));
+    assert_eq!(
+        stamp.age_exceeds(&clock, Microseconds(499_999_u32)),
+        Ok(true)
+    );
+    assert_eq!(stamp.age_exceeds(&clock, Seconds(1_u64)), Ok(false));
+}
+
+#[test]
+fn errors() {
+    let clock = clock(1_000);
+    let stamp = Timestamp::from(Instant::<Clock>::new(2_000));
+
+    assert_eq!(stamp.elapsed(&clock), Err(TimeError::NegDuration));
+    assert_eq!(
+        stamp.age_exceeds(&clock, Milliseconds(5_u32)),
+        Err(TimeError::NegDuration)
+    );
+
+    clock.running.set(false);
+    assert_eq!(Timestamp::new(&clock), Err(time::clock::Error::NotRunning));
+    assert_eq!(
+        stamp.elapsed(&clock),
+        Err(TimeError::Clock(time::clock::Error::NotRunning))
+    );
+}